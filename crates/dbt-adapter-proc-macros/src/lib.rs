@@ -3,13 +3,23 @@
 
 use proc_macro::TokenStream;
 use quote::quote;
-use syn::{parse_macro_input, DeriveInput};
+use syn::{parse_macro_input, DeriveInput, Ident};
 
 /// Derive macro for minijinja Object trait for structs that implement BaseColumn
-#[proc_macro_derive(BaseColumnObject)]
+///
+/// Adapter-specific methods can be exposed with
+/// `#[base_column(extra_methods(method_a, method_b))]`.  Each listed method is
+/// dispatched as `self.method_a(args)` and must return
+/// `Result<minijinja::Value, minijinja::Error>`.
+#[proc_macro_derive(BaseColumnObject, attributes(base_column))]
 pub fn derive_base_column_object(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     let name = &input.ident;
+    let extra_methods = match parse_extra_methods(&input) {
+        Ok(extra_methods) => extra_methods,
+        Err(err) => return err.to_compile_error().into(),
+    };
+    let extra_method_names = extra_methods.iter().map(|method| method.to_string());
 
     let expanded = quote! {
 
@@ -35,6 +45,7 @@ pub fn derive_base_column_object(input: TokenStream) -> TokenStream {
                         let result = self.can_expand_to(other)?;
                         Ok(Value::from(result))
                     }
+                    #( #extra_method_names => self.#extra_methods(args), )*
                     _ => Err(minijinja::Error::new(
                         minijinja::ErrorKind::InvalidOperation,
                         format!("Unknown method on BaseColumnObject: '{}'", name),
@@ -72,6 +83,31 @@ pub fn derive_base_column_object(input: TokenStream) -> TokenStream {
     TokenStream::from(expanded)
 }
 
+/// Collects the method names listed in `#[base_column(extra_methods(...))]`.
+fn parse_extra_methods(input: &DeriveInput) -> syn::Result<Vec<Ident>> {
+    let mut methods = vec![];
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("base_column"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("extra_methods") {
+                meta.parse_nested_meta(|method| match method.path.get_ident() {
+                    Some(ident) => {
+                        methods.push(ident.clone());
+                        Ok(())
+                    }
+                    None => Err(method.error("expected a method name")),
+                })
+            } else {
+                Err(meta.error("unsupported base_column attribute, expected `extra_methods(...)`"))
+            }
+        })?;
+    }
+    Ok(methods)
+}
+
 /// Derive macro for minijinja Object trait for structs that implement StaticBaseColumn
 #[proc_macro_derive(StaticBaseColumnObject)]
 pub fn derive_static_base_column_object(input: TokenStream) -> TokenStream {
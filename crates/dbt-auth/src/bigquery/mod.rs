@@ -1,6 +1,7 @@
 use crate::AdapterConfig;
 use crate::Auth;
 use crate::AuthError;
+use crate::config::yml_value_to_string;
 use dbt_xdbc::bigquery::auth_type;
 use dbt_xdbc::{Backend, bigquery, database};
 use serde::{Deserialize, Serialize};
@@ -8,6 +9,13 @@ use std::path::Path;
 
 type YmlValue = dbt_serde_yaml::Value;
 
+/// Keys a BigQuery profile has to set, see [crate::required_keys]
+pub(crate) const REQUIRED_KEYS: &[&[&str]] = &[
+    &["method"],
+    &["project", "database"],
+    &["dataset", "schema"],
+];
+
 #[derive(Deserialize, Serialize)]
 struct KeyFileJson {
     #[serde(rename = "type")]
@@ -164,6 +172,73 @@ impl BigqueryAuth {
         }
         Ok(())
     }
+
+    /// Impersonate `impersonate_service_account`, optionally through the
+    /// service accounts of `impersonation_chain`, with the configured method
+    /// providing the source credentials.
+    fn config_impersonation(
+        config: &AdapterConfig,
+        builder: &mut database::Builder,
+    ) -> Result<(), AuthError> {
+        let Some(target) = config.get_string("impersonate_service_account") else {
+            if config.contains_key("impersonation_chain") {
+                return Err(AuthError::config(
+                    "'impersonation_chain' requires 'impersonate_service_account'",
+                ));
+            }
+            return Ok(());
+        };
+        if config.contains_key("keyfile") || config.contains_key("keyfile_json") {
+            return Err(AuthError::config(
+                "'impersonate_service_account' can't be combined with 'keyfile' or 'keyfile_json'",
+            ));
+        }
+        validate_service_account_email("impersonate_service_account", &target)?;
+        builder.with_named_option(bigquery::IMPERSONATE_TARGET_PRINCIPAL, target.as_ref())?;
+
+        let delegates = match config.get("impersonation_chain") {
+            None => vec![],
+            Some(YmlValue::Sequence(accounts, _)) => accounts
+                .iter()
+                .map(|account| yml_value_to_string(account).into_owned())
+                .collect(),
+            Some(YmlValue::String(accounts, _)) => accounts
+                .split(',')
+                .map(|account| account.trim().to_string())
+                .collect(),
+            Some(_) => {
+                return Err(AuthError::config(
+                    "'impersonation_chain' must be a list or a comma-separated string of service accounts",
+                ));
+            }
+        };
+        for delegate in &delegates {
+            validate_service_account_email("impersonation_chain", delegate)?;
+        }
+        if !delegates.is_empty() {
+            builder.with_named_option(bigquery::IMPERSONATE_DELEGATES, delegates.join(","))?;
+        }
+        Ok(())
+    }
+}
+
+/// Loosely checks that `account` looks like a service account email, the
+/// backend reports anything more specific when impersonating.
+fn validate_service_account_email(field: &str, account: &str) -> Result<(), AuthError> {
+    if account.is_empty() {
+        return Err(AuthError::config(format!("'{field}' must not be empty")));
+    }
+    let looks_like_email = account
+        .split_once('@')
+        .is_some_and(|(name, domain)| !name.is_empty() && domain.contains('.'))
+        && !account.contains(char::is_whitespace);
+    if looks_like_email {
+        Ok(())
+    } else {
+        Err(AuthError::config(format!(
+            "'{field}' must be a service account email, got '{account}'"
+        )))
+    }
 }
 
 impl Auth for BigqueryAuth {
@@ -211,6 +286,7 @@ impl Auth for BigqueryAuth {
                 "Missing required 'method' field in BigQuery config",
             ));
         }
+        Self::config_impersonation(config, &mut builder)?;
 
         Ok(builder)
     }
@@ -422,4 +498,61 @@ method: oauth
         try_configure(config)
             .expect("Expected no error when extra fields are supplied for OAuth method");
     }
+
+    #[test]
+    fn test_impersonate_service_account() {
+        let mut config = base_config_oauth();
+        config.insert(
+            "impersonate_service_account".into(),
+            "target@my-project.iam.gserviceaccount.com".into(),
+        );
+        config.insert(
+            "impersonation_chain".into(),
+            dbt_serde_yaml::from_str::<YmlValue>(
+                "[first@my-project.iam.gserviceaccount.com, second@my-project.iam.gserviceaccount.com]",
+            )
+            .unwrap(),
+        );
+        let builder = try_configure(config).unwrap();
+        assert_eq!(
+            other_option_value(&builder, bigquery::AUTH_TYPE).unwrap(),
+            auth_type::DEFAULT
+        );
+        assert_eq!(
+            other_option_value(&builder, bigquery::IMPERSONATE_TARGET_PRINCIPAL).unwrap(),
+            "target@my-project.iam.gserviceaccount.com"
+        );
+        assert_eq!(
+            other_option_value(&builder, bigquery::IMPERSONATE_DELEGATES).unwrap(),
+            "first@my-project.iam.gserviceaccount.com,second@my-project.iam.gserviceaccount.com"
+        );
+    }
+
+    #[test]
+    fn test_impersonate_service_account_validation() {
+        for account in ["", "not-an-email", "@my-project.iam.gserviceaccount.com"] {
+            let mut config = base_config_oauth();
+            config.insert("impersonate_service_account".into(), account.into());
+            let err = try_configure(config).unwrap_err();
+            assert!(
+                err.msg().contains("impersonate_service_account"),
+                "{account}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_impersonate_service_account_excludes_keyfile() {
+        let mut config = base_config_oauth();
+        config.insert(
+            "impersonate_service_account".into(),
+            "target@my-project.iam.gserviceaccount.com".into(),
+        );
+        config.insert("keyfile".into(), "some.json".into());
+        let err = try_configure(config).unwrap_err();
+        assert_eq!(
+            err.msg(),
+            "'impersonate_service_account' can't be combined with 'keyfile' or 'keyfile_json'"
+        );
+    }
 }
@@ -2,8 +2,16 @@ use crate::{AdapterConfig, Auth, AuthError};
 
 use dbt_xdbc::{Backend, database, databricks};
 
+use std::borrow::Cow;
+
 pub struct DatabricksAuth;
 
+/// Keys a Databricks profile has to set, see [crate::required_keys]
+pub(crate) const REQUIRED_KEYS: &[&[&str]] = &[&["host"], &["http_path"], &["schema"]];
+
+/// `method` selecting machine-to-machine OAuth with a client id and secret
+const OAUTH_M2M_METHOD: &str = "oauth_m2m";
+
 impl Auth for DatabricksAuth {
     fn backend(&self) -> Backend {
         #[cfg(feature = "odbc")]
@@ -36,14 +44,27 @@ impl Auth for DatabricksAuth {
                 }
             }
 
-            // configures the ODBC driver and the defaults needed for token authentication
             builder
-                .with_username(odbc::DEFAULT_TOKEN_UID)
                 .with_named_option(odbc::DRIVER, odbc::odbc_driver_path())?
                 .with_named_option(odbc::PORT, odbc::DEFAULT_PORT)?
                 .with_named_option(odbc::SSL, "1")?
-                .with_named_option(odbc::THRIFT_TRANSPORT, "2")?
-                .with_named_option(odbc::AUTH_MECHANISM, odbc::auth_mechanism_options::TOKEN)?;
+                .with_named_option(odbc::THRIFT_TRANSPORT, "2")?;
+            if is_oauth_m2m(config) {
+                let (client_id, client_secret) = oauth_m2m_credentials(config)?;
+                builder
+                    .with_named_option(odbc::AUTH_MECHANISM, odbc::auth_mechanism_options::OAUTH)?
+                    .with_named_option(
+                        odbc::AUTH_FLOW,
+                        odbc::auth_flow_options::CLIENT_CREDENTIALS,
+                    )?
+                    .with_named_option(odbc::AUTH_CLIENT_ID, client_id)?
+                    .with_named_option(odbc::AUTH_CLIENT_SECRET, client_secret)?;
+            } else {
+                // the defaults needed for token authentication
+                builder
+                    .with_username(odbc::DEFAULT_TOKEN_UID)
+                    .with_named_option(odbc::AUTH_MECHANISM, odbc::auth_mechanism_options::TOKEN)?;
+            }
         } else {
             validate_config(config)?;
             // all of the following options are required for any Databricks connection
@@ -84,7 +105,7 @@ impl Auth for DatabricksAuth {
                 )?;
             }
             // External Browser Oauth - U2M Oauth
-            else if !config.contains_key("client_secret") {
+            else if !config.contains_key("client_secret") && !is_oauth_m2m(config) {
                 if let Some(client_id) = config.get_string("client_id") {
                     builder.with_named_option(databricks::CLIENT_ID, client_id)?;
                 }
@@ -93,13 +114,11 @@ impl Auth for DatabricksAuth {
                     databricks::auth_type::EXTERNAL_BROWSER,
                 )?;
             }
-            // M2M Oauth
-            else if let Some(client_id) = config.get_string("client_id") {
+            // M2M Oauth, also selected explicitly with `method: oauth_m2m`
+            else if is_oauth_m2m(config) || config.contains_key("client_id") {
+                let (client_id, client_secret) = oauth_m2m_credentials(config)?;
                 builder.with_named_option(databricks::CLIENT_ID, client_id)?;
-                builder.with_named_option(
-                    databricks::CLIENT_SECRET,
-                    config.require_string("client_secret")?,
-                )?;
+                builder.with_named_option(databricks::CLIENT_SECRET, client_secret)?;
                 builder
                     .with_named_option(databricks::AUTH_TYPE, databricks::auth_type::OAUTH_M2M)?;
             } else {
@@ -110,6 +129,27 @@ impl Auth for DatabricksAuth {
     }
 }
 
+fn is_oauth_m2m(config: &AdapterConfig) -> bool {
+    config
+        .get_string("method")
+        .is_some_and(|method| method == OAUTH_M2M_METHOD)
+}
+
+/// The client id and secret of an `oauth_m2m` profile, which must both be set
+fn oauth_m2m_credentials(
+    config: &AdapterConfig,
+) -> Result<(Cow<'_, str>, Cow<'_, str>), AuthError> {
+    match (
+        config.get_string("client_id"),
+        config.get_string("client_secret"),
+    ) {
+        (Some(client_id), Some(client_secret)) => Ok((client_id, client_secret)),
+        _ => Err(AuthError::config(
+            "The configs 'client_id' and 'client_secret' are both required for `method: oauth_m2m`",
+        )),
+    }
+}
+
 fn validate_config(config: &AdapterConfig) -> Result<(), AuthError> {
     if !config.contains_key("http_path") {
         return Err(AuthError::config("http_path is required"));
@@ -121,7 +161,7 @@ fn validate_config(config: &AdapterConfig) -> Result<(), AuthError> {
         .get("auth_type")
         .map(|v| v == "oauth")
         .unwrap_or(false);
-    if !config.contains_key("token") && !is_oauth {
+    if !config.contains_key("token") && !is_oauth && !is_oauth_m2m(config) {
         return Err(AuthError::config(
             "The config `auth_type: oauth` is required when not using access token",
         ));
@@ -288,6 +328,53 @@ mod tests {
         run_config_test(config, &expected).unwrap();
     }
 
+    #[test]
+    fn test_m2m_oauth_method() {
+        let config = Mapping::from_iter([
+            ("host".into(), "H".into()),
+            ("schema".into(), "S".into()),
+            (
+                "http_path".into(),
+                "/sql/1.0/warehouses/warehouse-id".into(),
+            ),
+            ("method".into(), "oauth_m2m".into()),
+            ("client_id".into(), "I".into()),
+            ("client_secret".into(), "X".into()),
+            ("database".into(), "C".into()),
+        ]);
+
+        let expected = vec![
+            (databricks::CLIENT_ID, "I"),
+            (databricks::CLIENT_SECRET, "X"),
+            (databricks::SCHEMA, "S"),
+            (databricks::HOST, "H"),
+            (databricks::WAREHOUSE, "warehouse-id"),
+            (databricks::CATALOG, "C"),
+            (databricks::AUTH_TYPE, databricks::auth_type::OAUTH_M2M),
+        ];
+        run_config_test(config, &expected).unwrap();
+    }
+
+    #[test]
+    fn test_m2m_oauth_method_requires_client_secret() {
+        let config = Mapping::from_iter([
+            ("host".into(), "H".into()),
+            ("schema".into(), "S".into()),
+            (
+                "http_path".into(),
+                "/sql/1.0/warehouses/warehouse-id".into(),
+            ),
+            ("method".into(), "oauth_m2m".into()),
+            ("client_id".into(), "I".into()),
+            ("database".into(), "C".into()),
+        ]);
+        let result = run_config_test(config, &[]);
+        assert_eq!(
+            result.unwrap_err().msg(),
+            "The configs 'client_id' and 'client_secret' are both required for `method: oauth_m2m`"
+        );
+    }
+
     #[test]
     fn test_external_browser_oauth() {
         let config = Mapping::from_iter([
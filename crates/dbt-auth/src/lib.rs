@@ -24,6 +24,15 @@ pub trait Auth: Send + Sync {
 
     /// Configure the XDBC database builder.
     fn configure(&self, config: &AdapterConfig) -> Result<database::Builder, AuthError>;
+
+    /// Check that `config` holds usable credentials, e.g. for `dbt debug`.
+    ///
+    /// By default this only runs [Auth::configure] and discards the builder,
+    /// so it reports configuration errors without connecting. Backends can
+    /// override it with a lightweight connectivity probe.
+    fn validate(&self, config: &AdapterConfig) -> Result<(), AuthError> {
+        self.configure(config).map(|_| ())
+    }
 }
 
 /// Factory function to create an Auth instance based on the backend type.
@@ -39,6 +48,22 @@ pub fn auth_for_backend(backend: Backend) -> Box<dyn Auth> {
     }
 }
 
+/// Keys a profile has to set for the given backend to be able to connect.
+///
+/// Every entry lists alternative spellings of the same key (e.g. `dataset`
+/// and `schema` on BigQuery), any one of which satisfies it.
+pub fn required_keys(backend: Backend) -> &'static [&'static [&'static str]] {
+    match backend {
+        Backend::Snowflake => snowflake::REQUIRED_KEYS,
+        Backend::Postgres => postgres::REQUIRED_KEYS,
+        Backend::BigQuery => bigquery::REQUIRED_KEYS,
+        Backend::Databricks | Backend::DatabricksODBC => databricks::REQUIRED_KEYS,
+        Backend::Redshift | Backend::RedshiftODBC => redshift::REQUIRED_KEYS,
+        Backend::Salesforce => salesforce::REQUIRED_KEYS,
+        Backend::Generic { .. } => &[],
+    }
+}
+
 /// Error type for [dbt_auth].
 ///
 /// For display purposes, it must be converted into an [AdapterError] first, outside of this crate.
@@ -100,3 +125,28 @@ impl From<dbt_serde_yaml::Error> for AuthError {
         AuthError::YAML(err)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dbt_serde_yaml::Mapping;
+
+    #[test]
+    fn test_default_validate_surfaces_configure_errors() {
+        let auth = auth_for_backend(Backend::Snowflake);
+        let mut config = Mapping::from_iter([
+            ("user".into(), "U".into()),
+            ("password".into(), "P".into()),
+            ("account".into(), "A".into()),
+            ("role".into(), "role".into()),
+            ("warehouse".into(), "warehouse".into()),
+        ]);
+        assert!(auth.validate(&AdapterConfig::new(config.clone())).is_ok());
+
+        config.insert("connect_timeout".into(), "soon".into());
+        match auth.validate(&AdapterConfig::new(config)) {
+            Err(AuthError::Config(msg)) => assert!(msg.contains("connect_timeout"), "{msg}"),
+            other => panic!("expected a config error, got {other:?}"),
+        }
+    }
+}
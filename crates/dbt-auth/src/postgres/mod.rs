@@ -4,6 +4,15 @@ use dbt_xdbc::{Backend, database};
 
 pub struct PostgresAuth;
 
+/// Keys a Postgres profile has to set, see [crate::required_keys]
+pub(crate) const REQUIRED_KEYS: &[&[&str]] = &[
+    &["user"],
+    &["password"],
+    &["host"],
+    &["port"],
+    &["database", "dbname"],
+];
+
 impl Auth for PostgresAuth {
     fn backend(&self) -> Backend {
         Backend::Postgres
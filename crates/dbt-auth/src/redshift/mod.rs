@@ -12,6 +12,14 @@ use percent_encoding::utf8_percent_encode;
 
 pub struct RedshiftAuth;
 
+/// Keys a Redshift profile has to set, see [crate::required_keys]
+pub(crate) const REQUIRED_KEYS: &[&[&str]] = &[
+    &["host"],
+    &["port"],
+    &["database", "dbname"],
+    &["user", "db_user"],
+];
+
 impl Auth for RedshiftAuth {
     fn backend(&self) -> Backend {
         #[cfg(feature = "odbc")]
@@ -65,8 +73,6 @@ impl Auth for RedshiftAuth {
             let port = utf8_percent_encode(&port, SET).to_string();
             let dbname = utf8_percent_encode(&dbname, SET).to_string();
 
-            // ignored for serverless, but still required
-            let user = config.require_string("user")?;
             match method {
                 "database" => {
                     for key in ["iam_profile", "cluster_id"].iter() {
@@ -79,6 +85,7 @@ impl Auth for RedshiftAuth {
 
                     builder.with_named_option(CLUSTER_TYPE, REDSHIFT)?;
 
+                    let user = config.require_string("user")?;
                     let password = config.require_string("password")?;
 
                     let user = utf8_percent_encode(&user, SET).to_string();
@@ -89,6 +96,14 @@ impl Auth for RedshiftAuth {
                     builder.with_parse_uri(connection_str)?;
                 }
                 "iam" => {
+                    let require_iam = |key: &str| {
+                        config.get_string(key).ok_or_else(|| {
+                            AuthError::config(format!(
+                                "Field '{key}' is required when 'method' is set to 'iam'"
+                            ))
+                        })
+                    };
+
                     // XXX: We can only tell serverless vs cluster from the host input
                     let is_serverless = host.contains("redshift-serverless");
 
@@ -97,16 +112,27 @@ impl Auth for RedshiftAuth {
                         builder.with_named_option(CLUSTER_TYPE, SERVERLESS)?;
                     } else {
                         builder.with_named_option(CLUSTER_TYPE, REDSHIFT)?;
-                        let cluster_id = config.require_string("cluster_id")?;
+                        let cluster_id = require_iam("cluster_id")?;
                         builder.with_named_option(CLUSTER_IDENTIFIER, cluster_id)?;
                     }
 
-                    let region = config.require_string("region")?;
-                    let iam_profile = config.require_string("iam_profile")?;
+                    let region = require_iam("region")?;
+                    // The database user to request temporary credentials for,
+                    // ignored for serverless. Falls back to the profile's `user`.
+                    let db_user = match config
+                        .get_string("db_user")
+                        .or_else(|| config.get_string("user"))
+                    {
+                        Some(db_user) => db_user,
+                        None => require_iam("db_user")?,
+                    };
 
                     builder.with_named_option(AWS_REGION, region)?;
-                    builder.with_named_option(AWS_PROFILE, iam_profile)?;
-                    builder.with_named_option(ADBC_OPTION_USERNAME, user)?;
+                    // Without a profile, the default AWS credential chain is used
+                    if let Some(iam_profile) = config.get_string("iam_profile") {
+                        builder.with_named_option(AWS_PROFILE, iam_profile)?;
+                    }
+                    builder.with_named_option(ADBC_OPTION_USERNAME, db_user)?;
 
                     let connection_str = format!("postgresql://{host}:{port}/{dbname}");
                     builder.with_parse_uri(connection_str)?;
@@ -123,7 +149,140 @@ impl Auth for RedshiftAuth {
     }
 }
 
-// todo: add auth tests for Redshift ADBC driver
+#[cfg(not(feature = "odbc"))]
+#[cfg(test)]
+mod adbc_tests {
+    use super::*;
+    use adbc_core::options::{OptionDatabase, OptionValue};
+    use dbt_serde_yaml::Mapping;
+
+    fn str_value(value: &OptionValue) -> &str {
+        match value {
+            OptionValue::String(s) => s.as_str(),
+            _ => panic!("unexpected value"),
+        }
+    }
+
+    // Build a base configuration common to all IAM tests.
+    fn base_iam_config() -> Mapping {
+        Mapping::from_iter([
+            ("method".into(), "iam".into()),
+            (
+                "host".into(),
+                "my-cluster.abc123.us-east-1.redshift.amazonaws.com".into(),
+            ),
+            ("port".into(), "5439".into()),
+            ("database".into(), "dev".into()),
+            ("cluster_id".into(), "my-cluster".into()),
+            ("region".into(), "us-east-1".into()),
+            ("db_user".into(), "analyst".into()),
+        ])
+    }
+
+    fn run_config_test(config: Mapping, expected: &[(&str, &str)]) {
+        let auth = RedshiftAuth {};
+        let builder = auth
+            .configure(&AdapterConfig::new(config))
+            .expect("configure");
+
+        let mut results = Mapping::default();
+        for (k, v) in builder.into_iter() {
+            let key = match k {
+                OptionDatabase::Uri => {
+                    // Credentials are never embedded in the URI for IAM
+                    let uri = str_value(&v);
+                    assert!(
+                        uri.starts_with(
+                            "postgresql://my-cluster.abc123.us-east-1.redshift.amazonaws.com:5439/dev"
+                        ),
+                        "{uri}"
+                    );
+                    assert!(!uri.contains("password"), "{uri}");
+                    continue;
+                }
+                OptionDatabase::Other(name) => name.to_owned(),
+                _ => continue,
+            };
+            results.insert(key.into(), str_value(&v).into());
+        }
+
+        for &(key, expected_val) in expected {
+            assert_eq!(
+                results
+                    .get(key)
+                    .unwrap_or_else(|| panic!("Missing key: {key}")),
+                &expected_val,
+                "Value mismatch for key: {key}"
+            );
+        }
+        assert_eq!(
+            results.len(),
+            expected.len(),
+            "Unexpected extra keys:
+    left: {results:?}
+    right: {expected:?}",
+        );
+    }
+
+    fn config_error(config: Mapping) -> String {
+        let auth = RedshiftAuth {};
+        match auth.configure(&AdapterConfig::new(config)) {
+            Ok(_) => panic!("expected a configuration error"),
+            Err(err) => err.msg().to_string(),
+        }
+    }
+
+    #[test]
+    fn test_iam_auth() {
+        let expected = [
+            (CLUSTER_TYPE, REDSHIFT),
+            (CLUSTER_IDENTIFIER, "my-cluster"),
+            (AWS_REGION, "us-east-1"),
+            (ADBC_OPTION_USERNAME, "analyst"),
+        ];
+        run_config_test(base_iam_config(), &expected);
+    }
+
+    #[test]
+    fn test_iam_auth_with_profile() {
+        let mut config = base_iam_config();
+        config.insert("iam_profile".into(), "analytics".into());
+        let expected = [
+            (CLUSTER_TYPE, REDSHIFT),
+            (CLUSTER_IDENTIFIER, "my-cluster"),
+            (AWS_REGION, "us-east-1"),
+            (AWS_PROFILE, "analytics"),
+            (ADBC_OPTION_USERNAME, "analyst"),
+        ];
+        run_config_test(config, &expected);
+    }
+
+    #[test]
+    fn test_iam_auth_missing_fields() {
+        let mut config = base_iam_config();
+        config.remove("region");
+        assert_eq!(
+            config_error(config),
+            "Field 'region' is required when 'method' is set to 'iam'"
+        );
+
+        let mut config = base_iam_config();
+        config.remove("cluster_id");
+        assert_eq!(
+            config_error(config),
+            "Field 'cluster_id' is required when 'method' is set to 'iam'"
+        );
+
+        let mut config = base_iam_config();
+        config.remove("db_user");
+        config.remove("user");
+        assert_eq!(
+            config_error(config),
+            "Field 'db_user' is required when 'method' is set to 'iam'"
+        );
+    }
+}
+
 #[cfg(feature = "odbc")]
 #[cfg(test)]
 mod tests {
@@ -8,6 +8,10 @@ use dbt_xdbc::{Backend, database, salesforce};
 
 pub struct SalesforceAuth;
 
+/// Keys a Salesforce profile has to set, see [crate::required_keys]
+pub(crate) const REQUIRED_KEYS: &[&[&str]] =
+    &[&["method"], &["username"], &["client_id"], &["login_url"]];
+
 /// Salesforce authentication methods
 enum AuthMethod {
     /// JWT Bearer authentication for Salesforce
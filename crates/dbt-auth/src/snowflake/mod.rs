@@ -1,24 +1,54 @@
 mod key_format;
 
+use crate::config::{YmlValue, yml_value_to_string};
 use crate::{AdapterConfig, Auth, AuthError};
 use database::Builder as DatabaseBuilder;
 use dbt_xdbc::database::LogLevel;
+use dbt_xdbc::duration::format_go_duration;
 use dbt_xdbc::{Backend, database, snowflake};
 
 use std::borrow::Cow;
+use std::collections::BTreeMap;
 use std::fs;
+use std::time::Duration;
 
 const APP_NAME: &str = "dbt";
 
 // WARNING: Still needs adjustment on what is considered must-have
 const REQUIRED_PARAMS: [&str; 5] = ["user", "password", "account", "role", "warehouse"];
 
+/// Keys a Snowflake profile has to set, see [crate::required_keys]
+pub(crate) const REQUIRED_KEYS: &[&[&str]] = &[
+    &["account"],
+    &["user"],
+    &["database"],
+    &["warehouse"],
+    &["schema"],
+    &[
+        "password",
+        "private_key",
+        "private_key_path",
+        "authenticator",
+    ],
+];
+
 const DEFAULT_CONNECT_TIMEOUT: &str = "10s";
 
-/// dbt Core expects durations in seconds only so this utility appends that s
-/// https://pkg.go.dev/time#ParseDuration for permitted units
-fn postfix_seconds_unit(value: &str) -> String {
-    format!("{value}s")
+/// dbt Core expects `connect_timeout` in seconds, the driver a Go duration
+fn login_timeout(config: &AdapterConfig) -> Result<String, AuthError> {
+    let Some(value) = config.get_string("connect_timeout") else {
+        return Ok(DEFAULT_CONNECT_TIMEOUT.to_string());
+    };
+    let seconds = value
+        .parse::<f64>()
+        .ok()
+        .and_then(|seconds| Duration::try_from_secs_f64(seconds).ok())
+        .ok_or_else(|| {
+            AuthError::config(format!(
+                "`connect_timeout` must be a non-negative number of seconds, got '{value}'"
+            ))
+        })?;
+    Ok(format_go_duration(seconds))
 }
 
 trait ConfigureBuilder {
@@ -284,6 +314,52 @@ impl AuthMethod {
     }
 }
 
+/// Collect the Snowflake session parameters from the `session_parameters`
+/// mapping and the top-level `query_tag` of the profile.
+///
+/// Session parameters are passed to the driver under their lowercase name,
+/// which the driver forwards to the session. See
+/// [snowflake::QUERY_TAG_PARAM_KEY] for the precedence of the query tags.
+fn session_parameters(config: &AdapterConfig) -> Result<BTreeMap<String, String>, AuthError> {
+    let mut params = BTreeMap::new();
+    match config.get("session_parameters") {
+        None | Some(YmlValue::Null(_)) => {}
+        Some(YmlValue::Mapping(mapping, _)) => {
+            for (key, value) in mapping {
+                let key = yml_value_to_string(key).to_lowercase();
+                if key.starts_with("adbc.") {
+                    return Err(AuthError::config(format!(
+                        "'{key}' in session_parameters is not a Snowflake session parameter."
+                    )));
+                }
+                let value = match value {
+                    YmlValue::String(..) | YmlValue::Number(..) | YmlValue::Bool(..) => {
+                        yml_value_to_string(value).into_owned()
+                    }
+                    _ => {
+                        return Err(AuthError::config(format!(
+                            "Value of session parameter '{key}' must be a string, number or boolean."
+                        )));
+                    }
+                };
+                params.insert(key, value);
+            }
+        }
+        Some(_) => {
+            return Err(AuthError::config(
+                "Field session_parameters must be a mapping of parameter names to values.",
+            ));
+        }
+    }
+    if let Some(query_tag) = config.get_string(snowflake::QUERY_TAG_PARAM_KEY) {
+        params.insert(
+            snowflake::QUERY_TAG_PARAM_KEY.to_string(),
+            query_tag.into_owned(),
+        );
+    }
+    Ok(params)
+}
+
 pub struct SnowflakeAuth;
 
 impl SnowflakeAuth {
@@ -328,12 +404,7 @@ impl SnowflakeAuth {
             )?;
         }
 
-        let connect_timeout_duration = config
-            .get_string("connect_timeout")
-            .as_deref()
-            .map(postfix_seconds_unit)
-            .unwrap_or_else(|| DEFAULT_CONNECT_TIMEOUT.to_string());
-        builder.with_named_option(snowflake::LOGIN_TIMEOUT, connect_timeout_duration)?;
+        builder.with_named_option(snowflake::LOGIN_TIMEOUT, login_timeout(config)?)?;
 
         AuthMethod::new(config, &method)?.configure(builder)
     }
@@ -473,12 +544,7 @@ impl SnowflakeAuth {
             ));
         }
 
-        let connect_timeout_duration = config
-            .get_string("connect_timeout")
-            .as_deref()
-            .map(postfix_seconds_unit)
-            .unwrap_or_else(|| DEFAULT_CONNECT_TIMEOUT.to_string());
-        builder.with_named_option(snowflake::LOGIN_TIMEOUT, connect_timeout_duration)?;
+        builder.with_named_option(snowflake::LOGIN_TIMEOUT, login_timeout(config)?)?;
 
         builder.with_named_option(snowflake::APPLICATION_NAME, "dbt")?;
         Ok(builder)
@@ -502,6 +568,9 @@ impl Auth for SnowflakeAuth {
                 // V1 compatible
             }
         }?;
+        for (key, value) in session_parameters(config)? {
+            builder.with_named_option(key, value)?;
+        }
         // disable any logging from Gosnowflake that's not a fatal/panic
         builder.with_named_option(snowflake::LOG_TRACING, LogLevel::Fatal.to_string())?;
         Ok(builder)
@@ -610,6 +679,80 @@ mod tests {
         run_config_test(config, &expected);
     }
 
+    #[test]
+    fn test_session_parameters_and_query_tag() {
+        let mut config = base_config();
+        config.insert(
+            "session_parameters".into(),
+            YmlValue::mapping(Mapping::from_iter([
+                ("TIMEZONE".into(), "UTC".into()),
+                (
+                    "STATEMENT_TIMEOUT_IN_SECONDS".into(),
+                    YmlValue::number(3600i64.into()),
+                ),
+                ("QUERY_TAG".into(), "from_session_parameters".into()),
+            ])),
+        );
+        config.insert("query_tag".into(), "nightly".into());
+        let expected = [
+            ("user", "U"),
+            ("password", "P"),
+            (snowflake::ACCOUNT, "A"),
+            (snowflake::ROLE, "role"),
+            (snowflake::WAREHOUSE, "warehouse"),
+            (snowflake::APPLICATION_NAME, APP_NAME),
+            (snowflake::LOG_TRACING, "fatal"),
+            (snowflake::LOGIN_TIMEOUT, DEFAULT_CONNECT_TIMEOUT),
+            ("timezone", "UTC"),
+            ("statement_timeout_in_seconds", "3600"),
+            (snowflake::QUERY_TAG_PARAM_KEY, "nightly"),
+        ];
+        run_config_test(config, &expected);
+    }
+
+    #[test]
+    fn test_session_parameters_query_tag_without_top_level_query_tag() {
+        let mut config = base_config();
+        config.insert(
+            "session_parameters".into(),
+            YmlValue::mapping(Mapping::from_iter([(
+                "QUERY_TAG".into(),
+                "from_session_parameters".into(),
+            )])),
+        );
+        let expected = [
+            ("user", "U"),
+            ("password", "P"),
+            (snowflake::ACCOUNT, "A"),
+            (snowflake::ROLE, "role"),
+            (snowflake::WAREHOUSE, "warehouse"),
+            (snowflake::APPLICATION_NAME, APP_NAME),
+            (snowflake::LOG_TRACING, "fatal"),
+            (snowflake::LOGIN_TIMEOUT, DEFAULT_CONNECT_TIMEOUT),
+            (snowflake::QUERY_TAG_PARAM_KEY, "from_session_parameters"),
+        ];
+        run_config_test(config, &expected);
+    }
+
+    #[test]
+    fn test_session_parameters_reject_nested_values() {
+        let mut config = base_config();
+        config.insert(
+            "session_parameters".into(),
+            YmlValue::mapping(Mapping::from_iter([(
+                "TIMEZONE".into(),
+                YmlValue::sequence(vec!["UTC".into()]),
+            )])),
+        );
+        let Err(err) = SnowflakeAuth {}.configure(&AdapterConfig::new(config)) else {
+            panic!("expected a configuration error");
+        };
+        assert_eq!(
+            err.msg(),
+            "Value of session parameter 'timezone' must be a string, number or boolean."
+        );
+    }
+
     #[test]
     fn test_simple_pass_with_custom_connect_timeout_a() {
         let mut config = base_config();
@@ -622,7 +765,7 @@ mod tests {
             (snowflake::WAREHOUSE, "warehouse"),
             (snowflake::APPLICATION_NAME, APP_NAME),
             (snowflake::LOG_TRACING, "fatal"),
-            (snowflake::LOGIN_TIMEOUT, "100s"),
+            (snowflake::LOGIN_TIMEOUT, "1m40s"),
         ];
         run_config_test(config, &expected);
     }
@@ -35,6 +35,26 @@ pub enum Cancellable<E> {
     Error(E),
 }
 
+impl<E> Cancellable<E> {
+    /// Maps the error payload with `f`, leaving `Cancelled` untouched.
+    /// エラーのペイロードを `f` で変換します。`Cancelled` はそのまま残ります。
+    pub fn map_err<F>(self, f: impl FnOnce(E) -> F) -> Cancellable<F> {
+        match self {
+            Cancellable::Cancelled => Cancellable::Cancelled,
+            Cancellable::Error(e) => Cancellable::Error(f(e)),
+        }
+    }
+
+    /// Converts from `&Cancellable<E>` to `Cancellable<&E>`.
+    /// `&Cancellable<E>` を `Cancellable<&E>` に変換します。
+    pub fn as_ref(&self) -> Cancellable<&E> {
+        match self {
+            Cancellable::Cancelled => Cancellable::Cancelled,
+            Cancellable::Error(e) => Cancellable::Error(e),
+        }
+    }
+}
+
 impl<E: From<CancelledError>> Cancellable<E> {
     /// Flatten `Cancellable<E>` into `E`.
     ///
@@ -219,6 +239,32 @@ impl CancellationTokenLike for CancellationTokenWithFlagImpl {
     }
 }
 
+/// Implementation used by [CancellationToken::either()].
+#[derive(Debug)]
+struct EitherCancellationTokenImpl {
+    first: Box<dyn CancellationTokenLike>,
+    second: Box<dyn CancellationTokenLike>,
+}
+
+impl Clone for EitherCancellationTokenImpl {
+    fn clone(&self) -> Self {
+        EitherCancellationTokenImpl {
+            first: self.first.clone_box(),
+            second: self.second.clone_box(),
+        }
+    }
+}
+
+impl CancellationTokenLike for EitherCancellationTokenImpl {
+    fn is_cancelled(&self) -> bool {
+        self.first.is_cancelled() || self.second.is_cancelled()
+    }
+
+    fn clone_box(&self) -> Box<dyn CancellationTokenLike> {
+        Box::new(self.clone())
+    }
+}
+
 /// A cancellation token that can be used to check for cancellation requests.
 ///
 /// A token is created from a [CancellationTokenSource] and can be used to
@@ -313,6 +359,23 @@ impl CancellationToken {
         });
         CancellationToken { inner }
     }
+
+    /// Combines this cancellation token with another one.
+    ///
+    /// The resulting token is cancelled as soon as either of the two tokens
+    /// is cancelled, e.g. a per-request token and a global shutdown token.
+    /// 
+    /// このキャンセル トークンを別のトークンと組み合わせます。
+    /// 
+    /// 結果のトークンは、2 つのトークンのいずれかがキャンセルされるとすぐに
+    /// キャンセルされます (例: リクエストごとのトークンとグローバル シャットダウン トークン)。
+    pub fn either(self, other: CancellationToken) -> CancellationToken {
+        let inner = Box::new(EitherCancellationTokenImpl {
+            first: self.inner,
+            second: other.inner,
+        });
+        CancellationToken { inner }
+    }
 }
 
 /// Creates a cancellation token that never cancels.
@@ -412,6 +475,32 @@ mod tests {
         assert!(new_token.is_cancelled());
     }
 
+    #[test]
+    fn test_either() {
+        let request_cts = CancellationTokenSource::new();
+        let shutdown_cts = CancellationTokenSource::new();
+
+        let token = request_cts.token().either(shutdown_cts.token());
+        let cloned = token.clone();
+        assert!(!token.is_cancelled());
+        assert!(!cloned.is_cancelled());
+
+        request_cts.cancel();
+        assert!(token.is_cancelled());
+        assert!(cloned.is_cancelled());
+
+        let token = request_cts.token().either(shutdown_cts.token());
+        let cloned = token.clone();
+        assert!(!token.is_cancelled());
+
+        shutdown_cts.cancel();
+        assert!(token.is_cancelled());
+        assert!(cloned.is_cancelled());
+
+        // a fresh request token is not affected by the other source
+        assert!(!request_cts.token().is_cancelled());
+    }
+
     #[test]
     fn test_thread_safety() {
         let cts = CancellationTokenSource::new();
@@ -475,6 +564,30 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_cancellable_map_err() {
+        let cancelled: Cancellable<MyError> = Cancellable::Cancelled;
+        assert!(matches!(
+            cancelled.map_err(|e| e.to_string()),
+            Cancellable::Cancelled
+        ));
+
+        let err = Cancellable::Error(MyError::new());
+        match err.as_ref().map_err(|e| e.to_string()) {
+            Cancellable::Error(msg) => assert_eq!(msg, "MyError: cancelled=false"),
+            Cancellable::Cancelled => panic!("expected an error"),
+        }
+        // as_ref() leaves the original in place
+        assert!(matches!(
+            err,
+            Cancellable::Error(MyError { cancelled: false })
+        ));
+        assert!(matches!(
+            Cancellable::<MyError>::Cancelled.as_ref(),
+            Cancellable::Cancelled
+        ));
+    }
+
     fn long_cancellable_task(token: &CancellationToken) -> Result<(), Cancellable<MyError>> {
         // ...some work...
         token.check_cancellation()?; // check before proceeding
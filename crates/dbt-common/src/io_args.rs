@@ -38,7 +38,13 @@ pub struct IoArgs {
     pub out_dir: PathBuf,
     pub log_path: Option<PathBuf>,
     pub otm_file_name: Option<String>,
+    /// Size after which the `otm_file_name` output continues in a new file, see
+    /// [crate::tracing::FsTraceConfig::with_otm_file_max_bytes]
+    pub otm_file_max_bytes: Option<u64>,
     pub otm_parquet_file_name: Option<String>,
+    /// Share of non-severe spans kept in the telemetry files, see
+    /// [crate::tracing::FsTraceConfig::with_trace_sampling]
+    pub trace_sample_ratio: Option<f64>,
     pub export_to_otlp: bool,
     pub log_format: LogFormat,
     pub log_level: Option<LevelFilter>,
@@ -290,6 +296,14 @@ impl fmt::Debug for EvalArgs {
     }
 }
 
+/// Returns true if execution should stop after `phase`: either the requested
+/// target phase has been reached, or errors were recorded for this invocation.
+/// This is the decision behind `checkpoint_maybe_exit!`.
+pub fn should_exit_after(phase: Phases, arg: &EvalArgs) -> bool {
+    arg.phase <= phase
+        || crate::error_counter::get_error_counter(arg.io.invocation_id.to_string().as_str()) > 0
+}
+
 impl EvalArgs {
     // todo: switch to using a builder pattern that doesn't clone...
     pub fn with_target(&self, target: String) -> Self {
@@ -754,6 +768,32 @@ pub fn validate_project_name(name: &str) -> Result<String, String> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_should_exit_after_debug_when_target_is_debug() {
+        let arg = EvalArgs {
+            phase: Phases::Debug,
+            io: IoArgs {
+                invocation_id: uuid::Uuid::new_v4(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        assert!(should_exit_after(Phases::Debug, &arg));
+    }
+
+    #[test]
+    fn test_should_exit_after_debug_continues_otherwise() {
+        let arg = EvalArgs {
+            phase: Phases::All,
+            io: IoArgs {
+                invocation_id: uuid::Uuid::new_v4(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        assert!(!should_exit_after(Phases::Debug, &arg));
+    }
+
     #[test]
     fn test_check_single_var() {
         let result = check_var("key: value").unwrap();
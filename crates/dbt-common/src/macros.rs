@@ -803,6 +803,7 @@ macro_rules! show_warning {
                 dbt_core_code: None,
                 original_severity_number,
                 original_severity_text: original_severity_text.to_string(),
+                context: err.context_kv().clone(),
                 location: RecordCodeLocation::none(), // Will be auto injected
             }),
             "{}",
@@ -1180,10 +1181,7 @@ macro_rules! maybe_interactive_or_exit {
 #[macro_export]
 macro_rules! checkpoint_maybe_exit {
     ( $phase:expr, $arg:expr, $start_time:expr ) => {
-        if $arg.phase <= $phase
-            || $crate::error_counter::get_error_counter($arg.io.invocation_id.to_string().as_str())
-                > 0
-        {
+        if $crate::io_args::should_exit_after($phase, &$arg) {
             return show_progress_exit!($arg, $start_time);
         }
     };
@@ -476,6 +476,19 @@ pub fn parse_model_specifiers(tokens: &[String]) -> FsResult<SelectExpression> {
     Ok(root)
 }
 
+/// Parse a whole selector string, e.g. `"tag:nightly,config.materialized:incremental +model_a"`.
+///
+/// Whitespace separates *OR* terms and binds looser than `,`, which separates
+/// *AND* terms, so `a,b c` parses as `(a AND b) OR c`. Each atom may carry graph
+/// operators (`+a`, `2+a`, `a+`, `a+3`, `@a`) and a `method[.arg]*:` qualifier.
+pub fn parse_selector(input: &str) -> FsResult<SelectExpression> {
+    let tokens = input
+        .split_whitespace()
+        .map(str::to_string)
+        .collect::<Vec<_>>();
+    parse_model_specifiers(&tokens)
+}
+
 /// helper used by the CLI to add an extra criterion (resource_type etc.)
 pub fn conjoin_predicate(
     maybe_sel: Option<SelectExpression>,
@@ -810,6 +823,80 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_parse_selector_comma_binds_tighter_than_space() -> FsResult<()> {
+        let result = parse_selector("tag:nightly,config.materialized:incremental +model_a")?;
+        let SelectExpression::Or(or_terms) = result else {
+            panic!("Expected SelectExpression::Or, got {result:?}");
+        };
+        assert_eq!(or_terms.len(), 2);
+        let SelectExpression::And(and_terms) = &or_terms[0] else {
+            panic!("Expected SelectExpression::And, got {:?}", or_terms[0]);
+        };
+        assert_eq!(
+            and_terms,
+            &vec![
+                SelectExpression::Atom(parse_single_selector("tag:nightly")?),
+                SelectExpression::Atom(parse_single_selector("config.materialized:incremental")?),
+            ]
+        );
+        let SelectExpression::Atom(config) = &and_terms[1] else {
+            unreachable!()
+        };
+        assert_eq!(config.method, MethodName::Config);
+        assert_eq!(config.method_args, vec!["materialized".to_string()]);
+        assert_eq!(
+            or_terms[1],
+            SelectExpression::Atom(parse_single_selector("+model_a")?)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_selector_graph_depths() -> FsResult<()> {
+        let result = parse_selector("  +model_a   2+model_b model_c+3 ")?;
+        let SelectExpression::Or(or_terms) = result else {
+            panic!("Expected SelectExpression::Or, got {result:?}");
+        };
+        let depths = or_terms
+            .iter()
+            .map(|term| match term {
+                SelectExpression::Atom(c) => (c.value.as_str(), c.parents_depth, c.children_depth),
+                other => panic!("Expected SelectExpression::Atom, got {other:?}"),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            depths,
+            vec![
+                ("model_a", Some(u32::MAX), None),
+                ("model_b", Some(2), None),
+                ("model_c", None, Some(3)),
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_selector_malformed() {
+        for (input, expected) in [
+            ("", "empty selector list passed to --select/--exclude"),
+            ("   ", "empty selector list passed to --select/--exclude"),
+            (
+                " , ",
+                "selector contained only delimiters but no actual criteria",
+            ),
+            ("tag:", "Invalid selector spec: `tag:`"),
+            ("bogus:model_a", "Invalid node selector method: `bogus`"),
+            (
+                "model_a @model_b+",
+                "Invalid selector `@model_b+` - \"@\" and trailing \"+\" are incompatible",
+            ),
+        ] {
+            let err = parse_selector(input).unwrap_err();
+            assert_eq!(err.to_string().trim(), expected, "input: `{input}`");
+        }
+    }
+
     #[test]
     fn test_column_selector_identifier() -> FsResult<()> {
         let input = "column:node123.foo_col";
@@ -2,7 +2,9 @@ use crate::{ErrorCode, FsResult, ectx, fs_err};
 
 use crate::error::LiftableResult;
 use std::fs::Metadata;
+use std::io::Write as _;
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::time::SystemTime;
 
 /// Wrapper around [`std::fs::canonicalize`] that returns a useful error in case of failure.
@@ -56,6 +58,81 @@ pub fn write<P: AsRef<Path>, C: AsRef<[u8]>>(path: P, contents: C) -> FsResult<(
     std::fs::write(path, contents).lift(ectx!("Failed to write file: {}", path.display()))
 }
 
+/// Writes `contents` to `path` atomically: readers see either the previous
+/// file or the complete new one, never a partially written file.
+///
+/// See [write_atomic_with].
+pub fn write_atomic<P: AsRef<Path>, C: AsRef<[u8]>>(path: P, contents: C) -> FsResult<()> {
+    let path = path.as_ref();
+    write_atomic_with(path, |file| {
+        file.write_all(contents.as_ref())
+            .lift(ectx!("Failed to write file: {}", path.display()))
+    })
+}
+
+/// Atomically replaces `path` with the content produced by `write`.
+///
+/// `write` fills a temporary file in the same directory as `path`, which is
+/// then synced and renamed into place. If `write` or any later step fails,
+/// the temporary file is removed and `path` is left untouched. When the rename
+/// crosses devices, the temporary file is copied over `path` instead, which is
+/// not atomic.
+pub fn write_atomic_with<P, F>(path: P, write: F) -> FsResult<()>
+where
+    P: AsRef<Path>,
+    F: FnOnce(&mut std::fs::File) -> FsResult<()>,
+{
+    static TEMP_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+    let path = path.as_ref();
+    let file_name = path.file_name().ok_or_else(|| {
+        fs_err!(
+            ErrorCode::InvalidArgument,
+            "Failed to write file: {} is not a file path",
+            path.display()
+        )
+    })?;
+    let temp_path = path.with_file_name(format!(
+        ".{}.{}.{}.tmp",
+        file_name.to_string_lossy(),
+        std::process::id(),
+        TEMP_COUNTER.fetch_add(1, Ordering::Relaxed)
+    ));
+
+    let result = write_and_replace(&temp_path, path, write);
+    if result.is_err() {
+        let _ = std::fs::remove_file(&temp_path);
+    }
+    result
+}
+
+fn write_and_replace<F>(temp_path: &Path, path: &Path, write: F) -> FsResult<()>
+where
+    F: FnOnce(&mut std::fs::File) -> FsResult<()>,
+{
+    let mut file = File::create(temp_path)?;
+    write(&mut file)?;
+    file.sync_all()
+        .lift(ectx!("Failed to sync file: {}", temp_path.display()))?;
+    drop(file);
+    replace_file(temp_path, path)
+}
+
+/// Renames `from` over `to`, falling back to a copy when they are on different devices.
+fn replace_file(from: &Path, to: &Path) -> FsResult<()> {
+    match std::fs::rename(from, to) {
+        Err(e) if e.kind() == std::io::ErrorKind::CrossesDevices => {
+            copy(from, to)?;
+            remove_file(from)
+        }
+        result => result.lift(ectx!(
+            "Failed to move file {} to {}",
+            from.display(),
+            to.display()
+        )),
+    }
+}
+
 /// Wrapper around [`std::fs::metadata`] that returns a useful error in case of failure.
 pub fn metadata<P: AsRef<Path>>(path: P) -> FsResult<Metadata> {
     let path = path.as_ref();
@@ -197,4 +274,42 @@ mod tests {
         remove_dir_all(&target_path).unwrap();
         assert!(result.is_ok());
     }
+
+    fn write_atomic_test_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("{name}_{}", std::process::id()));
+        let _ = remove_dir_all(&dir);
+        create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_write_atomic_replaces_content() {
+        let dir = write_atomic_test_dir("stdfs_write_atomic");
+        let path = dir.join("manifest.json");
+
+        write_atomic(&path, b"first").unwrap();
+        assert_eq!(read_to_string(&path).unwrap(), "first");
+        write_atomic(&path, b"second").unwrap();
+        assert_eq!(read_to_string(&path).unwrap(), "second");
+
+        // no temporary file is left behind
+        assert_eq!(read_dir(&dir).unwrap().count(), 1);
+        remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_write_atomic_failure_keeps_original() {
+        let dir = write_atomic_test_dir("stdfs_write_atomic_failure");
+        let path = dir.join("manifest.json");
+        write(&path, "original").unwrap();
+
+        let result = write_atomic_with(&path, |file| {
+            file.write_all(b"partial").unwrap();
+            Err(fs_err!(ErrorCode::IoError, "simulated failure"))
+        });
+        assert!(result.is_err());
+        assert_eq!(read_to_string(&path).unwrap(), "original");
+        assert_eq!(read_dir(&dir).unwrap().count(), 1);
+        remove_dir_all(&dir).unwrap();
+    }
 }
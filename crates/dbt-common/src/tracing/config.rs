@@ -1,4 +1,5 @@
 use std::path::PathBuf;
+use std::time::Duration;
 
 use super::convert::log_level_filter_to_tracing;
 use crate::{
@@ -22,6 +23,9 @@ pub struct FsTraceConfig {
     pub(super) max_log_verbosity: tracing::level_filters::LevelFilter,
     /// Path for production telemetry output (JSONL format)
     pub(super) otm_file_path: Option<PathBuf>,
+    /// If set, the JSONL output continues in a new numbered file once the
+    /// current one would exceed this size
+    pub(super) otm_file_max_bytes: Option<u64>,
     /// Path for production telemetry output (Parquet format)
     pub(super) otm_parquet_file_path: Option<PathBuf>,
     /// Invocation ID used as trace ID for correlation
@@ -29,11 +33,24 @@ pub struct FsTraceConfig {
     /// If True, traces will be forwarded to OTLP endpoints, if any
     /// are set via OTEL environment variables. See `OTLPExporterLayer::new`
     pub(super) export_to_otlp: bool,
+    /// Maximum number of spans (and logs) sent to OTLP in one export. If
+    /// unset, the OTEL SDK's default applies, which honors the standard
+    /// `OTEL_BSP_*` and `OTEL_BLRP_*` variables
+    pub(super) otlp_batch_size: Option<usize>,
+    /// Maximum time a span (or log) waits in a partial batch before export.
+    /// If unset, the OTEL SDK's default applies
+    pub(super) otlp_flush_interval: Option<Duration>,
     /// If True, progress bar layer will be enabled
     pub(super) enable_progress: bool,
     /// The log format being used. As of today (while old logging infra exists) - this is used to
     /// enable jsonl output on stdout if needed.
     pub(super) log_format: LogFormat,
+    /// Probability of writing a span less severe than `trace_sample_threshold`
+    /// to the file writers. Spans that end with an error are always written.
+    /// 1.0 disables sampling.
+    pub(super) trace_sample_ratio: f64,
+    /// Spans at this level or more severe are never sampled out
+    pub(super) trace_sample_threshold: tracing::Level,
 }
 
 impl Default for FsTraceConfig {
@@ -42,11 +59,16 @@ impl Default for FsTraceConfig {
             package: "unknown",
             max_log_verbosity: tracing::level_filters::LevelFilter::INFO,
             otm_file_path: None,
+            otm_file_max_bytes: None,
             otm_parquet_file_path: None,
             invocation_id: uuid::Uuid::new_v4(),
             enable_progress: false,
             export_to_otlp: false,
+            otlp_batch_size: None,
+            otlp_flush_interval: None,
             log_format: LogFormat::Default,
+            trace_sample_ratio: 1.0,
+            trace_sample_threshold: tracing::Level::WARN,
         }
     }
 }
@@ -107,6 +129,7 @@ impl FsTraceConfig {
                     },
                 )
             }),
+            otm_file_max_bytes: io_args.otm_file_max_bytes,
             otm_parquet_file_path: io_args
                 .otm_parquet_file_name
                 .as_ref()
@@ -115,7 +138,78 @@ impl FsTraceConfig {
             // TODO. For now never print to stdout. Maybe remove with the debug layer?
             enable_progress: io_args.log_format == LogFormat::Default,
             export_to_otlp: io_args.export_to_otlp,
+            otlp_batch_size: None,
+            otlp_flush_interval: None,
             log_format: io_args.log_format,
+            trace_sample_ratio: 1.0,
+            trace_sample_threshold: tracing::Level::WARN,
         }
+        .with_trace_sampling(
+            io_args.trace_sample_ratio.unwrap_or(1.0),
+            tracing::Level::WARN,
+        )
+    }
+
+    /// Export to OTLP in batches of up to `batch_size`, flushing a partial
+    /// batch after `flush_interval`. Pending batches are flushed on shutdown.
+    pub fn with_otlp_batching(mut self, batch_size: usize, flush_interval: Duration) -> Self {
+        self.otlp_batch_size = Some(batch_size.max(1));
+        self.otlp_flush_interval = Some(flush_interval);
+        self
+    }
+
+    /// Rotate the JSONL output to `<name>.1.jsonl`, `<name>.2.jsonl`, ...
+    /// whenever the current file would exceed `max_bytes`
+    pub fn with_otm_file_max_bytes(mut self, max_bytes: u64) -> Self {
+        self.otm_file_max_bytes = Some(max_bytes);
+        self
+    }
+
+    /// Keep only `ratio` of the spans less severe than `threshold` in the
+    /// JSONL and parquet outputs. `ratio` is clamped to [0.0, 1.0].
+    pub fn with_trace_sampling(mut self, ratio: f64, threshold: tracing::Level) -> Self {
+        self.trace_sample_ratio = ratio.clamp(0.0, 1.0);
+        self.trace_sample_threshold = threshold;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config_from(io_args: &IoArgs) -> FsTraceConfig {
+        FsTraceConfig::new(Some(PathBuf::from("project")), None, io_args, "test")
+    }
+
+    #[test]
+    fn trace_sample_ratio_comes_from_io_args() {
+        assert_eq!(config_from(&IoArgs::default()).trace_sample_ratio, 1.0);
+
+        let io_args = IoArgs {
+            trace_sample_ratio: Some(0.25),
+            ..Default::default()
+        };
+        let config = config_from(&io_args);
+        assert_eq!(config.trace_sample_ratio, 0.25);
+        assert_eq!(config.trace_sample_threshold, tracing::Level::WARN);
+
+        // out of range ratios are clamped
+        let io_args = IoArgs {
+            trace_sample_ratio: Some(2.0),
+            ..Default::default()
+        };
+        assert_eq!(config_from(&io_args).trace_sample_ratio, 1.0);
+    }
+
+    #[test]
+    fn otm_file_max_bytes_comes_from_io_args() {
+        assert_eq!(config_from(&IoArgs::default()).otm_file_max_bytes, None);
+
+        let io_args = IoArgs {
+            otm_file_max_bytes: Some(1024),
+            ..Default::default()
+        };
+        assert_eq!(config_from(&io_args).otm_file_max_bytes, Some(1024));
     }
 }
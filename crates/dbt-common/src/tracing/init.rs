@@ -17,6 +17,8 @@ use super::{
         data_layer::TelemetryDataLayer, jsonl_writer::TelemetryJsonlWriterLayer,
         otlp::OTLPExporterLayer, parquet_writer::TelemetryParquetWriterLayer,
     },
+    rotating_file::RotatingFileWriter,
+    sampling::SpanSampler,
 };
 use crate::{
     ErrorCode, FsError, FsResult, logging::LogFormat, stdfs::File,
@@ -217,12 +219,19 @@ where
     let strip_code_location = !cfg!(debug_assertions);
 
     // Create the data layer
-    let data_layer = TelemetryDataLayer::new(trace_id, strip_code_location);
+    // Spans dropped by sampling are still processed by all layers, but
+    // skipped by the file writers
+    let sampler = SpanSampler::new(config.trace_sample_ratio, config.trace_sample_threshold);
+    let data_layer = TelemetryDataLayer::new(trace_id, strip_code_location, sampler);
 
     // Create jsonl writer layer if file path provided
     let jsonl_writer_layer = if let Some(file_path) = config.otm_file_path {
-        let file = File::create(file_path)?;
-        let (writer, handle) = BackgroundWriter::new(file);
+        let (writer, handle) = match config.otm_file_max_bytes {
+            Some(max_bytes) => {
+                BackgroundWriter::new(RotatingFileWriter::new(file_path, max_bytes)?)
+            }
+            None => BackgroundWriter::new(File::create(file_path)?),
+        };
 
         // Keep a handle for shutdown
         shutdown_items.push(Box::new(handle));
@@ -278,7 +287,8 @@ where
 
     // Create OTLP layer - if enabled and endpoint is set via env vars
     let maybe_otlp_layer = if config.export_to_otlp
-        && let Some(otlp_layer) = OTLPExporterLayer::new()
+        && let Some(otlp_layer) =
+            OTLPExporterLayer::new(config.otlp_batch_size, config.otlp_flush_interval)
     {
         shutdown_items.push(Box::new(otlp_layer.tracer_provider()));
         shutdown_items.push(Box::new(otlp_layer.logger_provider()));
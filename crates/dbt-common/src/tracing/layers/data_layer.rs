@@ -2,6 +2,8 @@ use super::super::{
     convert::tracing_level_to_severity,
     event_info::{get_log_event_attrs, get_log_message, store_event_data, take_event_attributes},
     init::process_span,
+    memory::peak_rss_bytes,
+    sampling::{SampledOut, SpanSampler},
     span_info::{get_span_debug_extra_attrs, get_span_event_attrs},
 };
 use rand::RngCore;
@@ -31,6 +33,8 @@ where
     fallback_trace_id: u128,
     /// Whether to strip code location from span & log attributes.
     strip_code_location: bool,
+    /// If set, spans it drops are marked with [SampledOut] for the file writers.
+    sampler: Option<SpanSampler>,
     __phantom: std::marker::PhantomData<S>,
 }
 
@@ -38,10 +42,15 @@ impl<S> TelemetryDataLayer<S>
 where
     S: Subscriber + for<'lookup> tracing_subscriber::registry::LookupSpan<'lookup>,
 {
-    pub(crate) fn new(fallback_trace_id: u128, strip_code_location: bool) -> Self {
+    pub(crate) fn new(
+        fallback_trace_id: u128,
+        strip_code_location: bool,
+        sampler: Option<SpanSampler>,
+    ) -> Self {
         Self {
             fallback_trace_id,
             strip_code_location,
+            sampler,
             __phantom: std::marker::PhantomData,
         }
     }
@@ -157,6 +166,12 @@ where
         // And store the attributes in the span extensions as well,
         // we use this to update them post creation and add to closing span record
         ext_mut.insert(attributes);
+
+        if let Some(sampler) = &self.sampler
+            && !sampler.keep(metadata.level())
+        {
+            ext_mut.insert(SampledOut);
+        }
     }
 
     fn on_close(&self, id: span::Id, ctx: Context<'_, S>) {
@@ -213,7 +228,7 @@ where
 
         let status = span.extensions().get::<SpanStatus>().cloned();
 
-        let attributes = span
+        let mut attributes = span
             .extensions()
             .get::<TelemetryAttributes>()
             .cloned()
@@ -222,6 +237,13 @@ where
                 start_attributes
             });
 
+        // Phase spans record the memory high water mark of the process at close
+        if let TelemetryAttributes::Phase(phase_info) = &mut attributes
+            && let Some(peak_rss_bytes) = peak_rss_bytes()
+        {
+            phase_info.set_peak_rss_bytes(peak_rss_bytes);
+        }
+
         let record = SpanEndInfo {
             trace_id,
             span_id,
@@ -306,6 +328,7 @@ where
                         dbt_core_code: None,
                         original_severity_number: severity_number,
                         original_severity_text: severity_text.to_string(),
+                        context: Default::default(),
                         location: self.get_location(metadata),
                     })
                 })
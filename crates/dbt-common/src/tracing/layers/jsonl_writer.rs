@@ -2,7 +2,11 @@ use dbt_telemetry::{SpanEndInfo, SpanStartInfo, TelemetryRecordRef};
 use tracing::{Subscriber, span};
 use tracing_subscriber::{Layer, layer::Context};
 
-use super::super::{event_info::with_current_thread_event_data, shared_writer::SharedWriter};
+use super::super::{
+    event_info::with_current_thread_event_data,
+    sampling::{SampledOut, is_sampled_out},
+    shared_writer::SharedWriter,
+};
 
 /// A tracing layer that reads telemetry data from extensions and writes it as JSON.
 ///
@@ -18,6 +22,14 @@ impl TelemetryJsonlWriterLayer {
             writer: Box::new(writer),
         }
     }
+
+    fn write_span_start(&self, record: &SpanStartInfo) {
+        if let Ok(mut json) = serde_json::to_string(&TelemetryRecordRef::SpanStart(record)) {
+            json.push('\n');
+            // Currently we silently ignore write errors
+            let _ = self.writer.write(json.as_str());
+        }
+    }
 }
 
 impl<S> Layer<S> for TelemetryJsonlWriterLayer
@@ -29,14 +41,16 @@ where
             .span(id)
             .expect("Span must exist for id in the current context");
 
+        let extensions = span.extensions();
+        if is_sampled_out(&extensions) {
+            // Written on close if the span ends with an error
+            return;
+        }
+
         // Get the TelemetryRecord from extensions. It must be there unless we messed
         // up data layer / layer order.
-        if let Some(record) = span.extensions().get::<SpanStartInfo>() {
-            if let Ok(mut json) = serde_json::to_string(&TelemetryRecordRef::SpanStart(record)) {
-                json.push('\n');
-                // Currently we silently ignore write errors
-                let _ = self.writer.write(json.as_str());
-            }
+        if let Some(record) = extensions.get::<SpanStartInfo>() {
+            self.write_span_start(record);
         } else {
             unreachable!("Unexpectedly missing span start data!");
         }
@@ -47,9 +61,20 @@ where
             .span(&id)
             .expect("Span must exist for id in the current context");
 
+        let extensions = span.extensions();
+        if is_sampled_out(&extensions) {
+            return;
+        }
+        if extensions.get::<SampledOut>().is_some()
+            && let Some(record) = extensions.get::<SpanStartInfo>()
+        {
+            // Sampled out span that ended with an error, its start was skipped
+            self.write_span_start(record);
+        }
+
         // Get the TelemetryRecord from extensions. It must be there unless we messed
         // up data layer / layer order.
-        if let Some(record) = span.extensions().get::<SpanEndInfo>() {
+        if let Some(record) = extensions.get::<SpanEndInfo>() {
             if let Ok(mut json) = serde_json::to_string(&TelemetryRecordRef::SpanEnd(record)) {
                 json.push('\n');
                 // Currently we silently ignore write errors
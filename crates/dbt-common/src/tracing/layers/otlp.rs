@@ -1,4 +1,5 @@
 use std::collections::HashMap;
+use std::time::Duration;
 
 use super::super::{TelemetryShutdown, event_info::with_current_thread_event_data};
 use crate::constants::DBT_FUSION;
@@ -21,9 +22,15 @@ use opentelemetry::{
 };
 use opentelemetry_otlp::WithExportConfig;
 use opentelemetry_sdk::Resource;
-use opentelemetry_sdk::logs::{SdkLogger, SdkLoggerProvider};
+use opentelemetry_sdk::logs::{
+    BatchConfig as LogBatchConfig, BatchConfigBuilder as LogBatchConfigBuilder, BatchLogProcessor,
+    LogExporter, SdkLogger, SdkLoggerProvider,
+};
 use opentelemetry_sdk::resource::EnvResourceDetector;
-use opentelemetry_sdk::trace::{SdkTracer, SdkTracerProvider};
+use opentelemetry_sdk::trace::{
+    BatchConfig as TraceBatchConfig, BatchConfigBuilder as TraceBatchConfigBuilder,
+    BatchSpanProcessor, SdkTracer, SdkTracerProvider, SpanExporter,
+};
 use opentelemetry_semantic_conventions::attribute::{CODE_FILE_PATH, CODE_LINE_NUMBER};
 use opentelemetry_semantic_conventions::resource::{SERVICE_NAME, SERVICE_VERSION};
 use tracing::{Subscriber, span};
@@ -146,16 +153,10 @@ where
     ///   can be used to specify a full endpoint for traces, with non-default routes.
     /// - the environment variable `OTEL_EXPORTER_OTLP_LOGS_ENDPOINT` - works
     ///   can be used to specify a full endpoint for logs, with non-default routes.
-    pub(crate) fn new() -> Option<Self> {
-        // Set up resource with service information
-        let resource = Resource::builder()
-            .with_detectors(&[Box::new(EnvResourceDetector::new())])
-            .with_attributes(vec![
-                KeyValue::new(SERVICE_NAME, DBT_FUSION),
-                KeyValue::new(SERVICE_VERSION, env!("CARGO_PKG_VERSION")),
-            ])
-            .build();
-
+    ///
+    /// `batch_size` and `flush_interval` override the OTEL SDK's batch config,
+    /// see [OTLPExporterLayer::with_exporters].
+    pub(crate) fn new(batch_size: Option<usize>, flush_interval: Option<Duration>) -> Option<Self> {
         // Add OTLP trace HTTP exporter
         let tracing_http_exporter = match opentelemetry_otlp::SpanExporter::builder()
             .with_http()
@@ -166,12 +167,6 @@ where
             Err(_) => return None,
         };
 
-        // Initialize a tracer provider.
-        let tracer_provider = SdkTracerProvider::builder()
-            .with_resource(resource.clone())
-            .with_batch_exporter(tracing_http_exporter)
-            .build();
-
         // Create OTLP logger exporter
         let logger_http_export = match opentelemetry_otlp::LogExporterBuilder::new()
             .with_http()
@@ -182,29 +177,76 @@ where
             Err(_) => return None,
         };
 
+        let layer = Self::with_exporters(
+            tracing_http_exporter,
+            logger_http_export,
+            batch_size,
+            flush_interval,
+        );
+
+        // Set the global tracer provider. Clone is necessary but cheap, as it is a reference
+        // to the same object.
+        global::set_tracer_provider(layer.tracer_provider());
+
+        Some(layer)
+    }
+
+    /// Creates a layer exporting through the given exporters. Spans and logs
+    /// are exported in batches of up to `batch_size`, at least every
+    /// `flush_interval`, and any pending batch is flushed on shutdown. Unset
+    /// values come from the OTEL SDK's batch config, which reads the standard
+    /// `OTEL_BSP_*` and `OTEL_BLRP_*` variables. Unlike
+    /// [OTLPExporterLayer::new], this leaves the global tracer provider alone.
+    pub(crate) fn with_exporters<SE, LE>(
+        span_exporter: SE,
+        log_exporter: LE,
+        batch_size: Option<usize>,
+        flush_interval: Option<Duration>,
+    ) -> Self
+    where
+        SE: SpanExporter + 'static,
+        LE: LogExporter + 'static,
+    {
+        // Set up resource with service information
+        let resource = Resource::builder()
+            .with_detectors(&[Box::new(EnvResourceDetector::new())])
+            .with_attributes(vec![
+                KeyValue::new(SERVICE_NAME, DBT_FUSION),
+                KeyValue::new(SERVICE_VERSION, env!("CARGO_PKG_VERSION")),
+            ])
+            .build();
+
+        // Initialize a tracer provider.
+        let span_processor = BatchSpanProcessor::builder(span_exporter)
+            .with_batch_config(trace_batch_config(batch_size, flush_interval))
+            .build();
+        let tracer_provider = SdkTracerProvider::builder()
+            .with_resource(resource.clone())
+            .with_span_processor(span_processor)
+            .build();
+
         // Initialize a logger provider.
+        let log_processor = BatchLogProcessor::builder(log_exporter)
+            .with_batch_config(log_batch_config(batch_size, flush_interval))
+            .build();
         let logger_provider = SdkLoggerProvider::builder()
             .with_resource(resource)
-            .with_batch_exporter(logger_http_export)
+            .with_log_processor(log_processor)
             .build();
 
-        // Set the global tracer provider. Clone is necessary but cheap, as it is a reference
-        // to the same object.
-        global::set_tracer_provider(tracer_provider.clone());
-
         // Get tracer
         let tracer = tracer_provider.tracer(DBT_FUSION);
 
         // Get root logger
         let logger = logger_provider.logger(DBT_FUSION);
 
-        Some(OTLPExporterLayer {
+        OTLPExporterLayer {
             tracer_provider,
             logger_provider,
             tracer,
             logger,
             __phantom: std::marker::PhantomData,
-        })
+        }
     }
 
     pub(crate) fn tracer_provider(&self) -> SdkTracerProvider {
@@ -218,6 +260,33 @@ where
     }
 }
 
+/// The OTEL SDK's span batch config, with the given overrides
+fn trace_batch_config(
+    batch_size: Option<usize>,
+    flush_interval: Option<Duration>,
+) -> TraceBatchConfig {
+    let mut builder = TraceBatchConfigBuilder::default();
+    if let Some(batch_size) = batch_size {
+        builder = builder.with_max_export_batch_size(batch_size);
+    }
+    if let Some(flush_interval) = flush_interval {
+        builder = builder.with_scheduled_delay(flush_interval);
+    }
+    builder.build()
+}
+
+/// The OTEL SDK's log batch config, with the given overrides
+fn log_batch_config(batch_size: Option<usize>, flush_interval: Option<Duration>) -> LogBatchConfig {
+    let mut builder = LogBatchConfigBuilder::default();
+    if let Some(batch_size) = batch_size {
+        builder = builder.with_max_export_batch_size(batch_size);
+    }
+    if let Some(flush_interval) = flush_interval {
+        builder = builder.with_scheduled_delay(flush_interval);
+    }
+    builder.build()
+}
+
 impl TelemetryShutdown for SdkTracerProvider {
     fn shutdown(&mut self) -> FsResult<()> {
         SdkTracerProvider::shutdown(self).map_err(|otel_error| {
@@ -410,3 +479,61 @@ where
         });
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tracing::layers::data_layer::TelemetryDataLayer;
+    use opentelemetry_sdk::logs::InMemoryLogExporter;
+    use opentelemetry_sdk::trace::InMemorySpanExporter;
+    use tracing_subscriber::{Registry, layer::SubscriberExt};
+
+    #[test]
+    fn test_pending_batch_is_flushed_on_shutdown() {
+        let span_exporter = InMemorySpanExporter::default();
+        let log_exporter = InMemoryLogExporter::default();
+
+        // Neither the batch size nor the flush interval will be reached
+        let otlp_layer = OTLPExporterLayer::with_exporters(
+            span_exporter.clone(),
+            log_exporter.clone(),
+            Some(1024),
+            Some(Duration::from_secs(3600)),
+        );
+        let mut tracer_provider = otlp_layer.tracer_provider();
+        let mut logger_provider = otlp_layer.logger_provider();
+
+        let trace_id = uuid::Uuid::new_v4().as_u128();
+        let data_layer = TelemetryDataLayer::new(trace_id, false, None);
+        let subscriber = Registry::default().with(data_layer).with(otlp_layer);
+
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::info_span!("root_span").in_scope(|| {
+                tracing::info!("Log message in root span");
+                tracing::info_span!("child_span").in_scope(|| {});
+            })
+        });
+
+        assert!(span_exporter.get_finished_spans().unwrap().is_empty());
+
+        TelemetryShutdown::shutdown(&mut tracer_provider).unwrap();
+        TelemetryShutdown::shutdown(&mut logger_provider).unwrap();
+
+        assert_eq!(span_exporter.get_finished_spans().unwrap().len(), 2);
+        assert_eq!(log_exporter.get_emitted_logs().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_default_batching_is_the_sdk_config() {
+        // without overrides the SDK's config (and so its OTEL_BSP_* and
+        // OTEL_BLRP_* variables) is used as is
+        assert_eq!(
+            format!("{:?}", trace_batch_config(None, None)),
+            format!("{:?}", TraceBatchConfigBuilder::default().build())
+        );
+        assert_eq!(
+            format!("{:?}", log_batch_config(None, None)),
+            format!("{:?}", LogBatchConfigBuilder::default().build())
+        );
+    }
+}
@@ -12,7 +12,9 @@ use parquet::{arrow::ArrowWriter, basic::Compression, file::properties::WriterPr
 use tracing::{Subscriber, span};
 use tracing_subscriber::{Layer, layer::Context};
 
-use super::super::{event_info::with_current_thread_event_data, init::TelemetryShutdown};
+use super::super::{
+    event_info::with_current_thread_event_data, init::TelemetryShutdown, sampling::is_sampled_out,
+};
 use crate::{ErrorCode, FsResult};
 
 /// Buffer size for parquet record batching. This is the buffer in our part of the code
@@ -266,8 +268,13 @@ where
             .span(&id)
             .expect("Span must exist for id in the current context");
 
+        let extensions = span.extensions();
+        if is_sampled_out(&extensions) {
+            return;
+        }
+
         // Get the TelemetryRecord from extensions
-        if let Some(record) = span.extensions().get::<dbt_telemetry::SpanEndInfo>() {
+        if let Some(record) = extensions.get::<dbt_telemetry::SpanEndInfo>() {
             let telemetry_record = TelemetryRecord::SpanEnd(record.clone());
 
             // Filter
@@ -433,6 +440,7 @@ mod tests {
                 dbt_core_code: Some(format!("test_code_{i}")),
                 original_severity_number: SeverityNumber::Info,
                 original_severity_text: "INFO".to_string(),
+                context: Default::default(),
                 location: RecordCodeLocation::none(),
             }),
         })
@@ -558,7 +566,7 @@ mod tests {
 
         // We need the data layer to populate span extensions
         let trace_id = uuid::Uuid::new_v4().as_u128();
-        let data_layer = TelemetryDataLayer::new(trace_id, false);
+        let data_layer = TelemetryDataLayer::new(trace_id, false, None);
 
         // Create a Registry-based subscriber with both layers
         let subscriber = Registry::default().with(data_layer).with(parquet_layer);
@@ -0,0 +1,43 @@
+/// Returns the peak resident set size of the current process in bytes, or
+/// `None` if it can't be determined on this platform.
+///
+/// On unix platforms this is `ru_maxrss` from `getrusage(RUSAGE_SELF)`.
+pub fn peak_rss_bytes() -> Option<u64> {
+    #[cfg(unix)]
+    {
+        let mut usage = std::mem::MaybeUninit::<libc::rusage>::uninit();
+        // SAFETY: `usage` is a valid out pointer, initialized by a successful call
+        let usage = unsafe {
+            if libc::getrusage(libc::RUSAGE_SELF, usage.as_mut_ptr()) != 0 {
+                return None;
+            }
+            usage.assume_init()
+        };
+        let max_rss = u64::try_from(usage.ru_maxrss).ok()?;
+        // macOS reports bytes, the other unix platforms kilobytes
+        if cfg!(target_vendor = "apple") {
+            Some(max_rss)
+        } else {
+            Some(max_rss * 1024)
+        }
+    }
+
+    #[cfg(not(unix))]
+    {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(unix)]
+    fn test_peak_rss_bytes() {
+        // Touch a few megabytes so the high water mark is at least that
+        let buffer = std::hint::black_box(vec![1u8; 8 * 1024 * 1024]);
+        let peak = peak_rss_bytes().unwrap();
+        assert!(peak >= buffer.len() as u64, "{peak}");
+    }
+}
@@ -8,7 +8,10 @@ pub mod event_info;
 mod init;
 mod invocation;
 mod layers;
+pub mod memory;
 pub mod metrics;
+mod rotating_file;
+mod sampling;
 mod shared;
 mod shared_writer;
 pub mod span_info;
@@ -21,15 +24,13 @@ pub use shared::ToTracingValue;
 
 #[cfg(test)]
 mod tests {
-    use crate::logging::LogFormat;
-
     use super::*;
 
     use constants::TRACING_ATTR_FIELD;
     use dbt_telemetry::{
-        DebugValue, DevInternalInfo, LegacyLogEventInfo, LogEventInfo, LogRecordInfo,
-        RecordCodeLocation, SeverityNumber, SpanEndInfo, SpanStartInfo, TelemetryAttributes,
-        TelemetryRecord, UnknownInfo,
+        BuildPhaseInfo, DebugValue, DevInternalInfo, LegacyLogEventInfo, LogEventInfo,
+        LogRecordInfo, RecordCodeLocation, SeverityNumber, SpanEndInfo, SpanStartInfo,
+        TelemetryAttributes, TelemetryRecord, UnknownInfo,
         serialize::arrow::{create_arrow_schema, deserialize_from_arrow},
     };
     use event_info::with_current_thread_event_data;
@@ -43,6 +44,16 @@ mod tests {
         layer::{Context, Layered},
     };
 
+    /// Config shared by the tests: full verbosity, no file outputs
+    fn test_trace_config(invocation_id: uuid::Uuid) -> FsTraceConfig {
+        FsTraceConfig {
+            package: "test_package",
+            max_log_verbosity: tracing::level_filters::LevelFilter::TRACE,
+            invocation_id,
+            ..Default::default()
+        }
+    }
+
     // Custom layer to capture telemetry data
     #[derive(Clone)]
     struct TestLayer {
@@ -115,20 +126,9 @@ mod tests {
         // Init telemetry using internal API allowing to set thread local subscriber.
         // This avoids collisions with other unit tests, but prevents us from testing
         // the fallback logic with the global parent span
-        let (subscriber, shutdown_items) = create_tracing_subcriber_with_layer(
-            FsTraceConfig {
-                package: "test_package",
-                max_log_verbosity: tracing::level_filters::LevelFilter::TRACE,
-                invocation_id,
-                otm_file_path: None,
-                otm_parquet_file_path: None,
-                enable_progress: false,
-                export_to_otlp: false,
-                log_format: LogFormat::Default,
-            },
-            test_layer,
-        )
-        .expect("Failed to initialize tracing");
+        let (subscriber, shutdown_items) =
+            create_tracing_subcriber_with_layer(test_trace_config(invocation_id), test_layer)
+                .expect("Failed to initialize tracing");
 
         let dummy_root_span = tracing::info_span!("not used");
 
@@ -139,6 +139,7 @@ mod tests {
             dbt_core_code: Some("test_code".to_string()),
             original_severity_number: SeverityNumber::Warn,
             original_severity_text: "WARN".to_string(),
+            context: Default::default(),
             // This is important. Our infra will auto-populate the location from the callsite,
             // and we want to test that it works correctly, capturing real callsite
             location: RecordCodeLocation::none(),
@@ -198,6 +199,59 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_show_warning_emits_error_context() {
+        let invocation_id = uuid::Uuid::new_v4();
+
+        let (test_layer, _, _, log_records) = TestLayer::new();
+
+        let (subscriber, shutdown_items) =
+            create_tracing_subcriber_with_layer(test_trace_config(invocation_id), test_layer)
+                .expect("Failed to initialize tracing");
+
+        let dummy_root_span = tracing::info_span!("not used");
+
+        let mut telemetry_handle = TelemetryHandle::new(shutdown_items, dummy_root_span);
+
+        let io = crate::io_args::IoArgs {
+            invocation_id,
+            ..Default::default()
+        };
+        let err = dbt_error::FsError::new(dbt_error::ErrorCode::Generic, "boom")
+            .with_context_kv("file", "models/a.sql")
+            .with_context_kv("node_id", "model.pkg.a");
+
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::info_span!("test_root_span").in_scope(|| {
+                crate::show_warning!(io, err);
+            })
+        });
+
+        let shutdown_errs = telemetry_handle.shutdown();
+        assert_eq!(shutdown_errs.len(), 0);
+
+        let log_records = Arc::into_inner(log_records)
+            .expect("Should have no refs")
+            .into_inner()
+            .expect("Should have no locks");
+
+        let expected_context = BTreeMap::from([
+            ("file".to_string(), "models/a.sql".to_string()),
+            ("node_id".to_string(), "model.pkg.a".to_string()),
+        ]);
+        let severities = log_records
+            .iter()
+            .map(|log_record| match &log_record.attributes {
+                TelemetryAttributes::Log(LogEventInfo { context, .. }) => {
+                    assert_eq!(context, &expected_context);
+                    log_record.severity_number
+                }
+                other => panic!("Expected Log attributes, got {other:?}"),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(severities, vec![SeverityNumber::Warn]);
+    }
+
     #[test]
     fn test_tracing_jsonl() {
         let invocation_id = uuid::Uuid::new_v4();
@@ -212,14 +266,8 @@ mod tests {
         // the fallback logic with the global parent span
         let (subscriber, shutdown_items) = create_tracing_subcriber_with_layer(
             FsTraceConfig {
-                package: "test_package",
-                max_log_verbosity: tracing::level_filters::LevelFilter::TRACE,
-                invocation_id,
                 otm_file_path: Some(temp_file_path.clone()),
-                otm_parquet_file_path: None,
-                enable_progress: false,
-                export_to_otlp: false,
-                log_format: LogFormat::Default,
+                ..test_trace_config(invocation_id)
             },
             None::<Box<dyn Layer<Layered<EnvFilter, Registry>> + Send + Sync>>,
         )
@@ -362,20 +410,9 @@ mod tests {
         // Init telemetry using internal API allowing to set thread local subscriber.
         // This avoids collisions with other unit tests, but prevents us from testing
         // the fallback logic with the global parent span
-        let (subscriber, shutdown_items) = create_tracing_subcriber_with_layer(
-            FsTraceConfig {
-                package: "test_package",
-                max_log_verbosity: tracing::level_filters::LevelFilter::TRACE,
-                invocation_id,
-                otm_file_path: None,
-                otm_parquet_file_path: None,
-                enable_progress: false,
-                export_to_otlp: false,
-                log_format: LogFormat::Default,
-            },
-            test_layer,
-        )
-        .expect("Failed to initialize tracing");
+        let (subscriber, shutdown_items) =
+            create_tracing_subcriber_with_layer(test_trace_config(invocation_id), test_layer)
+                .expect("Failed to initialize tracing");
 
         let dummy_root_span = tracing::info_span!("not used");
 
@@ -546,20 +583,9 @@ mod tests {
         // Init telemetry using internal API allowing to set thread local subscriber.
         // This avoids collisions with other unit tests, but prevents us from testing
         // the fallback logic with the global parent span
-        let (subscriber, shutdown_items) = create_tracing_subcriber_with_layer(
-            FsTraceConfig {
-                package: "test_package",
-                max_log_verbosity: tracing::level_filters::LevelFilter::TRACE,
-                invocation_id,
-                otm_file_path: None,
-                otm_parquet_file_path: None,
-                enable_progress: false,
-                export_to_otlp: false,
-                log_format: LogFormat::Default,
-            },
-            test_layer,
-        )
-        .expect("Failed to initialize tracing");
+        let (subscriber, shutdown_items) =
+            create_tracing_subcriber_with_layer(test_trace_config(invocation_id), test_layer)
+                .expect("Failed to initialize tracing");
 
         let dummy_root_span = tracing::info_span!("not used");
 
@@ -615,20 +641,9 @@ mod tests {
         // Init telemetry using internal API allowing to set thread local subscriber.
         // This avoids collisions with other unit tests, but prevents us from testing
         // the fallback logic with the global parent span
-        let (subscriber, shutdown_items) = create_tracing_subcriber_with_layer(
-            FsTraceConfig {
-                package: "test_package",
-                max_log_verbosity: tracing::level_filters::LevelFilter::TRACE,
-                invocation_id,
-                otm_file_path: None,
-                otm_parquet_file_path: None,
-                enable_progress: false,
-                export_to_otlp: false,
-                log_format: LogFormat::Default,
-            },
-            test_layer,
-        )
-        .expect("Failed to initialize tracing");
+        let (subscriber, shutdown_items) =
+            create_tracing_subcriber_with_layer(test_trace_config(invocation_id), test_layer)
+                .expect("Failed to initialize tracing");
 
         let dummy_root_span = tracing::info_span!("not used");
 
@@ -640,6 +655,7 @@ mod tests {
             dbt_core_code: Some("root_code".to_string()),
             original_severity_number: SeverityNumber::Info,
             original_severity_text: "INFO".to_string(),
+            context: Default::default(),
             location: RecordCodeLocation::none(),
         });
 
@@ -648,6 +664,7 @@ mod tests {
             dbt_core_code: Some("child_code".to_string()),
             original_severity_number: SeverityNumber::Debug,
             original_severity_text: "DEBUG".to_string(),
+            context: Default::default(),
             location: RecordCodeLocation::none(),
         });
 
@@ -656,6 +673,7 @@ mod tests {
             dbt_core_code: Some("event1_code".to_string()),
             original_severity_number: SeverityNumber::Warn,
             original_severity_text: "WARN".to_string(),
+            context: Default::default(),
             location: RecordCodeLocation::none(),
         });
 
@@ -664,6 +682,7 @@ mod tests {
             dbt_core_code: Some("event2_code".to_string()),
             original_severity_number: SeverityNumber::Error,
             original_severity_text: "ERROR".to_string(),
+            context: Default::default(),
             location: RecordCodeLocation::none(),
         });
 
@@ -813,14 +832,8 @@ mod tests {
         // the fallback logic with the global parent span
         let (subscriber, shutdown_items) = create_tracing_subcriber_with_layer(
             FsTraceConfig {
-                package: "test_package",
-                max_log_verbosity: tracing::level_filters::LevelFilter::TRACE,
-                invocation_id,
-                otm_file_path: None,
                 otm_parquet_file_path: Some(temp_file_path.clone()),
-                enable_progress: false,
-                export_to_otlp: false,
-                log_format: LogFormat::Default,
+                ..test_trace_config(invocation_id)
             },
             None::<Box<dyn Layer<Layered<EnvFilter, Registry>> + Send + Sync>>,
         )
@@ -841,6 +854,7 @@ mod tests {
             dbt_core_code: Some("test_code".to_string()),
             original_severity_number: SeverityNumber::Warn,
             original_severity_text: "WARN".to_string(),
+            context: Default::default(),
             location: RecordCodeLocation::none(),
         });
 
@@ -988,4 +1002,128 @@ mod tests {
         // Clean up
         let _ = fs::remove_file(&temp_file_path);
     }
+
+    /// Runs two successful spans and one failing span with the given sample
+    /// ratio, returning `(span name, is end record)` for the span records in
+    /// the JSONL output
+    fn run_sampled_spans(ratio: f64, file_name: &str) -> Vec<(String, bool)> {
+        let temp_file_path = std::env::temp_dir().join(file_name);
+
+        let (subscriber, shutdown_items) = create_tracing_subcriber_with_layer(
+            FsTraceConfig {
+                otm_file_path: Some(temp_file_path.clone()),
+                ..test_trace_config(uuid::Uuid::new_v4())
+            }
+            .with_trace_sampling(ratio, tracing::Level::WARN),
+            None::<Box<dyn Layer<Layered<EnvFilter, Registry>> + Send + Sync>>,
+        )
+        .expect("Failed to initialize tracing");
+
+        let dummy_root_span = tracing::info_span!("not used");
+        let mut telemetry_handle = TelemetryHandle::new(shutdown_items, dummy_root_span);
+
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::info_span!("ok_root_span").in_scope(|| {
+                tracing::info_span!("ok_child_span").in_scope(|| {});
+
+                let failing_span = tracing::info_span!("failing_span");
+                span_info::record_span_status(&failing_span, Some("boom"));
+            })
+        });
+
+        let shutdown_errs = telemetry_handle.shutdown();
+        assert_eq!(shutdown_errs.len(), 0);
+
+        let file_contents =
+            fs::read_to_string(&temp_file_path).expect("Failed to read temporary OTM file");
+        fs::remove_file(&temp_file_path).expect("Failed to remove temporary file");
+
+        file_contents
+            .lines()
+            .filter_map(|line| {
+                match serde_json::from_str::<TelemetryRecord>(line)
+                    .expect("Failed to parse TelemetryRecord from line")
+                {
+                    TelemetryRecord::SpanStart(SpanStartInfo {
+                        attributes: TelemetryAttributes::Unknown(UnknownInfo { name, .. }),
+                        ..
+                    }) => Some((name, false)),
+                    TelemetryRecord::SpanEnd(SpanEndInfo {
+                        attributes: TelemetryAttributes::Unknown(UnknownInfo { name, .. }),
+                        ..
+                    }) => Some((name, true)),
+                    _ => None,
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_tracing_sampling_zero_keeps_only_error_spans() {
+        let spans = run_sampled_spans(0.0, "test_otm_sampling_zero.jsonl");
+
+        assert_eq!(
+            spans,
+            vec![
+                ("failing_span".to_string(), false),
+                ("failing_span".to_string(), true),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_tracing_sampling_one_keeps_everything() {
+        let spans = run_sampled_spans(1.0, "test_otm_sampling_one.jsonl");
+
+        assert_eq!(spans.len(), 6, "Expected start and end of all 3 spans");
+        for name in ["ok_root_span", "ok_child_span", "failing_span"] {
+            assert!(spans.contains(&(name.to_string(), false)));
+            assert!(spans.contains(&(name.to_string(), true)));
+        }
+    }
+
+    #[test]
+    fn test_phase_span_records_peak_rss() {
+        let (test_layer, _, span_ends, _) = TestLayer::new();
+
+        let (subscriber, shutdown_items) = create_tracing_subcriber_with_layer(
+            test_trace_config(uuid::Uuid::new_v4()),
+            test_layer,
+        )
+        .expect("Failed to initialize tracing");
+
+        let dummy_root_span = tracing::info_span!("not used");
+        let mut telemetry_handle = TelemetryHandle::new(shutdown_items, dummy_root_span);
+
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::info_span!(
+                "parse_phase",
+                { TRACING_ATTR_FIELD } = TelemetryAttributes::Phase(BuildPhaseInfo::Parsing {
+                    peak_rss_bytes: None
+                })
+                .to_tracing_value()
+            )
+            .in_scope(|| {
+                // Touch every page so the buffer is actually resident
+                let buffer = vec![1u8; 64 * 1024 * 1024];
+                assert_eq!(std::hint::black_box(&buffer).len(), 64 * 1024 * 1024);
+            })
+        });
+
+        let shutdown_errs = telemetry_handle.shutdown();
+        assert_eq!(shutdown_errs.len(), 0);
+
+        let span_ends = span_ends.lock().unwrap();
+        assert_eq!(span_ends.len(), 1, "Expected 1 span end record");
+        let TelemetryAttributes::Phase(phase_info) = &span_ends[0].attributes else {
+            panic!(
+                "Expected Phase attributes, got {:?}",
+                span_ends[0].attributes
+            );
+        };
+        let peak_rss_bytes = phase_info
+            .peak_rss_bytes()
+            .expect("Expected peak_rss_bytes on the phase span");
+        assert!(peak_rss_bytes >= 64 * 1024 * 1024);
+    }
 }
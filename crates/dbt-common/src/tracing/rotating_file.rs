@@ -0,0 +1,123 @@
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+use crate::{FsResult, stdfs::File};
+
+/// A file writer that starts a new file once the current one would exceed
+/// `max_bytes`.
+///
+/// The first file is written to `path` itself, later ones to `path` with a
+/// numeric suffix before the extension, e.g. `otm.jsonl`, `otm.1.jsonl`,
+/// `otm.2.jsonl`. Every `write` call is written whole to a single file, so
+/// callers passing complete records never see them split across files.
+/// A record larger than `max_bytes` is written to a file of its own.
+pub struct RotatingFileWriter {
+    path: PathBuf,
+    max_bytes: u64,
+    file: fs::File,
+    written: u64,
+    index: usize,
+}
+
+impl RotatingFileWriter {
+    pub fn new(path: PathBuf, max_bytes: u64) -> FsResult<Self> {
+        let file = File::create(&path)?;
+        Ok(Self {
+            path,
+            max_bytes,
+            file,
+            written: 0,
+            index: 0,
+        })
+    }
+
+    /// The path of the `index`th file, the first being `path` itself
+    fn segment_path(path: &Path, index: usize) -> PathBuf {
+        if index == 0 {
+            return path.to_path_buf();
+        }
+        let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+        let file_name = match path.extension() {
+            Some(ext) => format!("{stem}.{index}.{}", ext.to_string_lossy()),
+            None => format!("{stem}.{index}"),
+        };
+        path.with_file_name(file_name)
+    }
+
+    fn rotate(&mut self) -> io::Result<()> {
+        self.file.flush()?;
+        self.index += 1;
+        self.file = fs::File::create(Self::segment_path(&self.path, self.index))?;
+        self.written = 0;
+        Ok(())
+    }
+}
+
+impl Write for RotatingFileWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if self.written > 0 && self.written + buf.len() as u64 > self.max_bytes {
+            self.rotate()?;
+        }
+        // Write the whole buffer here, rather than let `write_all` call us
+        // again with a remainder that could land in the next file
+        self.file.write_all(buf)?;
+        self.written += buf.len() as u64;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.file.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_segment_path() {
+        let path = Path::new("logs/otm.jsonl");
+        assert_eq!(
+            RotatingFileWriter::segment_path(path, 0),
+            PathBuf::from("logs/otm.jsonl")
+        );
+        assert_eq!(
+            RotatingFileWriter::segment_path(path, 2),
+            PathBuf::from("logs/otm.2.jsonl")
+        );
+    }
+
+    #[test]
+    fn test_rotates_on_record_boundaries() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("otm.jsonl");
+
+        let mut writer = RotatingFileWriter::new(path.clone(), 64).unwrap();
+        for i in 0..10 {
+            writer
+                .write_all(format!("{{\"record\":{i},\"padding\":\"xxxxxxxx\"}}\n").as_bytes())
+                .unwrap();
+        }
+        writer.flush().unwrap();
+
+        let mut records = Vec::new();
+        let mut index = 0;
+        loop {
+            let segment = RotatingFileWriter::segment_path(&path, index);
+            if !segment.exists() {
+                break;
+            }
+            let contents = fs::read_to_string(&segment).unwrap();
+            assert!(contents.ends_with('\n'));
+            for line in contents.lines() {
+                let value: serde_json::Value = serde_json::from_str(line).unwrap();
+                records.push(value["record"].as_u64().unwrap());
+            }
+            index += 1;
+        }
+
+        assert!(index > 1, "Expected more than one file, got {index}");
+        assert_eq!(records, (0..10).collect::<Vec<_>>());
+    }
+}
@@ -0,0 +1,43 @@
+use dbt_telemetry::{SpanStatus, StatusCode};
+use tracing::Level;
+use tracing_subscriber::registry::Extensions;
+
+/// Marker stored in span extensions by the data layer for spans that were
+/// sampled out. File writers skip such spans unless they end with an error.
+pub(super) struct SampledOut;
+
+/// Probabilistically drops spans less severe than a threshold.
+#[derive(Debug, Clone, Copy)]
+pub(super) struct SpanSampler {
+    ratio: f64,
+    threshold: Level,
+}
+
+impl SpanSampler {
+    /// Returns `None` when nothing would ever be dropped
+    pub(super) fn new(ratio: f64, threshold: Level) -> Option<Self> {
+        (ratio < 1.0).then_some(Self {
+            ratio: ratio.clamp(0.0, 1.0),
+            threshold,
+        })
+    }
+
+    /// Decides whether a new span at `level` should be kept
+    pub(super) fn keep(&self, level: &Level) -> bool {
+        // More verbose levels compare greater, i.e. ERROR < WARN < INFO
+        *level <= self.threshold || rand::random_bool(self.ratio)
+    }
+}
+
+/// Whether a writer should skip the span: it was sampled out and has not
+/// (yet) ended with an error status.
+pub(super) fn is_sampled_out(extensions: &Extensions<'_>) -> bool {
+    extensions.get::<SampledOut>().is_some()
+        && !matches!(
+            extensions.get::<SpanStatus>(),
+            Some(SpanStatus {
+                code: StatusCode::Error,
+                ..
+            })
+        )
+}
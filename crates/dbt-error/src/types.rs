@@ -6,6 +6,7 @@ use itertools::Itertools as _;
 use regex::Regex;
 use std::{
     backtrace::Backtrace,
+    collections::BTreeMap,
     fmt::{self, Debug, Display, Formatter},
     io, panic,
     path::{Path, PathBuf},
@@ -25,6 +26,8 @@ pub struct FsError {
     pub context: String,
     cause: Option<WrappedError>,
     backtrace: Backtrace,
+    // Structured key/value context, see [FsError::with_context_kv]
+    context_kv: BTreeMap<String, String>,
 
     // Chain of errors, to allow returning multiple errors in a single
     // [FsResult]:
@@ -38,6 +41,7 @@ impl Debug for FsError {
             .field("code", &self.code)
             .field("location", &self.location)
             .field("context", &self.context)
+            .field("context_kv", &self.context_kv)
             .field("cause", &self.cause)
             .finish()
     }
@@ -110,6 +114,7 @@ impl FsError {
             context: context.into(),
             cause: None,
             backtrace: Backtrace::capture(),
+            context_kv: BTreeMap::new(),
             next: None,
         }
     }
@@ -121,6 +126,7 @@ impl FsError {
             context: context.into(),
             cause: None,
             backtrace: Backtrace::force_capture(),
+            context_kv: BTreeMap::new(),
             next: None,
         }
     }
@@ -136,6 +142,7 @@ impl FsError {
             context: context.into(),
             cause: None,
             backtrace,
+            context_kv: BTreeMap::new(),
             next: None,
         }
     }
@@ -173,6 +180,7 @@ impl FsError {
                     context,
                     cause,
                     backtrace: err.backtrace,
+                    context_kv: BTreeMap::new(),
                     next: None,
                 }
             })
@@ -219,6 +227,12 @@ impl FsError {
         s
     }
 
+    /// True if this error was caused by a user-initiated cancellation, as
+    /// opposed to a genuine failure.
+    pub fn is_cancelled(&self) -> bool {
+        self.code == ErrorCode::OperationCanceled
+    }
+
     /// True if this error contains multiple errors.
     pub fn is_multiple_errors(&self) -> bool {
         self.next.is_some()
@@ -370,6 +384,21 @@ impl FsError {
         }
     }
 
+    /// Attaches a structured key/value pair to this error, e.g. the `node_id`
+    /// or file being processed when the error occurred.
+    ///
+    /// Pairs accumulate as the error propagates; if the same key is attached
+    /// more than once, the outermost (last) value wins.
+    pub fn with_context_kv(mut self, key: &str, value: impl Into<String>) -> Self {
+        self.context_kv.insert(key.to_string(), value.into());
+        self
+    }
+
+    /// Returns the structured key/value pairs attached to this error.
+    pub fn context_kv(&self) -> &BTreeMap<String, String> {
+        &self.context_kv
+    }
+
     pub fn with_code(self, code: ErrorCode) -> Self {
         FsError { code, ..self }
     }
@@ -1221,3 +1250,49 @@ mod private {
 
     impl<T> Sealed for Result<T, io::Error> {}
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_with_context_kv_accumulates() {
+        let err = FsError::new(ErrorCode::Generic, "boom")
+            .with_context_kv("file", "models/a.sql")
+            .with_context_kv("node_id", "model.pkg.a");
+        let kv = err.context_kv();
+        assert_eq!(kv.len(), 2);
+        assert_eq!(kv["file"], "models/a.sql");
+        assert_eq!(kv["node_id"], "model.pkg.a");
+    }
+
+    #[test]
+    fn test_with_context_kv_outermost_wins() {
+        let res: FsResult<()> = Err(Box::new(
+            FsError::new(ErrorCode::Generic, "boom").with_context_kv("node_id", "inner"),
+        ));
+        let err = res
+            .with_context(|| ErrContext {
+                code: None,
+                location: None,
+                context: Some("while resolving".to_string()),
+            })
+            .unwrap_err();
+        let err = (*err).with_context_kv("node_id", "outer");
+        assert_eq!(err.context, "while resolving");
+        assert_eq!(err.context_kv().len(), 1);
+        assert_eq!(err.context_kv()["node_id"], "outer");
+    }
+
+    #[test]
+    fn test_from_cancelled_error() {
+        let err = FsError::from(CancelledError);
+        assert!(err.is_cancelled());
+        assert_eq!(err.code, ErrorCode::OperationCanceled);
+        assert_eq!(err.code as u16, 8995);
+
+        let err: Box<FsError> = CancelledError.into();
+        assert!(err.is_cancelled());
+        assert!(!FsError::new(ErrorCode::Generic, "boom").is_cancelled());
+    }
+}
@@ -245,6 +245,26 @@ impl Dialect {
         }
     }
 
+    /// Applies this dialect's case folding for unquoted identifiers (e.g.
+    /// uppercasing in Snowflake). Identifiers that cannot be written unquoted
+    /// in this dialect are returned unchanged.
+    pub fn normalize_identifier(&self, ident: Identifier) -> Identifier {
+        if ident.is_empty()
+            || !ident
+                .name()
+                .chars()
+                .all(|c| self.is_valid_identifier_char(c))
+        {
+            return ident;
+        }
+        match self {
+            Dialect::Snowflake => Identifier::new(ident.to_ascii_uppercase()),
+            // BigQuery dataset and table names are case-sensitive
+            Dialect::Bigquery => ident,
+            _ => Identifier::new(ident.to_ascii_lowercase()),
+        }
+    }
+
     fn parse_identifier_partial<'input>(
         &self,
         sql: &'input str,
@@ -272,6 +272,25 @@ impl<'a> Qualified<'a> {
         dialect.into().parse_qualified_name(sql)
     }
 
+    /// Returns this name with the dialect's case folding for unquoted
+    /// identifiers applied to each part (see [Dialect::normalize_identifier]),
+    /// so that equal names are also equal under [Self::matches_exact] and can
+    /// be used as case-sensitive map keys.
+    ///
+    /// Parts still wrapped in the dialect's quote character are unquoted and
+    /// otherwise kept as written.
+    pub fn normalized(&self, dialect: Dialect) -> QualifiedName {
+        let quote = dialect.quote_char();
+        self.to_owned().map(|ident| {
+            let name = ident.name();
+            if name.len() >= 2 && name.starts_with(quote) && name.ends_with(quote) {
+                dialect.parse_identifier(name).unwrap_or(ident)
+            } else {
+                dialect.normalize_identifier(ident)
+            }
+        })
+    }
+
     /// Transform this name by applying a function to each component of the
     /// qualified name, returning the result as a new qualified name.
     pub fn map<F>(self, mut f: F) -> Qualified<'a>
@@ -786,8 +805,50 @@ impl ColumnRef {
     pub fn parse(sql: &str, dialect: impl Into<Dialect>) -> Result<Self, Box<InternalError>> {
         dialect.into().parse_column_ref(sql)
     }
+
+    /// Resolve the table of this column against the relations in scope, as
+    /// written (possibly partially qualified) in the query. Returns the unique
+    /// relation whose name matches this column's table.
+    pub fn resolve(&self, available: &[QualifiedName]) -> Result<QualifiedName, ResolveError> {
+        let mut candidates = available
+            .iter()
+            .filter(|name| name.matches_fqn(&self.table_name))
+            .cloned()
+            .collect::<Vec<_>>();
+        match candidates.len() {
+            0 => Err(ResolveError::NotFound(self.clone())),
+            1 => Ok(candidates.pop().expect("one candidate")),
+            _ => Err(ResolveError::Ambiguous(self.clone(), candidates)),
+        }
+    }
 }
 
+/// Error returned by [ColumnRef::resolve].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ResolveError {
+    /// No relation in scope matches the column's table
+    NotFound(ColumnRef),
+    /// More than one relation in scope matches the column's table
+    Ambiguous(ColumnRef, Vec<QualifiedName>),
+}
+
+impl std::fmt::Display for ResolveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ResolveError::NotFound(column) => {
+                write!(f, "Column {column} does not match any relation in scope")
+            }
+            ResolveError::Ambiguous(column, candidates) => write!(
+                f,
+                "Column {column} is ambiguous: it matches {}",
+                candidates.iter().join(", ")
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ResolveError {}
+
 impl ColumnRef {
     pub fn try_parse(
         name: &str,
@@ -879,3 +940,114 @@ impl NamedItemCollection for arrow_schema::Fields {
             .find(|(_, field)| name.matches(field.name()))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_unquoted_three_part_name() {
+        let name = QualifiedName::parse("db.schema.tbl", Dialect::Trino).unwrap();
+        assert!(name.matches_exact(&QualifiedName::full("db", "schema", "tbl")));
+
+        // Snowflake folds unquoted parts to uppercase
+        let name = QualifiedName::parse("db.schema.tbl", Dialect::Snowflake).unwrap();
+        assert!(name.matches_exact(&QualifiedName::full("DB", "SCHEMA", "TBL")));
+    }
+
+    #[test]
+    fn test_parse_quoted_parts_with_dots_and_escaped_quotes() {
+        let name =
+            QualifiedName::parse("\"My.DB\" . schema.\"Say \"\"hi\"\"\"", Dialect::Snowflake)
+                .unwrap();
+        assert!(name.catalog().unwrap().matches_exact("My.DB"));
+        assert!(name.schema().unwrap().matches_exact("SCHEMA"));
+        assert!(name.table().matches_exact("Say \"hi\""));
+
+        let name =
+            QualifiedName::parse("`my-project`.`data.set`.`t\\`bl`", Dialect::Bigquery).unwrap();
+        assert!(name.catalog().unwrap().matches_exact("my-project"));
+        assert!(name.schema().unwrap().matches_exact("data.set"));
+        assert!(name.table().matches_exact("t`bl"));
+    }
+
+    #[test]
+    fn test_parse_unterminated_quote() {
+        let err = QualifiedName::parse("db.\"schema.tbl", Dialect::Snowflake).unwrap_err();
+        assert!(
+            err.to_string().contains("Unterminated quoted identifier"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn test_column_ref_resolve_unique_match() {
+        let column = ColumnRef::try_parse("id", "db", "sch", "orders", Dialect::Snowflake).unwrap();
+        let available = [
+            QualifiedName::bare("ORDERS"),
+            QualifiedName::partial("SCH", "CUSTOMERS"),
+        ];
+        assert_eq!(column.resolve(&available), Ok(available[0].clone()));
+    }
+
+    #[test]
+    fn test_column_ref_resolve_ambiguous() {
+        let column = ColumnRef::new(FullyQualifiedName::new("db", "sch", "orders"), "id");
+        let available = [
+            QualifiedName::bare("orders"),
+            QualifiedName::full("db", "sch", "orders"),
+        ];
+        assert_eq!(
+            column.resolve(&available),
+            Err(ResolveError::Ambiguous(column.clone(), available.to_vec()))
+        );
+    }
+
+    #[test]
+    fn test_column_ref_resolve_not_found() {
+        let column = ColumnRef::new(FullyQualifiedName::new("db", "sch", "orders"), "id");
+        let available = [
+            QualifiedName::bare("customers"),
+            QualifiedName::partial("other", "orders"),
+        ];
+        let err = column.resolve(&available).unwrap_err();
+        assert_eq!(err, ResolveError::NotFound(column));
+        assert_eq!(
+            err.to_string(),
+            "Column db.sch.orders.id does not match any relation in scope"
+        );
+    }
+
+    #[test]
+    fn test_normalized_snowflake_folds_unquoted_parts() {
+        let lower = QualifiedName::full("db", "schema", "tbl").normalized(Dialect::Snowflake);
+        let upper = QualifiedName::full("DB", "Schema", "TBL").normalized(Dialect::Snowflake);
+        assert!(lower.matches_exact(&upper));
+        assert_eq!(lower.to_string(), "DB.SCHEMA.TBL");
+    }
+
+    #[test]
+    fn test_normalized_preserves_quoted_parts() {
+        let name = QualifiedName::full("\"My DB\"", "schema", "\"Order Items\"")
+            .normalized(Dialect::Snowflake);
+        assert!(name.catalog().unwrap().matches_exact("My DB"));
+        assert!(name.schema().unwrap().matches_exact("SCHEMA"));
+        assert!(name.table().matches_exact("Order Items"));
+
+        // A quoted part is not folded even if it could be written unquoted
+        let quoted = QualifiedName::bare("\"Tbl\"").normalized(Dialect::Snowflake);
+        assert!(quoted.table().matches_exact("Tbl"));
+        let unquoted = QualifiedName::bare("Tbl").normalized(Dialect::Snowflake);
+        assert!(unquoted.table().matches_exact("TBL"));
+        assert!(!quoted.matches_exact(&unquoted));
+
+        let name = QualifiedName::partial("\"Public\"", "Orders").normalized(Dialect::Postgresql);
+        assert!(name.matches_exact(&QualifiedName::partial("Public", "orders")));
+    }
+
+    #[test]
+    fn test_normalized_lowercases_for_postgres() {
+        let name = QualifiedName::partial("Public", "Orders").normalized(Dialect::Postgresql);
+        assert!(name.matches_exact(&QualifiedName::partial("public", "orders")));
+    }
+}
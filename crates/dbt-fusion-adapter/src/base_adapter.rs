@@ -1,9 +1,11 @@
 use crate::cache::RelationCache;
-use crate::metadata::{CatalogAndSchema, MetadataAdapter};
+use crate::errors::{AdapterError, AdapterErrorKind, AsyncAdapterResult};
+use crate::metadata::{self, CatalogAndSchema, MetadataAdapter};
 use crate::sql_engine::SqlEngine;
 use crate::typed_adapter::TypedBaseAdapter;
 use crate::{AdapterResponse, AdapterResult};
 
+use arrow_schema::Schema;
 use dbt_agate::AgateTable;
 use dbt_common::FsResult;
 use dbt_common::adapter::SchemaRegistry;
@@ -235,6 +237,33 @@ pub trait BaseAdapter: fmt::Display + fmt::Debug + AdapterTyping + Send + Sync {
         self.execute(state, sql, false, true, limit, None)
     }
 
+    /// Validate a query against the warehouse without executing it.
+    fn execute_dry_run(&self, _state: &State, _sql: &str) -> AdapterResult<AdapterResponse> {
+        Err(AdapterError::new(
+            AdapterErrorKind::NotSupported,
+            format!(
+                "Dry run is not supported by the {} adapter",
+                self.adapter_type()
+            ),
+        ))
+    }
+
+    /// Fetch the schemas of `relations` in parallel over a bounded
+    /// number of connections, keeping the error of every relation that
+    /// could not be introspected.
+    #[allow(clippy::type_complexity)]
+    fn get_columns_in_relations(
+        &self,
+        relations: &[Arc<dyn BaseRelation>],
+    ) -> AsyncAdapterResult<'static, HashMap<String, AdapterResult<Arc<Schema>>>> {
+        let engine = self.engine().clone();
+        metadata::get_columns_in_relations(
+            move || engine.new_connection(None),
+            relations,
+            self.cancellation_token(),
+        )
+    }
+
     /// Add Query
     ///
     /// https://github.com/dbt-labs/dbt-adapters/blob/9f39ba3d94b02eeb3aef40fe161af844e15944e4/dbt-adapters/src/dbt/adapters/sql/connections.py#L69
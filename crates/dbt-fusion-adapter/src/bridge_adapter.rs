@@ -445,6 +445,15 @@ impl BaseAdapter for BridgeAdapter {
         Ok((response, table))
     }
 
+    #[tracing::instrument(skip(self, state), level = "trace")]
+    fn execute_dry_run(&self, state: &State, sql: &str) -> AdapterResult<AdapterResponse> {
+        let mut conn = self.borrow_tlocal_connection(node_id_from_state(state))?;
+        let query_ctx =
+            query_ctx_from_state_with_sql(state, sql)?.with_desc("execute_dry_run adapter call");
+        self.typed_adapter
+            .execute_dry_run(conn.as_mut(), &query_ctx)
+    }
+
     #[tracing::instrument(skip(self, state, bindings), level = "trace")]
     fn add_query(
         &self,
@@ -1,4 +1,6 @@
+use std::fmt;
 use std::sync::Arc;
+use std::time::Duration;
 
 use dashmap::DashMap;
 use dbt_schemas::schemas::relations::{base::BaseRelation, relation_configs::BaseRelationConfig};
@@ -22,10 +24,14 @@ impl RelationCacheEntry {
         relation: Arc<dyn BaseRelation>,
         relation_config: Option<Arc<dyn BaseRelationConfig>>,
     ) -> Self {
-        let created_at = std::time::UNIX_EPOCH
-            .elapsed()
-            .map(|d| d.as_millis())
-            .unwrap_or(0);
+        Self::new_at(SystemClock.now_millis(), relation, relation_config)
+    }
+
+    fn new_at(
+        created_at: u128,
+        relation: Arc<dyn BaseRelation>,
+        relation_config: Option<Arc<dyn BaseRelationConfig>>,
+    ) -> Self {
         Self {
             created_at,
             relation,
@@ -44,6 +50,24 @@ impl RelationCacheEntry {
     }
 }
 
+/// Source of the current time for expiring cache entries
+trait Clock: fmt::Debug + Send + Sync {
+    /// Milliseconds since the unix epoch
+    fn now_millis(&self) -> u128;
+}
+
+#[derive(Debug)]
+struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now_millis(&self) -> u128 {
+        std::time::UNIX_EPOCH
+            .elapsed()
+            .map(|d| d.as_millis())
+            .unwrap_or(0)
+    }
+}
+
 #[derive(Debug, Clone, Default)]
 struct SchemaEntry {
     relations: DashMap<RelationCacheKey, RelationCacheEntry>,
@@ -80,19 +104,58 @@ pub struct RelationCache {
     // The inner key is a unique key generated from a relation's fully qualified name
     // We also differentiate using [SchemaEntry] to see what information we actually know about that schema
     schemas_and_relations: DashMap<String, SchemaEntry>,
+    // Entries older than this are treated as missing, so that the next lookup
+    // re-introspects the warehouse. `None` keeps entries until evicted.
+    ttl: Option<Duration>,
+    // Defaults to the system clock, overridden in tests
+    clock: Option<Arc<dyn Clock>>,
 }
 
 impl RelationCache {
+    /// Creates a cache whose relations and schemas expire `ttl` after they
+    /// were inserted
+    pub fn with_ttl(ttl: Duration) -> Self {
+        Self {
+            ttl: Some(ttl),
+            ..Default::default()
+        }
+    }
+
+    #[cfg(test)]
+    fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = Some(clock);
+        self
+    }
+
+    fn now(&self) -> u128 {
+        match &self.clock {
+            Some(clock) => clock.now_millis(),
+            None => SystemClock.now_millis(),
+        }
+    }
+
+    /// Whether something cached at `cached_at` is past the TTL
+    fn is_expired(&self, cached_at: u128) -> bool {
+        self.ttl
+            .is_some_and(|ttl| self.now().saturating_sub(cached_at) >= ttl.as_millis())
+    }
+
     /// Retrieves a cached entry by relation
+    ///
+    /// Expired entries are evicted and reported as missing.
     pub fn get_relation(&self, relation: &Arc<dyn BaseRelation>) -> Option<RelationCacheEntry> {
         let (schema_key, relation_key) = Self::get_relation_cache_keys(relation);
-        if let Some(schema) = self.schemas_and_relations.get(&schema_key) {
-            schema
-                .relations
-                .get(&relation_key)
-                .map(|r| r.value().clone())
-        } else {
+        let entry = self
+            .schemas_and_relations
+            .get(&schema_key)?
+            .relations
+            .get(&relation_key)
+            .map(|r| r.value().clone())?;
+        if self.is_expired(entry.created_at) {
+            self.evict(&schema_key, &relation_key);
             None
+        } else {
+            Some(entry)
         }
     }
 
@@ -103,7 +166,7 @@ impl RelationCache {
         relation_config: Option<Arc<dyn BaseRelationConfig>>,
     ) -> Option<RelationCacheEntry> {
         let (schema_key, relation_key) = Self::get_relation_cache_keys(&relation);
-        let entry = RelationCacheEntry::new(relation, relation_config);
+        let entry = RelationCacheEntry::new_at(self.now(), relation, relation_config);
         self.schemas_and_relations
             .entry(schema_key)
             .or_default()
@@ -134,21 +197,17 @@ impl RelationCache {
 
     /// Inserts a schema and its relations into the cache
     pub fn insert_schema(&self, schema: CatalogAndSchema, relations: RelationVec) {
+        let cached_at = self.now();
         let cached_relations: DashMap<_, _> = relations
             .iter()
             .map(|r| {
                 (
                     Self::get_relation_cache_key_from_relation(r),
-                    RelationCacheEntry::new(r.clone(), None),
+                    RelationCacheEntry::new_at(cached_at, r.clone(), None),
                 )
             })
             .collect();
 
-        let cached_at = std::time::UNIX_EPOCH
-            .elapsed()
-            .map(|d| d.as_millis())
-            .unwrap_or(0);
-
         self.schemas_and_relations.insert(
             schema.to_string(),
             SchemaEntry {
@@ -172,7 +231,7 @@ impl RelationCache {
     pub fn contains_full_schema_for_relation(&self, relation: &Arc<dyn BaseRelation>) -> bool {
         self.schemas_and_relations
             .get(&Self::get_schema_cache_key_from_relation(relation))
-            .map(|entry| entry.is_complete)
+            .map(|entry| entry.is_complete && !self.is_expired(entry.cached_at))
             .unwrap_or(false)
     }
 
@@ -180,7 +239,7 @@ impl RelationCache {
     pub fn contains_full_schema(&self, schema: &CatalogAndSchema) -> bool {
         self.schemas_and_relations
             .get(&schema.to_string())
-            .map(|entry| entry.is_complete)
+            .map(|entry| entry.is_complete && !self.is_expired(entry.cached_at))
             .unwrap_or(false)
     }
 
@@ -188,7 +247,11 @@ impl RelationCache {
     pub fn contains_relation(&self, relation: &Arc<dyn BaseRelation>) -> bool {
         let (schema_key, relation_key) = Self::get_relation_cache_keys(relation);
         if let Some(relation_cache) = self.schemas_and_relations.get(&schema_key) {
-            relation_cache.value().relations.contains_key(&relation_key)
+            relation_cache
+                .value()
+                .relations
+                .get(&relation_key)
+                .is_some_and(|entry| !self.is_expired(entry.created_at))
         } else {
             false
         }
@@ -355,6 +418,81 @@ mod tests {
 
     use super::*;
     use dbt_schemas::schemas::{common::ResolvedQuoting, relations::DEFAULT_RESOLVED_QUOTING};
+    use std::sync::atomic::{AtomicU64, Ordering};
+
+    #[derive(Debug, Default)]
+    struct FakeClock(AtomicU64);
+
+    impl FakeClock {
+        fn advance(&self, by: Duration) {
+            self.0.fetch_add(by.as_millis() as u64, Ordering::SeqCst);
+        }
+    }
+
+    impl Clock for FakeClock {
+        fn now_millis(&self) -> u128 {
+            self.0.load(Ordering::SeqCst) as u128
+        }
+    }
+
+    fn test_relation(identifier: &str) -> Arc<dyn BaseRelation> {
+        crate::relation_object::create_relation(
+            AdapterType::Postgres,
+            "db".to_string(),
+            "schema".to_string(),
+            Some(identifier.to_string()),
+            None,
+            DEFAULT_RESOLVED_QUOTING,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_relation_expires_after_ttl() {
+        let clock = Arc::new(FakeClock::default());
+        let cache = RelationCache::with_ttl(Duration::from_secs(60)).with_clock(clock.clone());
+        let relation = test_relation("orders");
+        cache.insert_relation(relation.clone(), None);
+
+        clock.advance(Duration::from_secs(59));
+        assert!(cache.contains_relation(&relation));
+        assert!(cache.get_relation(&relation).is_some());
+
+        clock.advance(Duration::from_secs(1));
+        assert!(!cache.contains_relation(&relation));
+        assert!(cache.get_relation(&relation).is_none());
+        assert_eq!(cache.num_relations(), 0);
+
+        // Re-introspecting refreshes the entry
+        cache.insert_relation(relation.clone(), None);
+        assert!(cache.get_relation(&relation).is_some());
+    }
+
+    #[test]
+    fn test_schema_expires_after_ttl() {
+        let clock = Arc::new(FakeClock::default());
+        let cache = RelationCache::with_ttl(Duration::from_secs(60)).with_clock(clock.clone());
+        let relation = test_relation("orders");
+        let schema = CatalogAndSchema::from(&relation);
+        cache.insert_schema(schema.clone(), vec![relation.clone()]);
+        assert!(cache.contains_full_schema(&schema));
+
+        clock.advance(Duration::from_secs(60));
+        assert!(!cache.contains_full_schema(&schema));
+        assert!(!cache.contains_full_schema_for_relation(&relation));
+        assert!(cache.get_relation(&relation).is_none());
+    }
+
+    #[test]
+    fn test_no_ttl_never_expires() {
+        let clock = Arc::new(FakeClock::default());
+        let cache = RelationCache::default().with_clock(clock.clone());
+        let relation = test_relation("orders");
+        cache.insert_relation(relation.clone(), None);
+
+        clock.advance(Duration::from_secs(60 * 60 * 24));
+        assert!(cache.get_relation(&relation).is_some());
+    }
 
     #[test]
     fn test_different_key_creation() {
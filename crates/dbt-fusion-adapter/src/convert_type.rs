@@ -1,4 +1,9 @@
 use super::AdapterType;
+use crate::base_adapter::backend_of;
+
+use arrow_schema::{DataType, Field, TimeUnit};
+use dbt_xdbc::Backend;
+use dbt_xdbc::sql::types::{SqlType, TimeZoneSpec};
 
 pub fn convert_integer_type(adapter_type: AdapterType) -> String {
     let result = match adapter_type {
@@ -84,6 +89,114 @@ pub fn convert_text_type(adapter_type: AdapterType) -> String {
     result.to_string()
 }
 
+/// Render the warehouse column type for an Arrow [DataType], e.g. for DDL
+/// generated from an Arrow schema.
+///
+/// Decimals keep their precision and scale, and timestamps with an Arrow
+/// time zone map to the adapter's time-zone-aware timestamp type.
+pub fn arrow_to_warehouse_type(data_type: &DataType, adapter_type: AdapterType) -> String {
+    let backend = backend_of(adapter_type);
+    let sql_type = match data_type {
+        // Arrow durations don't have a SQL counterpart with the same semantics
+        DataType::Duration(_) => SqlType::Interval(None),
+        _ => SqlType::from_field(backend, &Field::new("", data_type.clone(), true))
+            .map(|(sql_type, _)| sql_type)
+            .unwrap_or(SqlType::Other(data_type.to_string())),
+    };
+    let sql_type = match (backend, sql_type) {
+        // BigQuery TIMESTAMP is an absolute point in time and DATETIME is the
+        // time-zone-naive civil time.
+        (Backend::BigQuery, SqlType::Timestamp { time_zone_spec, .. }) => match time_zone_spec {
+            TimeZoneSpec::With | TimeZoneSpec::Local => SqlType::Timestamp {
+                precision: None,
+                time_zone_spec: TimeZoneSpec::Unspecified,
+            },
+            TimeZoneSpec::Without | TimeZoneSpec::Unspecified => SqlType::DateTime,
+        },
+        // NUMERIC is limited to a scale of 9 and 29 digits before the decimal point
+        (Backend::BigQuery, SqlType::Numeric(Some((p, Some(s)))))
+            if s > 9 || i16::from(p) - i16::from(s) > 29 =>
+        {
+            SqlType::BigNumeric(Some((p, Some(s))))
+        }
+        (_, sql_type) => sql_type,
+    };
+    sql_type.to_string(backend)
+}
+
+/// Inverse of [arrow_to_warehouse_type]: parse a warehouse column type and
+/// return the Arrow [DataType] it is read as.
+///
+/// Returns `None` if the type can't be parsed or has no direct Arrow
+/// counterpart (e.g. JSON, GEOGRAPHY or an unconstrained NUMERIC on
+/// PostgreSQL).  Timestamps with a time zone use `UTC` as the Arrow time zone.
+pub fn warehouse_to_arrow_type(type_str: &str, adapter_type: AdapterType) -> Option<DataType> {
+    let backend = backend_of(adapter_type);
+    let (sql_type, _nullable) = SqlType::parse(backend, type_str).ok()?;
+
+    let decimal = |p: u8, s: i8| {
+        if p <= 38 {
+            DataType::Decimal128(p, s)
+        } else {
+            DataType::Decimal256(p, s)
+        }
+    };
+    let time_unit = |precision: Option<u8>| match precision.unwrap_or(match backend {
+        Backend::Snowflake => 9,
+        _ => 6,
+    }) {
+        0 => TimeUnit::Second,
+        1..=3 => TimeUnit::Millisecond,
+        4..=6 => TimeUnit::Microsecond,
+        _ => TimeUnit::Nanosecond,
+    };
+
+    let data_type = match sql_type {
+        SqlType::Boolean => DataType::Boolean,
+        SqlType::TinyInt => DataType::Int8,
+        SqlType::SmallInt => DataType::Int16,
+        SqlType::Integer => DataType::Int32,
+        SqlType::BigInt => DataType::Int64,
+        SqlType::Real => DataType::Float32,
+        SqlType::Float(_) | SqlType::Double => DataType::Float64,
+        SqlType::Numeric(Some((p, s))) | SqlType::BigNumeric(Some((p, s))) => {
+            decimal(p, s.unwrap_or(0))
+        }
+        SqlType::Numeric(None) => match backend {
+            Backend::Snowflake => decimal(38, 0),
+            Backend::BigQuery => decimal(38, 9),
+            Backend::Databricks | Backend::DatabricksODBC => decimal(10, 0),
+            _ => return None,
+        },
+        SqlType::BigNumeric(None) => match backend {
+            Backend::BigQuery => DataType::Decimal256(76, 38),
+            _ => return None,
+        },
+        SqlType::Char(_) | SqlType::Varchar(_) | SqlType::Text | SqlType::Clob => DataType::Utf8,
+        SqlType::Binary | SqlType::Blob => DataType::Binary,
+        SqlType::Date => DataType::Date32,
+        SqlType::Time { precision, .. } => match time_unit(precision) {
+            TimeUnit::Second => DataType::Time32(TimeUnit::Second),
+            TimeUnit::Millisecond => DataType::Time32(TimeUnit::Millisecond),
+            unit => DataType::Time64(unit),
+        },
+        SqlType::Timestamp {
+            precision,
+            time_zone_spec,
+        } => {
+            let with_time_zone = match time_zone_spec {
+                TimeZoneSpec::With | TimeZoneSpec::Local => true,
+                TimeZoneSpec::Without => false,
+                TimeZoneSpec::Unspecified => backend == Backend::BigQuery,
+            };
+            DataType::Timestamp(time_unit(precision), with_time_zone.then(|| "UTC".into()))
+        }
+        SqlType::DateTime => DataType::Timestamp(TimeUnit::Microsecond, None),
+        _ => return None,
+    };
+    Some(data_type)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -178,4 +291,129 @@ mod tests {
         assert_eq!(convert_text_type(AdapterType::Snowflake), "text");
         assert_eq!(convert_text_type(AdapterType::Redshift), "text");
     }
+
+    #[test]
+    fn test_arrow_to_warehouse_type() {
+        let timestamptz = DataType::Timestamp(TimeUnit::Microsecond, Some("UTC".into()));
+        let cases = [
+            (AdapterType::Snowflake, DataType::Int64, "BIGINT"),
+            (
+                AdapterType::Snowflake,
+                DataType::Decimal128(38, 9),
+                "NUMBER(38, 9)",
+            ),
+            (
+                AdapterType::Snowflake,
+                timestamptz.clone(),
+                "TIMESTAMP_TZ(6)",
+            ),
+            (AdapterType::Bigquery, DataType::Int64, "INT64"),
+            (
+                AdapterType::Bigquery,
+                DataType::Decimal128(38, 9),
+                "NUMERIC(38, 9)",
+            ),
+            (
+                AdapterType::Bigquery,
+                DataType::Decimal128(38, 10),
+                "BIGNUMERIC(38, 10)",
+            ),
+            (
+                AdapterType::Bigquery,
+                DataType::Decimal128(38, 0),
+                "BIGNUMERIC(38, 0)",
+            ),
+            (
+                AdapterType::Bigquery,
+                DataType::Decimal128(29, 0),
+                "NUMERIC(29, 0)",
+            ),
+            (AdapterType::Bigquery, timestamptz.clone(), "TIMESTAMP"),
+            (
+                AdapterType::Bigquery,
+                DataType::Timestamp(TimeUnit::Microsecond, None),
+                "DATETIME",
+            ),
+            (AdapterType::Postgres, DataType::Int64, "BIGINT"),
+            (
+                AdapterType::Postgres,
+                DataType::Decimal128(38, 9),
+                "NUMERIC(38, 9)",
+            ),
+        ];
+        for (adapter_type, data_type, expected) in cases {
+            assert_eq!(
+                arrow_to_warehouse_type(&data_type, adapter_type),
+                expected,
+                "{adapter_type} {data_type}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_warehouse_to_arrow_type() {
+        let timestamptz = |unit| DataType::Timestamp(unit, Some("UTC".into()));
+        let cases = [
+            (AdapterType::Snowflake, "BIGINT", DataType::Int64),
+            (
+                AdapterType::Snowflake,
+                "NUMBER(38, 9)",
+                DataType::Decimal128(38, 9),
+            ),
+            (
+                AdapterType::Snowflake,
+                "TIMESTAMP_TZ",
+                timestamptz(TimeUnit::Nanosecond),
+            ),
+            (AdapterType::Bigquery, "INT64", DataType::Int64),
+            (
+                AdapterType::Bigquery,
+                "NUMERIC(38, 9)",
+                DataType::Decimal128(38, 9),
+            ),
+            (
+                AdapterType::Bigquery,
+                "TIMESTAMP",
+                timestamptz(TimeUnit::Microsecond),
+            ),
+            (
+                AdapterType::Bigquery,
+                "DATETIME",
+                DataType::Timestamp(TimeUnit::Microsecond, None),
+            ),
+        ];
+        for (adapter_type, type_str, expected) in cases {
+            assert_eq!(
+                warehouse_to_arrow_type(type_str, adapter_type),
+                Some(expected),
+                "{adapter_type} {type_str}"
+            );
+        }
+        assert_eq!(
+            warehouse_to_arrow_type("NUMERIC", AdapterType::Postgres),
+            None
+        );
+        assert_eq!(
+            warehouse_to_arrow_type("not a type(", AdapterType::Postgres),
+            None
+        );
+    }
+
+    #[test]
+    fn test_arrow_warehouse_type_roundtrip() {
+        for adapter_type in [AdapterType::Snowflake, AdapterType::Bigquery] {
+            for data_type in [
+                DataType::Int64,
+                DataType::Decimal128(38, 9),
+                DataType::Timestamp(TimeUnit::Microsecond, Some("UTC".into())),
+            ] {
+                let type_str = arrow_to_warehouse_type(&data_type, adapter_type);
+                assert_eq!(
+                    warehouse_to_arrow_type(&type_str, adapter_type),
+                    Some(data_type),
+                    "{adapter_type} {type_str}"
+                );
+            }
+        }
+    }
 }
@@ -62,6 +62,410 @@ impl SqlLiteralFormatter {
     }
 }
 
+/// Reformats SQL for display, e.g. in `--debug` output. The query log keeps
+/// the executed SQL verbatim.
+///
+/// Keywords are upper-cased and each top-level clause (`SELECT`, `FROM`,
+/// `WHERE`, joins, ...) starts on its own line, with select list items
+/// indented one level.  Quoting rules follow the [AdapterType] (e.g. backtick
+/// identifiers and backslash escapes for BigQuery and Databricks). String
+/// literals (including prefixed ones such as `r'..'` or `E'..'`), comments,
+/// dollar-quoted bodies (`$$ ... $$`) and `@` references (stages, query
+/// parameters) are kept verbatim.
+///
+/// This is best-effort and never fails: if the input can't be tokenized
+/// (unterminated strings or comments, unbalanced parentheses), it is returned
+/// unchanged.
+pub fn format_sql(sql: &str, adapter: AdapterType) -> String {
+    match tokenize_sql(sql, adapter) {
+        Some(tokens) if !tokens.is_empty() => render_sql(&tokens),
+        _ => sql.to_string(),
+    }
+}
+
+const SQL_KEYWORDS: &[&str] = &[
+    "ALL",
+    "AND",
+    "AS",
+    "ASC",
+    "BETWEEN",
+    "BY",
+    "CASE",
+    "CAST",
+    "CREATE",
+    "CROSS",
+    "DELETE",
+    "DESC",
+    "DISTINCT",
+    "ELSE",
+    "END",
+    "EXCEPT",
+    "EXISTS",
+    "FALSE",
+    "FROM",
+    "FULL",
+    "GROUP",
+    "HAVING",
+    "IF",
+    "IN",
+    "INNER",
+    "INSERT",
+    "INTERSECT",
+    "INTO",
+    "IS",
+    "JOIN",
+    "LEFT",
+    "LIKE",
+    "LIMIT",
+    "NOT",
+    "NULL",
+    "ON",
+    "OR",
+    "ORDER",
+    "OUTER",
+    "OVER",
+    "PARTITION",
+    "QUALIFY",
+    "REPLACE",
+    "RIGHT",
+    "SELECT",
+    "SET",
+    "TABLE",
+    "THEN",
+    "TRUE",
+    "UNION",
+    "UPDATE",
+    "USING",
+    "VALUES",
+    "VIEW",
+    "WHEN",
+    "WHERE",
+    "WITH",
+];
+
+/// Keywords that are also commonly used as function names, e.g. `left(x, 1)`
+const SQL_FUNCTION_KEYWORDS: &[&str] = &["CAST", "IF", "LEFT", "REPLACE", "RIGHT"];
+
+const JOIN_MODIFIERS: &[&str] = &["CROSS", "FULL", "INNER", "LEFT", "OUTER", "RIGHT"];
+
+#[derive(Debug, Clone, PartialEq)]
+enum SqlToken {
+    Word(String),
+    Quoted(String),
+    LineComment(String),
+    BlockComment(String),
+    Op(String),
+    Punct(char),
+}
+
+impl SqlToken {
+    fn is_word(&self, upper: &str) -> bool {
+        matches!(self, SqlToken::Word(w) if w.eq_ignore_ascii_case(upper))
+    }
+
+    fn is_word_in(&self, uppers: &[&str]) -> bool {
+        uppers.iter().any(|upper| self.is_word(upper))
+    }
+}
+
+fn is_sql_keyword(upper: &str) -> bool {
+    SQL_KEYWORDS.contains(&upper)
+}
+
+fn tokenize_sql(sql: &str, adapter: AdapterType) -> Option<Vec<SqlToken>> {
+    let backslash_escapes = matches!(adapter, AdapterType::Bigquery | AdapterType::Databricks);
+    let chars: Vec<char> = sql.chars().collect();
+    let text = |from: usize, to: usize| chars[from..to].iter().collect::<String>();
+    let mut tokens = Vec::new();
+    let mut depth = 0usize;
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        let next = chars.get(i + 1).copied();
+        if c.is_whitespace() {
+            i += 1;
+        } else if c == '-' && next == Some('-') {
+            let end = (i..chars.len())
+                .find(|&j| chars[j] == '\n')
+                .unwrap_or(chars.len());
+            tokens.push(SqlToken::LineComment(text(i, end).trim_end().to_string()));
+            i = end;
+        } else if c == '/' && next == Some('*') {
+            let end = (i + 2..chars.len().saturating_sub(1))
+                .find(|&j| chars[j] == '*' && chars[j + 1] == '/')?
+                + 2;
+            tokens.push(SqlToken::BlockComment(text(i, end)));
+            i = end;
+        } else if c == '\'' || c == '"' || (c == '`' && backslash_escapes) {
+            let end = quoted_end(&chars, i, backslash_escapes)?;
+            tokens.push(SqlToken::Quoted(text(i, end)));
+            i = end;
+        } else if c == '`' {
+            // Backtick quoting isn't valid for this adapter
+            return None;
+        } else if let Some(tag) = dollar_quote_tag(&chars[i..]) {
+            // Dollar-quoted bodies (e.g. function or procedure definitions)
+            // are kept verbatim, up to the matching closing tag
+            let body = i + tag.len();
+            let end = (body..=chars.len().saturating_sub(tag.len()))
+                .find(|&j| chars[j..j + tag.len()] == tag[..])?
+                + tag.len();
+            tokens.push(SqlToken::Quoted(text(i, end)));
+            i = end;
+        } else if c == '@' && next.is_some_and(|n| !n.is_whitespace()) {
+            // Stage references (`@stage/path`, `@~`, `@%table`) and query
+            // parameters (`@param`) are kept verbatim
+            let end = (i + 1..chars.len())
+                .find(|&j| chars[j].is_whitespace() || matches!(chars[j], '(' | ')' | ',' | ';'))
+                .unwrap_or(chars.len());
+            tokens.push(SqlToken::Quoted(text(i, end)));
+            i = end;
+        } else if c.is_alphanumeric() || c == '_' || c == '$' {
+            let end = (i..chars.len())
+                .find(|&j| !(chars[j].is_alphanumeric() || chars[j] == '_' || chars[j] == '$'))
+                .unwrap_or(chars.len());
+            let prefix = text(i, end);
+            if let Some(quote @ ('\'' | '"')) = chars.get(end).copied()
+                && is_string_prefix(&prefix)
+            {
+                // Prefixed string literals (`r'..'`, `b"..."`, `E'..'`, `N'..'`)
+                let escapes =
+                    backslash_escapes || (quote == '\'' && prefix.eq_ignore_ascii_case("e"));
+                let end = quoted_end(&chars, end, escapes)?;
+                tokens.push(SqlToken::Quoted(text(i, end)));
+                i = end;
+                continue;
+            }
+            tokens.push(SqlToken::Word(prefix));
+            i = end;
+        } else if matches!(c, '(' | ')' | ',' | '.' | ';') {
+            match c {
+                '(' => depth += 1,
+                ')' => depth = depth.checked_sub(1)?,
+                _ => {}
+            }
+            tokens.push(SqlToken::Punct(c));
+            i += 1;
+        } else {
+            let is_op = |j: usize| {
+                "=<>!+-*/%|&^:~".contains(chars[j])
+                    && !(chars[j] == '-' && chars.get(j + 1) == Some(&'-'))
+                    && !(chars[j] == '/' && chars.get(j + 1) == Some(&'*'))
+            };
+            let end = if is_op(i) {
+                (i..chars.len()).find(|&j| !is_op(j)).unwrap_or(chars.len())
+            } else {
+                i + 1
+            };
+            tokens.push(SqlToken::Op(text(i, end)));
+            i = end;
+        }
+    }
+
+    (depth == 0).then_some(tokens)
+}
+
+/// The end (exclusive) of the quoted string or identifier starting at
+/// `chars[start]`, or `None` if it is unterminated.
+fn quoted_end(chars: &[char], start: usize, backslash_escapes: bool) -> Option<usize> {
+    let quote = chars[start];
+    let mut j = start + 1;
+    loop {
+        let ch = *chars.get(j)?;
+        let escaped =
+            (backslash_escapes && ch == '\\') || (ch == quote && chars.get(j + 1) == Some(&quote));
+        if escaped {
+            j += 2;
+        } else if ch == quote {
+            return Some(j + 1);
+        } else {
+            j += 1;
+        }
+    }
+}
+
+/// Whether `word` directly followed by a quote is a string literal prefix:
+/// raw and bytes (`r`, `b`, `rb`, `br`), escape (`E`), national (`N`) and
+/// hex (`X`) strings.
+fn is_string_prefix(word: &str) -> bool {
+    matches!(
+        word.to_ascii_lowercase().as_str(),
+        "r" | "b" | "rb" | "br" | "e" | "n" | "x"
+    )
+}
+
+/// The opening tag of a dollar-quoted string (`$$` or `$tag$`) at the start
+/// of `chars`, if any. Positional parameters such as `$1` are not tags.
+fn dollar_quote_tag(chars: &[char]) -> Option<&[char]> {
+    if chars.first() != Some(&'$') {
+        return None;
+    }
+    let close = chars[1..].iter().position(|&c| c == '$')? + 1;
+    let name = &chars[1..close];
+    let valid_name = name.first().is_none_or(|&c| c.is_alphabetic() || c == '_')
+        && name.iter().all(|&c| c.is_alphanumeric() || c == '_');
+    valid_name.then(|| &chars[..=close])
+}
+
+fn starts_sql_clause(upper: &str, prev: Option<&SqlToken>, next: Option<&SqlToken>) -> bool {
+    match upper {
+        "SELECT" | "FROM" | "WHERE" | "HAVING" | "LIMIT" | "QUALIFY" | "WITH" | "UNION"
+        | "EXCEPT" | "INTERSECT" => true,
+        "GROUP" | "ORDER" => next.is_some_and(|t| t.is_word("BY")),
+        "LEFT" | "RIGHT" | "INNER" | "FULL" | "CROSS" => {
+            next.is_some_and(|t| t.is_word("JOIN") || t.is_word("OUTER"))
+        }
+        "JOIN" => !prev.is_some_and(|t| t.is_word_in(JOIN_MODIFIERS)),
+        _ => false,
+    }
+}
+
+fn needs_space(prev: Option<&SqlToken>, token: &SqlToken) -> bool {
+    match (prev, token) {
+        (None, _) => false,
+        (_, SqlToken::Punct(',' | ')' | '.' | ';')) => false,
+        (Some(SqlToken::Punct('(' | '.')), _) => false,
+        (_, SqlToken::Op(op)) if op == "::" => false,
+        (Some(SqlToken::Op(op)), _) if op == "::" => false,
+        (Some(SqlToken::Word(word)), SqlToken::Punct('(')) => !is_function_name(word),
+        (Some(SqlToken::Quoted(_)), SqlToken::Punct('(')) => false,
+        _ => true,
+    }
+}
+
+fn is_function_name(word: &str) -> bool {
+    let upper = word.to_ascii_uppercase();
+    !is_sql_keyword(&upper) || SQL_FUNCTION_KEYWORDS.contains(&upper.as_str())
+}
+
+#[derive(Default)]
+struct SqlWriter {
+    out: String,
+    line_start: bool,
+}
+
+impl SqlWriter {
+    fn newline(&mut self, indent: usize) {
+        if !self.out.is_empty() {
+            self.out.truncate(self.out.trim_end_matches(' ').len());
+            self.out.push('\n');
+        }
+        self.out.push_str(&"    ".repeat(indent));
+        self.line_start = true;
+    }
+
+    fn push(&mut self, text: &str, space_before: bool) {
+        if space_before && !self.line_start {
+            self.out.push(' ');
+        }
+        self.out.push_str(text);
+        self.line_start = false;
+    }
+}
+
+fn render_sql(tokens: &[SqlToken]) -> String {
+    struct Paren {
+        // function call arguments and window specs never break into clauses
+        inline: bool,
+        has_clause: bool,
+    }
+
+    let mut w = SqlWriter::default();
+    let mut parens: Vec<Paren> = Vec::new();
+    let mut select_list_depths: Vec<usize> = Vec::new();
+    let mut pending_select_item = false;
+    let mut prev: Option<&SqlToken> = None;
+
+    for (i, token) in tokens.iter().enumerate() {
+        let next = tokens.get(i + 1);
+        let depth = parens.len();
+        let in_select_list = select_list_depths.last() == Some(&depth);
+
+        if let SqlToken::Word(word) = token {
+            let upper = word.to_ascii_uppercase();
+            let inline = parens.last().is_some_and(|p| p.inline);
+            if !inline && starts_sql_clause(&upper, prev, next) {
+                if in_select_list {
+                    select_list_depths.pop();
+                }
+                if let Some(paren) = parens.last_mut() {
+                    paren.has_clause = true;
+                }
+                w.newline(depth);
+                w.push(&upper, false);
+                pending_select_item = upper == "SELECT";
+                if pending_select_item {
+                    select_list_depths.push(depth);
+                }
+                prev = Some(token);
+                continue;
+            }
+        }
+
+        if pending_select_item && !token.is_word_in(&["DISTINCT", "ALL"]) {
+            pending_select_item = false;
+            w.newline(depth + 1);
+        }
+
+        match token {
+            SqlToken::Word(word) => {
+                let upper = word.to_ascii_uppercase();
+                let text = if is_sql_keyword(&upper) { &upper } else { word };
+                w.push(text, needs_space(prev, token));
+            }
+            SqlToken::Quoted(text) | SqlToken::BlockComment(text) | SqlToken::Op(text) => {
+                w.push(text, needs_space(prev, token));
+            }
+            SqlToken::LineComment(text) => {
+                w.push(text, true);
+                w.newline(if in_select_list { depth + 1 } else { depth });
+            }
+            SqlToken::Punct('(') => {
+                let inline = match prev {
+                    Some(SqlToken::Word(word)) => {
+                        word.eq_ignore_ascii_case("OVER") || is_function_name(word)
+                    }
+                    Some(SqlToken::Quoted(_)) => true,
+                    _ => false,
+                };
+                w.push("(", needs_space(prev, token));
+                parens.push(Paren {
+                    inline,
+                    has_clause: false,
+                });
+            }
+            SqlToken::Punct(')') => {
+                if in_select_list {
+                    select_list_depths.pop();
+                }
+                let paren = parens.pop();
+                if paren.is_some_and(|p| p.has_clause) {
+                    w.newline(depth.saturating_sub(1));
+                }
+                w.push(")", false);
+            }
+            SqlToken::Punct(',') => {
+                w.push(",", false);
+                if in_select_list {
+                    w.newline(depth + 1);
+                }
+            }
+            SqlToken::Punct(';') => {
+                w.push(";", false);
+                select_list_depths.clear();
+                w.newline(0);
+            }
+            SqlToken::Punct(c) => {
+                w.push(&c.to_string(), needs_space(prev, token));
+            }
+        }
+        prev = Some(token);
+    }
+
+    w.out.trim_end().to_string()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -88,4 +492,93 @@ mod tests {
         assert_eq!(formatter.format_str("\\"), "'\\'");
         assert_eq!(formatter.format_str("\\'"), "'\\\\''");
     }
+
+    #[test]
+    fn test_format_sql_simple_select() {
+        let expected = "SELECT\n    id,\n    name\nFROM my_table\nWHERE id > 1\nORDER BY name";
+        for sql in [
+            "select id, name from my_table where id > 1 order by name",
+            "SELECT id,name\n  FROM   my_table\nwhere id>1 ORDER   by name\n",
+        ] {
+            assert_eq!(format_sql(sql, AdapterType::Postgres), expected);
+        }
+        // Formatting is stable
+        assert_eq!(format_sql(expected, AdapterType::Postgres), expected);
+    }
+
+    #[test]
+    fn test_format_sql_joins_and_subqueries() {
+        let sql = "select distinct o.id, count(*) as n from orders o left join (select id from customers) c on o.id = c.id group by 1";
+        assert_eq!(
+            format_sql(sql, AdapterType::Snowflake),
+            "SELECT DISTINCT\n    o.id,\n    count(*) AS n\nFROM orders o\nLEFT JOIN (\n    SELECT\n        id\n    FROM customers\n) c ON o.id = c.id\nGROUP BY 1"
+        );
+    }
+
+    #[test]
+    fn test_format_sql_adapter_quoting() {
+        let sql = "select 'it\\'s' from `proj.ds.t`";
+        assert_eq!(
+            format_sql(sql, AdapterType::Bigquery),
+            "SELECT\n    'it\\'s'\nFROM `proj.ds.t`"
+        );
+        // Backticks and backslash escapes aren't valid for Postgres
+        assert_eq!(format_sql(sql, AdapterType::Postgres), sql);
+    }
+
+    #[test]
+    fn test_format_sql_dollar_quoted_body_is_opaque() {
+        let body = "$$\nbegin\n  select 1 from t where a = 'x';\n  return 1;\nend;\n$$";
+        let sql = format!("create function f() returns int as {body} language plpgsql");
+        assert_eq!(
+            format_sql(&sql, AdapterType::Postgres),
+            format!("CREATE function f() returns int AS {body} language plpgsql")
+        );
+
+        let tagged = "$fn$ select ';' $$ $fn$";
+        let sql = format!("select {tagged} as body");
+        assert_eq!(
+            format_sql(&sql, AdapterType::Snowflake),
+            format!("SELECT\n    {tagged} AS body")
+        );
+
+        // positional parameters are not dollar quotes
+        assert_eq!(
+            format_sql("select $1, $2 from t", AdapterType::Postgres),
+            "SELECT\n    $1,\n    $2\nFROM t"
+        );
+    }
+
+    #[test]
+    fn test_format_sql_prefixed_strings_and_references_are_opaque() {
+        assert_eq!(
+            format_sql(r#"select r'a\d+', b"x" from t"#, AdapterType::Bigquery),
+            "SELECT\n    r'a\\d+',\n    b\"x\"\nFROM t"
+        );
+        assert_eq!(
+            format_sql(r"select E'it\'s' as s", AdapterType::Postgres),
+            "SELECT\n    E'it\\'s' AS s"
+        );
+        assert_eq!(
+            format_sql(
+                "select * from @my_db.stages.s/data.csv",
+                AdapterType::Snowflake
+            ),
+            "SELECT\n    *\nFROM @my_db.stages.s/data.csv"
+        );
+    }
+
+    #[test]
+    fn test_format_sql_malformed_is_unchanged() {
+        for sql in [
+            "select 'unterminated from t",
+            "select (a from t",
+            "select a) from t",
+            "select a /* unterminated comment",
+            "select $$ unterminated body",
+            "",
+        ] {
+            assert_eq!(format_sql(sql, AdapterType::Postgres), sql);
+        }
+    }
 }
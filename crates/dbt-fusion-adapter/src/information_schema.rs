@@ -1,5 +1,10 @@
+use crate::AdapterResult;
+use crate::errors::{AdapterError, AdapterErrorKind};
 use crate::relation_object::RelationObject;
 
+use arrow::array::{Array, ArrayRef, BooleanArray, Int64Array, RecordBatch, StringArray};
+use arrow::compute::cast;
+use arrow_schema::DataType;
 use dbt_common::FsResult;
 use dbt_schemas::{
     dbt_types::RelationType,
@@ -35,6 +40,138 @@ impl InformationSchema {
     }
 }
 
+/// A column of a relation as described by `INFORMATION_SCHEMA.COLUMNS`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ColumnInfo {
+    pub name: String,
+    pub data_type: String,
+    pub is_nullable: bool,
+    /// 1-based position of the column within the relation
+    pub ordinal: usize,
+}
+
+impl ColumnInfo {
+    /// Parse the result of a `list_columns` query into [ColumnInfo]s ordered
+    /// by ordinal position.
+    ///
+    /// The batch must carry `column_name`, `data_type`, `is_nullable` and
+    /// `ordinal_position` columns (matched case-insensitively since some
+    /// warehouses upper-case them). A null `is_nullable` is treated as
+    /// nullable.
+    pub fn from_record_batch(record_batch: &RecordBatch) -> AdapterResult<Vec<ColumnInfo>> {
+        let names = string_column(record_batch, "column_name")?;
+        let data_types = string_column(record_batch, "data_type")?;
+        let nullables = parse_is_nullable(&column(record_batch, "is_nullable")?)?;
+        let ordinals = cast(&column(record_batch, "ordinal_position")?, &DataType::Int64)
+            .map_err(|e| AdapterError::new(AdapterErrorKind::Internal, e.to_string()))?;
+        let ordinals = ordinals.as_any().downcast_ref::<Int64Array>().unwrap();
+
+        let mut columns = (0..record_batch.num_rows())
+            .map(|i| {
+                if names.is_null(i) || ordinals.is_null(i) {
+                    return Err(AdapterError::new(
+                        AdapterErrorKind::Internal,
+                        format!("missing column name or ordinal position in row {i}"),
+                    ));
+                }
+                let ordinal = usize::try_from(ordinals.value(i)).map_err(|_| {
+                    AdapterError::new(
+                        AdapterErrorKind::Internal,
+                        format!("invalid ordinal position {} in row {i}", ordinals.value(i)),
+                    )
+                })?;
+                Ok(ColumnInfo {
+                    name: names.value(i).to_string(),
+                    data_type: if data_types.is_null(i) {
+                        String::new()
+                    } else {
+                        data_types.value(i).to_string()
+                    },
+                    is_nullable: nullables[i],
+                    ordinal,
+                })
+            })
+            .collect::<AdapterResult<Vec<_>>>()?;
+        columns.sort_by_key(|c| c.ordinal);
+        Ok(columns)
+    }
+}
+
+fn column(record_batch: &RecordBatch, name: &str) -> AdapterResult<ArrayRef> {
+    let schema = record_batch.schema();
+    let index = schema
+        .fields()
+        .iter()
+        .position(|f| f.name().eq_ignore_ascii_case(name))
+        .ok_or_else(|| {
+            AdapterError::new(
+                AdapterErrorKind::Internal,
+                format!("column '{name}' not found in information schema result"),
+            )
+        })?;
+    Ok(record_batch.column(index).clone())
+}
+
+fn string_column(record_batch: &RecordBatch, name: &str) -> AdapterResult<StringArray> {
+    let array = cast(&column(record_batch, name)?, &DataType::Utf8)
+        .map_err(|e| AdapterError::new(AdapterErrorKind::Internal, e.to_string()))?;
+    Ok(array
+        .as_any()
+        .downcast_ref::<StringArray>()
+        .unwrap()
+        .clone())
+}
+
+/// `is_nullable` is a `YES`/`NO` string in the SQL standard, but some
+/// drivers hand back a boolean instead.
+fn parse_is_nullable(array: &ArrayRef) -> AdapterResult<Vec<bool>> {
+    if let Some(bools) = array.as_any().downcast_ref::<BooleanArray>() {
+        return Ok((0..bools.len())
+            .map(|row| bools.is_null(row) || bools.value(row))
+            .collect());
+    }
+    let strings = cast(array, &DataType::Utf8)
+        .map_err(|e| AdapterError::new(AdapterErrorKind::Internal, e.to_string()))?;
+    let strings = strings.as_any().downcast_ref::<StringArray>().unwrap();
+    strings
+        .iter()
+        .enumerate()
+        .map(|(row, value)| {
+            let Some(value) = value else {
+                return Ok(true);
+            };
+            match value.trim().to_ascii_uppercase().as_str() {
+                "YES" | "Y" | "TRUE" => Ok(true),
+                "NO" | "N" | "FALSE" => Ok(false),
+                other => Err(AdapterError::new(
+                    AdapterErrorKind::Internal,
+                    format!("unexpected is_nullable value '{other}' in row {row}"),
+                )),
+            }
+        })
+        .collect()
+}
+
+/// The `INFORMATION_SCHEMA.COLUMNS` query behind `list_columns`, whose
+/// result [ColumnInfo::from_record_batch] parses.
+pub fn list_columns_sql(relation: &dyn BaseRelation) -> FsResult<String> {
+    let literal = |s: String| format!("'{}'", s.replace('\'', "''"));
+    let database = relation.get_database()?;
+    let information_schema = if database.is_empty() {
+        "INFORMATION_SCHEMA".to_string()
+    } else {
+        format!("{}.INFORMATION_SCHEMA", relation.quoted(&database))
+    };
+    Ok(format!(
+        "select column_name, data_type, is_nullable, ordinal_position \
+         from {information_schema}.COLUMNS \
+         where table_schema = {} and table_name = {} \
+         order by ordinal_position",
+        literal(relation.get_schema()?),
+        literal(relation.get_identifier()?),
+    ))
+}
+
 impl BaseRelationProperties for InformationSchema {
     fn include_policy(&self) -> Policy {
         unimplemented!("InformationSchema");
@@ -133,3 +270,129 @@ impl BaseRelation for InformationSchema {
         unimplemented!("InformationSchema")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::snowflake::relation::SnowflakeRelation;
+    use arrow::array::Int32Array;
+    use arrow::datatypes::{Field, Schema};
+    use dbt_schemas::schemas::common::ResolvedQuoting;
+    use dbt_schemas::schemas::relations::base::TableFormat;
+
+    fn batch(
+        names: Vec<&str>,
+        nullable: ArrayRef,
+        ordinals: Vec<i32>,
+        upper_case: bool,
+    ) -> RecordBatch {
+        let header = |s: &str| {
+            if upper_case {
+                s.to_ascii_uppercase()
+            } else {
+                s.to_string()
+            }
+        };
+        let schema = Schema::new(vec![
+            Field::new(header("column_name"), DataType::Utf8, false),
+            Field::new(header("data_type"), DataType::Utf8, false),
+            Field::new(header("is_nullable"), nullable.data_type().clone(), true),
+            Field::new(header("ordinal_position"), DataType::Int32, false),
+        ]);
+        let types = vec!["TEXT"; names.len()];
+        RecordBatch::try_new(
+            Arc::new(schema),
+            vec![
+                Arc::new(StringArray::from(names)),
+                Arc::new(StringArray::from(types)),
+                nullable,
+                Arc::new(Int32Array::from(ordinals)),
+            ],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_column_info_from_record_batch() {
+        let columns = batch(
+            vec!["b", "a"],
+            Arc::new(StringArray::from(vec!["NO", "YES"])),
+            vec![2, 1],
+            false,
+        );
+        let columns = ColumnInfo::from_record_batch(&columns).unwrap();
+        assert_eq!(
+            columns,
+            vec![
+                ColumnInfo {
+                    name: "a".to_string(),
+                    data_type: "TEXT".to_string(),
+                    is_nullable: true,
+                    ordinal: 1,
+                },
+                ColumnInfo {
+                    name: "b".to_string(),
+                    data_type: "TEXT".to_string(),
+                    is_nullable: false,
+                    ordinal: 2,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_column_info_is_nullable_edge_cases() {
+        let strings = batch(
+            vec!["a", "b", "c"],
+            Arc::new(StringArray::from(vec![Some("y"), None, Some(" no ")])),
+            vec![1, 2, 3],
+            true,
+        );
+        let nullable: Vec<_> = ColumnInfo::from_record_batch(&strings)
+            .unwrap()
+            .into_iter()
+            .map(|c| c.is_nullable)
+            .collect();
+        assert_eq!(nullable, vec![true, true, false]);
+
+        let bools = batch(
+            vec!["a", "b"],
+            Arc::new(BooleanArray::from(vec![false, true])),
+            vec![1, 2],
+            false,
+        );
+        let nullable: Vec<_> = ColumnInfo::from_record_batch(&bools)
+            .unwrap()
+            .into_iter()
+            .map(|c| c.is_nullable)
+            .collect();
+        assert_eq!(nullable, vec![false, true]);
+
+        let bad = batch(
+            vec!["a"],
+            Arc::new(StringArray::from(vec!["maybe"])),
+            vec![1],
+            false,
+        );
+        assert!(ColumnInfo::from_record_batch(&bad).is_err());
+    }
+
+    #[test]
+    fn test_list_columns_sql() {
+        let relation = SnowflakeRelation::new(
+            Some("analytics".to_string()),
+            Some("dbt_o'brien".to_string()),
+            Some("orders".to_string()),
+            None,
+            TableFormat::Default,
+            ResolvedQuoting::trues(),
+        );
+        assert_eq!(
+            list_columns_sql(&relation).unwrap(),
+            "select column_name, data_type, is_nullable, ordinal_position \
+             from \"analytics\".INFORMATION_SCHEMA.COLUMNS \
+             where table_schema = 'dbt_o''brien' and table_name = 'orders' \
+             order by ordinal_position"
+        );
+    }
+}
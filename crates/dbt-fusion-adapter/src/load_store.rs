@@ -148,6 +148,7 @@ impl ResultStore {
                     .parse::<i64>()
                     .unwrap_or(0),
                 query_id: None,
+                bytes_processed: None,
             };
 
             // Call store_result directly instead of using function
@@ -334,6 +334,52 @@ pub fn create_schemas_if_not_exists(
     );
     map_reduce.run(Arc::new(catalog_schemas), token)
 }
+
+/// Fetch the schemas of `relations` in parallel with at most
+/// [MAX_CONNECTIONS] connections created by `new_connection_f`.
+///
+/// The result maps each relation (rendered with
+/// [BaseRelation::render_self_as_str]) to its schema or to the error
+/// fetching that particular relation failed with.
+#[allow(clippy::type_complexity)]
+pub fn get_columns_in_relations(
+    new_connection_f: impl Fn() -> AdapterResult<Box<dyn Connection>> + Send + Sync + 'static,
+    relations: &[Arc<dyn BaseRelation>],
+    token: CancellationToken,
+) -> AsyncAdapterResult<'static, HashMap<String, AdapterResult<Arc<Schema>>>> {
+    type Acc = HashMap<String, AdapterResult<Arc<Schema>>>;
+    let new_connection_f = move || new_connection_f().map_err(Cancellable::Error);
+
+    let map_f = |conn: &'_ mut dyn Connection,
+                 relation: &Arc<dyn BaseRelation>|
+     -> AdapterResult<Arc<Schema>> {
+        let database = relation.database_as_resolved_str()?;
+        let schema = relation.schema_as_resolved_str()?;
+        let identifier = relation.identifier_as_resolved_str()?;
+        let table_schema = conn.get_table_schema(
+            Some(database.as_str()).filter(|s| !s.is_empty()),
+            Some(schema.as_str()).filter(|s| !s.is_empty()),
+            &identifier,
+        )?;
+        Ok(Arc::new(table_schema))
+    };
+
+    let reduce_f = |acc: &mut Acc,
+                    relation: Arc<dyn BaseRelation>,
+                    schema: AdapterResult<Arc<Schema>>|
+     -> Result<(), Cancellable<AdapterError>> {
+        acc.insert(relation.render_self_as_str(), schema);
+        Ok(())
+    };
+    let map_reduce = MapReduce::new(
+        Box::new(new_connection_f),
+        Box::new(map_f),
+        Box::new(reduce_f),
+        MAX_CONNECTIONS,
+    );
+    map_reduce.run(Arc::new(relations.to_vec()), token)
+}
+
 pub fn flatten_catalog_schemas(
     catalog_schemas: &BTreeMap<String, BTreeSet<String>>,
 ) -> Vec<(String, String)> {
@@ -370,3 +416,121 @@ fn create_schema_sql(
         _ => unimplemented!("create_schema_sql for adapter type: {}", adapter_type),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use arrow_schema::{DataType, Field};
+    use dbt_common::cancellation::never_cancels;
+    use dbt_schemas::schemas::common::ResolvedQuoting;
+    use std::sync::Mutex;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::{Duration, Instant};
+
+    /// Tracks how many `get_table_schema` calls are in flight
+    #[derive(Default)]
+    struct InFlight {
+        current: AtomicUsize,
+        peak: AtomicUsize,
+        fetched: Mutex<Vec<String>>,
+    }
+
+    struct FakeConnection(Arc<InFlight>);
+
+    impl Connection for FakeConnection {
+        fn new_statement(&mut self) -> adbc_core::error::Result<Box<dyn dbt_xdbc::Statement>> {
+            unimplemented!()
+        }
+        fn cancel(&mut self) -> adbc_core::error::Result<()> {
+            Ok(())
+        }
+        fn commit(&mut self) -> adbc_core::error::Result<()> {
+            Ok(())
+        }
+        fn rollback(&mut self) -> adbc_core::error::Result<()> {
+            Ok(())
+        }
+        fn get_table_schema(
+            &self,
+            _catalog: Option<&str>,
+            db_schema: Option<&str>,
+            table_name: &str,
+        ) -> adbc_core::error::Result<Schema> {
+            let in_flight = &self.0;
+            let current = in_flight.current.fetch_add(1, Ordering::SeqCst) + 1;
+            in_flight.peak.fetch_max(current, Ordering::SeqCst);
+            // Hold on to the call until a second one overlaps with it
+            let deadline = Instant::now() + Duration::from_secs(5);
+            while in_flight.peak.load(Ordering::SeqCst) < 2 && Instant::now() < deadline {
+                std::thread::sleep(Duration::from_millis(1));
+            }
+            in_flight.current.fetch_sub(1, Ordering::SeqCst);
+            in_flight
+                .fetched
+                .lock()
+                .unwrap()
+                .push(table_name.to_string());
+
+            if table_name == "missing" {
+                return Err(adbc_core::error::Error::with_message_and_status(
+                    "table does not exist",
+                    adbc_core::error::Status::NotFound,
+                ));
+            }
+            let column = format!("{}_{table_name}_id", db_schema.unwrap_or_default());
+            Ok(Schema::new(vec![Field::new(
+                column,
+                DataType::Int64,
+                false,
+            )]))
+        }
+    }
+
+    fn relation(identifier: &str) -> Arc<dyn BaseRelation> {
+        create_relation_internal(
+            AdapterType::Postgres,
+            "db".to_string(),
+            "analytics".to_string(),
+            Some(identifier.to_string()),
+            None,
+            ResolvedQuoting::falses(),
+        )
+        .unwrap()
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn test_get_columns_in_relations_in_parallel() {
+        let in_flight = Arc::new(InFlight::default());
+        let in_flight_clone = in_flight.clone();
+        let new_connection_f = move || -> AdapterResult<Box<dyn Connection>> {
+            Ok(Box::new(FakeConnection(in_flight_clone.clone())))
+        };
+        let relations = ["orders", "customers", "missing", "payments"]
+            .into_iter()
+            .map(relation)
+            .collect::<Vec<_>>();
+
+        let schemas = get_columns_in_relations(new_connection_f, &relations, never_cancels())
+            .await
+            .unwrap();
+
+        assert!(in_flight.peak.load(Ordering::SeqCst) >= 2);
+        assert_eq!(in_flight.fetched.lock().unwrap().len(), relations.len());
+        assert_eq!(schemas.len(), relations.len());
+        for relation in &relations {
+            let identifier = relation.identifier_as_resolved_str().unwrap();
+            let schema = &schemas[&relation.render_self_as_str()];
+            if identifier == "missing" {
+                let err = schema.as_ref().unwrap_err();
+                assert!(err.to_string().contains("table does not exist"), "{err}");
+            } else {
+                let schema = schema.as_ref().unwrap();
+                assert_eq!(
+                    schema.field(0).name(),
+                    &format!("analytics_{identifier}_id")
+                );
+            }
+        }
+    }
+}
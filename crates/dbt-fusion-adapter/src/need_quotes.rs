@@ -1,6 +1,7 @@
 use dbt_xdbc::Backend;
 
-use crate::reserved_keywords::is_keyword_ignore_ascii_case;
+use crate::base_adapter::{AdapterType, backend_of};
+use crate::reserved_keywords::{is_keyword_ignore_ascii_case, is_reserved_keyword};
 
 /// The character used to quote identifiers in this backend's dialect.
 pub const fn quote_char(backend: Backend) -> char {
@@ -54,8 +55,41 @@ pub fn need_quotes(backend: Backend, id: &str) -> bool {
         // therefore if the identifier contains any lowercase characters, it
         // needs to be quoted to preserve the original casing.
         || (matches!(backend, Backend::Snowflake) && id.chars().any(|c| c.is_ascii_lowercase()))
-        // In Redshift, unquoted identifiers are normalized to lowercase,
-        // therefore if the identifier contains any uppercase characters, it
-        // needs to be quoted to preserve the original casing.
-        || (matches!(backend, Backend::Redshift | Backend::RedshiftODBC) && id.chars().any(|c| c.is_ascii_uppercase()))
+        // In Postgres and Redshift, unquoted identifiers are normalized to
+        // lowercase, therefore if the identifier contains any uppercase
+        // characters, it needs to be quoted to preserve the original casing.
+        || (matches!(backend, Backend::Postgres | Backend::Redshift | Backend::RedshiftODBC) && id.chars().any(|c| c.is_ascii_uppercase()))
+}
+
+/// Returns true if the identifier has to be quoted when rendered for the
+/// given adapter: it contains characters outside the adapter's safe set,
+/// is a reserved keyword, or its casing would be folded.
+pub fn needs_quotes(identifier: &str, adapter: AdapterType) -> bool {
+    need_quotes(backend_of(adapter), identifier) || is_reserved_keyword(adapter, identifier)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_needs_quotes() {
+        use AdapterType::*;
+        // (adapter, simple lowercase, mixed case)
+        let cases = [
+            (Postgres, false, true),
+            (Snowflake, true, true),
+            (Bigquery, false, false),
+            (Databricks, false, false),
+            (Redshift, false, true),
+            (Salesforce, false, false),
+        ];
+        for (adapter, lowercase, mixed_case) in cases {
+            assert_eq!(needs_quotes("orders", adapter), lowercase, "{adapter}");
+            assert_eq!(needs_quotes("MyOrders", adapter), mixed_case, "{adapter}");
+            assert!(needs_quotes("my orders", adapter), "{adapter}");
+            assert!(needs_quotes("select", adapter), "{adapter}");
+        }
+        assert!(!needs_quotes("ORDERS", Snowflake));
+    }
 }
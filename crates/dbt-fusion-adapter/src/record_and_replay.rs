@@ -6,9 +6,12 @@ use crate::stmt_splitter::StmtSplitter;
 
 use adbc_core::error::{Error as AdbcError, Result as AdbcResult, Status as AdbcStatus};
 use adbc_core::options::{OptionStatement, OptionValue};
-use arrow::array::{RecordBatch, RecordBatchIterator, RecordBatchReader};
+use arrow::array::{
+    ArrayRef, AsArray, LargeStringArray, RecordBatch, RecordBatchIterator, RecordBatchReader,
+    StringArray,
+};
 use arrow_json::writer::LineDelimitedWriter;
-use arrow_schema::{ArrowError, Field, Schema, SchemaBuilder};
+use arrow_schema::{ArrowError, DataType, Field, Schema, SchemaBuilder};
 use dashmap::DashMap;
 use dbt_common::cancellation::CancellationToken;
 use dbt_xdbc::{Backend, Connection, QueryCtx, Statement};
@@ -39,6 +42,114 @@ static DBT_TMP_UUID_PATTERN: Lazy<Regex> = Lazy::new(|| {
     Regex::new(r"dbt_tmp_[0-9a-f]{8}_[0-9a-f]{4}_[0-9a-f]{4}_[0-9a-f]{4}_[0-9a-f]{12}").unwrap()
 });
 
+/// Mask written in place of redacted content
+pub const REDACTED: &str = "***";
+
+// Built-in patterns for credential-like key/value pairs, e.g.
+// `password = 'hunter2'`, `"token": "abc"` or `IDENTIFIED BY 'pw'`. The
+// value (group 2) is masked, the key and any quoting are kept.
+static CREDENTIAL_PATTERNS: Lazy<Vec<Regex>> = Lazy::new(|| {
+    vec![
+        Regex::new(
+            r#"(?i)(\b(?:password|passwd|pwd|secret|token|api_?key|private_?key|access_?key|client_?secret|credentials)\b"?\s*(?:=>|=|:)\s*)('(?:[^']|'')*'|"(?:[^"\\]|\\.)*"|[^\s,;)]+)"#,
+        )
+        .unwrap(),
+        Regex::new(r#"(?i)(identified\s+by\s+)('(?:[^']|'')*'|"(?:[^"\\]|\\.)*")"#).unwrap(),
+    ]
+});
+
+/// Masks secrets in recorded requests and responses before they are
+/// written to disk. Replay applies the same policy to the live request,
+/// so recordings match regardless of the actual secret values.
+///
+/// The default policy masks the values of credential-like keys such as
+/// `password = '...'` or `IDENTIFIED BY '...'`.
+#[derive(Clone, Debug)]
+pub struct RedactionPolicy {
+    /// Whether the built-in credential patterns are applied
+    builtin: bool,
+    /// Additional patterns; every match is replaced with [REDACTED]
+    patterns: Vec<Regex>,
+}
+
+impl Default for RedactionPolicy {
+    fn default() -> Self {
+        RedactionPolicy {
+            builtin: true,
+            patterns: Vec::new(),
+        }
+    }
+}
+
+impl RedactionPolicy {
+    /// A policy that leaves content untouched
+    pub fn none() -> Self {
+        RedactionPolicy {
+            builtin: false,
+            patterns: Vec::new(),
+        }
+    }
+
+    /// Adds a pattern whose matches are masked
+    pub fn with_pattern(mut self, pattern: Regex) -> Self {
+        self.patterns.push(pattern);
+        self
+    }
+
+    /// Returns `input` with every secret masked
+    pub fn redact<'a>(&self, input: &'a str) -> Cow<'a, str> {
+        let mut output = Cow::Borrowed(input);
+        if self.builtin {
+            for re in CREDENTIAL_PATTERNS.iter() {
+                if let Cow::Owned(redacted) = re.replace_all(&output, |caps: &regex::Captures| {
+                    let value = &caps[2];
+                    let quote = match value.chars().next() {
+                        Some(q @ ('\'' | '"')) => q.to_string(),
+                        _ => String::new(),
+                    };
+                    format!("{}{quote}{REDACTED}{quote}", &caps[1])
+                }) {
+                    output = Cow::Owned(redacted);
+                }
+            }
+        }
+        for re in &self.patterns {
+            if let Cow::Owned(redacted) = re.replace_all(&output, REDACTED) {
+                output = Cow::Owned(redacted);
+            }
+        }
+        output
+    }
+
+    /// Masks every string column of `batch`
+    fn redact_batch(&self, batch: &RecordBatch) -> Result<RecordBatch, ArrowError> {
+        let columns = batch
+            .columns()
+            .iter()
+            .map(|column| -> ArrayRef {
+                match column.data_type() {
+                    DataType::Utf8 => Arc::new(
+                        column
+                            .as_string::<i32>()
+                            .iter()
+                            .map(|v| v.map(|v| self.redact(v)))
+                            .collect::<StringArray>(),
+                    ),
+                    DataType::LargeUtf8 => Arc::new(
+                        column
+                            .as_string::<i64>()
+                            .iter()
+                            .map(|v| v.map(|v| self.redact(v)))
+                            .collect::<LargeStringArray>(),
+                    ),
+                    _ => column.clone(),
+                }
+            })
+            .collect::<Vec<_>>();
+        RecordBatch::try_new(batch.schema(), columns)
+    }
+}
+
 fn checksum8(input: &str) -> String {
     // NOTE: This is cleaning we need to do for our auto generated
     // schemas in tests. Note ideal it is not localized but if things
@@ -57,12 +168,13 @@ fn checksum8(input: &str) -> String {
 // be node id followed by the number of times that node id appeared in
 // queries thus far. However, for pre-compile we do not have node id
 // and only sql content that we checksum and then append to it a
-// sequence number.
-fn compute_file_name(query_ctx: &QueryCtx) -> AdbcResult<String> {
+// sequence number. The checksum is taken over the redacted sql so
+// recordings are found no matter which secrets the query carried.
+fn compute_file_name(query_ctx: &QueryCtx, redaction: &RedactionPolicy) -> AdbcResult<String> {
     let id = match query_ctx.node_id() {
         Some(node_id) => node_id,
         None => match query_ctx.sql() {
-            Some(sql) => checksum8(&sql),
+            Some(sql) => checksum8(&redaction.redact(&sql)),
             None => {
                 return Err(AdbcError::with_message_and_status(
                     "Neither node id nor sql was set in the query context",
@@ -92,6 +204,8 @@ pub struct RecordEngineInner {
     path: PathBuf,
     /// Actual (wrapped) engine
     engine: Arc<SqlEngine>,
+    /// Masks secrets before anything is written to disk
+    redaction: RedactionPolicy,
 }
 
 /// Engine used for recording db interaction; recording engine is
@@ -101,7 +215,19 @@ pub struct RecordEngine(Arc<RecordEngineInner>);
 
 impl RecordEngine {
     pub fn new(path: PathBuf, engine: Arc<SqlEngine>) -> Self {
-        let inner = RecordEngineInner { path, engine };
+        Self::with_redaction_policy(path, engine, RedactionPolicy::default())
+    }
+
+    pub fn with_redaction_policy(
+        path: PathBuf,
+        engine: Arc<SqlEngine>,
+        redaction: RedactionPolicy,
+    ) -> Self {
+        let inner = RecordEngineInner {
+            path,
+            engine,
+            redaction,
+        };
         RecordEngine(Arc::new(inner))
     }
 
@@ -261,8 +387,9 @@ impl Statement for RecordEngineStatement {
             .clone()
             .expect("query has to be set before executing a statement");
 
+        let redaction = &self.record_engine.redaction;
         let sql = match query_ctx.sql() {
-            Some(sql) => sql,
+            Some(sql) => redaction.redact(&sql).into_owned(),
             None => "none".to_string(),
         };
 
@@ -272,7 +399,7 @@ impl Statement for RecordEngineStatement {
         let path = self.record_engine.path.clone();
         create_dir_all(&path).map_err(|e| from_io_error(e, Some(&path)))?;
 
-        let file_name = compute_file_name(&query_ctx)?;
+        let file_name = compute_file_name(&query_ctx, redaction)?;
         let json_path = path.join(format!("{file_name}.json"));
         let sql_path = path.join(format!("{file_name}.sql"));
         let err_path = path.join(format!("{file_name}.err"));
@@ -285,12 +412,16 @@ impl Statement for RecordEngineStatement {
             Ok(mut reader) => {
                 let schema = reader.schema();
                 let batches: Vec<RecordBatch> = reader.by_ref().collect::<Result<_, _>>()?;
+                let redacted_batches = batches
+                    .iter()
+                    .map(|batch| redaction.redact_batch(batch))
+                    .collect::<Result<Vec<_>, _>>()?;
                 let file =
                     File::create(&json_path).map_err(|e| from_io_error(e, Some(&json_path)))?;
                 let writer = BufWriter::new(file);
                 let mut json_writer = LineDelimitedWriter::new(writer);
 
-                for batch in &batches {
+                for batch in &redacted_batches {
                     json_writer.write(batch)?;
                 }
                 json_writer.finish()?;
@@ -300,7 +431,7 @@ impl Statement for RecordEngineStatement {
                 let props = WriterProperties::builder().build();
                 let mut writer = ArrowWriter::try_new(file, schema.clone(), Some(props))
                     .map_err(from_parquet_error)?;
-                for batch in &batches {
+                for batch in &redacted_batches {
                     writer.write(batch).map_err(from_parquet_error)?;
                 }
                 writer.close().map_err(from_parquet_error)?;
@@ -314,7 +445,7 @@ impl Statement for RecordEngineStatement {
             }
             Err(err) => {
                 let err_msg = format!("{err}");
-                fs::write(&err_path, err_msg.clone())
+                fs::write(&err_path, redaction.redact(&err_msg).as_ref())
                     .map_err(|e| from_io_error(e, Some(&err_path)))?;
                 // do not create json or parquet, relay original error
                 Err(AdbcError::with_message_and_status(
@@ -376,6 +507,8 @@ struct ReplayEngineInner {
     stmt_splitter: Arc<dyn StmtSplitter>,
     /// Global CLI cancellation token
     cancellation_token: CancellationToken,
+    /// Must match the policy the recordings were made with
+    redaction: RedactionPolicy,
 }
 
 impl ReplayEngineInner {
@@ -403,10 +536,20 @@ impl ReplayEngine {
             adapter_factory,
             stmt_splitter,
             cancellation_token: token,
+            redaction: RedactionPolicy::default(),
         };
         ReplayEngine(Arc::new(inner))
     }
 
+    /// Replaces the redaction policy; must be called before any
+    /// connection is created
+    pub fn with_redaction_policy(mut self, redaction: RedactionPolicy) -> Self {
+        Arc::get_mut(&mut self.0)
+            .expect("redaction policy must be set before the replay engine is shared")
+            .redaction = redaction;
+        self
+    }
+
     pub fn new_connection(&self, node_id: Option<String>) -> AdapterResult<Box<dyn Connection>> {
         let conn = ReplayEngineConnection(self.0.clone(), node_id);
         Ok(Box::new(conn))
@@ -584,7 +727,7 @@ impl Statement for ReplayEngineStatement {
         };
 
         let path = self.replay_engine.full_path();
-        let file_name = compute_file_name(&query_ctx)?;
+        let file_name = compute_file_name(&query_ctx, &self.replay_engine.redaction)?;
         let json_path = path.join(format!("{file_name}.json"));
         let parquet_path = path.join(format!("{file_name}.parquet"));
         let sql_path = path.join(format!("{file_name}.sql"));
@@ -598,7 +741,7 @@ impl Statement for ReplayEngineStatement {
         // dbt_tmp_800c2fb4_a0ba_4708_a0b1_813316032bfb
         let record_sql =
             fs::read_to_string(&sql_path).map_err(|e| from_io_error(e, Some(&sql_path)))?;
-        if normalize_dbt_tmp_name(&record_sql) != normalize_dbt_tmp_name(&replay_sql) {
+        if !recorded_sql_matches(&record_sql, &replay_sql, &self.replay_engine.redaction) {
             panic!(
                 "Recorded query ({record_sql}) and actual query ({replay_sql}) do not match ({sql_path:?})"
             );
@@ -690,10 +833,169 @@ pub fn normalize_dbt_tmp_name(sql: &str) -> String {
         .to_string()
 }
 
+/// Whether `replay_sql` matches the recorded query, either as is or
+/// after redaction
+fn recorded_sql_matches(record_sql: &str, replay_sql: &str, redaction: &RedactionPolicy) -> bool {
+    let record_sql = normalize_dbt_tmp_name(record_sql);
+    record_sql == normalize_dbt_tmp_name(replay_sql)
+        || record_sql == normalize_dbt_tmp_name(&redaction.redact(replay_sql))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    use adbc_core::PartitionedResult;
+    use dbt_common::adapter::AdapterType;
+
+    /// Statement returning a single row holding a connection string
+    struct FakeStatement;
+
+    impl Statement for FakeStatement {
+        fn bind(&mut self, _batch: RecordBatch) -> AdbcResult<()> {
+            unimplemented!()
+        }
+        fn bind_stream(&mut self, _reader: Box<dyn RecordBatchReader + Send>) -> AdbcResult<()> {
+            unimplemented!()
+        }
+        fn execute<'a>(&'a mut self) -> AdbcResult<Box<dyn RecordBatchReader + Send + 'a>> {
+            let schema = Arc::new(Schema::new(vec![Field::new("dsn", DataType::Utf8, true)]));
+            let column: ArrayRef = Arc::new(StringArray::from(vec!["user=admin password=hunter2"]));
+            let batch = RecordBatch::try_new(schema.clone(), vec![column])?;
+            Ok(Box::new(RecordBatchIterator::new(vec![Ok(batch)], schema)))
+        }
+        fn execute_update(&mut self) -> AdbcResult<Option<i64>> {
+            unimplemented!()
+        }
+        fn execute_schema(&mut self) -> AdbcResult<Schema> {
+            unimplemented!()
+        }
+        fn execute_partitions(&mut self) -> AdbcResult<PartitionedResult> {
+            unimplemented!()
+        }
+        fn get_parameter_schema(&self) -> AdbcResult<Schema> {
+            unimplemented!()
+        }
+        fn prepare(&mut self) -> AdbcResult<()> {
+            unimplemented!()
+        }
+        fn set_sql_query(&mut self, _query: &QueryCtx) -> AdbcResult<()> {
+            Ok(())
+        }
+        fn set_substrait_plan(&mut self, _plan: &[u8]) -> AdbcResult<()> {
+            unimplemented!()
+        }
+        fn cancel(&mut self) -> AdbcResult<()> {
+            Ok(())
+        }
+        fn set_option(&mut self, _key: OptionStatement, _value: OptionValue) -> AdbcResult<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_redact_builtin_credentials() {
+        let policy = RedactionPolicy::default();
+        assert_eq!(
+            policy.redact("create user u password = 'hunter2' must_change_password = false"),
+            "create user u password = '***' must_change_password = false"
+        );
+        assert_eq!(
+            policy.redact("select tokenizer = 'simple', token = 'abc'"),
+            "select tokenizer = 'simple', token = '***'"
+        );
+        assert_eq!(
+            policy.redact("private_key: abc, api_key => 'k'"),
+            "private_key: ***, api_key => '***'"
+        );
+        assert_eq!(
+            policy.redact(r#"{"user": "u", "client_secret": "abc"}"#),
+            r#"{"user": "u", "client_secret": "***"}"#
+        );
+        assert_eq!(
+            policy.redact("alter user u identified by 'hunter2'"),
+            "alter user u identified by '***'"
+        );
+        assert_eq!(policy.redact("select 1"), "select 1");
+        assert_eq!(
+            RedactionPolicy::none().redact("password = 'hunter2'"),
+            "password = 'hunter2'"
+        );
+    }
+
+    #[test]
+    fn test_redact_configured_pattern() {
+        let policy = RedactionPolicy::none().with_pattern(Regex::new(r"sk-[0-9a-z]+").unwrap());
+        assert_eq!(policy.redact("select 'sk-abc123'"), "select '***'");
+    }
+
+    #[test]
+    fn test_recorded_password_is_masked_and_replays() {
+        let path =
+            std::env::temp_dir().join(format!("dbt_record_redaction_{}", std::process::id()));
+        let record_engine = Arc::new(RecordEngineInner {
+            path: path.clone(),
+            engine: Arc::new(SqlEngine::Mock(AdapterType::Snowflake)),
+            redaction: RedactionPolicy::default(),
+        });
+        let node_id = "model.test.redaction";
+        let sql = "create user u password = 'hunter2'";
+        let query_ctx = QueryCtx::new("test_adapter")
+            .with_node_id(node_id)
+            .with_sql(sql);
+
+        let mut stmt = RecordEngineStatement::new(record_engine.clone(), Box::new(FakeStatement));
+        stmt.set_sql_query(&query_ctx).unwrap();
+        // The live result is not redacted, only what is written to disk
+        let batches = stmt
+            .execute()
+            .unwrap()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        let value = batches[0].column(0).as_string::<i32>().value(0).to_string();
+        assert_eq!(value, "user=admin password=hunter2");
+
+        let recorded = |ext: &str| path.join(format!("{node_id}-0.{ext}"));
+        let record_sql = fs::read_to_string(recorded("sql")).unwrap();
+        assert_eq!(record_sql, "create user u password = '***'");
+        let record_json = fs::read_to_string(recorded("json")).unwrap();
+        assert!(!record_json.contains("hunter2"), "{record_json}");
+        let file = File::open(recorded("parquet")).unwrap();
+        let reader = ParquetRecordBatchReaderBuilder::try_new(file)
+            .unwrap()
+            .build()
+            .unwrap();
+        for batch in reader {
+            let batch = batch.unwrap();
+            let value = batch.column(0).as_string::<i32>().value(0);
+            assert_eq!(value, "user=admin password=***");
+        }
+
+        // Replay matches both the original and a differently-valued secret
+        let redaction = &record_engine.redaction;
+        assert!(recorded_sql_matches(&record_sql, sql, redaction));
+        assert!(recorded_sql_matches(
+            &record_sql,
+            "create user u password = 'rotated'",
+            redaction
+        ));
+        assert!(!recorded_sql_matches(
+            &record_sql,
+            "create user v password = 'hunter2'",
+            redaction
+        ));
+        // Recordings made without redaction still replay under a policy
+        assert!(recorded_sql_matches(sql, sql, redaction));
+
+        // Without a node id, recordings are keyed on the redacted sql
+        assert_eq!(
+            checksum8(&redaction.redact(sql)),
+            checksum8(&redaction.redact("create user u password = 'rotated'"))
+        );
+
+        fs::remove_dir_all(&path).unwrap();
+    }
+
     #[test]
     fn test_normalize_dbt_tmp_name() {
         // Test basic UUID replacement
@@ -3,8 +3,13 @@
 // All keywords are in uppercase and sorted so that binary search can be used
 // to check for membership.
 
+use crate::base_adapter::{AdapterType, backend_of};
+
 use dbt_xdbc::Backend;
 
+use std::collections::HashSet;
+use std::sync::LazyLock;
+
 pub fn sorted_keywords_for(backend: Backend) -> &'static [&'static str] {
     use Backend::*;
     match backend {
@@ -49,6 +54,55 @@ pub fn is_keyword_ignore_ascii_case(backend: Backend, token: &str) -> Option<&'s
         .map(|idx| sorted_keywords[idx])
 }
 
+/// Returns the set of reserved keywords of the given adapter.
+///
+/// Besides the dialect-specific keywords, this always includes the core
+/// SQL keywords reserved by every supported dialect.
+pub fn reserved_keywords(adapter: AdapterType) -> &'static HashSet<&'static str> {
+    fn keyword_set(adapter: AdapterType) -> HashSet<&'static str> {
+        COMMON_RESERVED_KEYWORDS
+            .iter()
+            .chain(sorted_keywords_for(backend_of(adapter)))
+            .copied()
+            .collect()
+    }
+    static POSTGRES: LazyLock<HashSet<&str>> = LazyLock::new(|| keyword_set(AdapterType::Postgres));
+    static SNOWFLAKE: LazyLock<HashSet<&str>> =
+        LazyLock::new(|| keyword_set(AdapterType::Snowflake));
+    static BIGQUERY: LazyLock<HashSet<&str>> = LazyLock::new(|| keyword_set(AdapterType::Bigquery));
+    static DATABRICKS: LazyLock<HashSet<&str>> =
+        LazyLock::new(|| keyword_set(AdapterType::Databricks));
+    static REDSHIFT: LazyLock<HashSet<&str>> = LazyLock::new(|| keyword_set(AdapterType::Redshift));
+    static SALESFORCE: LazyLock<HashSet<&str>> =
+        LazyLock::new(|| keyword_set(AdapterType::Salesforce));
+    match adapter {
+        AdapterType::Postgres => &POSTGRES,
+        AdapterType::Snowflake => &SNOWFLAKE,
+        AdapterType::Bigquery => &BIGQUERY,
+        AdapterType::Databricks => &DATABRICKS,
+        AdapterType::Redshift => &REDSHIFT,
+        AdapterType::Salesforce => &SALESFORCE,
+    }
+}
+
+/// Returns true if `word` is a reserved keyword of the given adapter,
+/// ignoring ASCII case.
+pub fn is_reserved_keyword(adapter: AdapterType, word: &str) -> bool {
+    let keywords = reserved_keywords(adapter);
+    if word.bytes().any(|b| b.is_ascii_lowercase()) {
+        keywords.contains(word.to_ascii_uppercase().as_str())
+    } else {
+        keywords.contains(word)
+    }
+}
+
+/// Keywords reserved by every supported dialect.
+static COMMON_RESERVED_KEYWORDS: &[&str] = &[
+    "ALL", "AND", "AS", "CASE", "CREATE", "DISTINCT", "ELSE", "END", "FROM", "GROUP", "HAVING",
+    "IN", "INTO", "IS", "JOIN", "NOT", "NULL", "ON", "OR", "ORDER", "SELECT", "THEN", "UNION",
+    "WHEN", "WHERE", "WITH",
+];
+
 static BIGQUERY_RESERVED_KEYWORDS: &[&str] = &[
     "ALL",
     "AND",
@@ -325,6 +379,11 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_common_keywords_sorted() {
+        assert_is_sorted(COMMON_RESERVED_KEYWORDS);
+    }
+
     #[test]
     fn test_bigquery_keywords_sorted() {
         assert_is_sorted(BIGQUERY_RESERVED_KEYWORDS);
@@ -382,4 +441,39 @@ mod tests {
             assert_eq!(is_kw(&not_kw), None);
         }
     }
+
+    const ALL_ADAPTERS: [AdapterType; 6] = [
+        AdapterType::Postgres,
+        AdapterType::Snowflake,
+        AdapterType::Bigquery,
+        AdapterType::Databricks,
+        AdapterType::Redshift,
+        AdapterType::Salesforce,
+    ];
+
+    #[test]
+    fn test_select_is_reserved_everywhere() {
+        for adapter in ALL_ADAPTERS {
+            assert!(is_reserved_keyword(adapter, "SELECT"), "{adapter}");
+            assert!(is_reserved_keyword(adapter, "select"), "{adapter}");
+            assert!(reserved_keywords(adapter).contains("SELECT"), "{adapter}");
+            assert!(!is_reserved_keyword(adapter, "selected"), "{adapter}");
+        }
+    }
+
+    #[test]
+    fn test_adapter_specific_keywords() {
+        for adapter in ALL_ADAPTERS {
+            let expected = matches!(adapter, AdapterType::Snowflake);
+            assert_eq!(is_reserved_keyword(adapter, "Ilike"), expected, "{adapter}");
+            let expected = matches!(adapter, AdapterType::Snowflake | AdapterType::Bigquery);
+            assert_eq!(
+                is_reserved_keyword(adapter, "qualify"),
+                expected,
+                "{adapter}"
+            );
+        }
+        assert!(is_reserved_keyword(AdapterType::Redshift, "unload"));
+        assert!(!is_reserved_keyword(AdapterType::Postgres, "unload"));
+    }
 }
@@ -1,3 +1,5 @@
+use arrow::array::RecordBatch;
+use dbt_common::adapter::AdapterType;
 use minijinja::Value;
 use minijinja::listener::RenderingEventListener;
 use minijinja::value::{Enumerator, Object};
@@ -18,6 +20,66 @@ pub struct AdapterResponse {
     pub rows_affected: i64,
     /// Query ID of executed statement, if available
     pub query_id: Option<String>,
+    /// Bytes scanned by the statement, if reported by the backend
+    pub bytes_processed: Option<i64>,
+}
+
+impl AdapterResponse {
+    /// Build the response for a statement whose last result is `batch`.
+    ///
+    /// Snowflake and BigQuery report the query id, the number of rows a DML
+    /// statement affected and (BigQuery only) the bytes processed in the
+    /// result schema metadata. Other backends leave `query_id` and
+    /// `bytes_processed` unset and `rows_affected` is the number of rows
+    /// returned.
+    pub fn from_record_batch(adapter_type: AdapterType, batch: Option<&RecordBatch>) -> Self {
+        let num_rows = batch.map_or(0, |batch| batch.num_rows()) as i64;
+        let schema = batch.map(|batch| batch.schema());
+        let metadata = |key: &str| {
+            schema
+                .as_ref()
+                .and_then(|schema| schema.metadata().get(key).cloned())
+        };
+        let (query_id, rows_affected, bytes_processed) = match adapter_type {
+            AdapterType::Snowflake => (
+                metadata(dbt_xdbc::snowflake::RESULT_QUERY_ID),
+                metadata(dbt_xdbc::snowflake::RESULT_ROWS_AFFECTED),
+                None,
+            ),
+            AdapterType::Bigquery => (
+                metadata(dbt_xdbc::bigquery::QUERY_RESULT_JOB_ID),
+                metadata(dbt_xdbc::bigquery::QUERY_RESULT_NUM_DML_AFFECTED_ROWS),
+                metadata(dbt_xdbc::bigquery::QUERY_RESULT_TOTAL_BYTES_PROCESSED),
+            ),
+            _ => (None, None, None),
+        };
+        AdapterResponse {
+            // TODO: This is hardcoded, should be derived from the sql statement?
+            message: format!("SELECT {num_rows}"),
+            // TODO: This is hardcoded, should be derived from the sql statement?
+            code: "SELECT".to_string(),
+            rows_affected: rows_affected
+                .and_then(|rows| rows.parse().ok())
+                .unwrap_or(num_rows),
+            query_id: query_id.filter(|id| !id.is_empty()),
+            bytes_processed: bytes_processed.and_then(|bytes| bytes.parse().ok()),
+        }
+    }
+}
+
+/// The leading keyword of `sql`, upper-cased; queries starting with a CTE
+/// or a parenthesis are `SELECT`s.
+pub(crate) fn statement_code(sql: &str) -> String {
+    let keyword = sql
+        .trim_start()
+        .split(|c: char| !c.is_ascii_alphabetic())
+        .next()
+        .unwrap_or_default()
+        .to_ascii_uppercase();
+    match keyword.as_str() {
+        "" | "WITH" => "SELECT".to_string(),
+        _ => keyword,
+    }
 }
 
 impl Object for AdapterResponse {
@@ -36,12 +98,19 @@ impl Object for AdapterResponse {
             "code" => Some(Value::from(self.code.clone())),
             "rows_affected" => Some(Value::from(self.rows_affected)),
             "query_id" => Some(Value::from(self.query_id.clone())),
+            "bytes_processed" => Some(Value::from(self.bytes_processed)),
             _ => None,
         }
     }
 
     fn enumerate(self: &Arc<Self>) -> Enumerator {
-        Enumerator::Str(&["message", "code", "rows_affected", "query_id"])
+        Enumerator::Str(&[
+            "message",
+            "code",
+            "rows_affected",
+            "query_id",
+            "bytes_processed",
+        ])
     }
 }
 
@@ -57,6 +126,7 @@ impl TryFrom<Value> for AdapterResponse {
                 code: "".to_string(),
                 rows_affected: 0,
                 query_id: None,
+                bytes_processed: None,
             })
         } else {
             Err(MinijinjaError::new(
@@ -127,3 +197,79 @@ impl Object for ResultObject {
         Enumerator::Str(&["table", "data", "response"])
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::array::Int32Array;
+    use arrow_schema::{DataType, Field, Schema};
+    use std::collections::HashMap;
+
+    fn batch(num_rows: i32, metadata: &[(&str, &str)]) -> RecordBatch {
+        let metadata: HashMap<String, String> = metadata
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        let schema =
+            Schema::new(vec![Field::new("id", DataType::Int32, false)]).with_metadata(metadata);
+        RecordBatch::try_new(
+            Arc::new(schema),
+            vec![Arc::new(Int32Array::from_iter_values(0..num_rows))],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_response_from_snowflake_result() {
+        let batch = batch(
+            1,
+            &[
+                (dbt_xdbc::snowflake::RESULT_QUERY_ID, "01b2-0000-abcd"),
+                (dbt_xdbc::snowflake::RESULT_ROWS_AFFECTED, "42"),
+            ],
+        );
+        let response = AdapterResponse::from_record_batch(AdapterType::Snowflake, Some(&batch));
+        assert_eq!(response.query_id.as_deref(), Some("01b2-0000-abcd"));
+        assert_eq!(response.rows_affected, 42);
+        assert_eq!(response.bytes_processed, None);
+    }
+
+    #[test]
+    fn test_response_from_bigquery_result() {
+        let batch = batch(
+            2,
+            &[
+                (dbt_xdbc::bigquery::QUERY_RESULT_JOB_ID, "job_123"),
+                (
+                    dbt_xdbc::bigquery::QUERY_RESULT_TOTAL_BYTES_PROCESSED,
+                    "1048576",
+                ),
+            ],
+        );
+        let response = AdapterResponse::from_record_batch(AdapterType::Bigquery, Some(&batch));
+        assert_eq!(response.query_id.as_deref(), Some("job_123"));
+        assert_eq!(response.bytes_processed, Some(1048576));
+        // no DML statistics: fall back to the number of rows returned
+        assert_eq!(response.rows_affected, 2);
+
+        let response = Arc::new(response);
+        assert_eq!(
+            response.get_value(&Value::from("bytes_processed")),
+            Some(Value::from(1048576))
+        );
+    }
+
+    #[test]
+    fn test_response_from_result_without_stats() {
+        // metadata is only read for the backends that set it
+        let batch = batch(3, &[(dbt_xdbc::snowflake::RESULT_QUERY_ID, "ignored")]);
+        let response = AdapterResponse::from_record_batch(AdapterType::Postgres, Some(&batch));
+        assert_eq!(response.query_id, None);
+        assert_eq!(response.bytes_processed, None);
+        assert_eq!(response.rows_affected, 3);
+
+        let response = AdapterResponse::from_record_batch(AdapterType::Snowflake, None);
+        assert_eq!(response.query_id, None);
+        assert_eq!(response.rows_affected, 0);
+    }
+}
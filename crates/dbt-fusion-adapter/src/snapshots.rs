@@ -1,6 +1,12 @@
+use crate::AdapterResult;
+use crate::errors::{AdapterError, AdapterErrorKind};
+
 use dbt_schemas::schemas::serde::StringOrArrayOfStrings;
+use minijinja::Value;
 use serde::Deserialize;
 
+use std::collections::BTreeMap;
+
 /// SnapshotStrategy
 #[derive(Clone, Debug, Deserialize)]
 pub struct SnapshotStrategy {
@@ -10,3 +16,198 @@ pub struct SnapshotStrategy {
     pub scd_id: Option<String>,
     pub hard_deletes: Option<String>,
 }
+
+/// Outcome of querying the snapshot source
+#[derive(Clone, Copy, Debug)]
+pub enum SnapshotSource<'a> {
+    /// The source returned rows; holds the source query
+    Rows(&'a str),
+    /// The source succeeded but returned no rows
+    Empty,
+    /// The source query failed
+    Failed,
+}
+
+impl SnapshotStrategy {
+    /// Whether rows absent from the source are closed out
+    pub fn invalidates_hard_deletes(&self) -> bool {
+        self.hard_deletes.as_deref() == Some("invalidate")
+    }
+
+    /// Generate the update closing out (setting `valid_to` to `now`) the
+    /// current rows of `target` whose unique key is absent from the source.
+    ///
+    /// An empty source closes out every current row. A failed source is a
+    /// no-op, so that a broken query never invalidates the whole snapshot.
+    /// Returns `None` when there is nothing to run.
+    pub fn invalidate_hard_deletes_sql(
+        &self,
+        target: &str,
+        source: SnapshotSource<'_>,
+        valid_to: &str,
+        now: &str,
+    ) -> Option<String> {
+        if !self.invalidates_hard_deletes() {
+            return None;
+        }
+        let unique_keys = self.unique_key.as_ref()?.to_strings();
+        if unique_keys.is_empty() {
+            return None;
+        }
+        let close_out =
+            format!("update {target}\nset {valid_to} = {now}\nwhere {valid_to} is null");
+        match source {
+            SnapshotSource::Failed => None,
+            SnapshotSource::Empty => Some(close_out),
+            SnapshotSource::Rows(source_sql) => {
+                let key_match = unique_keys
+                    .iter()
+                    .map(|key| format!("snapshot_source.{key} = {target}.{key}"))
+                    .collect::<Vec<_>>()
+                    .join("\n      and ");
+                Some(format!(
+                    "{close_out}\n  and not exists (\n    select 1\n    from ({source_sql}) as snapshot_source\n    where {key_match}\n  )"
+                ))
+            }
+        }
+    }
+}
+
+/// Resolve the hard-delete behavior of a snapshot from its `hard_deletes`
+/// config and the legacy `invalidate_hard_deletes` flag.
+///
+/// https://github.com/dbt-labs/dbt-adapters/blob/4467d4a65503659ede940d8d8d97f16fad9c72cb/dbt-adapters/src/dbt/adapters/base/impl.py#L1903
+pub fn hard_deletes_behavior(config: &BTreeMap<String, Value>) -> AdapterResult<String> {
+    let invalidate_hard_deletes = config.get("invalidate_hard_deletes");
+    let hard_deletes = config.get("hard_deletes");
+
+    if invalidate_hard_deletes.is_some() && hard_deletes.is_some() {
+        return Err(AdapterError::new(
+            AdapterErrorKind::Configuration,
+            "You cannot set both the invalidate_hard_deletes and hard_deletes config properties on the same snapshot.",
+        ));
+    }
+
+    // Like the Python implementation, a falsy invalidate_hard_deletes
+    // is the same as not setting it
+    if invalidate_hard_deletes.is_some_and(|v| v.is_true()) {
+        return Ok("invalidate".to_string());
+    }
+
+    match hard_deletes {
+        None => Ok("ignore".to_string()),
+        Some(val) => match val.as_str() {
+            Some("invalidate") => Ok("invalidate".to_string()),
+            Some("new_record") => Ok("new_record".to_string()),
+            Some("ignore") => Ok("ignore".to_string()),
+            Some(_) | None => Err(AdapterError::new(
+                AdapterErrorKind::Configuration,
+                "Invalid setting for property hard_deletes.",
+            )),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strategy(hard_deletes: &str, unique_key: StringOrArrayOfStrings) -> SnapshotStrategy {
+        SnapshotStrategy {
+            unique_key: Some(unique_key),
+            updated_at: Some("updated_at".to_string()),
+            row_changed: None,
+            scd_id: None,
+            hard_deletes: Some(hard_deletes.to_string()),
+        }
+    }
+
+    fn sql(strategy: &SnapshotStrategy, source: SnapshotSource<'_>) -> Option<String> {
+        strategy.invalidate_hard_deletes_sql(
+            "analytics.orders_snapshot",
+            source,
+            "dbt_valid_to",
+            "current_timestamp()",
+        )
+    }
+
+    #[test]
+    fn test_deleted_key_is_closed_out() {
+        let strategy = strategy(
+            "invalidate",
+            StringOrArrayOfStrings::ArrayOfStrings(vec!["id".to_string(), "region".to_string()]),
+        );
+        let sql = sql(&strategy, SnapshotSource::Rows("select * from raw.orders")).unwrap();
+        assert_eq!(
+            sql,
+            "update analytics.orders_snapshot
+set dbt_valid_to = current_timestamp()
+where dbt_valid_to is null
+  and not exists (
+    select 1
+    from (select * from raw.orders) as snapshot_source
+    where snapshot_source.id = analytics.orders_snapshot.id
+      and snapshot_source.region = analytics.orders_snapshot.region
+  )"
+        );
+    }
+
+    #[test]
+    fn test_empty_source_closes_everything() {
+        let strategy = strategy(
+            "invalidate",
+            StringOrArrayOfStrings::String("id".to_string()),
+        );
+        assert_eq!(
+            sql(&strategy, SnapshotSource::Empty).unwrap(),
+            "update analytics.orders_snapshot
+set dbt_valid_to = current_timestamp()
+where dbt_valid_to is null"
+        );
+    }
+
+    #[test]
+    fn test_failed_source_or_other_behavior_is_noop() {
+        let invalidate = strategy(
+            "invalidate",
+            StringOrArrayOfStrings::String("id".to_string()),
+        );
+        assert_eq!(sql(&invalidate, SnapshotSource::Failed), None);
+        let ignore = strategy("ignore", StringOrArrayOfStrings::String("id".to_string()));
+        assert_eq!(sql(&ignore, SnapshotSource::Empty), None);
+        assert_eq!(sql(&ignore, SnapshotSource::Rows("select 1 as id")), None);
+    }
+
+    #[test]
+    fn test_hard_deletes_behavior() {
+        let config = |entries: &[(&str, Value)]| {
+            entries
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.clone()))
+                .collect::<BTreeMap<_, _>>()
+        };
+        assert_eq!(hard_deletes_behavior(&config(&[])).unwrap(), "ignore");
+        assert_eq!(
+            hard_deletes_behavior(&config(&[("invalidate_hard_deletes", Value::from(true))]))
+                .unwrap(),
+            "invalidate"
+        );
+        assert_eq!(
+            hard_deletes_behavior(&config(&[("invalidate_hard_deletes", Value::from(false))]))
+                .unwrap(),
+            "ignore"
+        );
+        assert_eq!(
+            hard_deletes_behavior(&config(&[("hard_deletes", Value::from("new_record"))])).unwrap(),
+            "new_record"
+        );
+        assert!(hard_deletes_behavior(&config(&[("hard_deletes", Value::from("bogus"))])).is_err());
+        assert!(
+            hard_deletes_behavior(&config(&[
+                ("invalidate_hard_deletes", Value::from(true)),
+                ("hard_deletes", Value::from("ignore")),
+            ]))
+            .is_err()
+        );
+    }
+}
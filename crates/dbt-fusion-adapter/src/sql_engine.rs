@@ -3,15 +3,17 @@ use crate::auth::Auth;
 use crate::base_adapter::{AdapterFactory, backend_of};
 use crate::config::AdapterConfig;
 use crate::errors::{AdapterError, AdapterErrorKind, AdapterResult};
+use crate::response::{AdapterResponse, statement_code};
 use crate::stmt_splitter::StmtSplitter;
 
 use adbc_core::options::{OptionStatement, OptionValue};
-use arrow::array::RecordBatch;
-use arrow::compute::concat_batches;
-use arrow_schema::Schema;
+use arrow::array::{AsArray, RecordBatch};
+use arrow::compute::{cast, concat_batches};
+use arrow::datatypes::Int64Type;
+use arrow_schema::{DataType, Schema};
 use core::result::Result;
 use dbt_common::adapter::AdapterType;
-use dbt_common::cancellation::{Cancellable, CancellationToken, never_cancels};
+use dbt_common::cancellation::{Cancellable, CancellationToken, CancelledError, never_cancels};
 use dbt_common::constants::EXECUTING;
 use dbt_frontend_common::dialect::Dialect;
 use dbt_xdbc::semaphore::Semaphore;
@@ -318,61 +320,115 @@ impl SqlEngine {
         options: Options,
         fetch: bool,
     ) -> AdapterResult<RecordBatch> {
-        assert!(query_ctx.sql().is_some() || !options.is_empty());
-        Self::log_query_ctx_for_execution(query_ctx);
+        self.execute_attempt(query_ctx, conn, options, fetch, &mut false)
+    }
 
+    /// Execute the given SQL query or statement, retrying (up to
+    /// [RetryConfig::max_attempts] attempts, with exponential backoff) in
+    /// case of a transient error, see [is_transient_error].
+    ///
+    /// A statement that failed after being sent to the warehouse is only
+    /// retried if it is idempotent, since it may have been partially applied.
+    /// Permanent errors are returned right away. Cancellation is checked
+    /// between attempts and while waiting for the next one.
+    pub fn execute_with_retry(
+        &self,
+        query_ctx: &QueryCtx,
+        conn: &'_ mut dyn Connection,
+        options: Options,
+        fetch: bool,
+        retry: &RetryConfig,
+    ) -> AdapterResult<RecordBatch> {
         let token = self.cancellation_token();
-        let do_execute = |conn: &'_ mut dyn Connection| -> Result<
-            (Arc<Schema>, Vec<RecordBatch>),
-            Cancellable<adbc_core::error::Error>,
-        > {
-            use dbt_xdbc::statement::Statement as _;
-
-            let mut stmt = conn.new_statement()?;
-            stmt.set_sql_query(query_ctx)?;
-
-            options
-                .into_iter()
-                .try_for_each(|(key, value)| stmt.set_option(OptionStatement::Other(key), value))?;
-
-            // Make sure we don't create more statements after global cancellation.
-            token.check_cancellation()?;
+        let mut attempt = 1;
+        loop {
+            let mut executed = false;
+            match self.execute_attempt(query_ctx, conn, options.clone(), fetch, &mut executed) {
+                Ok(result) => return Ok(result),
+                Err(err)
+                    if attempt < retry.max_attempts
+                        && is_transient_error(&err)
+                        && (!executed || is_idempotent(query_ctx)) =>
+                {
+                    let backoff = retry.backoff(attempt);
+                    log::debug!(
+                        "Transient error on attempt {attempt}/{}, retrying in {backoff:?}: {err}",
+                        retry.max_attempts
+                    );
+                    sleep_unless_cancelled(&token, backoff).map_err(|_| cancelled_error())?;
+                    attempt += 1;
+                }
+                Err(err) => return Err(err),
+            }
+        }
+    }
 
-            // Track the statement so execution can be cancelled
-            // when the user Ctrl-C's the process.
-            let mut stmt = TrackedStatement::new(stmt);
+    /// Like [SqlEngine::execute_with_options], but `executed` is set once the
+    /// statement has been sent to the warehouse, so callers can tell whether
+    /// a failed statement may have had side effects.
+    fn execute_attempt(
+        &self,
+        query_ctx: &QueryCtx,
+        conn: &'_ mut dyn Connection,
+        options: Options,
+        fetch: bool,
+        executed: &mut bool,
+    ) -> AdapterResult<RecordBatch> {
+        assert!(query_ctx.sql().is_some() || !options.is_empty());
+        Self::log_query_ctx_for_execution(query_ctx);
 
-            let reader = stmt.execute()?;
-            let schema = reader.schema();
-            let mut batches = Vec::with_capacity(1);
-            if !fetch {
-                return Ok((schema, batches));
-            }
-            for res in reader {
-                let batch = res.map_err(adbc_core::error::Error::from)?;
-                batches.push(batch);
-                // Check for cancellation before processing the next batch
-                // or concatenating the batches produced so far.
-                token.check_cancellation()?;
-            }
-            Ok((schema, batches))
-        };
         let _span = span!("SqlEngine::execute");
-        let (schema, batches) = match do_execute(conn) {
+        let (schema, batches) = match self.try_execute(query_ctx, conn, options, fetch, executed) {
             Ok(res) => res,
-            Err(Cancellable::Cancelled) => {
-                let e = AdapterError::new(
-                    AdapterErrorKind::Cancelled,
-                    "SQL statement execution was cancelled",
-                );
-                return Err(e);
-            }
+            Err(Cancellable::Cancelled) => return Err(cancelled_error()),
             Err(Cancellable::Error(e)) => return Err(e.into()),
         };
         let total_batch = concat_batches(&schema, &batches)?;
         Ok(total_batch)
     }
 
+    fn try_execute(
+        &self,
+        query_ctx: &QueryCtx,
+        conn: &'_ mut dyn Connection,
+        options: Options,
+        fetch: bool,
+        executed: &mut bool,
+    ) -> Result<(Arc<Schema>, Vec<RecordBatch>), Cancellable<adbc_core::error::Error>> {
+        use dbt_xdbc::statement::Statement as _;
+
+        let token = self.cancellation_token();
+        let mut stmt = conn.new_statement()?;
+        stmt.set_sql_query(query_ctx)?;
+
+        options
+            .into_iter()
+            .try_for_each(|(key, value)| stmt.set_option(OptionStatement::Other(key), value))?;
+
+        // Make sure we don't create more statements after global cancellation.
+        token.check_cancellation()?;
+
+        // Track the statement so execution can be cancelled
+        // when the user Ctrl-C's the process.
+        let mut stmt = TrackedStatement::new(stmt).with_backend(self.backend());
+
+        *executed = true;
+        let reader = stmt.execute()?;
+        let schema = reader.schema();
+        let mut batches = Vec::with_capacity(1);
+        if !fetch {
+            return Ok((schema, batches));
+        }
+        for res in reader {
+            let batch = res.map_err(adbc_core::error::Error::from)?;
+            batches.push(batch);
+            // Check for cancellation before processing the next batch
+            // or concatenating the batches produced so far.
+            token.check_cancellation()?;
+        }
+        Ok((schema, batches))
+    }
+
     /// Format query context as we want to see it in a log file and log it in query_log
     pub fn log_query_ctx_for_execution(ctx: &QueryCtx) {
         let mut buf = String::new();
@@ -391,6 +447,10 @@ impl SqlEngine {
             None => writeln!(&mut buf, "-- desc: not provided").unwrap(),
         }
 
+        if let Some(request_id) = ctx.request_id() {
+            writeln!(&mut buf, "-- request_id: {request_id}").unwrap();
+        }
+
         if let Some(sql) = ctx.sql() {
             write!(&mut buf, "{sql}").unwrap();
             if !sql.ends_with(";") {
@@ -424,6 +484,17 @@ impl SqlEngine {
         }
     }
 
+    /// Retry policy for [SqlEngine::execute_with_retry]: the profile's
+    /// `query_retries` is the number of retries of a statement after the
+    /// first attempt. Without it, statements run once.
+    pub fn retry_config(&self) -> RetryConfig {
+        self.config("query_retries")
+            .and_then(|retries| retries.parse::<u32>().ok())
+            .map_or_else(RetryConfig::default, |retries| {
+                RetryConfig::with_max_attempts(retries.saturating_add(1))
+            })
+    }
+
     pub fn cancellation_token(&self) -> CancellationToken {
         match self {
             Self::Warehouse(actual_engine) => actual_engine.cancellation_token(),
@@ -434,44 +505,416 @@ impl SqlEngine {
     }
 }
 
-/// Execute query and retry in case of an error. Retry is done (up to
-/// the given limit) regardless of the error encountered.
+fn cancelled_error() -> AdapterError {
+    AdapterError::new(
+        AdapterErrorKind::Cancelled,
+        "SQL statement execution was cancelled",
+    )
+}
+
+/// Retry policy for [SqlEngine::execute_with_retry]. The default makes a
+/// single attempt, i.e. retries are opt-in.
+#[derive(Clone, Debug)]
+pub struct RetryConfig {
+    /// Total number of attempts, including the first one.
+    pub max_attempts: u32,
+    /// Backoff before the second attempt; doubled for every attempt after that.
+    pub initial_backoff: Duration,
+    /// Upper bound for the backoff between two attempts.
+    pub max_backoff: Duration,
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self {
+            max_attempts: 1,
+            initial_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(30),
+        }
+    }
+}
+
+impl RetryConfig {
+    /// The default backoff, with the given total number of attempts.
+    pub fn with_max_attempts(max_attempts: u32) -> Self {
+        Self {
+            max_attempts,
+            ..Default::default()
+        }
+    }
+
+    /// Backoff after the given (1-based) failed attempt: exponential, capped
+    /// at [RetryConfig::max_backoff], with up to 50% random jitter subtracted
+    /// so concurrent retries don't hit the warehouse in lockstep.
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let exp = self
+            .initial_backoff
+            .saturating_mul(2u32.saturating_pow(attempt.saturating_sub(1)))
+            .min(self.max_backoff);
+        exp.mul_f64(1.0 - rand::random_range(0.0..0.5))
+    }
+}
+
+/// SQLSTATE codes of errors that are worth retrying: connection failures,
+/// serialization failures and deadlocks, resource exhaustion and timeouts.
+const TRANSIENT_SQLSTATES: &[&str] = &[
+    "08000", "08001", "08003", "08004", "08006", "08S01", // connection exceptions
+    "40001", "40P01", // serialization failure, deadlock
+    "53000", "53300", // insufficient resources, too many connections
+    "57P03", // cannot connect now
+    "HYT00", "HYT01", // timeouts
+];
+
+/// Vendor codes drivers report for HTTP-level rate limiting and
+/// unavailability (429 Too Many Requests, 502/503/504).
+const TRANSIENT_VENDOR_CODES: &[i32] = &[429, 502, 503, 504];
+
+/// Whether the error is transient, i.e. the statement may succeed if retried.
+pub fn is_transient_error(err: &AdapterError) -> bool {
+    TRANSIENT_SQLSTATES.contains(&err.sqlstate())
+        || err
+            .vendor_code()
+            .is_some_and(|code| TRANSIENT_VENDOR_CODES.contains(&code))
+        || matches!(
+            err.kind(),
+            AdapterErrorKind::Xdbc(adbc_core::error::Status::Timeout)
+        )
+}
+
+/// Whether running the statement twice has the same effect as running it
+/// once, so it can be retried even if it failed after being sent.
+fn is_idempotent(query_ctx: &QueryCtx) -> bool {
+    query_ctx.sql().is_some_and(|sql| {
+        matches!(
+            statement_code(&sql).as_str(),
+            "SELECT" | "SHOW" | "DESCRIBE" | "DESC" | "EXPLAIN"
+        )
+    })
+}
+
+/// Sleep for the given duration, waking up early if the token is cancelled.
+fn sleep_unless_cancelled(
+    token: &CancellationToken,
+    duration: Duration,
+) -> Result<(), CancelledError> {
+    const TICK: Duration = Duration::from_millis(100);
+    let mut remaining = duration;
+    loop {
+        token.check_cancellation()?;
+        if remaining.is_zero() {
+            return Ok(());
+        }
+        let step = remaining.min(TICK);
+        thread::sleep(step);
+        remaining -= step;
+    }
+}
+
+/// Validate a query against the warehouse without running it.
+///
+/// BigQuery submits each query as a dry-run job and Snowflake plans every
+/// statement with `EXPLAIN`, so neither has side effects. Other backends have
+/// no equivalent and return [AdapterErrorKind::NotSupported].
 ///
-/// https://github.com/dbt-labs/dbt-adapters/blob/996a302fa9107369eb30d733dadfaf307023f33d/dbt-adapters/src/dbt/adapters/sql/connections.py#L84
-pub fn execute_query_with_retry(
+/// For Snowflake the response carries the planner's estimate of the bytes
+/// scanned (the `bytesAssigned` of each plan's `GlobalStats` row) in
+/// [AdapterResponse::bytes_processed]. The BigQuery driver does not expose
+/// the statistics of dry-run jobs, so no estimate is reported there.
+pub fn execute_dry_run(
     engine: Arc<SqlEngine>,
+    adapter_type: AdapterType,
     conn: &'_ mut dyn Connection,
     query_ctx: &QueryCtx,
-    retry_limit: u32,
-    options: &Options,
-    fetch: bool,
-) -> AdapterResult<RecordBatch> {
-    let mut attempt = 0;
-    let mut last_error = None;
-
-    while attempt < retry_limit {
-        match engine.execute_with_options(query_ctx, conn, options.clone(), fetch) {
-            Ok(result) => return Ok(result),
-            Err(err) => {
-                last_error = Some(err.clone());
-                thread::sleep(Duration::from_secs(1));
-                attempt += 1;
+) -> AdapterResult<AdapterResponse> {
+    let sql = query_ctx.sql().ok_or_else(|| {
+        AdapterError::new(AdapterErrorKind::Internal, "Missing query in the context")
+    })?;
+    match adapter_type {
+        AdapterType::Bigquery => {
+            let options = vec![(
+                dbt_xdbc::bigquery::QUERY_DRY_RUN.to_string(),
+                OptionValue::String("true".to_string()),
+            )];
+            engine.execute_with_options(query_ctx, conn, options, false)?;
+            let code = statement_code(&sql);
+            Ok(AdapterResponse {
+                message: format!("DRY RUN {code}"),
+                code,
+                rows_affected: 0,
+                query_id: None,
+                bytes_processed: None,
+            })
+        }
+        AdapterType::Snowflake => {
+            let mut bytes_assigned = None;
+            for statement in engine.split_statements(&sql, adapter_type.into()) {
+                let plan = engine.execute_with_options(
+                    &query_ctx.with_sql(format!("EXPLAIN {statement}")),
+                    conn,
+                    Options::new(),
+                    true,
+                )?;
+                if let Some(bytes) = explain_bytes_assigned(&plan) {
+                    *bytes_assigned.get_or_insert(0) += bytes;
+                }
             }
+            Ok(AdapterResponse {
+                message: match bytes_assigned {
+                    Some(bytes) => format!("EXPLAIN {bytes} bytes"),
+                    None => "EXPLAIN".to_string(),
+                },
+                code: "EXPLAIN".to_string(),
+                rows_affected: 0,
+                query_id: None,
+                bytes_processed: bytes_assigned,
+            })
         }
+        _ => Err(AdapterError::new(
+            AdapterErrorKind::NotSupported,
+            format!("Dry run is not supported by the {adapter_type} adapter"),
+        )),
     }
+}
 
-    if let Some(err) = last_error {
-        Err(err)
-    } else {
-        unreachable!("last_error should not be None if we exit the loop")
-    }
+/// The `bytesAssigned` of the `GlobalStats` rows of a Snowflake `EXPLAIN`
+/// plan, i.e. the bytes the statement is estimated to scan.
+fn explain_bytes_assigned(plan: &RecordBatch) -> Option<i64> {
+    let column = |name: &str| {
+        plan.schema()
+            .fields()
+            .iter()
+            .position(|field| field.name().eq_ignore_ascii_case(name))
+            .map(|i| plan.column(i).clone())
+    };
+    let operations = cast(&column("operation")?, &DataType::Utf8).ok()?;
+    let bytes = cast(&column("bytesAssigned")?, &DataType::Int64).ok()?;
+    let operations = operations.as_string_opt::<i32>()?;
+    let bytes = bytes.as_primitive_opt::<Int64Type>()?;
+    let mut stats = operations
+        .iter()
+        .zip(bytes.iter())
+        .filter(|(operation, _)| *operation == Some("GlobalStats"))
+        .map(|(_, bytes)| bytes)
+        .peekable();
+    stats.peek()?;
+    stats.sum()
 }
 
 #[cfg(test)]
 mod tests {
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use adbc_core::error::{Error as AdbcError, Status};
+    use arrow::array::{Int64Array, RecordBatch, StringArray};
+    use arrow_schema::{DataType, Field, Schema};
+    use dbt_common::adapter::AdapterType;
     use dbt_xdbc::QueryCtx;
+    use dbt_xdbc::test_utils::{IssuedStatement, MockConnection};
+
+    use super::{RetryConfig, SqlEngine, execute_dry_run, explain_bytes_assigned};
+    use crate::errors::AdapterErrorKind;
+    use crate::response::AdapterResponse;
+
+    fn dry_run(adapter_type: AdapterType, sql: &str) -> (bool, Vec<IssuedStatement>) {
+        let mut conn = MockConnection::new();
+        let query_ctx = QueryCtx::new("test_adapter").with_sql(sql);
+        let result = execute_dry_run(
+            Arc::new(SqlEngine::Mock(adapter_type)),
+            adapter_type,
+            &mut conn,
+            &query_ctx,
+        );
+        let ok = match result {
+            Ok(_) => true,
+            Err(err) => {
+                assert_eq!(err.kind(), AdapterErrorKind::NotSupported);
+                false
+            }
+        };
+        (ok, conn.issued())
+    }
+
+    const WRITES: &str = "create table t as select 1 as id; insert into t select 2";
+
+    #[test]
+    fn test_dry_run_snowflake_only_explains() {
+        let (ok, issued) = dry_run(AdapterType::Snowflake, WRITES);
+        assert!(ok);
+        assert_eq!(issued.len(), 2);
+        for IssuedStatement { sql, .. } in &issued {
+            assert!(
+                sql.starts_with("EXPLAIN "),
+                "issued a non-EXPLAIN statement: {sql}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_dry_run_bigquery_sets_dry_run_option() {
+        let (ok, issued) = dry_run(AdapterType::Bigquery, WRITES);
+        assert!(ok);
+        assert_eq!(issued.len(), 1);
+        assert!(
+            issued[0]
+                .options
+                .contains(&format!("{}=true", dbt_xdbc::bigquery::QUERY_DRY_RUN))
+        );
+    }
+
+    #[test]
+    fn test_explain_bytes_assigned() {
+        let plan = RecordBatch::try_new(
+            Arc::new(Schema::new(vec![
+                Field::new("step", DataType::Int64, true),
+                Field::new("operation", DataType::Utf8, true),
+                Field::new("bytesAssigned", DataType::Int64, true),
+            ])),
+            vec![
+                Arc::new(Int64Array::from(vec![None, Some(1), Some(1)])),
+                Arc::new(StringArray::from(vec![
+                    "GlobalStats",
+                    "Result",
+                    "TableScan",
+                ])),
+                Arc::new(Int64Array::from(vec![Some(1024), None, None])),
+            ],
+        )
+        .unwrap();
+        assert_eq!(explain_bytes_assigned(&plan), Some(1024));
+        assert_eq!(
+            explain_bytes_assigned(&RecordBatch::new_empty(Arc::new(Schema::empty()))),
+            None
+        );
+    }
+
+    #[test]
+    fn test_dry_run_unsupported_issues_nothing() {
+        let (ok, issued) = dry_run(AdapterType::Postgres, WRITES);
+        assert!(!ok);
+        assert!(issued.is_empty());
+    }
+
+    fn execute_with_retry(sql: &str, failures: Vec<AdbcError>) -> (bool, usize) {
+        let mut conn = MockConnection::new().with_failures(failures);
+        let query_ctx = QueryCtx::new("test_adapter").with_sql(sql);
+        let retry = RetryConfig {
+            max_attempts: 3,
+            initial_backoff: Duration::ZERO,
+            max_backoff: Duration::ZERO,
+        };
+        let result = SqlEngine::Mock(AdapterType::Snowflake).execute_with_retry(
+            &query_ctx,
+            &mut conn,
+            vec![],
+            true,
+            &retry,
+        );
+        (result.is_ok(), conn.issued().len())
+    }
+
+    fn error_with_sqlstate(sqlstate: &str) -> AdbcError {
+        let mut err = AdbcError::with_message_and_status("Test ADBC error", Status::IO);
+        for (dst, src) in err.sqlstate.iter_mut().zip(sqlstate.bytes()) {
+            *dst = src as std::ffi::c_char;
+        }
+        err
+    }
+
+    #[test]
+    fn test_execute_with_retry_retries_transient_errors() {
+        let mut rate_limited = AdbcError::with_message_and_status("Too Many Requests", Status::IO);
+        rate_limited.vendor_code = 429;
+        let (ok, attempts) =
+            execute_with_retry("select 1", vec![error_with_sqlstate("08006"), rate_limited]);
+        assert!(ok);
+        assert_eq!(attempts, 3);
+
+        // gives up after max_attempts
+        let deadlocks = (0..3).map(|_| error_with_sqlstate("40001")).collect();
+        let (ok, attempts) = execute_with_retry("select 1", deadlocks);
+        assert!(!ok);
+        assert_eq!(attempts, 3);
+    }
+
+    #[test]
+    fn test_execute_with_retry_fails_fast_on_permanent_errors() {
+        // syntax error
+        let (ok, attempts) = execute_with_retry("select 1", vec![error_with_sqlstate("42601")]);
+        assert!(!ok);
+        assert_eq!(attempts, 1);
+    }
+
+    #[test]
+    fn test_execute_with_retry_does_not_repeat_sent_writes() {
+        // the connection was lost after the insert was sent: it may have been applied
+        let (ok, attempts) = execute_with_retry(
+            "insert into t values (1)",
+            vec![error_with_sqlstate("08006")],
+        );
+        assert!(!ok);
+        assert_eq!(attempts, 1);
+    }
+
+    #[test]
+    fn test_query_retries_are_opt_in() {
+        let engine = SqlEngine::Mock(AdapterType::Snowflake);
+        let retry = engine.retry_config();
+        assert_eq!(retry.max_attempts, 1);
+
+        let mut conn = MockConnection::new().with_failures([error_with_sqlstate("08006")]);
+        let result = engine.execute_with_retry(
+            &QueryCtx::new("test_adapter").with_sql("select 1"),
+            &mut conn,
+            vec![],
+            true,
+            &retry,
+        );
+        assert!(result.is_err());
+        assert_eq!(conn.issued().len(), 1);
+    }
 
-    use super::SqlEngine;
+    #[test]
+    fn test_retry_backoff_is_capped() {
+        let retry = RetryConfig {
+            max_attempts: 10,
+            initial_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(5),
+        };
+        for attempt in 1..10 {
+            let backoff = retry.backoff(attempt);
+            assert!(backoff <= Duration::from_secs(5));
+            assert!(backoff >= Duration::from_millis(500));
+        }
+    }
+
+    #[test]
+    fn test_result_metadata_reaches_the_response() {
+        let schema = Schema::new(vec![Field::new("id", DataType::Int64, false)]).with_metadata(
+            [(
+                dbt_xdbc::snowflake::RESULT_QUERY_ID.to_string(),
+                "01b2-0000-abcd".to_string(),
+            )]
+            .into(),
+        );
+        let batch = RecordBatch::try_new(
+            Arc::new(schema),
+            vec![Arc::new(Int64Array::from(vec![1, 2]))],
+        )
+        .unwrap();
+        let mut conn = MockConnection::new().with_batches(vec![batch.clone(), batch]);
+        let result = SqlEngine::Mock(AdapterType::Snowflake)
+            .execute_with_options(
+                &QueryCtx::new("test_adapter").with_sql("select id from t"),
+                &mut conn,
+                vec![],
+                true,
+            )
+            .unwrap();
+        let response = AdapterResponse::from_record_batch(AdapterType::Snowflake, Some(&result));
+        assert_eq!(response.query_id.as_deref(), Some("01b2-0000-abcd"));
+        assert_eq!(response.rows_affected, 4);
+    }
 
     #[test]
     fn test_log_for_execution() {
@@ -1,15 +1,18 @@
 use core::fmt;
 use std::sync::LazyLock;
 use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Instant;
 
 use adbc_core::PartitionedResult;
 use adbc_core::error::Result;
 use adbc_core::options::{OptionStatement, OptionValue};
 use arrow::array::{RecordBatch, RecordBatchReader};
-use arrow_schema::Schema;
+use arrow::error::ArrowError;
+use arrow_schema::{Schema, SchemaRef};
 use crossbeam_skiplist::SkipMap;
 use dbt_xdbc::semaphore::AcquireAllSemaphore;
-use dbt_xdbc::{QueryCtx, Statement};
+use dbt_xdbc::{Backend, QueryCtx, Statement};
+use tracing::field::Empty;
 
 /// Generate a unique statement ID for each [TrackedStatement]
 /// by incrementing this global atomic counter.
@@ -152,10 +155,56 @@ fn unregister_stmt(id: u64) {
     }
 }
 
+/// Reader returned by [TrackedStatement::execute].
+///
+/// Counts the rows of the result set and records them on the `execute` span,
+/// together with the elapsed time, once the reader is dropped.
+struct InstrumentedReader<'a> {
+    inner: Box<dyn RecordBatchReader + Send + 'a>,
+    span: tracing::Span,
+    start: Instant,
+    rows: usize,
+}
+
+impl Iterator for InstrumentedReader<'_> {
+    type Item = std::result::Result<RecordBatch, ArrowError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let next = self.inner.next();
+        if let Some(Ok(batch)) = &next {
+            self.rows += batch.num_rows();
+            #[cfg(feature = "statement-tracing-detail")]
+            tracing::trace!(
+                parent: &self.span,
+                batch_rows = batch.num_rows(),
+                batch_bytes = batch.get_array_memory_size(),
+                "fetched record batch"
+            );
+        }
+        next
+    }
+}
+
+impl RecordBatchReader for InstrumentedReader<'_> {
+    fn schema(&self) -> SchemaRef {
+        self.inner.schema()
+    }
+}
+
+impl Drop for InstrumentedReader<'_> {
+    fn drop(&mut self) {
+        self.span.record("rows", self.rows);
+        self.span
+            .record("elapsed_ms", self.start.elapsed().as_millis() as u64);
+    }
+}
+
 #[allow(dead_code)]
 pub struct TrackedStatement {
     stmt_id: u64,
     inner_ptr: &'static mut dyn Statement,
+    /// Backend reported on the `execute` span.
+    backend: Option<Backend>,
 }
 
 impl Drop for TrackedStatement {
@@ -171,9 +220,16 @@ impl TrackedStatement {
         Self {
             inner_ptr: ptr,
             stmt_id,
+            backend: None,
         }
     }
 
+    /// Set the backend reported on the `execute` span.
+    pub fn with_backend(mut self, backend: Backend) -> Self {
+        self.backend = Some(backend);
+        self
+    }
+
     #[inline]
     fn inner(&self) -> &dyn Statement {
         self.inner_ptr
@@ -193,7 +249,33 @@ impl Statement for TrackedStatement {
         self.inner_mut().bind_stream(reader)
     }
     fn execute<'a>(&'a mut self) -> Result<Box<dyn RecordBatchReader + Send + 'a>> {
-        self.inner_mut().execute()
+        let backend = self
+            .backend
+            .map_or_else(|| "unknown".to_string(), |b| b.to_string());
+        let span = tracing::debug_span!(
+            "execute",
+            stmt_id = self.stmt_id,
+            backend = backend.as_str(),
+            rows = Empty,
+            elapsed_ms = Empty,
+        );
+        let start = Instant::now();
+        let entered = span.enter();
+        let res = self.inner_mut().execute();
+        drop(entered);
+        match res {
+            Ok(inner) => Ok(Box::new(InstrumentedReader {
+                inner,
+                span,
+                start,
+                rows: 0,
+            })),
+            Err(e) => {
+                span.record("rows", 0);
+                span.record("elapsed_ms", start.elapsed().as_millis() as u64);
+                Err(e)
+            }
+        }
     }
     fn execute_update(&mut self) -> Result<Option<i64>> {
         self.inner_mut().execute_update()
@@ -219,6 +301,9 @@ impl Statement for TrackedStatement {
     fn cancel(&mut self) -> Result<()> {
         self.inner_mut().cancel()
     }
+    fn set_catalog_schema(&mut self, catalog: &str, schema: &str) -> Result<()> {
+        self.inner_mut().set_catalog_schema(catalog, schema)
+    }
 
     // adbc_core::Optionable<Option = OptionStatement> functions -----------------------------
 
@@ -242,3 +327,73 @@ impl Statement for TrackedStatement {
         self.inner().debug_fmt(f)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::sync::{Arc, Mutex};
+
+    use arrow::array::{Int32Array, RecordBatch};
+    use arrow_schema::{DataType, Field, Schema};
+    use dbt_xdbc::test_utils::MockConnection;
+    use dbt_xdbc::{Backend, Statement};
+    use tracing::field::{Field as TracingField, Visit};
+    use tracing::span::{Attributes, Id, Record};
+    use tracing::{Subscriber, subscriber::with_default};
+    use tracing_subscriber::layer::{Context, Layer, SubscriberExt as _};
+    use tracing_subscriber::registry::Registry;
+
+    use super::TrackedStatement;
+
+    /// In-memory layer collecting the fields of `execute` spans.
+    #[derive(Default, Clone)]
+    struct RecordingLayer(Arc<Mutex<HashMap<String, String>>>);
+
+    struct FieldVisitor<'a>(&'a mut HashMap<String, String>);
+
+    impl Visit for FieldVisitor<'_> {
+        fn record_str(&mut self, field: &TracingField, value: &str) {
+            self.0.insert(field.name().to_string(), value.to_string());
+        }
+        fn record_debug(&mut self, field: &TracingField, value: &dyn std::fmt::Debug) {
+            self.0
+                .insert(field.name().to_string(), format!("{value:?}"));
+        }
+    }
+
+    impl<S: Subscriber> Layer<S> for RecordingLayer {
+        fn on_new_span(&self, attrs: &Attributes<'_>, _id: &Id, _ctx: Context<'_, S>) {
+            if attrs.metadata().name() == "execute" {
+                attrs.record(&mut FieldVisitor(&mut self.0.lock().unwrap()));
+            }
+        }
+        fn on_record(&self, _id: &Id, values: &Record<'_>, _ctx: Context<'_, S>) {
+            values.record(&mut FieldVisitor(&mut self.0.lock().unwrap()));
+        }
+    }
+
+    #[test]
+    fn test_execute_span_attributes() {
+        let layer = RecordingLayer::default();
+        let subscriber = Registry::default().with(layer.clone());
+
+        // Two batches with three rows in total
+        let schema = Arc::new(Schema::new(vec![Field::new("id", DataType::Int32, false)]));
+        let batch = |ids: Vec<i32>| {
+            RecordBatch::try_new(schema.clone(), vec![Arc::new(Int32Array::from(ids))]).unwrap()
+        };
+        let conn = MockConnection::new().with_batches(vec![batch(vec![1, 2]), batch(vec![3])]);
+        let mut stmt =
+            TrackedStatement::new(Box::new(conn.statement())).with_backend(Backend::Snowflake);
+        with_default(subscriber, || {
+            let reader = stmt.execute().unwrap();
+            let rows: usize = reader.map(|batch| batch.unwrap().num_rows()).sum();
+            assert_eq!(rows, 3);
+        });
+
+        let fields = layer.0.lock().unwrap();
+        assert_eq!(fields.get("backend").map(String::as_str), Some("Snowflake"));
+        assert_eq!(fields.get("rows").map(String::as_str), Some("3"));
+        assert!(fields.contains_key("elapsed_ms"));
+    }
+}
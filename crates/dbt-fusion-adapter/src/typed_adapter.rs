@@ -1,11 +1,12 @@
 use crate::errors::{AdapterError, AdapterErrorKind};
 use crate::funcs::{execute_macro, none_value};
+use crate::information_schema::{ColumnInfo, list_columns_sql};
 use crate::metadata::CatalogAndSchema;
 use crate::record_batch_utils::get_column_values;
 use crate::relation_object::RelationObject;
 use crate::response::{AdapterResponse, ResultObject};
-use crate::snapshots::SnapshotStrategy;
-use crate::sql_engine::{SqlEngine, execute_query_with_retry};
+use crate::snapshots::{SnapshotStrategy, hard_deletes_behavior};
+use crate::sql_engine::{SqlEngine, execute_dry_run};
 use crate::{AdapterResult, AdapterType, AdapterTyping};
 use adbc_core::options::OptionValue;
 use dbt_agate::AgateTable;
@@ -111,32 +112,24 @@ pub trait TypedBaseAdapter: fmt::Debug + Send + Sync + AdapterTyping {
             .map(|(key, value)| (key, OptionValue::String(value)))
             .collect::<Vec<_>>();
 
+        let retry = engine.retry_config();
         let mut last_batch = None;
         for statement in statements {
-            last_batch = Some(execute_query_with_retry(
-                engine.clone(),
-                conn,
+            last_batch = Some(engine.execute_with_retry(
                 &query_ctx.with_sql(statement),
-                1,
-                &options,
+                conn,
+                options.clone(),
                 fetch,
+                &retry,
             )?);
         }
 
+        let response = AdapterResponse::from_record_batch(self.adapter_type(), last_batch.as_ref());
         let table = match last_batch {
             Some(batch) => AgateTable::from_record_batch(Arc::new(batch)),
             None => AgateTable::default(),
         };
 
-        let response = AdapterResponse {
-            // TODO: This is hardcoded, should be derived from the sql statement?
-            message: format!("SELECT {}", table.num_rows()),
-            // TODO: This is hardcoded, should be derived from the sql statement?
-            code: "SELECT".to_string(),
-            rows_affected: table.num_rows() as i64,
-            query_id: None,
-        };
-
         Ok((response, table))
     }
 
@@ -151,6 +144,16 @@ pub trait TypedBaseAdapter: fmt::Debug + Send + Sync + AdapterTyping {
         options: Option<HashMap<String, String>>,
     ) -> AdapterResult<(AdapterResponse, AgateTable)>;
 
+    /// Validate a query against the warehouse without executing it, see
+    /// [execute_dry_run] for the backends that support it.
+    fn execute_dry_run(
+        &self,
+        conn: &'_ mut dyn Connection,
+        query_ctx: &QueryCtx,
+    ) -> AdapterResult<AdapterResponse> {
+        execute_dry_run(self.engine().clone(), self.adapter_type(), conn, query_ctx)
+    }
+
     /// Execute a statement, expect no results.
     fn exec_stmt(
         &self,
@@ -748,6 +751,20 @@ pub trait TypedBaseAdapter: fmt::Debug + Send + Sync + AdapterTyping {
         ))
     }
 
+    /// Lists the columns of `relation` from the warehouse `INFORMATION_SCHEMA`,
+    /// ordered by ordinal position.
+    fn list_columns(
+        &self,
+        query_ctx: &QueryCtx,
+        conn: &'_ mut dyn Connection,
+        relation: &dyn BaseRelation,
+    ) -> AdapterResult<Vec<ColumnInfo>> {
+        let sql = list_columns_sql(relation)
+            .map_err(|e| AdapterError::new(AdapterErrorKind::Internal, e.to_string()))?;
+        let (_, table) = self.query(conn, &query_ctx.with_sql(sql), None)?;
+        ColumnInfo::from_record_batch(&table.original_record_batch())
+    }
+
     /// Behavior (flags)
     ///
     /// By default no adapter has extra flags, but each adapter can
@@ -913,32 +930,7 @@ pub trait TypedBaseAdapter: fmt::Debug + Send + Sync + AdapterTyping {
     ///
     /// https://github.com/dbt-labs/dbt-adapters/blob/4467d4a65503659ede940d8d8d97f16fad9c72cb/dbt-adapters/src/dbt/adapters/base/impl.py#L1903
     fn get_hard_deletes_behavior(&self, config: BTreeMap<String, Value>) -> AdapterResult<String> {
-        let invalidate_hard_deletes = config.get("invalidate_hard_deletes");
-        let hard_deletes = config.get("hard_deletes");
-
-        if invalidate_hard_deletes.is_some() && hard_deletes.is_some() {
-            return Err(AdapterError::new(
-                AdapterErrorKind::Configuration,
-                "You cannot set both the invalidate_hard_deletes and hard_deletes config properties on the same snapshot.",
-            ));
-        }
-
-        if invalidate_hard_deletes.is_some() {
-            return Ok("invalidate".to_string());
-        }
-
-        match hard_deletes {
-            None => Ok("ignore".to_string()),
-            Some(val) => match val.as_str() {
-                Some("invalidate") => Ok("invalidate".to_string()),
-                Some("new_record") => Ok("new_record".to_string()),
-                Some("ignore") => Ok("ignore".to_string()),
-                Some(_) | None => Err(AdapterError::new(
-                    AdapterErrorKind::Configuration,
-                    "Invalid setting for property hard_deletes.",
-                )),
-            },
-        }
+        hard_deletes_behavior(&config)
     }
 
     /// Convenience to check if this [TypedBaseAdapter] implementer is used for replaying recordings
@@ -161,6 +161,51 @@ impl Ident<'_> {
         self.name() == str_value.as_ref()
     }
 
+    /// Creates a new owned identifier by joining `parts` with `.`. An empty
+    /// iterator yields an empty identifier.
+    pub fn from_parts<I>(parts: I) -> Ident<'static>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let mut joined = String::new();
+        for (i, part) in parts.into_iter().enumerate() {
+            if i > 0 {
+                joined.push('.');
+            }
+            joined.push_str(part.as_ref());
+        }
+        Ident::new(joined)
+    }
+
+    /// Returns a copy of this [Ident] with one matching pair of surrounding
+    /// `"` or `` ` `` quotes removed. Unquoted input, a single stray quote, or
+    /// mismatched quote characters are returned unchanged.
+    pub fn strip_surrounding_quotes(&self) -> Ident<'static> {
+        let name = self.name();
+        let stripped = name
+            .strip_prefix('"')
+            .and_then(|rest| rest.strip_suffix('"'))
+            .or_else(|| {
+                name.strip_prefix('`')
+                    .and_then(|rest| rest.strip_suffix('`'))
+            });
+        match stripped {
+            Some(inner) => Ident::new(inner),
+            None => self.to_owned(),
+        }
+    }
+
+    /// Returns the name wrapped in the quote character `q`. Occurrences of
+    /// `q` inside the name are escaped by doubling them, as in SQL.
+    pub fn quoted_with(&self, q: char) -> String {
+        let mut escape = [0u8; 4];
+        let escaped = self
+            .name()
+            .replace(q, q.encode_utf8(&mut escape).repeat(2).as_str());
+        format!("{q}{escaped}{q}")
+    }
+
     // Internal: Get the raw pointer to the inner string value.
     fn as_ptr(&self) -> *const u8 {
         match self {
@@ -221,6 +266,53 @@ impl<'a> From<&Ident<'a>> for Ident<'a> {
     }
 }
 
+/// A dotted identifier path (e.g. `db.schema.table`) collected from its
+/// parts.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct IdentPath(Identifier);
+
+impl IdentPath {
+    /// Returns the joined path as an [Ident].
+    pub fn as_ident(&self) -> &Identifier {
+        &self.0
+    }
+
+    /// Consumes this [IdentPath] and returns the joined path as an [Ident].
+    pub fn into_ident(self) -> Identifier {
+        self.0
+    }
+}
+
+impl<'a> FromIterator<Ident<'a>> for IdentPath {
+    fn from_iter<I: IntoIterator<Item = Ident<'a>>>(iter: I) -> Self {
+        IdentPath(Ident::from_parts(iter))
+    }
+}
+
+impl<'a, 'b> FromIterator<&'b Ident<'a>> for IdentPath {
+    fn from_iter<I: IntoIterator<Item = &'b Ident<'a>>>(iter: I) -> Self {
+        IdentPath(Ident::from_parts(iter))
+    }
+}
+
+impl From<IdentPath> for Ident<'static> {
+    fn from(value: IdentPath) -> Self {
+        value.0
+    }
+}
+
+impl std::fmt::Display for IdentPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl AsRef<Path> for IdentPath {
+    fn as_ref(&self) -> &Path {
+        self.0.as_path()
+    }
+}
+
 impl AsRef<str> for Ident<'_> {
     fn as_ref(&self) -> &str {
         self.name()
@@ -232,3 +324,74 @@ impl AsRef<Path> for Ident<'_> {
         self.as_path()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_strip_surrounding_quotes() {
+        assert_eq!(
+            Ident::from("\"My Table\"")
+                .strip_surrounding_quotes()
+                .as_str(),
+            "My Table"
+        );
+        assert_eq!(
+            Ident::from("`my_table`")
+                .strip_surrounding_quotes()
+                .as_str(),
+            "my_table"
+        );
+        assert_eq!(
+            Ident::from("my_table").strip_surrounding_quotes().as_str(),
+            "my_table"
+        );
+        assert_eq!(Ident::from("").strip_surrounding_quotes().as_str(), "");
+    }
+
+    #[test]
+    fn test_strip_surrounding_quotes_malformed() {
+        for input in ["\"", "`", "\"my_table", "my_table`", "\"my_table`"] {
+            assert_eq!(
+                Ident::from(input).strip_surrounding_quotes().as_str(),
+                input
+            );
+        }
+        // Only one pair is removed
+        assert_eq!(
+            Ident::from("\"\"x\"\"").strip_surrounding_quotes().as_str(),
+            "\"x\""
+        );
+    }
+
+    #[test]
+    fn test_quoted_with() {
+        assert_eq!(Ident::from("my_table").quoted_with('"'), "\"my_table\"");
+        assert_eq!(Ident::from("my_table").quoted_with('`'), "`my_table`");
+        assert_eq!(Ident::from("").quoted_with('"'), "\"\"");
+        assert_eq!(Ident::from("a\"b").quoted_with('"'), "\"a\"\"b\"");
+    }
+
+    #[test]
+    fn test_ident_path_from_iter() {
+        let path: IdentPath = ["db", "schema", "t"].into_iter().map(Ident::from).collect();
+        assert_eq!(path.as_ident().as_str(), "db.schema.t");
+        assert_eq!(Ident::from(path), Ident::from("DB.SCHEMA.T"));
+
+        let parts = vec![Ident::new("db"), Ident::new("schema"), Ident::new("t")];
+        let path: IdentPath = parts.iter().collect();
+        assert_eq!(path.to_string(), "db.schema.t");
+        assert_eq!(
+            Ident::from_parts(["db", "schema", "t"]).as_str(),
+            "db.schema.t"
+        );
+    }
+
+    #[test]
+    fn test_ident_path_empty() {
+        let path: IdentPath = std::iter::empty::<Ident>().collect();
+        assert!(path.as_ident().is_empty());
+        assert!(Ident::from_parts(Vec::<String>::new()).is_empty());
+    }
+}
@@ -133,6 +133,7 @@ pub fn setup_bigquery_profile(
         job_creation_timeout_seconds: None,
         job_execution_timeout_seconds: None,
         job_retries: None,
+        query_retries: None,
         job_retry_deadline_seconds: None,
         target_name: None,
     };
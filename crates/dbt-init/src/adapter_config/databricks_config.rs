@@ -123,6 +123,7 @@ pub fn setup_databricks_profile(
         auth_type: None,
         compute: None,
         connect_retries: None,
+        query_retries: None,
         connect_timeout: None,
         retry_all: None,
         connect_max_idle: None,
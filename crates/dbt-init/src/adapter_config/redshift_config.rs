@@ -177,6 +177,7 @@ pub fn setup_redshift_profile(
         ra3_node: None,
         autocommit: None,
         retries: None,
+        query_retries: None,
         method: None,
         host: None,
         user: None,
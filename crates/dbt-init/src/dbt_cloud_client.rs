@@ -194,6 +194,7 @@ fn create_merged_db_config(
                 job_creation_timeout_seconds: None,
                 job_execution_timeout_seconds: None,
                 job_retries: None,
+                query_retries: None,
                 job_retry_deadline_seconds: None,
                 target_name: None,
             }))
@@ -309,6 +310,7 @@ fn create_merged_db_config(
                     job_creation_timeout_seconds: None,
                     job_execution_timeout_seconds: None,
                     job_retries: None,
+                    query_retries: None,
                     job_retry_deadline_seconds: None,
                     target_name: None,
                 };
@@ -362,6 +364,7 @@ fn create_merged_db_config(
                     dataproc_batch: None,
                     timeout_seconds: None,
                     job_retries: None,
+                    query_retries: None,
                     target_name: None,
                 };
                 bigquery_config.merge(connection_details);
@@ -2,6 +2,7 @@ pub mod adapter_config;
 pub mod dbt_cloud_client;
 pub mod init;
 pub mod profile_setup;
+pub mod profile_validation;
 pub mod yaml_utils;
 
 pub extern crate dbt_error as error;
@@ -12,6 +12,7 @@ use dbt_jinja_utils::serde::{into_typed_with_jinja, value_from_file};
 use dbt_loader::{args::LoadArgs, load_profiles};
 use dbt_schemas::schemas::profiles::DbConfig;
 use dbt_schemas::schemas::project::DbtProjectSimplified;
+use dbt_xdbc::Backend;
 
 use dialoguer::{Confirm, Select};
 
@@ -28,6 +29,82 @@ pub struct ProfileTarget {
 
 pub type Profiles = HashMap<String, ProfileTarget>;
 
+/// A fully specified profile, used to generate a profiles.yml block
+/// without any prompts (e.g. when scaffolding CI)
+#[derive(Debug, Clone)]
+pub struct ProfileSpec {
+    pub profile_name: String,
+    pub target: String,
+    pub config: DbConfig,
+}
+
+/// Generate the profiles.yml block for the given spec, after checking
+/// that every field the adapter requires is set.
+pub fn generate_profile(spec: ProfileSpec) -> FsResult<String> {
+    let missing = missing_required_fields(&spec.config)?;
+    if !missing.is_empty() {
+        return Err(fs_err!(
+            ErrorCode::InvalidConfig,
+            "Profile '{}' is missing required fields: {}",
+            spec.profile_name,
+            missing.join(", ")
+        ));
+    }
+
+    let mut outputs = HashMap::new();
+    outputs.insert(spec.target.clone(), spec.config);
+    let profile = ProfileTarget {
+        target: spec.target,
+        outputs,
+    };
+    profile_block_yaml(&spec.profile_name, &profile)
+}
+
+/// Required keys of the given config that are unset, see [dbt_auth::required_keys]
+fn missing_required_fields(config: &DbConfig) -> FsResult<Vec<String>> {
+    let backend = match config {
+        DbConfig::Snowflake(_) => Backend::Snowflake,
+        DbConfig::Bigquery(_) => Backend::BigQuery,
+        DbConfig::Postgres(_) => Backend::Postgres,
+        _ => {
+            return Err(fs_err!(
+                ErrorCode::InvalidConfig,
+                "Generating a profile is only supported for snowflake, bigquery and postgres"
+            ));
+        }
+    };
+    let config = dbt_serde_yaml::to_value(config).map_err(|e| {
+        fs_err!(
+            ErrorCode::IoError,
+            "Failed to serialize profile config: {}",
+            e
+        )
+    })?;
+    let is_set = |key: &str| {
+        config
+            .get(key)
+            .is_some_and(|value| !matches!(value, dbt_serde_yaml::Value::Null(_)))
+    };
+    Ok(dbt_auth::required_keys(backend)
+        .iter()
+        .filter(|aliases| !aliases.iter().any(|alias| is_set(alias)))
+        .map(|aliases| aliases.join("/"))
+        .collect())
+}
+
+/// Serialize a single top-level profile block
+fn profile_block_yaml(profile_name: &str, profile: &ProfileTarget) -> FsResult<String> {
+    let mut top: HashMap<String, ProfileTarget> = HashMap::new();
+    top.insert(profile_name.to_string(), profile.clone());
+    dbt_serde_yaml::to_string(&top).map_err(|e| {
+        fs_err!(
+            ErrorCode::IoError,
+            "Failed to serialize profile block: {}",
+            e
+        )
+    })
+}
+
 /// Load profile using the standard dbt-loader infrastructure
 fn load_profile_with_loader(
     profiles_dir: Option<&str>,
@@ -307,15 +384,7 @@ impl ProfileSetup {
             existing.push('\n');
         }
 
-        let mut top: HashMap<String, ProfileTarget> = HashMap::new();
-        top.insert(profile_name.to_string(), profile.clone());
-        let new_block = dbt_serde_yaml::to_string(&top).map_err(|e| {
-            fs_err!(
-                ErrorCode::IoError,
-                "Failed to serialize profile block: {}",
-                e
-            )
-        })?;
+        let new_block = profile_block_yaml(profile_name, profile)?;
 
         existing.push_str(&new_block);
 
@@ -506,3 +575,67 @@ impl ProfileSetup {
         &DbtCloudClient
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dbt_schemas::schemas::profiles::SnowflakeDbConfig;
+
+    fn snowflake_config() -> SnowflakeDbConfig {
+        SnowflakeDbConfig {
+            account: Some("ab12345.us-east-1".to_string()),
+            user: Some("ci_user".to_string()),
+            password: Some("{{ env_var('SNOWFLAKE_PASSWORD') }}".to_string()),
+            database: Some("analytics".to_string()),
+            warehouse: Some("transforming".to_string()),
+            schema: Some("dbt_ci".to_string()),
+            role: Some("transformer".to_string()),
+            ..Default::default()
+        }
+    }
+
+    fn spec(config: DbConfig) -> ProfileSpec {
+        ProfileSpec {
+            profile_name: "jaffle_shop".to_string(),
+            target: "ci".to_string(),
+            config,
+        }
+    }
+
+    #[test]
+    fn test_generate_snowflake_profile_round_trips() {
+        let config = snowflake_config();
+        let yaml = generate_profile(spec(DbConfig::Snowflake(Box::new(config.clone())))).unwrap();
+
+        let parsed: dbt_serde_yaml::Value = dbt_serde_yaml::from_str(&yaml).unwrap();
+        let profile = parsed.get("jaffle_shop").unwrap();
+        assert_eq!(profile.get("target").and_then(|v| v.as_str()), Some("ci"));
+        let output = profile.get("outputs").and_then(|o| o.get("ci")).unwrap();
+        assert_eq!(
+            output.get("type").and_then(|v| v.as_str()),
+            Some("snowflake")
+        );
+        let mut output = output.clone();
+        if let Some(mapping) = output.as_mapping_mut() {
+            mapping.remove("type");
+        }
+        let round_tripped: SnowflakeDbConfig = dbt_serde_yaml::from_value(output).unwrap();
+        assert_eq!(round_tripped, config);
+    }
+
+    #[test]
+    fn test_generate_profile_missing_required_fields() {
+        let config = SnowflakeDbConfig {
+            warehouse: None,
+            password: None,
+            ..snowflake_config()
+        };
+        let err = generate_profile(spec(DbConfig::Snowflake(Box::new(config)))).unwrap_err();
+        let message = err.to_string();
+        assert!(message.contains("warehouse"), "{message}");
+        assert!(message.contains("password"), "{message}");
+
+        let err = generate_profile(spec(DbConfig::Postgres(Box::default()))).unwrap_err();
+        assert!(err.to_string().contains("host"), "{err}");
+    }
+}
@@ -0,0 +1,231 @@
+use dbt_common::{ErrorCode, FsResult, fs_err};
+use dbt_schemas::schemas::profiles::{
+    BigqueryDbConfig, DatabricksDbConfig, PostgresDbConfig, RedshiftDbConfig, SalesforceDbConfig,
+    SnowflakeDbConfig,
+};
+use dbt_xdbc::Backend;
+use schemars::{JsonSchema, schema_for};
+
+use std::collections::BTreeSet;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+type YmlValue = dbt_serde_yaml::Value;
+
+/// A problem found in a target of an existing profiles.yml
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProfileWarning {
+    pub profile: String,
+    pub target: String,
+    pub kind: ProfileWarningKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProfileWarningKind {
+    /// A required key (any of its aliases) is not set
+    MissingKey(String),
+    /// A key the adapter does not know about
+    UnknownKey(String),
+}
+
+impl fmt::Display for ProfileWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            ProfileWarningKind::MissingKey(key) => write!(
+                f,
+                "Profile '{}' target '{}' is missing required key '{}'",
+                self.profile, self.target, key
+            ),
+            ProfileWarningKind::UnknownKey(key) => write!(
+                f,
+                "Profile '{}' target '{}' has unknown key '{}'",
+                self.profile, self.target, key
+            ),
+        }
+    }
+}
+
+/// Check every target of the profiles.yml at `path` that uses `backend`
+/// for missing and unknown keys, without connecting to the warehouse.
+///
+/// Required keys come from [dbt_auth::required_keys], known keys from the
+/// adapter's profile config schema.
+pub fn validate_existing_profile(path: &Path, backend: Backend) -> FsResult<Vec<ProfileWarning>> {
+    let content = fs::read_to_string(path)?;
+    let profiles: YmlValue = dbt_serde_yaml::from_str(&content).map_err(|e| {
+        fs_err!(
+            ErrorCode::InvalidConfig,
+            "Failed to parse {}: {}",
+            path.display(),
+            e
+        )
+    })?;
+    let (profile_type, known_keys) = profile_keys(backend)?;
+    let required_keys = dbt_auth::required_keys(backend);
+
+    let mut warnings = Vec::new();
+    let Some(profiles) = profiles.as_mapping() else {
+        return Ok(warnings);
+    };
+    for (profile_name, profile) in profiles {
+        let (Some(profile_name), Some(outputs)) = (
+            profile_name.as_str(),
+            profile.get("outputs").and_then(|o| o.as_mapping()),
+        ) else {
+            // e.g. the top-level `config` block
+            continue;
+        };
+        for (target_name, output) in outputs {
+            let Some(output) = output.as_mapping() else {
+                continue;
+            };
+            if output.get("type").and_then(|t| t.as_str()) != Some(profile_type) {
+                continue;
+            }
+            let warning = |kind| ProfileWarning {
+                profile: profile_name.to_string(),
+                target: target_name.as_str().unwrap_or_default().to_string(),
+                kind,
+            };
+            let keys = output
+                .keys()
+                .filter_map(|k| k.as_str())
+                .collect::<BTreeSet<_>>();
+
+            for aliases in required_keys {
+                if !aliases.iter().any(|alias| keys.contains(alias)) {
+                    warnings.push(warning(ProfileWarningKind::MissingKey(aliases.join("/"))));
+                }
+            }
+            for key in &keys {
+                let is_alias = required_keys.iter().any(|aliases| aliases.contains(key));
+                if *key != "type" && !is_alias && !known_keys.contains(*key) {
+                    warnings.push(warning(ProfileWarningKind::UnknownKey(key.to_string())));
+                }
+            }
+        }
+    }
+    Ok(warnings)
+}
+
+/// The profile `type` of the backend and the keys its config accepts
+fn profile_keys(backend: Backend) -> FsResult<(&'static str, BTreeSet<String>)> {
+    fn properties<T: JsonSchema>() -> BTreeSet<String> {
+        schema_for!(T)
+            .schema
+            .object
+            .map(|object| object.properties.into_keys().collect())
+            .unwrap_or_default()
+    }
+    let keys = match backend {
+        Backend::Snowflake => ("snowflake", properties::<SnowflakeDbConfig>()),
+        Backend::BigQuery => ("bigquery", properties::<BigqueryDbConfig>()),
+        Backend::Postgres => ("postgres", properties::<PostgresDbConfig>()),
+        Backend::Redshift | Backend::RedshiftODBC => ("redshift", properties::<RedshiftDbConfig>()),
+        Backend::Databricks | Backend::DatabricksODBC => {
+            ("databricks", properties::<DatabricksDbConfig>())
+        }
+        Backend::Salesforce => ("salesforce", properties::<SalesforceDbConfig>()),
+        Backend::Generic { .. } => {
+            return Err(fs_err!(
+                ErrorCode::InvalidArgument,
+                "Cannot validate profiles for a generic backend"
+            ));
+        }
+    };
+    Ok(keys)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const COMPLETE: &str = "
+jaffle_shop:
+  target: dev
+  outputs:
+    dev:
+      type: snowflake
+      account: ab12345
+      user: dbt
+      password: secret
+      role: transformer
+      database: analytics
+      warehouse: transforming
+      schema: dbt_dev
+      threads: 4
+    local:
+      type: postgres
+      host: localhost
+";
+
+    fn validate(name: &str, content: &str) -> Vec<ProfileWarning> {
+        validate_backend(name, content, Backend::Snowflake)
+    }
+
+    fn validate_backend(name: &str, content: &str, backend: Backend) -> Vec<ProfileWarning> {
+        let dir =
+            std::env::temp_dir().join(format!("dbt_init_validate_{name}_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("profiles.yml");
+        fs::write(&path, content).unwrap();
+        let warnings = validate_existing_profile(&path, backend).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+        warnings
+    }
+
+    #[test]
+    fn test_complete_profile_has_no_warnings() {
+        assert_eq!(validate("complete", COMPLETE), vec![]);
+    }
+
+    #[test]
+    fn test_missing_warehouse() {
+        let content = COMPLETE.replace("      warehouse: transforming\n", "");
+        assert_eq!(
+            validate("missing", &content),
+            vec![ProfileWarning {
+                profile: "jaffle_shop".to_string(),
+                target: "dev".to_string(),
+                kind: ProfileWarningKind::MissingKey("warehouse".to_string()),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_unknown_key() {
+        let content = COMPLETE.replace("threads: 4", "thread: 4");
+        assert_eq!(
+            validate("unknown", &content),
+            vec![ProfileWarning {
+                profile: "jaffle_shop".to_string(),
+                target: "dev".to_string(),
+                kind: ProfileWarningKind::UnknownKey("thread".to_string()),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_redshift_iam_profile_without_user() {
+        let content = "
+warehouse:
+  target: prod
+  outputs:
+    prod:
+      type: redshift
+      method: iam
+      host: example.abc123.us-east-1.redshift.amazonaws.com
+      port: 5439
+      database: analytics
+      schema: dbt_prod
+      db_user: dbt
+      cluster_id: example
+      region: us-east-1
+";
+        assert_eq!(
+            validate_backend("redshift_iam", content, Backend::Redshift),
+            vec![]
+        );
+    }
+}
@@ -1,5 +1,5 @@
 use crate::{
-    functions::register_base_functions, jinja_environment::JinjaEnv,
+    flags::Flags, functions::register_base_functions, jinja_environment::JinjaEnv,
     listener::RenderingEventListenerFactory,
 };
 use dbt_common::{ErrorCode, FsError, FsResult, fs_err, io_args::IoArgs, unexpected_fs_err};
@@ -44,6 +44,7 @@ pub struct JinjaEnvBuilder {
     env: Environment<'static>,
     adapter: Option<Arc<dyn BaseAdapter>>,
     globals: BTreeMap<String, Value>,
+    flags: Option<Flags>,
     root_package: Option<String>,
     undefined_behavior: minijinja::UndefinedBehavior,
     io_args: IoArgs,
@@ -57,6 +58,7 @@ impl JinjaEnvBuilder {
             env: Environment::new(),
             adapter: None,
             globals: BTreeMap::new(),
+            flags: None,
             root_package: None,
             undefined_behavior: Default::default(),
             io_args: IoArgs::default(),
@@ -94,6 +96,13 @@ impl JinjaEnvBuilder {
         self
     }
 
+    /// Register the `flags` global. It takes precedence over a `flags` entry
+    /// passed to `with_globals`, whichever is called first.
+    pub fn with_flags(mut self, flags: Flags) -> Self {
+        self.flags = Some(flags);
+        self
+    }
+
     /// Add IoArgs
     pub fn with_io_args(mut self, io_args: IoArgs) -> Self {
         self.io_args = io_args;
@@ -326,6 +335,9 @@ impl JinjaEnvBuilder {
         for (key, val) in self.globals {
             self.env.add_global(key, val);
         }
+        if let Some(flags) = self.flags {
+            self.env.add_global("flags", Value::from_object(flags));
+        }
 
         // Any extra steps (unknown method callback, etc.)
         minijinja_contrib::add_to_environment(&mut self.env);
@@ -797,6 +809,35 @@ all okay!");
         // assert!(rv.contains("<macro 'some_macro'>"));
         assert!(rv.contains("hello"));
     }
+
+    #[test]
+    fn test_with_flags_overrides_globals_in_any_order() {
+        let flags =
+            || Flags::new().with_cli_flags(BTreeMap::from([("x".to_string(), Value::from(1))]));
+        let globals = || {
+            BTreeMap::from([(
+                "flags".to_string(),
+                Value::from_object(
+                    Flags::new()
+                        .with_cli_flags(BTreeMap::from([("x".to_string(), Value::from(2))])),
+                ),
+            )])
+        };
+
+        let flags_first = JinjaEnvBuilder::new()
+            .with_flags(flags())
+            .with_globals(globals())
+            .build();
+        let globals_first = JinjaEnvBuilder::new()
+            .with_globals(globals())
+            .with_flags(flags())
+            .build();
+        for env in [flags_first, globals_first] {
+            let rv = env.render_str("{{ flags.x }}", context! {}, &[]).unwrap();
+            assert_eq!(rv, "1");
+        }
+    }
+
     #[test]
     fn test_date_format() {
         let env = JinjaEnvBuilder::new().build();
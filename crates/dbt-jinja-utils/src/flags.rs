@@ -23,12 +23,10 @@ impl Object for Flags {
     }
 
     fn get_value(self: &Arc<Self>, key: &Value) -> Option<Value> {
-        if let Some(s) = key.as_str() {
-            if self.flags.contains_key(s) {
-                return Some(self.flags[s].clone());
-            }
-        }
-        None
+        // Unknown flags resolve to undefined so that macros can probe for
+        // flags that are only set by some invocations (`flags.X is defined`)
+        key.as_str()
+            .map(|s| self.flags.get(s).cloned().unwrap_or(Value::UNDEFINED))
     }
 
     fn call_method(
@@ -187,6 +185,14 @@ impl Flags {
             Value::from(invocation_args.write_json),
         );
     }
+
+    /// Seed arbitrary flags (e.g. passed on the command line), overriding
+    /// any existing flag with the same name.
+    pub fn with_cli_flags(mut self, cli_flags: BTreeMap<String, Value>) -> Self {
+        self.flags.extend(cli_flags);
+        self
+    }
+
     /// Override self with other flags
     pub fn join(&mut self, other: Flags) -> Self {
         for (key, value) in other.flags {
@@ -201,3 +207,30 @@ impl Default for Flags {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use minijinja::{Environment, context};
+
+    fn render(flags: Flags, template: &str) -> String {
+        let mut env = Environment::new();
+        env.add_global("flags", Value::from_object(flags));
+        env.render_str(template, context! {}, &[]).unwrap()
+    }
+
+    #[test]
+    fn test_seeded_flag_is_readable() {
+        let flags = Flags::new()
+            .with_cli_flags(BTreeMap::from([("my_flag".to_string(), Value::from(true))]));
+        assert_eq!(render(flags, "{{ flags.my_flag }}"), "true");
+    }
+
+    #[test]
+    fn test_missing_flag_is_undefined() {
+        assert_eq!(
+            render(Flags::new(), "{{ flags.missing is undefined }}"),
+            "true"
+        );
+    }
+}
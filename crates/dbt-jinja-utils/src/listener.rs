@@ -7,7 +7,8 @@ use std::{
 };
 
 use minijinja::{
-    CodeLocation, MacroSpans, TypecheckingEventListener,
+    CodeLocation, MacroSpans, TypecheckingEventListener, Value,
+    arg_utils::ArgsIter,
     listener::{DefaultRenderingEventListener, RenderingEventListener},
 };
 
@@ -91,6 +92,56 @@ impl RenderingEventListenerFactory for DefaultRenderingEventListenerFactory {
     }
 }
 
+/// A `var` or `env_var` call captured by [VarCaptureListener]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VarReference {
+    /// The called function, `var` or `env_var`
+    pub function: String,
+    /// The variable name
+    pub name: String,
+    /// The rendered default value, if one was given
+    pub default: Option<String>,
+}
+
+/// Rendering event listener that records every `var`/`env_var` call, e.g.
+/// to build a graph of the config a node depends on.
+#[derive(Debug, Default, Clone)]
+pub struct VarCaptureListener {
+    /// Captured references, in call order. Clones of the listener share it.
+    pub captured: Rc<RefCell<Vec<VarReference>>>,
+}
+
+impl RenderingEventListener for VarCaptureListener {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "VarCaptureListener"
+    }
+
+    fn on_call(&self, name: &str, args: &[Value]) {
+        if name != "var" && name != "env_var" {
+            return;
+        }
+        let iter = ArgsIter::new(name, &["var"], args);
+        // Malformed calls are reported by the function itself
+        let Ok(var) = iter.next_arg::<&str>() else {
+            return;
+        };
+        let default = iter
+            .next_kwarg::<Option<&Value>>("default")
+            .ok()
+            .flatten()
+            .map(|default| default.to_string());
+        self.captured.borrow_mut().push(VarReference {
+            function: name.to_string(),
+            name: var.to_string(),
+            default,
+        });
+    }
+}
+
 /// Trait for creating and destroying Jinja type checking event listeners
 pub trait JinjaTypeCheckingEventListenerFactory: Send + Sync {
     /// Creates a new rendering event listener
@@ -220,3 +271,47 @@ impl TypecheckingEventListener for WarningPrinter {
         });
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use minijinja::{Environment, context};
+
+    #[test]
+    fn test_var_capture_listener() {
+        let mut env = Environment::new();
+        env.add_function("env_var", |_name: &str, default: Option<&str>| {
+            default.unwrap_or_default().to_string()
+        });
+        env.add_function("var", |_name: &str, default: Option<&str>| {
+            default.unwrap_or_default().to_string()
+        });
+        let listener = VarCaptureListener::default();
+        let listeners: Vec<Rc<dyn RenderingEventListener>> = vec![Rc::new(listener.clone())];
+
+        let rendered = env
+            .render_str(
+                r#"{{ env_var("FOO", "bar") }}-{{ var("start_date") }}"#,
+                context! {},
+                &listeners,
+            )
+            .unwrap();
+        assert_eq!(rendered, "bar-");
+
+        assert_eq!(
+            *listener.captured.borrow(),
+            vec![
+                VarReference {
+                    function: "env_var".to_string(),
+                    name: "FOO".to_string(),
+                    default: Some("bar".to_string()),
+                },
+                VarReference {
+                    function: "var".to_string(),
+                    name: "start_date".to_string(),
+                    default: None,
+                },
+            ]
+        );
+    }
+}
@@ -246,6 +246,7 @@ pub fn build_resolve_model_context<T: DefaultTo<T> + 'static>(
             freshness: None,
             contract: None,
             event_time: None,
+            rendered_code: None,
         },
         __adapter_attr__: AdapterAttr::default(),
         __other__: BTreeMap::new(),
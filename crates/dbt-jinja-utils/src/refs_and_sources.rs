@@ -2,6 +2,8 @@ use std::{
     any::Any,
     collections::{BTreeMap, HashSet},
     iter::Iterator,
+    rc::Rc,
+    sync::{Arc, Mutex},
 };
 
 use dbt_common::{
@@ -15,10 +17,16 @@ use dbt_schemas::{
         DbtSource, InternalDbtNodeAttributes, Nodes,
         common::DbtQuoting,
         ref_and_source::{DbtRef, DbtSourceWrapper},
+        serde::StringOrInteger,
     },
     state::{ModelStatus, RefsAndSourcesTracker},
 };
-use minijinja::Value as MinijinjaValue;
+use minijinja::{
+    Error as MinijinjaError, ErrorKind as MinijinjaErrorKind, State, Value as MinijinjaValue,
+    arg_utils::ArgParser, listener::RenderingEventListener, value::Object,
+};
+
+use crate::jinja_environment::JinjaEnv;
 
 /// A wrapper around refs and sources with methods to get and insert refs and sources
 #[derive(Debug, Default, Clone)]
@@ -523,3 +531,175 @@ pub fn resolve_dependencies(
     // Return the set of nodes that had resolution errors
     nodes_with_errors
 }
+
+/// The `ref()` and `source()` targets of a model, in call order
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ExtractedRefsAndSources {
+    /// `ref()` targets
+    pub refs: Vec<DbtRef>,
+    /// `source()` targets as `[source_name, table_name]`
+    pub sources: Vec<DbtSourceWrapper>,
+}
+
+/// Collects the `ref()` and `source()` targets of the given model SQL without
+/// resolving them against a project.
+///
+/// The SQL is rendered with `ref`, `source` and `config` replaced by capture
+/// functions: `ref`/`source` record their arguments and render as the bare
+/// model/table name, and `config` is a no-op. Any other function or macro must
+/// be available in `env`.
+pub fn extract_refs_and_sources(sql: &str, env: &JinjaEnv) -> FsResult<ExtractedRefsAndSources> {
+    let captured = Arc::new(Mutex::new(ExtractedRefsAndSources::default()));
+    let capture = |kind| {
+        MinijinjaValue::from_object(CaptureFunction {
+            kind,
+            captured: captured.clone(),
+        })
+    };
+    let ctx = BTreeMap::from([
+        ("ref".to_string(), capture(CaptureKind::Ref)),
+        ("source".to_string(), capture(CaptureKind::Source)),
+        ("config".to_string(), capture(CaptureKind::Config)),
+    ]);
+    env.render_str(sql, ctx, &[])?;
+    let extracted = captured.lock().unwrap().clone();
+    Ok(extracted)
+}
+
+#[derive(Debug, Clone, Copy)]
+enum CaptureKind {
+    Ref,
+    Source,
+    Config,
+}
+
+#[derive(Debug)]
+struct CaptureFunction {
+    kind: CaptureKind,
+    captured: Arc<Mutex<ExtractedRefsAndSources>>,
+}
+
+impl Object for CaptureFunction {
+    fn call(
+        self: &Arc<Self>,
+        _state: &State<'_, '_>,
+        args: &[MinijinjaValue],
+        _listeners: &[Rc<dyn RenderingEventListener>],
+    ) -> Result<MinijinjaValue, MinijinjaError> {
+        let mut parser = ArgParser::new(args, None);
+        match self.kind {
+            CaptureKind::Ref => {
+                let (package, name) = match parser.positional_len() {
+                    1 => (None, parser.next_positional::<String>()?),
+                    2 => (
+                        Some(parser.next_positional::<String>()?),
+                        parser.next_positional::<String>()?,
+                    ),
+                    _ => {
+                        return Err(MinijinjaError::new(
+                            MinijinjaErrorKind::InvalidOperation,
+                            "ref() takes 1 or 2 positional arguments",
+                        ));
+                    }
+                };
+                let version = parser
+                    .consume_optional_either_from_kwargs::<MinijinjaValue>("version", "v")
+                    .map(|version| match version.as_i64() {
+                        Some(version) => StringOrInteger::Integer(version),
+                        None => StringOrInteger::String(version.to_string()),
+                    });
+                self.captured.lock().unwrap().refs.push(DbtRef {
+                    name: name.clone(),
+                    package,
+                    version,
+                    location: None,
+                });
+                Ok(MinijinjaValue::from(name))
+            }
+            CaptureKind::Source => {
+                if parser.positional_len() != 2 {
+                    return Err(MinijinjaError::new(
+                        MinijinjaErrorKind::InvalidOperation,
+                        "source requires 2 string arguments",
+                    ));
+                }
+                let source_name = parser.next_positional::<String>()?;
+                let table_name = parser.next_positional::<String>()?;
+                self.captured
+                    .lock()
+                    .unwrap()
+                    .sources
+                    .push(DbtSourceWrapper {
+                        source: vec![source_name, table_name.clone()],
+                        location: None,
+                    });
+                Ok(MinijinjaValue::from(table_name))
+            }
+            CaptureKind::Config => Ok(MinijinjaValue::from("")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn extract(sql: &str) -> ExtractedRefsAndSources {
+        let env = JinjaEnv::new(minijinja::Environment::new());
+        extract_refs_and_sources(sql, &env).unwrap()
+    }
+
+    #[test]
+    fn test_extract_single_ref() {
+        let extracted =
+            extract("{{ config(materialized='table') }}\nselect * from {{ ref('stg_orders') }}");
+        assert_eq!(
+            extracted.refs,
+            vec![DbtRef {
+                name: "stg_orders".to_string(),
+                package: None,
+                version: None,
+                location: None,
+            }]
+        );
+        assert!(extracted.sources.is_empty());
+    }
+
+    #[test]
+    fn test_extract_single_source() {
+        let extracted = extract("select * from {{ source('raw', 'orders') }}");
+        assert!(extracted.refs.is_empty());
+        assert_eq!(
+            extracted.sources,
+            vec![DbtSourceWrapper {
+                source: vec!["raw".to_string(), "orders".to_string()],
+                location: None,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_extract_cross_package_and_versioned_refs() {
+        let extracted = extract(
+            "select * from {{ ref('jaffle_shop', 'customers') }} \
+             join {{ ref('orders', v=2) }} using (id)",
+        );
+        assert_eq!(
+            extracted.refs,
+            vec![
+                DbtRef {
+                    name: "customers".to_string(),
+                    package: Some("jaffle_shop".to_string()),
+                    version: None,
+                    location: None,
+                },
+                DbtRef {
+                    name: "orders".to_string(),
+                    package: None,
+                    version: Some(StringOrInteger::Integer(2)),
+                    location: None,
+                },
+            ]
+        );
+    }
+}
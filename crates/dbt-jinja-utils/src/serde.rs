@@ -341,6 +341,51 @@ where
     Ok(res)
 }
 
+/// Deserializes a Yaml string containing Jinja expressions into a target
+/// `Deserialize` type T.
+///
+/// Every rendered value, including the items of a rendered list or mapping,
+/// keeps the span of the templated string it was rendered from, so an invalid
+/// value is reported at its line and column in `input`.
+///
+/// Jinja 式を含む Yaml 文字列をターゲットの `Deserialize` 型 T にデシリアライズします。
+///
+/// レンダリングされたリストやマッピングの要素を含め、レンダリングされたすべての値は
+/// レンダリング元のテンプレート文字列のスパンを保持するため、無効な値は `input` 内の
+/// 行と列で報告されます。
+#[allow(clippy::too_many_arguments)]
+pub fn from_yaml_with_jinja<T, S>(
+    io_args: &IoArgs,
+    input: &str,
+    error_display_path: Option<&Path>,
+    should_render_secrets: bool,
+    env: &JinjaEnv,
+    ctx: &S,
+    listeners: &[Rc<dyn RenderingEventListener>],
+    dependency_package_name: Option<&str>,
+) -> FsResult<T>
+where
+    T: DeserializeOwned,
+    S: Serialize,
+{
+    let value = value_from_str(
+        io_args,
+        input,
+        error_display_path,
+        true,
+        dependency_package_name,
+    )?;
+    into_typed_with_jinja(
+        io_args,
+        value,
+        should_render_secrets,
+        env,
+        ctx,
+        listeners,
+        dependency_package_name,
+    )
+}
+
 fn detect_yaml_indentation(input: &str) -> Option<usize> {
     for line in input.lines() {
         if let Some((indentation, _)) = line.char_indices().find(|&(_, c)| !c.is_whitespace()) {
@@ -470,7 +515,7 @@ where
         Value::String(s, span) => {
             let expanded = render_jinja_str(s, should_render_secrets, env, ctx, listeners)
                 .map_err(|e| e.with_location(span.clone()))?;
-            Ok(Some(expanded.with_span(span.clone())))
+            Ok(Some(with_span_recursive(expanded, span)))
         }
         _ => Ok(None),
     };
@@ -500,6 +545,31 @@ where
     Ok((res, warnings))
 }
 
+/// Attaches `span` to `value` and to every value nested in it, so that values
+/// produced by rendering a Jinja string point back at that string.
+///
+/// `span` を `value` とその中にネストされたすべての値に付与し、Jinja 文字列の
+/// レンダリングによって生成された値がその文字列を指すようにします。
+fn with_span_recursive(value: Value, span: &dbt_serde_yaml::Span) -> Value {
+    let value = match value {
+        Value::Sequence(items, seq_span) => Value::Sequence(
+            items
+                .into_iter()
+                .map(|item| with_span_recursive(item, span))
+                .collect(),
+            seq_span,
+        ),
+        Value::Mapping(map, map_span) => Value::Mapping(
+            map.into_iter()
+                .map(|(k, v)| (with_span_recursive(k, span), with_span_recursive(v, span)))
+                .collect(),
+            map_span,
+        ),
+        other => other,
+    };
+    value.with_span(span.clone())
+}
+
 /// Strips any dunder fields (fields of the form `__<something>__`) from a dot-separated path string.
 /// For example, "foo.__bar__.baz" becomes "foo.baz".
 pub fn strip_dunder_fields_from_path(path: &str) -> String {
@@ -606,4 +676,50 @@ mod tests {
             "{{- config(enabled=true) -}}"
         ));
     }
+
+    #[derive(Debug, serde::Deserialize)]
+    #[allow(dead_code)]
+    struct Counted {
+        name: String,
+        count: i32,
+        #[serde(default)]
+        values: Vec<i32>,
+    }
+
+    fn load(input: &str) -> FsResult<Counted> {
+        let env = JinjaEnv::new(minijinja::Environment::new());
+        let ctx = std::collections::BTreeMap::<String, String>::new();
+        from_yaml_with_jinja(
+            &IoArgs::default(),
+            input,
+            Some(Path::new("models/schema.yml")),
+            false,
+            &env,
+            &ctx,
+            &[],
+            None,
+        )
+    }
+
+    #[test]
+    fn test_from_yaml_with_jinja_renders_values() {
+        let counted = load("name: \"{{ 'foo' }}\"\ncount: \"{{ 1 + 2 }}\"\n").unwrap();
+        assert_eq!(counted.name, "foo");
+        assert_eq!(counted.count, 3);
+    }
+
+    #[test]
+    fn test_from_yaml_with_jinja_invalid_scalar_location() {
+        let err = load("name: foo\ncount: \"{{ 'abc' }}\"\n").unwrap_err();
+        let location = err.location.clone().expect("error has a location");
+        assert_eq!((location.line, location.col), (2, 8));
+        assert_eq!(location.file, Path::new("models/schema.yml"));
+    }
+
+    #[test]
+    fn test_from_yaml_with_jinja_invalid_nested_value_location() {
+        let err = load("name: foo\ncount: 1\nvalues: \"{{ [1, 'x'] }}\"\n").unwrap_err();
+        let location = err.location.clone().expect("error has a location");
+        assert_eq!((location.line, location.col), (3, 9));
+    }
 }
@@ -219,6 +219,162 @@ pub fn truncate(state: &State, value: &Value, kwargs: Kwargs) -> Result<String,
     Ok(result)
 }
 
+/// Serializes a value to a JSON string.
+///
+/// By default the output is compact. Passing an integer `indent` pretty-prints
+/// the output with that many spaces per level.
+///
+/// ```jinja
+/// {{ config|to_json }}
+/// {{ config|to_json(indent=2) }}
+/// ```
+///
+/// Unlike the builtin `tojson` filter, the output is not HTML-escaped, which
+/// makes it suitable for building config blobs and SQL literals.
+pub fn to_json(value: &Value, indent: Option<usize>, kwargs: Kwargs) -> Result<String, Error> {
+    let indent = match kwargs.get::<Option<usize>>("indent")? {
+        Some(indent) => Some(indent),
+        None => indent,
+    };
+    kwargs.assert_all_used()?;
+
+    let rv = match indent {
+        Some(indent) => {
+            let mut out = Vec::<u8>::new();
+            let indentation = " ".repeat(indent);
+            let formatter = serde_json::ser::PrettyFormatter::with_indent(indentation.as_bytes());
+            let mut ser = serde_json::Serializer::with_formatter(&mut out, formatter);
+            serde::Serialize::serialize(value, &mut ser)
+                .map(|_| String::from_utf8(out).expect("serde_json emits valid UTF-8"))
+        }
+        None => serde_json::to_string(value),
+    };
+    rv.map_err(|err| {
+        Error::new(
+            ErrorKind::InvalidOperation,
+            format!("cannot serialize to JSON: {err}"),
+        )
+    })
+}
+
+/// Parses a JSON string into a value.
+///
+/// ```jinja
+/// {% set config = '{"enabled": true}'|from_json %}
+/// {{ config.enabled }}
+/// ```
+pub fn from_json(value: &str) -> Result<Value, Error> {
+    serde_json::from_str::<serde_json::Value>(value)
+        .map(Value::from_serialize)
+        .map_err(|err| Error::new(ErrorKind::InvalidOperation, format!("invalid JSON: {err}")))
+}
+
+/// Fails if the sandbox of the environment rejects any segment of the
+/// dotted attribute `path`.
+fn check_path_access(state: &State, path: &str) -> Result<(), Error> {
+    path.split('.')
+        .try_for_each(|segment| state.env().check_sandbox_access(segment))
+}
+
+/// Looks up an attribute or dotted path (`config.materialized`, `items.0`)
+/// on `value`, returning undefined if any segment is missing.
+fn get_path(value: &Value, path: &str) -> Value {
+    let mut rv = value.clone();
+    for part in path.split('.') {
+        rv = match part.parse::<usize>() {
+            Ok(idx) => rv.get_item_by_index(idx),
+            Err(_) => rv.get_attr(part),
+        }
+        .unwrap_or(Value::UNDEFINED);
+    }
+    rv
+}
+
+/// Removes duplicate items from a sequence, keeping the first occurrence.
+///
+/// ```jinja
+/// {{ ['a', 'b', 'a']|unique }}
+///   -> ['a', 'b']
+/// {{ columns|unique(attribute='name') }}
+/// {{ ['CA', 'NY', 'ca']|unique(case_sensitive=false) }}
+///   -> ['CA', 'NY']
+/// ```
+///
+/// The `attribute` keyword argument dedups on an attribute (a dotted path is
+/// supported) instead of the item itself. Strings are compared
+/// case-sensitively unless `case_sensitive=false` is passed.
+///
+/// **Note:** this replaces the builtin `unique` filter when registered, which
+/// compares case-insensitively by default.
+pub fn unique(state: &State, seq: &Value, kwargs: Kwargs) -> Result<Value, Error> {
+    use std::collections::BTreeSet;
+
+    let attr = kwargs.get::<Option<&str>>("attribute")?;
+    if let Some(attr) = attr {
+        check_path_access(state, attr)?;
+    }
+    let case_sensitive = kwargs
+        .get::<Option<bool>>("case_sensitive")?
+        .unwrap_or(true);
+    kwargs.assert_all_used()?;
+
+    let mut rv = Vec::new();
+    let mut seen = BTreeSet::new();
+    for item in seq.try_iter()? {
+        let mut key = match attr {
+            Some(attr) => get_path(&item, attr),
+            None => item.clone(),
+        };
+        if !case_sensitive {
+            if let Some(s) = key.as_str() {
+                key = Value::from(s.to_lowercase());
+            }
+        }
+        if seen.insert(key) {
+            rv.push(item);
+        }
+    }
+
+    Ok(Value::from(rv))
+}
+
+/// Groups a sequence of objects by an attribute, sorted by group key.
+///
+/// Items that lack the attribute (or where it is undefined) are placed in the
+/// `default` bucket instead of producing an error. Each group is a
+/// `(grouper, list)` tuple and items keep their original order within a group.
+///
+/// ```jinja
+/// {% for materialized, models in nodes|groupby_default("config.materialized", "view") %}
+///   {{ materialized }}: {{ models|map(attribute="name")|join(", ") }}
+/// {% endfor %}
+/// ```
+pub fn groupby_default(
+    state: &State,
+    seq: &Value,
+    attribute: &str,
+    default: Value,
+) -> Result<Value, Error> {
+    use std::collections::BTreeMap;
+
+    check_path_access(state, attribute)?;
+    let mut groups = BTreeMap::<Value, Vec<Value>>::new();
+    for item in seq.try_iter()? {
+        let mut key = get_path(&item, attribute);
+        if key.is_undefined() {
+            key = default.clone();
+        }
+        groups.entry(key).or_default().push(item);
+    }
+
+    Ok(Value::from(
+        groups
+            .into_iter()
+            .map(|(grouper, list)| Value::from_tuple(vec![grouper, Value::from(list)]))
+            .collect::<Vec<_>>(),
+    ))
+}
+
 /// Counts the words in a string.
 ///
 /// ```jinja
@@ -5,7 +5,7 @@ use std::sync::Arc;
 use minijinja::listener::RenderingEventListener;
 #[allow(unused)]
 use minijinja::value::Value;
-use minijinja::value::{from_args, Object, ObjectRepr};
+use minijinja::value::{from_args, Kwargs, Object, ObjectRepr};
 use minijinja::{Error, ErrorKind, State};
 
 /// Returns the current time in UTC as unix timestamp.
@@ -18,6 +18,76 @@ pub fn now() -> Value {
     Value::from(((now.unix_timestamp_nanos() / 1000) as f64) / 1_000_000.0)
 }
 
+/// Returns a list of `YYYY-MM-DD` date strings from `start` to `end`, inclusive.
+///
+/// ```jinja
+/// {% for day in daterange("2024-01-01", "2024-01-07") %}
+///   partition_date = '{{ day }}'
+/// {% endfor %}
+/// ```
+///
+/// The step is passed as the optional third argument or as the `step_days`
+/// keyword argument and defaults to `1` (`daterange(a, b, -1)` or
+/// `daterange(a, b, step_days=-1)`). A descending range requires a negative step;
+/// if the step points away from `end` the result is empty.  When `start` and
+/// `end` are equal the result is a single element.  The end date is only
+/// included if it is reached exactly by the step.
+#[cfg(feature = "datetime")]
+#[cfg_attr(docsrs, doc(cfg(feature = "datetime")))]
+pub fn daterange(
+    start: &str,
+    end: &str,
+    step_days: Option<i64>,
+    kwargs: Kwargs,
+) -> Result<Value, Error> {
+    use time::format_description::well_known::Iso8601;
+    use time::Date;
+
+    let parse = |value: &str| {
+        Date::parse(value, &Iso8601::PARSING).map_err(|err| {
+            Error::new(
+                ErrorKind::InvalidOperation,
+                format!("invalid date {value:?}, expected YYYY-MM-DD"),
+            )
+            .with_source(err)
+        })
+    };
+    let start = parse(start)?;
+    let end = parse(end)?;
+    let step = match (step_days, kwargs.get::<Option<i64>>("step_days")?) {
+        (Some(_), Some(_)) => {
+            return Err(Error::new(
+                ErrorKind::TooManyArguments,
+                "daterange got step_days both as an argument and as a keyword argument",
+            ))
+        }
+        (step, kwarg) => step.or(kwarg).unwrap_or(1),
+    };
+    kwargs.assert_all_used()?;
+    if step == 0 {
+        return Err(Error::new(
+            ErrorKind::InvalidOperation,
+            "daterange step_days must not be zero",
+        ));
+    }
+
+    let mut rv = Vec::new();
+    let mut current = Some(start);
+    while let Some(date) = current {
+        if (step > 0 && date > end) || (step < 0 && date < end) {
+            break;
+        }
+        rv.push(Value::from(format!(
+            "{:04}-{:02}-{:02}",
+            date.year(),
+            u8::from(date.month()),
+            date.day()
+        )));
+        current = date.checked_add(time::Duration::days(step));
+    }
+    Ok(Value::from(rv))
+}
+
 /// Returns a cycler.
 ///
 /// Similar to `loop.cycle`, but can be used outside loops or across
@@ -134,6 +204,41 @@ pub fn joiner(sep: Option<Value>) -> Value {
     })
 }
 
+/// Name of the global holding the environment-level seed (see [`set_rand_seed`]).
+#[cfg(feature = "rand")]
+const RAND_SEED_GLOBAL: &str = "__minijinja_contrib_rand_seed__";
+
+/// Environment-level seed registered by [`set_rand_seed`].
+#[cfg(feature = "rand")]
+#[derive(Debug)]
+struct RandSeed(u64);
+
+#[cfg(feature = "rand")]
+impl Object for RandSeed {
+    fn repr(self: &Arc<Self>) -> ObjectRepr {
+        ObjectRepr::Plain
+    }
+}
+
+/// Seeds `lipsum`, `randrange` and `random` for the whole environment.
+///
+/// Each call derives its own generator from `seed`, the name of the template
+/// and the position of the call within the render, so a template renders the
+/// same values every time regardless of which other templates were rendered
+/// before or concurrently.  A `RAND_SEED` context variable still takes
+/// precedence.
+///
+/// ```
+/// let mut env = minijinja::Environment::new();
+/// minijinja_contrib::add_to_environment(&mut env);
+/// minijinja_contrib::globals::set_rand_seed(&mut env, 42);
+/// ```
+#[cfg(feature = "rand")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+pub fn set_rand_seed(env: &mut minijinja::Environment, seed: u64) {
+    env.add_global(RAND_SEED_GLOBAL, Value::from_object(RandSeed(seed)));
+}
+
 /// Returns the rng for the state
 #[cfg(feature = "rand")]
 pub(crate) fn get_rng(state: &State) -> rand::rngs::SmallRng {
@@ -145,6 +250,18 @@ pub(crate) fn get_rng(state: &State) -> rand::rngs::SmallRng {
         .and_then(|x| u64::try_from(x).ok())
     {
         SmallRng::seed_from_u64(seed)
+    } else if let Some(seed) = state
+        .lookup(RAND_SEED_GLOBAL)
+        .and_then(|x| x.downcast_object::<RandSeed>())
+    {
+        // FNV-1a over the template name and call position, which unlike
+        // `DefaultHasher` is stable across builds
+        let call_index = state.next_call_index().to_le_bytes();
+        let mut hash = 0xcbf2_9ce4_8422_2325 ^ seed.0;
+        for byte in state.name().bytes().chain(call_index) {
+            hash = (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3);
+        }
+        SmallRng::seed_from_u64(hash)
     } else {
         SmallRng::from_entropy()
     }
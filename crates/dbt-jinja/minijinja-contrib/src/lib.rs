@@ -40,6 +40,10 @@ pub fn add_to_environment(env: &mut Environment) {
     env.add_filter("pluralize", filters::pluralize);
     env.add_filter("filesizeformat", filters::filesizeformat);
     env.add_filter("truncate", filters::truncate);
+    env.add_filter("to_json", filters::to_json);
+    env.add_filter("from_json", filters::from_json);
+    env.add_filter("unique", filters::unique);
+    env.add_filter("groupby_default", filters::groupby_default);
     let mut modules = BTreeMap::new();
     #[cfg(feature = "wordcount")]
     {
@@ -54,6 +58,7 @@ pub fn add_to_environment(env: &mut Environment) {
         env.add_filter("datetimeformat", filters::datetimeformat);
         env.add_filter("timeformat", filters::timeformat);
         env.add_filter("dateformat", filters::dateformat);
+        env.add_function("daterange", globals::daterange);
         let datetime_namespace = crate::modules::py_datetime::create_datetime_module();
         modules.insert(
             "datetime".to_string(),
@@ -211,6 +211,197 @@ fn test_truncate() {
     );
 }
 
+#[test]
+fn test_to_json_from_json_roundtrip() {
+    use minijinja_contrib::filters::{from_json, to_json};
+
+    let mut env = Environment::new();
+    env.add_filter("to_json", to_json);
+    env.add_filter("from_json", from_json);
+
+    let ctx = context! {
+        config => context! { enabled => true, name => "orders", tags => vec!["a", "b"] },
+    };
+    assert_eq!(
+        env.render_str(r"{{ config|to_json }}", ctx.clone(), &[])
+            .unwrap(),
+        r#"{"enabled":true,"name":"orders","tags":["a","b"]}"#
+    );
+    assert_eq!(
+        env.render_str(
+            r"{% set parsed = config|to_json|from_json %}{{ parsed.name }} {{ parsed.tags|length }} {{ parsed == config }}",
+            ctx,
+            &[]
+        )
+        .unwrap(),
+        "orders 2 true"
+    );
+}
+
+#[test]
+fn test_to_json_indent() {
+    use minijinja_contrib::filters::to_json;
+
+    let mut env = Environment::new();
+    env.add_filter("to_json", to_json);
+
+    let ctx = context! { config => context! { a => 1, b => vec![1, 2] } };
+    let expected = "{\n  \"a\": 1,\n  \"b\": [\n    1,\n    2\n  ]\n}";
+    assert_eq!(
+        env.render_str(r"{{ config|to_json(indent=2) }}", ctx.clone(), &[])
+            .unwrap(),
+        expected
+    );
+    assert_eq!(
+        env.render_str(r"{{ config|to_json(2) }}", ctx, &[])
+            .unwrap(),
+        expected
+    );
+}
+
+#[test]
+fn test_from_json_invalid() {
+    use minijinja_contrib::filters::from_json;
+
+    let mut env = Environment::new();
+    env.add_filter("from_json", from_json);
+
+    let err = env
+        .render_str(r#"{{ '{"a": 1'|from_json }}"#, context! {}, &[])
+        .unwrap_err();
+    assert!(
+        err.to_string()
+            .starts_with("invalid operation: invalid JSON:"),
+        "unexpected error: {err}"
+    );
+}
+
+#[test]
+fn test_unique() {
+    use minijinja_contrib::filters::unique;
+
+    let mut env = Environment::new();
+    env.add_filter("unique", unique);
+
+    assert_eq!(
+        env.render_str(r"{{ [3, 1, 3, 2, 1]|unique }}", context! {}, &[])
+            .unwrap(),
+        "[3, 1, 2]"
+    );
+
+    let ctx = context! {
+        columns => vec![
+            context! { name => "id", data_type => "int" },
+            context! { name => "amount", data_type => "numeric" },
+            context! { name => "id", data_type => "bigint" },
+        ],
+    };
+    assert_eq!(
+        env.render_str(
+            r"{% for c in columns|unique(attribute='name') %}{{ c.name }}:{{ c.data_type }} {% endfor %}",
+            ctx,
+            &[]
+        )
+        .unwrap(),
+        "id:int amount:numeric "
+    );
+}
+
+#[test]
+fn test_unique_case_sensitivity() {
+    use minijinja_contrib::filters::unique;
+
+    let mut env = Environment::new();
+    env.add_filter("unique", unique);
+
+    assert_eq!(
+        env.render_str(
+            r"{{ ['CA', 'NY', 'ca']|unique|join(',') }}",
+            context! {},
+            &[]
+        )
+        .unwrap(),
+        "CA,NY,ca"
+    );
+    assert_eq!(
+        env.render_str(
+            r"{{ ['CA', 'NY', 'ca']|unique(case_sensitive=false)|join(',') }}",
+            context! {},
+            &[]
+        )
+        .unwrap(),
+        "CA,NY"
+    );
+
+    // registering the contrib filters replaces the case-insensitive builtin
+    let mut env = Environment::new();
+    minijinja_contrib::add_to_environment(&mut env);
+    assert_eq!(
+        env.render_str(
+            r"{{ ['CA', 'NY', 'ca']|unique|join(',') }}",
+            context! {},
+            &[]
+        )
+        .unwrap(),
+        "CA,NY,ca"
+    );
+}
+
+#[test]
+fn test_groupby_default() {
+    use minijinja_contrib::filters::groupby_default;
+
+    let mut env = Environment::new();
+    env.add_filter("groupby_default", groupby_default);
+
+    let ctx = context! {
+        models => vec![
+            context! { name => "orders", materialized => "table" },
+            context! { name => "stg_orders" },
+            context! { name => "customers", materialized => "incremental" },
+            context! { name => "stg_customers" },
+            context! { name => "payments", materialized => "table" },
+        ],
+    };
+    assert_eq!(
+        env.render_str(
+            r#"{% for key, items in models|groupby_default("materialized", "view") %}{{ key }}={{ items|map(attribute="name")|join(",") }};{% endfor %}"#,
+            ctx,
+            &[]
+        )
+        .unwrap(),
+        "incremental=customers;table=orders,payments;view=stg_orders,stg_customers;"
+    );
+}
+
+#[test]
+fn test_attribute_filters_respect_sandbox() {
+    use minijinja_contrib::filters::{groupby_default, unique};
+
+    let mut env = Environment::new();
+    env.add_filter("unique", unique);
+    env.add_filter("groupby_default", groupby_default);
+    env.set_sandbox(true);
+
+    let ctx = context! { items => vec![context! { _secret => "x", name => "a" }] };
+    assert_eq!(
+        env.render_str(
+            r"{{ items|unique(attribute='name')|length }}",
+            ctx.clone(),
+            &[]
+        )
+        .unwrap(),
+        "1"
+    );
+    for template in [
+        r"{{ items|unique(attribute='_secret') }}",
+        r"{{ items|groupby_default('_secret', 'none') }}",
+    ] {
+        let err = env.render_str(template, ctx.clone(), &[]).unwrap_err();
+        assert_eq!(err.kind(), minijinja::ErrorKind::InvalidOperation);
+    }
+}
+
 #[test]
 #[cfg(feature = "wordwrap")]
 fn test_wordcount() {
@@ -85,3 +85,94 @@ fn test_randrange() {
     assert_snapshot!(render!(in env, r"{% set RAND_SEED = 42 %}{{ randrange(10) }}"), @"1");
     assert_snapshot!(render!(in env, r"{% set RAND_SEED = 42 %}{{ randrange(-50, 50) }}"), @"-20");
 }
+
+#[test]
+#[cfg(feature = "rand")]
+fn test_set_rand_seed() {
+    use minijinja_contrib::globals::set_rand_seed;
+
+    let seeded_env = |seed| {
+        let mut env = Environment::new();
+        minijinja_contrib::add_to_environment(&mut env);
+        set_rand_seed(&mut env, seed);
+        env
+    };
+    let tmpl = r"{% for _ in [1, 2, 3, 4, 5, 6, 7, 8] %}{{ randrange(1000000) }},{% endfor %}";
+
+    let a = seeded_env(42);
+    let b = seeded_env(42);
+    let first = a.render_str(tmpl, (), &[]).unwrap();
+    assert_eq!(first, b.render_str(tmpl, (), &[]).unwrap());
+    // every call within a render gets its own value
+    let values = first.split(',').collect::<std::collections::BTreeSet<_>>();
+    assert!(values.len() > 2, "{first}");
+
+    // rendering another template first does not shift the values
+    a.render_str(r"{{ randrange(10) }}", (), &[]).unwrap();
+    assert_eq!(first, a.render_str(tmpl, (), &[]).unwrap());
+
+    let other = seeded_env(7).render_str(tmpl, (), &[]).unwrap();
+    assert_ne!(first, other);
+
+    // RAND_SEED in the context still wins over the environment seed
+    assert_eq!(
+        a.render_str(r"{% set RAND_SEED = 42 %}{{ randrange(10) }}", (), &[])
+            .unwrap(),
+        "1"
+    );
+}
+
+#[test]
+#[cfg(feature = "datetime")]
+fn test_daterange() {
+    use minijinja_contrib::globals::daterange;
+
+    let mut env = Environment::new();
+    env.add_function("daterange", daterange);
+
+    assert_snapshot!(render!(in env, r#"{{ daterange("2024-02-26", "2024-03-03")|join(",") }}"#), @"2024-02-26,2024-02-27,2024-02-28,2024-02-29,2024-03-01,2024-03-02,2024-03-03");
+    assert_snapshot!(render!(in env, r#"{{ daterange("2024-01-01", "2024-01-01")|join(",") }}"#), @"2024-01-01");
+    assert_snapshot!(render!(in env, r#"{{ daterange("2024-01-07", "2024-01-01")|length }}"#), @"0");
+}
+
+#[test]
+#[cfg(feature = "datetime")]
+fn test_daterange_descending() {
+    use minijinja_contrib::globals::daterange;
+
+    let mut env = Environment::new();
+    env.add_function("daterange", daterange);
+
+    assert_snapshot!(render!(in env, r#"{{ daterange("2024-01-07", "2024-01-01", step_days=-2)|join(",") }}"#), @"2024-01-07,2024-01-05,2024-01-03,2024-01-01");
+    assert_snapshot!(render!(in env, r#"{{ daterange("2024-01-03", "2024-01-01", step_days=-1)|join(",") }}"#), @"2024-01-03,2024-01-02,2024-01-01");
+    assert_snapshot!(render!(in env, r#"{{ daterange("2024-01-07", "2024-01-01", -3)|join(",") }}"#), @"2024-01-07,2024-01-04,2024-01-01");
+
+    let err = env
+        .render_str(
+            r#"{{ daterange("2024-13-01", "2024-01-01") }}"#,
+            minijinja::context! {},
+            &[],
+        )
+        .unwrap_err();
+    assert!(err.to_string().contains("invalid date \"2024-13-01\""));
+
+    let err = env
+        .render_str(
+            r#"{{ daterange("2024-01-01", "2024-01-07", step=2) }}"#,
+            minijinja::context! {},
+            &[],
+        )
+        .unwrap_err();
+    assert!(err.to_string().contains("unknown keyword argument"));
+
+    let err = env
+        .render_str(
+            r#"{{ daterange("2024-01-01", "2024-01-07", 2, step_days=2) }}"#,
+            minijinja::context! {},
+            &[],
+        )
+        .unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("both as an argument and as a keyword argument"));
+}
@@ -1241,7 +1241,14 @@ impl<'source> CodeGenerator<'source> {
                         }
                     }
                 }
-                self.add(Instruction::CallFunction(name, arg_count, span));
+                if name == "reset_span"
+                    && arg_count == Some(7)
+                    && c.args.iter().all(|arg| matches!(arg, ast::CallArg::Pos(_)))
+                {
+                    self.add(Instruction::SetCurrentSpan(span));
+                } else {
+                    self.add(Instruction::CallFunction(name, arg_count, span));
+                }
             }
             #[cfg(feature = "multi_template")]
             ast::CallType::Block(name) => {
@@ -190,6 +190,10 @@ pub enum Instruction<'source> {
     /// Calls a global function
     CallFunction(&'source str, Option<u16>, Span),
 
+    /// Sets the current path and span from the seven `reset_span` arguments
+    /// on the stack and pushes undefined.
+    SetCurrentSpan(Span),
+
     /// Calls a method
     CallMethod(&'source str, Option<u16>, Span),
 
@@ -2,6 +2,10 @@ use std::collections::HashSet;
 use std::fmt::Write;
 
 use crate::compiler::ast;
+#[cfg(feature = "macros")]
+use crate::macro_unit::MacroSignature;
+#[cfg(feature = "macros")]
+use crate::ArgSpec;
 
 struct AssignmentTracker<'a> {
     out: HashSet<&'a str>,
@@ -47,6 +51,40 @@ pub fn find_macro_closure<'a>(m: &ast::Macro<'a>) -> HashSet<&'a str> {
     state.out
 }
 
+/// Finds the signatures of all macros declared at the top level of a template.
+#[cfg(feature = "macros")]
+pub fn find_macro_decls(t: &ast::Stmt<'_>) -> Vec<MacroSignature> {
+    let ast::Stmt::Template(template) = t else {
+        return Vec::new();
+    };
+    template
+        .children
+        .iter()
+        .filter_map(|child| match child {
+            ast::Stmt::Macro(stmt) => Some(&*stmt.0),
+            _ => None,
+        })
+        .map(|m| {
+            let required = m.args.len() - m.defaults.len();
+            let closure = find_macro_closure(m);
+            MacroSignature {
+                name: m.name.to_string(),
+                args: m
+                    .args
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(idx, arg)| match arg {
+                        ast::Expr::Var(var) => Some(ArgSpec::new(var.id, idx >= required)),
+                        _ => None,
+                    })
+                    .collect(),
+                varargs: closure.contains("varargs"),
+                kwargs: closure.contains("kwargs"),
+            }
+        })
+        .collect()
+}
+
 /// Finds all variables that are undeclared in a template.
 pub fn find_undeclared(t: &ast::Stmt<'_>, track_nested: bool) -> HashSet<String> {
     let mut state = AssignmentTracker {
@@ -1,5 +1,5 @@
 use std::borrow::Cow;
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 use std::fmt;
 use std::path::PathBuf;
 use std::rc::Rc;
@@ -8,6 +8,8 @@ use std::sync::Arc;
 use serde::Serialize;
 
 use crate::compiler::codegen::{CodeGenerationProfile, CodeGenerator};
+#[cfg(feature = "internal_debug")]
+use crate::compiler::instructions::Instruction;
 use crate::compiler::instructions::Instructions;
 use crate::compiler::parser::parse_expr;
 use crate::constants::{
@@ -18,6 +20,8 @@ use crate::error::{Error, ErrorKind};
 use crate::expression::Expression;
 use crate::listener::RenderingEventListener;
 use crate::machinery::Span;
+#[cfg(feature = "macros")]
+use crate::macro_unit::MacroSignature;
 use crate::output::Output;
 use crate::template::{CompiledTemplate, CompiledTemplateRef, Template, TemplateConfig};
 use crate::utils::{AutoEscape, BTreeMapKeysDebug, UndefinedBehavior};
@@ -30,6 +34,8 @@ type FormatterFunc = dyn Fn(&mut Output, &State, &Value) -> Result<(), Error> +
 type PathJoinFunc = dyn for<'s> Fn(&'s str, &'s str) -> Cow<'s, str> + Sync + Send;
 type UnknownMethodFunc =
     dyn Fn(&State, &Value, &str, &[Value]) -> Result<Value, Error> + Sync + Send;
+#[cfg(feature = "internal_debug")]
+type InstructionTraceFunc = dyn Fn(usize, &Instruction<'_>, usize) + Sync + Send;
 
 /// The maximum recursion in the VM.  Normally each stack frame
 /// adds one to this counter (eg: every time a frame is added).
@@ -67,7 +73,14 @@ pub struct Environment<'source> {
     debug: bool,
     #[cfg(feature = "fuel")]
     fuel: Option<u64>,
+    #[cfg(feature = "internal_debug")]
+    pub(crate) instruction_trace_callback: Option<Arc<InstructionTraceFunc>>,
     recursion_limit: usize,
+    max_collection_size: Option<usize>,
+    sandbox: bool,
+    sandbox_deny_list: BTreeSet<String>,
+    allow_non_string_caller_return: bool,
+    record_var_kinds: bool,
     /// The code generation profile for this environment.
     pub profile: CodeGenerationProfile,
 }
@@ -123,7 +136,14 @@ impl<'source> Environment<'source> {
             debug: cfg!(debug_assertions),
             #[cfg(feature = "fuel")]
             fuel: None,
+            #[cfg(feature = "internal_debug")]
+            instruction_trace_callback: None,
             recursion_limit: MAX_RECURSION,
+            max_collection_size: None,
+            sandbox: false,
+            sandbox_deny_list: BTreeSet::new(),
+            allow_non_string_caller_return: false,
+            record_var_kinds: false,
             profile,
         }
     }
@@ -154,7 +174,14 @@ impl<'source> Environment<'source> {
             debug: cfg!(debug_assertions),
             #[cfg(feature = "fuel")]
             fuel: None,
+            #[cfg(feature = "internal_debug")]
+            instruction_trace_callback: None,
             recursion_limit: MAX_RECURSION,
+            max_collection_size: None,
+            sandbox: false,
+            sandbox_deny_list: BTreeSet::new(),
+            allow_non_string_caller_return: false,
+            record_var_kinds: false,
             profile: CodeGenerationProfile::Render,
         }
     }
@@ -375,6 +402,20 @@ impl<'source> Environment<'source> {
         self.unknown_method_callback = Some(Arc::new(f));
     }
 
+    /// Sets a callback invoked before the VM executes each instruction.
+    ///
+    /// The callback receives the program counter, the instruction and the
+    /// depth of the value stack.  This is meant for debugging macro evaluation
+    /// and makes rendering considerably slower.
+    #[cfg(feature = "internal_debug")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "internal_debug")))]
+    pub fn set_instruction_trace_callback<F>(&mut self, f: F)
+    where
+        F: Fn(usize, &Instruction<'_>, usize) + Sync + Send + 'static,
+    {
+        self.instruction_trace_callback = Some(Arc::new(f));
+    }
+
     /// Removes all stored templates.
     ///
     /// This method is mainly useful when combined with a loader as it causes
@@ -431,6 +472,16 @@ impl<'source> Environment<'source> {
         Ok(Template::new(self, CompiledTemplateRef::Borrowed(compiled)))
     }
 
+    /// Returns the signatures of the macros declared in the named template.
+    ///
+    /// Each signature reports the macro name, its declared arguments (args
+    /// with a default are optional) and whether the body uses `varargs` or
+    /// `kwargs`.  This is useful for tooling such as autocompletion.
+    #[cfg(feature = "macros")]
+    pub fn macro_signatures(&self, template_name: &str) -> Result<Vec<MacroSignature>, Error> {
+        ok!(self.get_template(template_name, &[])).macro_signatures()
+    }
+
     /// Loads a template from a string.
     ///
     /// In some cases you really only need to work with (eg: render) a template to be
@@ -717,6 +768,112 @@ impl<'source> Environment<'source> {
         self.recursion_limit
     }
 
+    /// Sets the maximum number of items in a list, tuple or map literal.
+    ///
+    /// Building a larger collection fails with an
+    /// [`InvalidOperation`](crate::ErrorKind::InvalidOperation) error instead
+    /// of attempting the allocation.  This matters for item counts that are
+    /// only known at runtime, e.g. when unpacking into a list.  By default
+    /// there is no limit (`None`).
+    pub fn set_max_collection_size(&mut self, max: Option<usize>) {
+        self.max_collection_size = max;
+    }
+
+    /// Returns the maximum collection size, if any.
+    pub fn max_collection_size(&self) -> Option<usize> {
+        self.max_collection_size
+    }
+
+    /// Enables or disables the sandbox.
+    ///
+    /// In a sandboxed environment, attribute access (`a.b`, `a['b']`), method
+    /// calls (`a.b()`), namespace assignments and attribute paths given to
+    /// filters (`map(attribute='b')`, `groupby('b')`, ...) fail with an
+    /// [`InvalidOperation`](crate::ErrorKind::InvalidOperation) error when the
+    /// name starts with an underscore or is on the
+    /// [deny-list](Self::set_sandbox_deny_list).  Plain variable access is not
+    /// affected.  The sandbox is disabled by default.
+    pub fn set_sandbox(&mut self, enabled: bool) {
+        self.sandbox = enabled;
+    }
+
+    /// Returns `true` if the sandbox is enabled.
+    pub fn sandboxed(&self) -> bool {
+        self.sandbox
+    }
+
+    /// Sets the attribute and method names rejected by the sandbox.
+    pub fn set_sandbox_deny_list<I, S>(&mut self, names: I)
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.sandbox_deny_list = names.into_iter().map(Into::into).collect();
+    }
+
+    /// Returns the attribute and method names rejected by the sandbox.
+    pub fn sandbox_deny_list(&self) -> &BTreeSet<String> {
+        &self.sandbox_deny_list
+    }
+
+    /// Returns `true` if the sandbox rejects access to the attribute or
+    /// method `name`.
+    pub(crate) fn is_denied_by_sandbox(&self, name: &str) -> bool {
+        self.sandbox && (name.starts_with('_') || self.sandbox_deny_list.contains(name))
+    }
+
+    /// Fails if the sandbox rejects access to the attribute, item or method
+    /// `name`.
+    ///
+    /// Custom filters and functions that look up attributes by name should
+    /// call this so that they can't be used to bypass the sandbox.
+    pub fn check_sandbox_access(&self, name: &str) -> Result<(), Error> {
+        if self.is_denied_by_sandbox(name) {
+            Err(Error::new(
+                ErrorKind::InvalidOperation,
+                format!("access to `{name}` is not allowed in a sandboxed environment"),
+            ))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Allows `caller()` to return values other than strings.
+    ///
+    /// By default a `return()` inside a call block must produce a string and
+    /// anything else fails with an
+    /// [`InvalidOperation`](crate::ErrorKind::InvalidOperation) error.  With
+    /// this enabled, a non-string value is handed back to the calling macro as
+    /// is, so call blocks can build lists, maps and other structured values.
+    ///
+    /// String returns are unaffected: as before, returning a string from a
+    /// call block makes the calling macro itself return that string, while a
+    /// non-string value becomes the result of `caller()` and the calling macro
+    /// keeps running.
+    pub fn set_allow_non_string_caller_return(&mut self, enabled: bool) {
+        self.allow_non_string_caller_return = enabled;
+    }
+
+    /// Returns `true` if `caller()` may return values other than strings.
+    pub fn allow_non_string_caller_return(&self) -> bool {
+        self.allow_non_string_caller_return
+    }
+
+    /// Enables recording the runtime kinds of looked up variables.
+    ///
+    /// When enabled, every variable lookup records the top-level kind of the
+    /// value on the [`State`](crate::State), see
+    /// [`State::observed_var_kinds`](crate::State::observed_var_kinds).  This
+    /// is disabled by default as it adds work to every lookup.
+    pub fn set_record_var_kinds(&mut self, enabled: bool) {
+        self.record_var_kinds = enabled;
+    }
+
+    /// Returns `true` if variable kinds are recorded during evaluation.
+    pub fn record_var_kinds(&self) -> bool {
+        self.record_var_kinds
+    }
+
     /// Compiles an expression.
     ///
     /// This lets one compile an expression in the template language and
@@ -784,8 +941,11 @@ impl<'source> Environment<'source> {
     }
 
     /// Removes a filter by name.
-    pub fn remove_filter(&mut self, name: &str) {
-        self.filters.remove(name);
+    ///
+    /// Returns `true` if a filter with that name was registered.  Templates
+    /// using the filter afterwards fail with an unknown filter error.
+    pub fn remove_filter(&mut self, name: &str) -> bool {
+        self.filters.remove(name).is_some()
     }
 
     /// Adds a new test function.
@@ -805,8 +965,11 @@ impl<'source> Environment<'source> {
     }
 
     /// Removes a test by name.
-    pub fn remove_test(&mut self, name: &str) {
-        self.tests.remove(name);
+    ///
+    /// Returns `true` if a test with that name was registered.  Templates
+    /// using the test afterwards fail with an unknown test error.
+    pub fn remove_test(&mut self, name: &str) -> bool {
+        self.tests.remove(name).is_some()
     }
 
     /// Returns the dialect of the environment.
@@ -384,6 +384,64 @@ impl Error {
             .map(|x| x.start_offset as usize..x.end_offset as usize)
     }
 
+    /// Renders the error followed by the lines of `source` covered by its
+    /// span, with the offending range underlined by carets.
+    ///
+    /// `source` must be the source the span refers to, usually the template
+    /// source.  A span that starts past the end of a line or of the source
+    /// gets a single caret right after the last character.  If the error
+    /// carries no span only the error itself is rendered.
+    pub fn display_with_source(&self, source: &str) -> String {
+        use std::fmt::Write as _;
+
+        let mut rv = self.to_string();
+        let Some(span) = self.span() else {
+            return rv;
+        };
+
+        let lines: Vec<&str> = source.lines().collect();
+        let start_line = span.start_line.max(1) as usize;
+        let mut end_line = (span.end_line as usize).max(start_line);
+        let mut end_col = span.end_col.max(1) as usize;
+        // a span ending right after a newline does not highlight the next line
+        if end_line > start_line && end_col == 1 {
+            end_line -= 1;
+            end_col = usize::MAX;
+        }
+
+        let gutter = end_line.to_string().len();
+        let _ = write!(rv, "\n{:gutter$} |", "");
+        for line_no in start_line..=end_line {
+            let line = lines.get(line_no - 1).copied().unwrap_or("");
+            let len = line.chars().count();
+            let from = if line_no == start_line {
+                (span.start_col.max(1) as usize - 1).min(len)
+            } else {
+                0
+            };
+            let to = if line_no == end_line {
+                (end_col - 1).min(len)
+            } else {
+                len
+            };
+            let mut carets = to.saturating_sub(from);
+            if line_no == start_line {
+                carets = carets.max(1);
+            }
+            let _ = write!(rv, "\n{line_no:>gutter$} | {line}");
+            if carets > 0 {
+                let _ = write!(
+                    rv,
+                    "\n{:gutter$} | {}{}",
+                    "",
+                    " ".repeat(from),
+                    "^".repeat(carets)
+                );
+            }
+        }
+        rv
+    }
+
     pub(crate) fn with_span(&self, file_path: &Path, span: &Span) -> Error {
         Error {
             repr: Box::new(ErrorRepr {
@@ -303,6 +303,18 @@ mod builtins {
     use std::fmt::Write;
     use std::mem;
 
+    /// Returns the dotted attribute `path` if the sandbox allows looking up
+    /// each of its segments.
+    ///
+    /// Filters taking an `attribute` path must pass it through here before
+    /// any lookup, so that they can't be used to bypass the sandbox.
+    fn sandboxed_path<'p>(state: &State, path: &'p str) -> Result<&'p str, Error> {
+        for segment in path.split('.') {
+            ok!(state.env().check_sandbox_access(segment));
+        }
+        Ok(path)
+    }
+
     /// Converts a value to uppercase.
     ///
     /// ```jinja
@@ -540,6 +552,7 @@ mod builtins {
     /// Joins a sequence by a character
     #[cfg_attr(docsrs, doc(cfg(feature = "builtins")))]
     pub fn join(
+        state: &State,
         val: &Value,
         joiner: Option<Cow<'_, str>>,
         kwargs: Kwargs,
@@ -550,6 +563,7 @@ mod builtins {
 
         let joiner = joiner.as_ref().unwrap_or(&Cow::Borrowed(""));
         let attr = ok!(kwargs.get::<Option<&str>>("attribute"));
+        let attr = ok!(attr.map(|attr| sandboxed_path(state, attr)).transpose());
         ok!(kwargs.assert_all_used());
 
         let iter = ok!(val.try_iter().map_err(|err| {
@@ -824,7 +838,10 @@ mod builtins {
     /// {{ value['key'] == value|attr('key') }} -> true
     /// ```
     #[cfg_attr(docsrs, doc(cfg(feature = "builtins")))]
-    pub fn attr(value: &Value, key: &Value) -> Result<Value, Error> {
+    pub fn attr(state: &State, value: &Value, key: &Value) -> Result<Value, Error> {
+        if let Some(key) = key.as_str() {
+            ok!(sandboxed_path(state, key));
+        }
         value.get_item(key)
     }
 
@@ -986,6 +1003,7 @@ mod builtins {
         .collect::<Vec<_>>();
         let case_sensitive = ok!(kwargs.get::<Option<bool>>("case_sensitive")).unwrap_or(false);
         if let Some(attr) = ok!(kwargs.get::<Option<&str>>("attribute")) {
+            let attr = ok!(sandboxed_path(state, attr));
             items.sort_by(|a, b| match (a.get_path(attr), b.get_path(attr)) {
                 (Ok(a), Ok(b)) => cmp_helper(&a, &b, case_sensitive),
                 _ => Ordering::Equal,
@@ -1347,6 +1365,9 @@ mod builtins {
         } else {
             None
         };
+        if let Some(ref attr) = attr {
+            ok!(sandboxed_path(state, attr));
+        }
         for value in ok!(state.undefined_behavior().try_iter(value)) {
             let test_value = if let Some(ref attr) = attr {
                 ok!(value.get_path(attr))
@@ -1489,6 +1510,9 @@ mod builtins {
             } else {
                 Value::UNDEFINED
             };
+            if let Some(path) = attr.as_str() {
+                ok!(sandboxed_path(state, path));
+            }
             for value in ok!(state.undefined_behavior().try_iter(value)) {
                 let sub_val = match attr.as_str() {
                     Some(path) => value.get_path(path),
@@ -1579,13 +1603,19 @@ mod builtins {
     /// the "CA" group will have two values.  This can be disabled by passing
     /// `case_sensitive=True`.
     #[cfg_attr(docsrs, doc(cfg(feature = "builtins")))]
-    pub fn groupby(value: Value, attribute: Option<&str>, kwargs: Kwargs) -> Result<Value, Error> {
+    pub fn groupby(
+        state: &State,
+        value: Value,
+        attribute: Option<&str>,
+        kwargs: Kwargs,
+    ) -> Result<Value, Error> {
         let default = ok!(kwargs.get::<Option<Value>>("default")).unwrap_or_default();
         let case_sensitive = ok!(kwargs.get::<Option<bool>>("case_sensitive")).unwrap_or(false);
         let attr = match attribute {
             Some(attr) => attr,
             None => ok!(kwargs.get::<&str>("attribute")),
         };
+        let attr = ok!(sandboxed_path(state, attr));
         let mut items: Vec<Value> = ok!(value.try_iter()).collect();
         items.sort_by(|a, b| {
             let a = a.get_path_or_default(attr, &default);
@@ -1679,6 +1709,7 @@ mod builtins {
         use std::collections::BTreeSet;
 
         let attr = ok!(kwargs.get::<Option<&str>>("attribute"));
+        let attr = ok!(attr.map(|attr| sandboxed_path(state, attr)).transpose());
         let case_sensitive = ok!(kwargs.get::<Option<bool>>("case_sensitive")).unwrap_or(false);
         ok!(kwargs.assert_all_used());
 
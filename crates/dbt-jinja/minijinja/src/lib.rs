@@ -258,7 +258,7 @@ pub use self::error::{Error, ErrorKind};
 pub use self::expression::Expression;
 pub use self::output::{MacroSpans, Output};
 pub use self::output_tracker::OutputTracker;
-pub use self::template::Template;
+pub use self::template::{Template, TemplateStats};
 pub use self::utils::{AutoEscape, HtmlEscape, UndefinedBehavior};
 
 /// Re-export for convenience.
@@ -3,7 +3,7 @@
 
 use std::{cell::RefCell, path::Path};
 
-use crate::{machinery::Span, MacroSpans};
+use crate::{machinery::Span, MacroSpans, Value};
 
 /// A listener for rendering events. This is used for LSP
 pub trait RenderingEventListener: std::fmt::Debug {
@@ -19,6 +19,10 @@ pub trait RenderingEventListener: std::fmt::Debug {
     /// Called when a reference is encountered.
     fn on_reference(&self, _name: &str) {}
 
+    /// Called right after [Self::on_reference] when the reference is a
+    /// function call, with the (unevaluated by the callee) call arguments.
+    fn on_call(&self, _name: &str, _args: &[Value]) {}
+
     /// Called when a macro start is encountered.
     #[allow(clippy::too_many_arguments)]
     fn on_macro_start(
@@ -29,3 +29,16 @@ pub struct MacroInfo {
     /// The args of the macro.
     pub args: Vec<ArgSpec>,
 }
+
+/// The signature of a macro declared in a template.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MacroSignature {
+    /// The name of the macro.
+    pub name: String,
+    /// The declared args of the macro.  Args with a default are optional.
+    pub args: Vec<ArgSpec>,
+    /// Whether the macro body refers to `varargs`.
+    pub varargs: bool,
+    /// Whether the macro body refers to `kwargs`.
+    pub kwargs: bool,
+}
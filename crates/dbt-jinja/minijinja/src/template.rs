@@ -11,12 +11,16 @@ use crate::compiler::cfg::build_cfg;
 use crate::compiler::codegen::{CodeGenerationProfile, CodeGenerator};
 use crate::compiler::instructions::Instructions;
 use crate::compiler::lexer::WhitespaceConfig;
+#[cfg(feature = "macros")]
+use crate::compiler::meta::find_macro_decls;
 use crate::compiler::meta::find_undeclared;
 use crate::compiler::parser::parse;
 use crate::compiler::typecheck::FunctionRegistry;
 use crate::environment::Environment;
 use crate::error::Error;
 use crate::listener::RenderingEventListener;
+#[cfg(feature = "macros")]
+use crate::macro_unit::MacroSignature;
 use crate::syntax::SyntaxConfig;
 use crate::utils::AutoEscape;
 use crate::value::{self, Value};
@@ -62,6 +66,15 @@ pub struct Template<'env: 'source, 'source> {
     pub(crate) compiled: CompiledTemplateRef<'env, 'source>,
 }
 
+/// Size of a compiled template, see [`Template::stats`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct TemplateStats {
+    /// Number of bytecode instructions, including the ones of all blocks.
+    pub instructions: usize,
+    /// Number of blocks (`{% block %}`) declared in the template.
+    pub blocks: usize,
+}
+
 impl fmt::Debug for Template<'_, '_> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut ds = f.debug_struct("Template");
@@ -292,6 +305,46 @@ impl<'env, 'source> Template<'env, 'source> {
         }
     }
 
+    /// Returns the signatures of the macros declared in this template.
+    ///
+    /// ```rust
+    /// # use minijinja::Environment;
+    /// let mut env = Environment::new();
+    /// env.add_template("x", "{% macro foo(a, b=1) %}{% endmacro %}", &[]).unwrap();
+    /// let tmpl = env.get_template("x", &[]).unwrap();
+    /// let signatures = tmpl.macro_signatures().unwrap();
+    /// assert_eq!(signatures[0].name, "foo");
+    /// ```
+    #[cfg(feature = "macros")]
+    pub fn macro_signatures(&self) -> Result<Vec<MacroSignature>, Error> {
+        let ast = ok!(parse(
+            self.compiled.instructions.source(),
+            self.name(),
+            self.compiled.syntax_config.clone(),
+            Default::default(),
+        ));
+        Ok(find_macro_decls(&ast))
+    }
+
+    /// Returns the size of the compiled template.
+    ///
+    /// This is useful for profiling template complexity.
+    ///
+    /// ```rust
+    /// # use minijinja::Environment;
+    /// let mut env = Environment::new();
+    /// env.add_template("x", "{% block body %}{{ foo }}{% endblock %}", &[]).unwrap();
+    /// let stats = env.get_template("x", &[]).unwrap().stats();
+    /// assert_eq!(stats.blocks, 1);
+    /// ```
+    pub fn stats(&self) -> TemplateStats {
+        let block_instructions: usize = self.compiled.blocks.values().map(|b| b.len()).sum();
+        TemplateStats {
+            instructions: self.compiled.instructions.len() + block_instructions,
+            blocks: self.compiled.blocks.len(),
+        }
+    }
+
     /// Creates an empty [`State`] for this template.
     ///
     /// It's very rare that you need to actually do this but it can be useful when
@@ -4,7 +4,7 @@ use dashmap::DashMap;
 
 use crate::types::{
     builtins::Reference, dict::DictType, function::LambdaType, list::ListType, struct_::StructType,
-    tuple::TupleType, union::UnionType, DynObject, Type,
+    tuple::TupleType, DynObject, Type,
 };
 
 /// The error type for the funcsign parser.
@@ -301,7 +301,8 @@ fn _parse_type(
                 let (fields, consumed) = parse_fields(tokens, index + 1, registry.clone())?;
                 Ok((Type::Struct(StructType::new(fields)), 1 + consumed))
             }
-            "optional" => {
+            // `Optional[T]` is sugar for `T | none`
+            "optional" | "Optional" => {
                 let (parameters, consumed) = parse_list(tokens, index + 1, registry.clone())?;
                 if parameters.len() != 1 {
                     let location = get_token_location(tokens, index);
@@ -310,10 +311,7 @@ fn _parse_type(
                         location,
                     ))
                 } else {
-                    Ok((
-                        Type::Union(UnionType::new(vec![parameters[0].clone(), Type::None])),
-                        1 + consumed,
-                    ))
+                    Ok((parameters[0].union(&Type::None), 1 + consumed))
                 }
             }
             "none" => Ok((Type::None, 1)),
@@ -626,6 +624,7 @@ pub(crate) fn parse_type(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::types::union::UnionType;
 
     #[test]
     fn test_parse_simple_function() {
@@ -1039,4 +1038,61 @@ mod tests {
         let result = parse_type("string  |  integer  |  bool", registry).unwrap();
         matches!(result, Type::Union(_));
     }
+
+    #[test]
+    fn test_parse_optional_sugar() {
+        let registry = Arc::new(DashMap::new());
+        let optional = parse_type("Optional[string]", registry.clone()).unwrap();
+        let union = parse_type("string | none", registry.clone()).unwrap();
+        assert_eq!(optional, union);
+        assert_eq!(
+            optional,
+            Type::Union(UnionType::new([Type::String(None), Type::None]))
+        );
+        assert_eq!(optional, parse_type("optional[string]", registry).unwrap());
+    }
+
+    #[test]
+    fn test_parse_nested_optional() {
+        let registry = Arc::new(DashMap::new());
+        let optional = parse_type("Optional[list[integer]]", registry.clone()).unwrap();
+        let union = parse_type("list[integer] | none", registry.clone()).unwrap();
+        assert_eq!(optional, union);
+
+        let optional =
+            parse_type("dict[string, Optional[list[integer]]]", registry.clone()).unwrap();
+        let union = parse_type("dict[string, list[integer] | none]", registry.clone()).unwrap();
+        assert_eq!(optional, union);
+
+        let optional = parse_type("Optional[Optional[string]]", registry.clone()).unwrap();
+        let union = parse_type("string | none", registry.clone()).unwrap();
+        assert_eq!(optional, union);
+
+        let optional = parse_type("Optional[string | integer]", registry.clone()).unwrap();
+        let union = parse_type("string | integer | none", registry).unwrap();
+        assert_eq!(optional, union);
+    }
+
+    #[test]
+    fn test_parse_optional_in_signature() {
+        let registry = Arc::new(DashMap::new());
+        let (args, ret_type) = parse(
+            "(Optional[string], integer) -> Optional[bool]",
+            registry.clone(),
+        )
+        .unwrap();
+        assert_eq!(
+            args[0],
+            parse_type("string | none", registry.clone()).unwrap()
+        );
+        assert_eq!(args[1], Type::Integer(None));
+        assert_eq!(ret_type, parse_type("bool | none", registry).unwrap());
+    }
+
+    #[test]
+    fn test_parse_optional_requires_one_parameter() {
+        let registry = Arc::new(DashMap::new());
+        assert!(parse_type("Optional[]", registry.clone()).is_err());
+        assert!(parse_type("Optional[string, integer]", registry).is_err());
+    }
 }
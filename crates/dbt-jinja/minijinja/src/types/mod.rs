@@ -412,6 +412,132 @@ impl Type {
         }
     }
 
+    /// Checks if a value of this type can be used where `other` is expected.
+    ///
+    /// This is a more permissive relation than [`Type::is_subtype_of`] that the
+    /// type checker uses for assignments and argument passing.
+    ///
+    /// # Assignability Rules
+    ///
+    /// - **Invalid**: `Invalid` is never assignable, and nothing is assignable to it
+    /// - **Any type**: every type is assignable to a hard `Any`
+    /// - **Numeric widening**: `Integer` is assignable to `Float`
+    /// - **Union types**: a type is assignable to a union if it is assignable to any
+    ///   member (so `None` is assignable to a union containing `None`); a union is
+    ///   assignable if all of its members are
+    /// - **Container types**: List, Iterable, Dict and Tuple are covariant in their
+    ///   element types
+    /// - **Undefined**: only assignable to `Undefined` or a union containing it
+    /// - Everything else falls back to [`Type::is_subtype_of`]
+    pub fn is_assignable_to(&self, other: &Type) -> bool {
+        match (self, other) {
+            (Type::Invalid, _) | (_, Type::Invalid) => false,
+
+            (_, Type::Any { hard: true }) => true,
+            (Type::Any { hard: true }, _) => true,
+
+            (Type::Integer(_), Type::Float) => true,
+
+            (Type::Union(UnionType { types }), other_type) => {
+                types.iter().all(|ty| ty.is_assignable_to(other_type))
+            }
+            (type_, Type::Union(UnionType { types })) => {
+                types.iter().any(|ty| type_.is_assignable_to(ty))
+            }
+
+            (Type::List(a), Type::List(b)) => a.element.is_assignable_to(&b.element),
+            (Type::List(a), Type::Iterable(b)) => a.element.is_assignable_to(&b.element),
+            (Type::Iterable(a), Type::Iterable(b)) => a.element.is_assignable_to(&b.element),
+            (Type::Dict(a), Type::Dict(b)) => {
+                a.key.is_assignable_to(&b.key) && a.value.is_assignable_to(&b.value)
+            }
+            (Type::Tuple(a), Type::Tuple(b)) => {
+                a.fields.len() == b.fields.len()
+                    && a.fields
+                        .iter()
+                        .zip(b.fields.iter())
+                        .all(|(a_elem, b_elem)| a_elem.is_assignable_to(b_elem))
+            }
+
+            _ => self.is_subtype_of(other),
+        }
+    }
+
+    /// Describes the type in user-facing terms, for type-check error messages.
+    ///
+    /// Unlike the `Debug` output this produces phrases such as `list of string`,
+    /// `string or none` and `dict[string, integer]`.  Literal values of strings
+    /// and integers are not included.
+    pub fn describe(&self) -> String {
+        match self {
+            Type::String(_) => "string".to_string(),
+            Type::Integer(_) => "integer".to_string(),
+            Type::Float => "float".to_string(),
+            Type::Bool => "bool".to_string(),
+            Type::Bytes => "bytes".to_string(),
+            Type::TimeStamp => "timestamp".to_string(),
+            Type::Tuple(tuple) => format!(
+                "tuple[{}]",
+                tuple
+                    .fields
+                    .iter()
+                    .map(Type::describe)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            Type::List(list) => format!("list of {}", list.element.describe_nested()),
+            Type::Iterable(iterable) => {
+                format!("iterable of {}", iterable.element.describe_nested())
+            }
+            Type::Dict(dict) => format!("dict[{}, {}]", dict.key.describe(), dict.value.describe()),
+            Type::Struct(struct_) => format!(
+                "struct {{{}}}",
+                struct_
+                    .fields
+                    .iter()
+                    .map(|(name, ty)| format!("{name}: {}", ty.describe()))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            Type::Union(union) => {
+                // Keep `none` last so optionals read as `X or none`
+                let (nones, others): (Vec<_>, Vec<_>) =
+                    union.types.iter().partition(|t| matches!(t, Type::None));
+                others
+                    .into_iter()
+                    .chain(nones)
+                    .map(Type::describe_nested)
+                    .collect::<Vec<_>>()
+                    .join(" or ")
+            }
+            Type::Plain => "value".to_string(),
+            Type::None => "none".to_string(),
+            Type::Undefined => "undefined".to_string(),
+            Type::Invalid => "invalid".to_string(),
+            Type::Exception => "exception".to_string(),
+            Type::Any { .. } => "any".to_string(),
+            Type::Kwargs(_) => "keyword arguments".to_string(),
+            Type::Frame => "frame".to_string(),
+            Type::Object(obj) => obj
+                .type_name()
+                .rsplit("::")
+                .next()
+                .unwrap_or_default()
+                .to_string(),
+            Type::StdColumn => "column".to_string(),
+            Type::Namespace(name) => format!("namespace {name}"),
+        }
+    }
+
+    // Like `describe`, but wraps unions in parentheses so they read
+    // unambiguously inside `list of ...` and other unions.
+    fn describe_nested(&self) -> String {
+        match self {
+            Type::Union(_) => format!("({})", self.describe()),
+            _ => self.describe(),
+        }
+    }
+
     /// Check if the type can be compared with another type
     ///
     /// # Arguments
@@ -881,3 +1007,140 @@ impl Ord for DynObject {
 
 unsafe impl Send for DynObject {}
 unsafe impl Sync for DynObject {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn union(types: impl IntoIterator<Item = Type>) -> Type {
+        Type::Union(UnionType::new(types))
+    }
+
+    fn list(element: Type) -> Type {
+        Type::List(ListType::new(element))
+    }
+
+    fn dict(key: Type, value: Type) -> Type {
+        Type::Dict(DictType::new(key, value))
+    }
+
+    #[test]
+    fn test_assignable_identity() {
+        for ty in [
+            Type::String(None),
+            Type::Integer(None),
+            Type::Float,
+            Type::Bool,
+            Type::None,
+            Type::Undefined,
+        ] {
+            assert!(ty.is_assignable_to(&ty), "{ty:?}");
+        }
+    }
+
+    #[test]
+    fn test_assignable_numeric_widening() {
+        assert!(Type::Integer(None).is_assignable_to(&Type::Float));
+        assert!(Type::Integer(Some(1)).is_assignable_to(&Type::Float));
+        assert!(!Type::Float.is_assignable_to(&Type::Integer(None)));
+        assert!(!Type::Bool.is_assignable_to(&Type::Float));
+    }
+
+    #[test]
+    fn test_assignable_to_any() {
+        let any = Type::Any { hard: true };
+        assert!(Type::String(None).is_assignable_to(&any));
+        assert!(list(Type::Integer(None)).is_assignable_to(&any));
+        assert!(Type::None.is_assignable_to(&any));
+        assert!(Type::Undefined.is_assignable_to(&any));
+        assert!(any.is_assignable_to(&Type::String(None)));
+        assert!(!Type::String(None).is_assignable_to(&Type::Any { hard: false }));
+    }
+
+    #[test]
+    fn test_assignable_none_to_union() {
+        let optional_string = union([Type::String(None), Type::None]);
+        assert!(Type::None.is_assignable_to(&optional_string));
+        assert!(Type::String(None).is_assignable_to(&optional_string));
+        assert!(!Type::None.is_assignable_to(&Type::String(None)));
+        assert!(!Type::None.is_assignable_to(&union([Type::String(None), Type::Bool])));
+    }
+
+    #[test]
+    fn test_assignable_union_widening() {
+        let narrow = union([Type::Integer(None), Type::None]);
+        let wide = union([Type::Float, Type::String(None), Type::None]);
+        assert!(narrow.is_assignable_to(&wide));
+        assert!(!wide.is_assignable_to(&narrow));
+        assert!(Type::Integer(None).is_assignable_to(&union([Type::Float, Type::Bool])));
+        assert!(!union([Type::Integer(None), Type::Bool]).is_assignable_to(&Type::Float));
+    }
+
+    #[test]
+    fn test_assignable_container_covariance() {
+        assert!(list(Type::Integer(None)).is_assignable_to(&list(Type::Float)));
+        assert!(!list(Type::Float).is_assignable_to(&list(Type::Integer(None))));
+        assert!(list(Type::Integer(None))
+            .is_assignable_to(&Type::Iterable(IterableType::new(Type::Float))));
+        // an iterable is not necessarily a list, although the looser
+        // is_subtype_of treats the two as compatible
+        assert!(!Type::Iterable(IterableType::new(Type::Integer(None)))
+            .is_assignable_to(&list(Type::Integer(None))));
+        assert!(Type::Iterable(IterableType::new(Type::Integer(None)))
+            .is_subtype_of(&list(Type::Integer(None))));
+        assert!(list(Type::None).is_assignable_to(&list(union([Type::String(None), Type::None]))));
+
+        assert!(dict(Type::String(None), Type::Integer(None))
+            .is_assignable_to(&dict(Type::String(None), Type::Float)));
+        assert!(!dict(Type::String(None), Type::Float)
+            .is_assignable_to(&dict(Type::String(None), Type::Integer(None))));
+        assert!(!dict(Type::Integer(None), Type::Float)
+            .is_assignable_to(&dict(Type::String(None), Type::Float)));
+
+        assert!(
+            Type::Tuple(TupleType::new(vec![Type::Integer(None), Type::None]))
+                .is_assignable_to(&Type::Tuple(TupleType::new(vec![Type::Float, Type::None])))
+        );
+        assert!(!Type::Tuple(TupleType::new(vec![Type::Integer(None)]))
+            .is_assignable_to(&Type::Tuple(TupleType::new(vec![Type::Float, Type::None]))));
+    }
+
+    #[test]
+    fn test_assignable_invalid_and_undefined() {
+        assert!(!Type::Invalid.is_assignable_to(&Type::Invalid));
+        assert!(!Type::Invalid.is_assignable_to(&Type::Any { hard: true }));
+        assert!(!Type::Any { hard: true }.is_assignable_to(&Type::Invalid));
+        assert!(!Type::String(None).is_assignable_to(&Type::Invalid));
+        assert!(!list(Type::Invalid).is_assignable_to(&list(Type::Any { hard: true })));
+
+        assert!(!Type::Undefined.is_assignable_to(&Type::String(None)));
+        assert!(!Type::Undefined.is_assignable_to(&Type::None));
+        assert!(Type::Undefined.is_assignable_to(&union([Type::String(None), Type::Undefined])));
+        assert!(!Type::String(None).is_assignable_to(&Type::Undefined));
+    }
+
+    #[test]
+    fn test_describe() {
+        assert_eq!(list(Type::String(None)).describe(), "list of string");
+        assert_eq!(
+            union([Type::None, Type::String(Some("x".to_string()))]).describe(),
+            "string or none"
+        );
+        assert_eq!(
+            dict(Type::String(None), Type::Integer(None)).describe(),
+            "dict[string, integer]"
+        );
+        assert_eq!(
+            Type::Tuple(TupleType::new(vec![Type::String(None), Type::Float])).describe(),
+            "tuple[string, float]"
+        );
+        assert_eq!(
+            list(union([Type::Integer(None), Type::None])).describe(),
+            "list of (integer or none)"
+        );
+        assert_eq!(
+            dict(Type::String(None), list(Type::Bool)).describe(),
+            "dict[string, list of bool]"
+        );
+    }
+}
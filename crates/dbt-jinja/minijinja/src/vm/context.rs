@@ -127,6 +127,11 @@ impl Stack {
     pub fn peek(&self) -> &Value {
         self.values.last().unwrap()
     }
+
+    #[cfg(feature = "internal_debug")]
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
 }
 
 impl From<Vec<Value>> for Stack {
@@ -173,10 +178,22 @@ impl<'env> StackWrapper<'env> {
     }
 }
 
+/// The number of macro names reported when the recursion limit is exceeded.
+const MAX_REPORTED_MACRO_FRAMES: usize = 5;
+
+/// A macro being evaluated, linked to the macro that called it. Contexts
+/// share their callers' frames, so entering a macro does not copy the stack.
+pub(crate) struct MacroFrame {
+    name: String,
+    caller: Option<Arc<MacroFrame>>,
+}
+
 pub(crate) struct Context<'env> {
     stack: StackWrapper<'env>,
     outer_stack_depth: usize,
     recursion_limit: usize,
+    /// The innermost macro currently being evaluated, if any.
+    pub macro_stack: Option<Arc<MacroFrame>>,
     pub current_path: PathBuf,
     pub current_span: Span,
 }
@@ -233,6 +250,7 @@ impl<'env> Context<'env> {
             stack: StackWrapper(Vec::with_capacity(32)),
             outer_stack_depth: 0,
             recursion_limit,
+            macro_stack: None,
             current_path: PathBuf::new(),
             current_span: Span::default(),
         }
@@ -420,11 +438,38 @@ impl<'env> Context<'env> {
         self.outer_stack_depth -= delta;
     }
 
+    /// Inherits the macro call stack of `parent` and records that `name` is
+    /// now being evaluated on top of it.
+    pub fn enter_macro(&mut self, parent: &Context<'_>, name: &str) {
+        self.macro_stack = Some(Arc::new(MacroFrame {
+            name: name.to_string(),
+            caller: parent.macro_stack.clone(),
+        }));
+    }
+
     fn check_depth(&self) -> Result<(), Error> {
         if self.depth() > self.recursion_limit {
+            let mut names = Vec::with_capacity(MAX_REPORTED_MACRO_FRAMES);
+            let mut frame = self.macro_stack.as_deref();
+            while let Some(current) = frame {
+                if names.len() == MAX_REPORTED_MACRO_FRAMES {
+                    names.push("...");
+                    break;
+                }
+                names.push(current.name.as_str());
+                frame = current.caller.as_deref();
+            }
+            if names.is_empty() {
+                return Err(Error::new(
+                    ErrorKind::InvalidOperation,
+                    "recursion limit exceeded",
+                ));
+            }
+            names.reverse();
+            let frames = names.join(" -> ");
             return Err(Error::new(
                 ErrorKind::InvalidOperation,
-                "recursion limit exceeded",
+                format!("recursion limit exceeded (macro call stack: {frames})"),
             ));
         }
         Ok(())
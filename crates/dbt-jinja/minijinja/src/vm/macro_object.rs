@@ -103,7 +103,9 @@ impl Object for Macro {
         // Because macros cannot return anything other than strings (most importantly they)
         // can't return other macros this is however not an issue, as modifications in the
         // macro cannot leak out.
+        let macro_name = self.name.as_str().unwrap_or_default();
         let rv = vm.eval_macro(
+            macro_name,
             instructions,
             *offset,
             self.closure.clone(),
@@ -1,3 +1,4 @@
+use std::cell::Cell;
 use std::collections::BTreeMap;
 use std::mem;
 use std::path::PathBuf;
@@ -25,7 +26,7 @@ use crate::value::namespace_object::Namespace;
 use crate::value::Object;
 use crate::value::{
     mutable_map, mutable_vec, ops, value_map_with_capacity, value_optimization, Kwargs, ObjectRepr,
-    Value, ValueMap,
+    Value, ValueKind, ValueMap,
 };
 use crate::vm::context::{LoopState, Stack};
 use crate::vm::loop_object::Loop;
@@ -60,6 +61,13 @@ pub(crate) const INCLUDE_RECURSION_COST: usize = 10;
 #[cfg(feature = "macros")]
 const MACRO_RECURSION_COST: usize = 4;
 
+// the maximum number of times the result of `var`/`env_var` is re-rendered.
+const MAX_VAR_RENDER_DEPTH: usize = 10;
+
+thread_local! {
+    static VAR_RENDER_DEPTH: Cell<usize> = const { Cell::new(0) };
+}
+
 /// Helps to evaluate something.
 #[cfg_attr(feature = "internal_debug", derive(Debug))]
 pub struct Vm<'env> {
@@ -110,6 +118,18 @@ impl<'env> Vm<'env> {
         Vm { env }
     }
 
+    /// Fails if building a collection of `count` items would exceed the
+    /// environment's maximum collection size.
+    fn check_collection_size(&self, count: usize) -> Result<(), Error> {
+        match self.env.max_collection_size() {
+            Some(max) if count > max => Err(Error::new(
+                ErrorKind::InvalidOperation,
+                format!("cannot build a collection of {count} items, the maximum is {max}"),
+            )),
+            _ => Ok(()),
+        }
+    }
+
     /// Evaluates the given inputs.
     ///
     /// It returns both the last value left on the stack as well as the state
@@ -163,6 +183,7 @@ impl<'env> Vm<'env> {
     #[allow(clippy::too_many_arguments)]
     pub fn eval_macro(
         &self,
+        name: &str,
         instructions: &Instructions<'env>,
         pc: usize,
         closure: Value,
@@ -192,6 +213,7 @@ impl<'env> Vm<'env> {
         }
         ctx.store("varargs", Value::from(varargs));
         ctx.store("kwargs", Value::from(kwargs));
+        ctx.enter_macro(&state.ctx, name);
 
         ok!(ctx.incr_depth(state.ctx.depth() + MACRO_RECURSION_COST));
         self.do_eval(
@@ -203,6 +225,8 @@ impl<'env> Vm<'env> {
                 instructions,
                 blocks: BTreeMap::default(),
                 loaded_templates: Default::default(),
+                observed_var_kinds: BTreeMap::default(),
+                call_counter: state.call_counter.clone(),
                 #[cfg(feature = "macros")]
                 id: state.id,
                 #[cfg(feature = "macros")]
@@ -357,6 +381,11 @@ impl<'env> Vm<'env> {
                 }};
             }
 
+            #[cfg(feature = "internal_debug")]
+            if let Some(ref trace) = self.env.instruction_trace_callback {
+                trace(pc, instr, stack.len());
+            }
+
             // if the fuel consumption feature is enabled, track the fuel
             // consumption here.
             #[cfg(feature = "fuel")]
@@ -394,7 +423,11 @@ impl<'env> Vm<'env> {
                             .expect("we just checked that it is some")
                             .is_undefined()
                     {
-                        stack.push(state.lookup(name).expect("we just checked that it is some"));
+                        let value = state.lookup(name).expect("we just checked that it is some");
+                        if self.env.record_var_kinds() {
+                            state.record_var_kind(name, &value);
+                        }
+                        stack.push(value);
                     } else if namespace_registry.contains_key(&Value::from(name as &str)) {
                         stack.push(Value::from_object(NamespaceName::new(name)));
                     // check if it is a regular variable in state first
@@ -417,6 +450,9 @@ impl<'env> Vm<'env> {
                     }
                 }
                 Instruction::GetAttr(name, span) => {
+                    self.env
+                        .check_sandbox_access(name)
+                        .map_err(|e| state.with_span_error(e, span))?;
                     let a = stack.pop();
                     // This is a common enough operation that it's interesting to consider a fast
                     // path here.  This is slightly faster than the regular attr lookup because we
@@ -469,6 +505,9 @@ impl<'env> Vm<'env> {
                     });
                 }
                 Instruction::SetAttr(name, span) => {
+                    self.env
+                        .check_sandbox_access(name)
+                        .map_err(|e| state.with_span_error(e, span))?;
                     let b = stack.pop();
                     let a = stack.pop();
                     if let Some(ns) = b.downcast_object_ref::<Namespace>() {
@@ -485,6 +524,11 @@ impl<'env> Vm<'env> {
                 }
                 Instruction::GetItem(span) => {
                     let a = stack.pop();
+                    if let Some(key) = a.as_str() {
+                        self.env
+                            .check_sandbox_access(key)
+                            .map_err(|e| state.with_span_error(e, span))?;
+                    }
                     let b = stack.pop();
                     stack.push(match b.get_item_opt(&a) {
                         Some(value) => value
@@ -507,7 +551,9 @@ impl<'env> Vm<'env> {
                 Instruction::LoadConst(value) => {
                     stack.push(value.clone());
                 }
-                Instruction::BuildMap(pair_count, _) => {
+                Instruction::BuildMap(pair_count, span) => {
+                    self.check_collection_size(*pair_count)
+                        .map_err(|e| state.with_span_error(e, span))?;
                     let mut map = value_map_with_capacity(*pair_count);
                     stack.reverse_top(*pair_count * 2);
                     for _ in 0..*pair_count {
@@ -557,8 +603,10 @@ impl<'env> Vm<'env> {
                     }
                     stack.push(Kwargs::wrap(rv));
                 }
-                Instruction::BuildList(n, _span) => {
+                Instruction::BuildList(n, span) => {
                     let count = n.unwrap_or_else(|| stack.pop().try_into().unwrap());
+                    self.check_collection_size(count)
+                        .map_err(|e| state.with_span_error(e, span))?;
                     let mut v = Vec::with_capacity(untrusted_size_hint(count));
                     for _ in 0..count {
                         v.push(stack.pop());
@@ -567,8 +615,10 @@ impl<'env> Vm<'env> {
                     let v = mutable_vec::MutableVec::from(v);
                     stack.push(Value::from_object(v))
                 }
-                Instruction::BuildTuple(count, _span) => {
+                Instruction::BuildTuple(count, span) => {
                     let count = count.unwrap_or_else(|| stack.pop().try_into().unwrap());
+                    self.check_collection_size(count)
+                        .map_err(|e| state.with_span_error(e, span))?;
                     let mut v = Vec::with_capacity(untrusted_size_hint(count));
                     for _ in 0..count {
                         v.push(stack.pop());
@@ -827,29 +877,22 @@ impl<'env> Vm<'env> {
                     stack.drop_top(arg_count);
                     stack.push(Value::from(rv));
                 }
+                Instruction::SetCurrentSpan(this_span) => {
+                    let args = stack.get_call_args(Some(7));
+                    let (path, span) = current_span_from_args(args, this_span);
+                    state.set_current_span(path, span);
+                    stack.drop_top(7);
+                    stack.push(Value::UNDEFINED);
+                }
                 Instruction::CallFunction(name, arg_count, this_span) => {
-                    // reset_span is a special function that resets the current span
-                    // it is only used internally like in run_operation.rs,
-                    // where we want to set the location to yml file
+                    // Deprecated: the compiler emits `SetCurrentSpan` for calls to
+                    // `reset_span` with seven positional arguments. This only
+                    // catches other call shapes (e.g. splatted arguments).
                     if *name == "reset_span" {
                         let args = stack.get_call_args(*arg_count);
                         let arg_count = args.len();
-                        let file_path = args[0].as_str().unwrap_or_default();
-                        let start_line = args[1].as_i64().unwrap_or_default() as u32;
-                        let start_col = args[2].as_i64().unwrap_or_default() as u32;
-                        let start_offset = args[3].as_i64().unwrap_or_default() as u32;
-                        let end_line = args[4].as_i64().unwrap_or_default() as u32;
-                        let end_col = args[5].as_i64().unwrap_or_default() as u32;
-                        let end_offset = args[6].as_i64().unwrap_or_default() as u32;
-                        state.ctx.current_path = PathBuf::from(file_path);
-                        state.ctx.current_span = Span {
-                            start_line: start_line - this_span.end_line,
-                            start_col,
-                            start_offset,
-                            end_line: end_line - this_span.end_line,
-                            end_col,
-                            end_offset,
-                        };
+                        let (path, span) = current_span_from_args(args, this_span);
+                        state.set_current_span(path, span);
                         stack.drop_top(arg_count);
                         stack.push(Value::UNDEFINED);
                         pc += 1;
@@ -859,6 +902,9 @@ impl<'env> Vm<'env> {
                         listener.on_reference(name);
                     });
                     let args = stack.get_call_args(*arg_count);
+                    listeners.iter().for_each(|listener| {
+                        listener.on_call(name, args);
+                    });
                     // super is a special function reserved for super-ing into blocks.
                     let rv = if *name == "super" {
                         if !args.is_empty() {
@@ -1017,14 +1063,19 @@ impl<'env> Vm<'env> {
                                 .push(state.macros[caller_macro.macro_ref_id]);
                         }
 
+                        // keep the macro call stack intact across the template boundary
+                        new_state.ctx.macro_stack = state.ctx.macro_stack.clone();
+
                         // look up and evaluate the macro
                         let func = new_state.lookup(name).unwrap();
                         func.call(&new_state, &args, listeners)
                             .map_err(|err| state.with_span_error(err, this_span))?
                     } else if *name == "render" {
                         let raw = args[0].as_str().unwrap_or_default();
+                        let context = merge_render_context(state.get_base_context(), args.get(1))
+                            .map_err(|err| state.with_span_error(err, this_span))?;
                         let template = state.env().template_from_str(raw, listeners)?;
-                        let rendered_sql = template.render(state.get_base_context(), listeners)?;
+                        let rendered_sql = template.render(context, listeners)?;
                         Value::from(rendered_sql)
                     } else {
                         return Err(state.with_span_error(
@@ -1036,15 +1087,8 @@ impl<'env> Vm<'env> {
                         ));
                     };
                     // Render the result of var function if it it has macro calls
-                    let rv = if (name == &"var" || name == &"env_var")
-                        && rv.as_str().unwrap_or_default().contains("{{")
-                    {
-                        let rv = self.env.render_str(
-                            rv.as_str().unwrap(),
-                            state.get_base_context(),
-                            listeners,
-                        )?;
-                        Value::from(rv)
+                    let rv = if name == &"var" || name == &"env_var" {
+                        ok!(self.rerender_var_result(name, rv, state, listeners))
                     } else {
                         rv
                     };
@@ -1053,6 +1097,9 @@ impl<'env> Vm<'env> {
                     stack.push(rv);
                 }
                 Instruction::CallMethod(name, arg_count, this_span) => {
+                    self.env
+                        .check_sandbox_access(name)
+                        .map_err(|e| state.with_span_error(e, this_span))?;
                     let args = stack.get_call_args(*arg_count);
                     let arg_count = args.len();
 
@@ -1070,8 +1117,8 @@ impl<'env> Vm<'env> {
                         let Ok(template) = self.env.get_template(&qualified_name, listeners) else {
                             return Err(state.with_span_error(
                                 Error::new(
-                                    ErrorKind::UnknownFunction,
-                                    format!("Jinja macro or function `{name}` is unknown"),
+                                    ErrorKind::UnknownMethod,
+                                    format!("namespace `{ns_name}` has no method `{name}`"),
                                 ),
                                 this_span,
                             ));
@@ -1087,11 +1134,12 @@ impl<'env> Vm<'env> {
                             .unwrap_or_else(|| Value::from_serialize(Span::default()));
 
                         let ctx = state.get_base_context_with_path_and_span(&path, &span);
-                        let macro_state = template.eval_to_state_with_outer_stack_depth(
+                        let mut macro_state = template.eval_to_state_with_outer_stack_depth(
                             ctx,
                             listeners,
                             state.ctx.depth() + MACRO_RECURSION_COST,
                         )?;
+                        macro_state.ctx.macro_stack = state.ctx.macro_stack.clone();
                         let func = macro_state.lookup(name).unwrap();
                         func.call(&macro_state, args, listeners)
                             .map_err(|err| state.with_span_error(err, this_span))?
@@ -1324,6 +1372,11 @@ impl<'env> Vm<'env> {
         if is_caller_return {
             let rv = stack.pop();
             if rv.as_str().is_none() {
+                if self.env.allow_non_string_caller_return() {
+                    // not wrapped in `CallerReturn`: the value is the result of
+                    // `caller()` rather than an early return of the calling macro
+                    return Ok(rv);
+                }
                 return Err(Error::new(
                     ErrorKind::InvalidOperation,
                     "caller() must return a string",
@@ -1455,6 +1508,44 @@ impl<'env> Vm<'env> {
         rv.map_err(|err| Error::new(ErrorKind::EvalBlock, "error in super block").with_source(err))
     }
 
+    /// Re-renders the result of `var`/`env_var` until it no longer contains
+    /// template expressions.  Nested re-renders (a var referring to another
+    /// var) count against the same budget so that recursive var references
+    /// fail instead of overflowing the stack.
+    fn rerender_var_result(
+        &self,
+        name: &str,
+        mut rv: Value,
+        state: &State<'_, 'env>,
+        listeners: &[Rc<dyn RenderingEventListener>],
+    ) -> Result<Value, Error> {
+        let active = VAR_RENDER_DEPTH.with(|depth| depth.get());
+        let mut iterations = 0;
+        while let Some(raw) = rv.as_str().filter(|raw| raw.contains("{{")) {
+            if active + iterations >= MAX_VAR_RENDER_DEPTH {
+                return Err(Error::new(
+                    ErrorKind::InvalidOperation,
+                    format!(
+                        "result of `{name}` did not stabilize after {MAX_VAR_RENDER_DEPTH} \
+                         re-renders; check for recursive var references"
+                    ),
+                ));
+            }
+            iterations += 1;
+            VAR_RENDER_DEPTH.with(|depth| depth.set(active + iterations));
+            let rendered = self
+                .env
+                .render_str(raw, state.get_base_context(), listeners);
+            VAR_RENDER_DEPTH.with(|depth| depth.set(active));
+            let rendered = ok!(rendered);
+            if rendered == raw {
+                break;
+            }
+            rv = Value::from(rendered);
+        }
+        Ok(rv)
+    }
+
     fn prepare_loop_recursion(&self, state: &mut State) -> Result<usize, Error> {
         if let Some(loop_ctx) = state.ctx.current_loop() {
             if let Some(recurse_jump_target) = loop_ctx.recurse_jump_target {
@@ -1650,6 +1741,50 @@ impl<'env> Vm<'env> {
     }
 }
 
+/// Merges the optional context argument of the `render` builtin over the
+/// base context.
+fn merge_render_context(base: Value, extra: Option<&Value>) -> Result<Value, Error> {
+    let Some(extra) = extra else {
+        return Ok(base);
+    };
+    if extra.kind() != ValueKind::Map {
+        return Err(Error::new(
+            ErrorKind::InvalidOperation,
+            format!(
+                "render() expects a map as its context argument, got {}",
+                extra.kind()
+            ),
+        ));
+    }
+    let merged = mutable_map::MutableMap::new();
+    for context in [&base, extra] {
+        for key in ok!(context.try_iter()) {
+            let value = ok!(context.get_item(&key));
+            merged.insert(key, value);
+        }
+    }
+    Ok(Value::from_object(merged))
+}
+
+/// Reads the `(path, start_line, start_col, start_offset, end_line, end_col,
+/// end_offset)` arguments of a `reset_span` call.  The lines are made relative
+/// to the call site so that later spans offset onto the new location.
+fn current_span_from_args(args: &[Value], this_span: &Span) -> (PathBuf, Span) {
+    let arg = |idx: usize| args.get(idx).and_then(|v| v.as_i64()).unwrap_or_default() as u32;
+    let path = args.first().and_then(|v| v.as_str()).unwrap_or_default();
+    (
+        PathBuf::from(path),
+        Span {
+            start_line: arg(1).saturating_sub(this_span.end_line),
+            start_col: arg(2),
+            start_offset: arg(3),
+            end_line: arg(4).saturating_sub(this_span.end_line),
+            end_col: arg(5),
+            end_offset: arg(6),
+        },
+    )
+}
+
 /// Deserialize a path from a value
 pub fn deserialize_path(value: &Value) -> PathBuf {
     PathBuf::from(value.as_str().unwrap())
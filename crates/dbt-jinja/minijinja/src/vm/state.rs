@@ -6,9 +6,13 @@ use crate::listener::RenderingEventListener;
 use crate::machinery::Span;
 use crate::output::Output;
 use crate::template::Template;
+use crate::types::dict::DictType;
+use crate::types::iterable::IterableType;
+use crate::types::list::ListType;
+use crate::types::Type;
 use crate::utils::{AutoEscape, UndefinedBehavior};
 use crate::value::mutable_map::MutableMap;
-use crate::value::{ArgType, Value};
+use crate::value::{ArgType, Value, ValueKind};
 use crate::vm::context::Context;
 
 use serde::Deserialize;
@@ -17,6 +21,7 @@ use serde::Deserialize;
 use crate::vm::fuel::FuelTracker;
 use std::collections::{BTreeMap, BTreeSet};
 use std::fmt;
+use std::path::PathBuf;
 use std::rc::Rc;
 
 /// When macros are used, the state carries an `id` counter.  Whenever a state is
@@ -52,6 +57,8 @@ pub struct State<'template, 'env> {
     pub(crate) instructions: &'template Instructions<'env>,
     pub(crate) blocks: BTreeMap<&'env str, BlockStack<'template, 'env>>,
     pub(crate) loaded_templates: BTreeSet<&'env str>,
+    pub(crate) observed_var_kinds: BTreeMap<String, Type>,
+    pub(crate) call_counter: std::sync::Arc<std::sync::atomic::AtomicU64>,
     #[cfg(feature = "macros")]
     pub(crate) id: isize,
     #[cfg(feature = "macros")]
@@ -93,6 +100,8 @@ impl<'template, 'env> State<'template, 'env> {
             instructions,
             blocks,
             loaded_templates: BTreeSet::new(),
+            observed_var_kinds: BTreeMap::new(),
+            call_counter: Default::default(),
             #[cfg(feature = "macros")]
             macros: Default::default(),
             #[cfg(feature = "macros")]
@@ -102,6 +111,45 @@ impl<'template, 'env> State<'template, 'env> {
         }
     }
 
+    /// Returns the runtime kinds of the variables looked up while this state
+    /// was evaluated, if enabled with
+    /// [`Environment::set_record_var_kinds`](crate::Environment::set_record_var_kinds).
+    ///
+    /// This is not type inference: a variable's kind is the top-level kind of
+    /// the values it held when looked up, regardless of how the template uses
+    /// it.  Literal values are erased (a string variable is `String(None)`)
+    /// and the contents of lists and maps are left as `Any`, so recording
+    /// never walks a value.  If a variable held values of different kinds,
+    /// for instance because it was reassigned, the union of those kinds is
+    /// stored.  Callables such as macros and functions are not recorded.
+    pub fn observed_var_kinds(&self) -> BTreeMap<String, Type> {
+        self.observed_var_kinds.clone()
+    }
+
+    /// Records the kind of a looked up variable for [`State::observed_var_kinds`].
+    pub(crate) fn record_var_kind(&mut self, name: &str, value: &Value) {
+        let any = || Type::Any { hard: false };
+        let type_ = match value.kind() {
+            ValueKind::Plain | ValueKind::Undefined | ValueKind::Invalid => return,
+            ValueKind::Number if value.is_integer() => Type::Integer(None),
+            ValueKind::Number => Type::Float,
+            ValueKind::Bool => Type::Bool,
+            ValueKind::String => Type::String(None),
+            ValueKind::Bytes => Type::Bytes,
+            ValueKind::Seq => Type::List(ListType::new(any())),
+            ValueKind::Map => Type::Dict(DictType::new(any(), any())),
+            ValueKind::Iterable => Type::Iterable(IterableType::new(any())),
+            ValueKind::None => Type::None,
+        };
+        match self.observed_var_kinds.get_mut(name) {
+            Some(existing) if *existing == type_ => {}
+            Some(existing) => *existing = existing.union(&type_),
+            None => {
+                self.observed_var_kinds.insert(name.to_string(), type_);
+            }
+        }
+    }
+
     /// Helper method to attach span information to an error.
     /// This reduces code duplication by centralizing the common pattern of
     /// `error.with_span(&state.ctx.current_path.clone(), &span.with_offset(&state.ctx.current_span))`.
@@ -113,6 +161,29 @@ impl<'template, 'env> State<'template, 'env> {
         )
     }
 
+    /// Overrides the path and span that errors raised from here on are
+    /// reported against.
+    ///
+    /// Spans of later instructions are offset by `span`, so its lines are
+    /// expected relative to the current position in the template.  This is
+    /// what the compiler emits for `reset_span(...)` calls, e.g. to point
+    /// errors of an inline `run-operation` at its yml file.
+    pub fn set_current_span(&mut self, path: impl Into<PathBuf>, span: Span) {
+        self.ctx.current_path = path.into();
+        self.ctx.current_span = span;
+    }
+
+    /// Returns the position of this call among all calls to this method
+    /// during the current render, starting at `0`.
+    ///
+    /// Macros share the counter of the render that invokes them, so the
+    /// position only depends on the template and the order of evaluation
+    /// within it, never on other renders of the same environment.
+    pub fn next_call_index(&self) -> u64 {
+        self.call_counter
+            .fetch_add(1, std::sync::atomic::Ordering::Relaxed)
+    }
+
     /// Creates an empty state for an environment.
     pub fn new_for_env(env: &'env Environment) -> State<'env, 'env> {
         State::new(
@@ -1475,6 +1475,11 @@ impl<'src> TypeChecker<'src> {
                     typestate.drop_top(arg_count.unwrap_or(0) as usize);
                     typestate.stack.push(Type::Bool);
                 }
+                Instruction::SetCurrentSpan(_span) => {
+                    // TYPECHECK: NO
+                    typestate.drop_top(7);
+                    typestate.stack.push(Type::Undefined);
+                }
                 Instruction::CallFunction(name, arg_count, span) => {
                     // TYPECHECK: YES
                     listener.set_span(span);
@@ -147,6 +147,57 @@ fn test_path_join() {
     assert_eq!(t.render((), &[]).unwrap(), "bar.txt");
 }
 
+#[test]
+fn test_remove_filter() {
+    let mut env = Environment::new();
+    env.add_filter("shout", |value: String| value.to_uppercase());
+    assert_eq!(env.render_str("{{ 'hi'|shout }}", (), &[]).unwrap(), "HI");
+
+    assert!(env.remove_filter("shout"));
+    assert!(!env.remove_filter("shout"));
+    let err = env.render_str("{{ 'hi'|shout }}", (), &[]).unwrap_err();
+    assert_eq!(err.kind(), minijinja::ErrorKind::UnknownFilter);
+
+    // builtin filters can be removed as well
+    assert!(env.remove_filter("upper"));
+    let err = env.render_str("{{ 'hi'|upper }}", (), &[]).unwrap_err();
+    assert_eq!(err.kind(), minijinja::ErrorKind::UnknownFilter);
+}
+
+#[test]
+fn test_remove_test() {
+    let mut env = Environment::new();
+    env.add_test("short", |value: String| value.len() < 3);
+    assert_eq!(
+        env.render_str("{{ 'hi' is short }}", (), &[]).unwrap(),
+        "true"
+    );
+
+    assert!(env.remove_test("short"));
+    assert!(!env.remove_test("short"));
+    let err = env.render_str("{{ 'hi' is short }}", (), &[]).unwrap_err();
+    assert_eq!(err.kind(), minijinja::ErrorKind::UnknownTest);
+}
+
+#[test]
+fn test_keep_trailing_newline_lf() {
+    let source = "select {{ 1 }} as id\n";
+
+    let mut env = Environment::new();
+    assert!(!env.keep_trailing_newline());
+    assert_eq!(env.render_str(source, (), &[]).unwrap(), "select 1 as id");
+
+    env.set_keep_trailing_newline(true);
+    assert_eq!(env.render_str(source, (), &[]).unwrap(), "select 1 as id\n");
+
+    // Only a single trailing newline is stripped
+    env.set_keep_trailing_newline(false);
+    assert_eq!(
+        env.render_str("select 1\n\n", (), &[]).unwrap(),
+        "select 1\n"
+    );
+}
+
 #[test]
 fn test_keep_trailing_newlines() {
     let mut env = Environment::new();
@@ -210,3 +261,194 @@ fn test_iter() {
     assert!(renders.contains(&("hello", "Hello World!".into())));
     assert!(renders.contains(&("goodbye", "Goodbye World!".into())));
 }
+
+#[test]
+#[cfg(feature = "macros")]
+fn test_macro_signatures() {
+    use minijinja::ArgSpec;
+
+    let mut env = Environment::new();
+    env.add_template(
+        "macros",
+        r#"
+        {%- macro greet(name, greeting="Hello") -%}
+            {{ greeting }} {{ name }}
+        {%- endmacro -%}
+        {%- macro join_all(sep=",") -%}
+            {{ varargs|join(sep) }}{{ kwargs }}
+        {%- endmacro -%}
+        "#,
+        &[],
+    )
+    .unwrap();
+
+    let signatures = env.macro_signatures("macros").unwrap();
+    assert_eq!(signatures.len(), 2);
+
+    assert_eq!(signatures[0].name, "greet");
+    assert_eq!(
+        signatures[0].args,
+        vec![ArgSpec::new("name", false), ArgSpec::new("greeting", true)]
+    );
+    assert!(!signatures[0].varargs);
+    assert!(!signatures[0].kwargs);
+
+    assert_eq!(signatures[1].name, "join_all");
+    assert_eq!(signatures[1].args, vec![ArgSpec::new("sep", true)]);
+    assert!(signatures[1].varargs);
+    assert!(signatures[1].kwargs);
+
+    assert!(env.macro_signatures("missing").is_err());
+}
+
+#[test]
+fn test_max_collection_size() {
+    let mut env = Environment::new();
+    env.set_max_collection_size(Some(2));
+    assert_eq!(env.max_collection_size(), Some(2));
+
+    // literals of variables are built at runtime rather than folded into constants
+    let ctx = minijinja::context! { a => 1 };
+    assert_eq!(env.render_str("{{ [a, a] }}", &ctx, &[]).unwrap(), "[1, 1]");
+    assert_eq!(
+        env.render_str("{{ (a, a)|length }}", &ctx, &[]).unwrap(),
+        "2"
+    );
+
+    for template in ["{{ [a, a, a] }}", "{{ {'x': a, 'y': a, 'z': a} }}"] {
+        let err = env.render_str(template, &ctx, &[]).unwrap_err();
+        assert_eq!(err.kind(), minijinja::ErrorKind::InvalidOperation);
+        assert!(err.to_string().contains("the maximum is 2"), "{err}");
+    }
+}
+
+#[test]
+fn test_sandbox() {
+    use minijinja::listener::RenderingEventListener;
+    use minijinja::value::Object;
+    use minijinja::{Error, ErrorKind, State};
+    use std::rc::Rc;
+    use std::sync::Arc;
+
+    #[derive(Debug)]
+    struct Handle;
+
+    impl Object for Handle {
+        fn get_value(self: &Arc<Self>, key: &Value) -> Option<Value> {
+            Some(Value::from(format!("attr {key}")))
+        }
+
+        fn call_method(
+            self: &Arc<Self>,
+            _state: &State<'_, '_>,
+            method: &str,
+            _args: &[Value],
+            _listeners: &[Rc<dyn RenderingEventListener>],
+        ) -> Result<Value, Error> {
+            Ok(Value::from(format!("called {method}")))
+        }
+    }
+
+    let mut env = Environment::new();
+    let ctx = minijinja::context! { h => Value::from_object(Handle), x => 42 };
+
+    // without the sandbox everything is reachable
+    assert_eq!(
+        env.render_str("{{ h.delete() }}", &ctx, &[]).unwrap(),
+        "called delete"
+    );
+
+    env.set_sandbox(true);
+    env.set_sandbox_deny_list(["delete"]);
+    assert!(env.sandboxed());
+
+    assert_eq!(env.render_str("{{ x }}", &ctx, &[]).unwrap(), "42");
+    assert_eq!(
+        env.render_str("{{ h.name }}", &ctx, &[]).unwrap(),
+        "attr name"
+    );
+    assert_eq!(
+        env.render_str("{{ h.read() }}", &ctx, &[]).unwrap(),
+        "called read"
+    );
+
+    for template in [
+        "{{ h.delete() }}",
+        "{{ h._secret }}",
+        "{{ h._reset() }}",
+        "{{ h.delete }}",
+    ] {
+        let err = env.render_str(template, &ctx, &[]).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidOperation, "{template}");
+        assert!(err.to_string().contains("sandboxed"), "{err}");
+    }
+
+    // item access and the attribute filters are subject to the same rules
+    assert_eq!(
+        env.render_str("{{ h['name'] }}|{{ h|attr('name') }}", &ctx, &[])
+            .unwrap(),
+        "attr name|attr name"
+    );
+    assert_eq!(
+        env.render_str("{{ [h]|map(attribute='name')|join }}", &ctx, &[])
+            .unwrap(),
+        "attr name"
+    );
+    for template in [
+        "{{ h['_secret'] }}",
+        "{{ h['delete'] }}",
+        "{{ h|attr('_secret') }}",
+        "{{ [h]|map(attribute='_secret')|list }}",
+        "{{ [{'h': h}]|map(attribute='h._secret')|list }}",
+    ] {
+        let err = env.render_str(template, &ctx, &[]).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidOperation, "{template}");
+        assert!(err.to_string().contains("sandboxed"), "{err}");
+    }
+
+    // so is every filter looking up an attribute path, and namespace assignment
+    assert_eq!(
+        env.render_str(
+            "{% for g in [h]|groupby('name') %}{{ g.grouper }}{% endfor %}",
+            &ctx,
+            &[]
+        )
+        .unwrap(),
+        "attr name"
+    );
+    for template in [
+        "{% for g in [h]|groupby('_secret') %}{{ g.grouper }}{% endfor %}",
+        "{% for g in [h]|groupby(attribute='delete') %}{{ g.grouper }}{% endfor %}",
+        "{{ [h]|unique(attribute='_secret')|list }}",
+        "{{ [h, h]|sort(attribute='_secret')|list }}",
+        "{{ [h]|selectattr('_secret')|list }}",
+        "{{ [h]|rejectattr('_secret')|list }}",
+        "{{ [h]|join(',', attribute='_secret') }}",
+        "{% set ns = namespace() %}{% set ns._secret = 1 %}",
+    ] {
+        let err = env.render_str(template, &ctx, &[]).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidOperation, "{template}");
+        assert!(err.to_string().contains("sandboxed"), "{err}");
+    }
+}
+
+#[test]
+fn test_template_stats() {
+    let mut env = Environment::new();
+    env.add_template("empty", "", &[]).unwrap();
+    env.add_template(
+        "complex",
+        "{% macro item(x) %}<li>{{ x }}</li>{% endmacro %}\
+         {% block body %}{% for x in seq %}{{ item(x) }}{% endfor %}{% endblock %}",
+        &[],
+    )
+    .unwrap();
+
+    let stats = env.get_template("complex", &[]).unwrap().stats();
+    assert_eq!(stats.blocks, 1);
+    assert!(stats.instructions > 0);
+
+    let empty = env.get_template("empty", &[]).unwrap().stats();
+    assert_eq!(empty.blocks, 0);
+    assert!(stats.instructions > empty.instructions);
+}
@@ -7,6 +7,7 @@ use insta::assert_snapshot;
 use similar_asserts::assert_eq;
 
 use minijinja::arg_utils::ArgParser;
+use minijinja::value::mutable_map::MutableMap;
 use minijinja::value::{Kwargs, Object, Value};
 use minijinja::{args, context, render, Environment, ErrorKind};
 
@@ -469,3 +470,129 @@ fn test_macro_default_arg_referencing_other_arg() {
     assert_snapshot!(lines[0].trim(), @"something");
     assert_snapshot!(lines[1].trim(), @"account_number");
 }
+
+#[test]
+fn test_recursion_limit_reports_macro_stack() {
+    let mut env = Environment::new();
+    env.set_recursion_limit(50);
+
+    let template = r#"
+    {% macro recurse_forever(i) %}{{ recurse_forever(i + 1) }}{% endmacro %}
+    {{ recurse_forever(0) }}
+    "#;
+
+    let err = env.render_str(template, context! {}, &[]).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidOperation);
+    let detail = err.detail().unwrap();
+    assert!(detail.starts_with("recursion limit exceeded"), "{detail}");
+    assert!(
+        detail.contains("... -> recurse_forever -> recurse_forever"),
+        "{detail}"
+    );
+}
+
+#[test]
+fn test_render_with_explicit_context() {
+    let env = Environment::new();
+    let rv = env
+        .render_str(r#"{{ render("{{ x }}", {"x": 5}) }}"#, context! {}, &[])
+        .unwrap();
+    assert_eq!(rv, "5");
+}
+
+#[test]
+fn test_render_with_explicit_context_keeps_base_context() {
+    let env = Environment::new();
+    // the base context is only forwarded when it is a mutable map
+    let base = MutableMap::new();
+    base.insert(Value::from("y"), Value::from("base"));
+    let rv = env
+        .render_str(
+            r#"{{ render("{{ x }}-{{ y }}", {"x": 5}) }}"#,
+            Value::from_object(base),
+            &[],
+        )
+        .unwrap();
+    assert_eq!(rv, "5-base");
+}
+
+#[test]
+fn test_render_with_non_map_context() {
+    let env = Environment::new();
+    let err = env
+        .render_str(r#"{{ render("{{ x }}", [1, 2]) }}"#, context! {}, &[])
+        .unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidOperation);
+}
+
+fn env_with_vars(vars: &'static [(&'static str, &'static str)]) -> Environment<'static> {
+    let mut env = Environment::new();
+    env.add_function(
+        "var",
+        move |name: String| -> Result<Value, minijinja::Error> {
+            Ok(vars
+                .iter()
+                .find(|(key, _)| *key == name)
+                .map(|(_, value)| Value::from(*value))
+                .unwrap_or_default())
+        },
+    );
+    env
+}
+
+#[test]
+fn test_var_rerender_single_level() {
+    let env = env_with_vars(&[("a", "{{ 1 + 1 }}")]);
+    let rv = env.render_str("{{ var('a') }}", context! {}, &[]).unwrap();
+    assert_eq!(rv, "2");
+}
+
+#[test]
+fn test_var_rerender_two_levels() {
+    let env = env_with_vars(&[("a", r#"{{ "{{ 'x' ~ 1 }}" }}"#)]);
+    let rv = env.render_str("{{ var('a') }}", context! {}, &[]).unwrap();
+    assert_eq!(rv, "x1");
+}
+
+#[test]
+fn test_var_rerender_self_reference_hits_limit() {
+    let env = env_with_vars(&[("a", "{{ var('a') }}")]);
+    let err = env
+        .render_str("{{ var('a') }}", context! {}, &[])
+        .unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidOperation);
+    assert!(err.to_string().contains("did not stabilize"), "{err}");
+}
+
+#[test]
+fn test_caller_non_string_return() {
+    let template = "{% macro collect() %}{% set items = caller() %}\
+                    {{ items|length }}:{{ items[1] }}{% endmacro %}\
+                    {% call collect() %}{% do return([1, 2, 3]) %}{% endcall %}";
+
+    let mut env = Environment::new();
+    assert!(!env.allow_non_string_caller_return());
+    let err = env.render_str(template, context! {}, &[]).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidOperation);
+    assert!(
+        err.to_string().contains("caller() must return a string"),
+        "{err}"
+    );
+
+    env.set_allow_non_string_caller_return(true);
+    let rv = env.render_str(template, context! {}, &[]).unwrap();
+    assert_eq!(rv, "3:2");
+}
+
+#[test]
+fn test_caller_string_return_with_non_string_returns_allowed() {
+    let template = "{% macro wrap() %}before {{ caller() }} after{% endmacro %}\
+                    {% call wrap() %}{% do return('early') %}{% endcall %}";
+
+    let mut env = Environment::new();
+    let strict = env.render_str(template, context! {}, &[]).unwrap();
+    env.set_allow_non_string_caller_return(true);
+    let rv = env.render_str(template, context! {}, &[]).unwrap();
+    assert_eq!(rv, strict);
+    assert!(!rv.contains("after"), "{rv}");
+}
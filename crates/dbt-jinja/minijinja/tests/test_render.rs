@@ -3,7 +3,7 @@ use minijinja::{
     constants::MACRO_NAMESPACE_REGISTRY,
     context,
     value::{mutable_vec::MutableVec, ValueMap},
-    Environment, Value,
+    Environment, ErrorKind, Value,
 };
 
 #[test]
@@ -105,3 +105,22 @@ writing
   am
   writing");
 }
+
+#[test]
+fn test_macro_namespace_unknown_method() {
+    let mut env = Environment::new();
+    let mut macro_namespace_registry = ValueMap::new();
+    macro_namespace_registry.insert(
+        Value::from("re"),
+        Value::from_object(MutableVec::from(vec![Value::from("search")])),
+    );
+    env.add_global(
+        MACRO_NAMESPACE_REGISTRY,
+        Value::from_object(macro_namespace_registry),
+    );
+    let err = env
+        .render_str("{{ re.notamethod('x') }}", context! {}, &[])
+        .unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::UnknownMethod);
+    assert_snapshot!(err.detail().unwrap(), @"namespace `re` has no method `notamethod`");
+}
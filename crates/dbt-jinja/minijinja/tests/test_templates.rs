@@ -14,7 +14,7 @@ use insta::assert_snapshot;
 use minijinja::constants::{CURRENT_PATH, CURRENT_SPAN};
 use minijinja::machinery::Span;
 use minijinja::value::{Enumerator, Object, ObjectRepr, Rest, Value};
-use minijinja::{context, render, Environment, Error, ErrorKind, State};
+use minijinja::{context, render, Environment, Error, ErrorKind, State, Type};
 
 use similar_asserts::assert_eq;
 
@@ -343,6 +343,30 @@ fn test_loop_changed() {
     assert_eq!(rv, "12345");
 }
 
+#[test]
+fn test_loop_changed_toggles() {
+    let rv = minijinja::render!(
+        r#"{% for i in items %}{{ loop.changed(i) }}{% if not loop.last %},{% endif %}{% endfor %}"#,
+        items => vec!["a", "a", "b", "b", "b", "a", "c"],
+    );
+    assert_eq!(rv, "true,false,true,false,false,true,true");
+
+    let rv = minijinja::render!(
+        r#"
+        {%- for row in rows -%}
+          {% if loop.changed(row.group) %}[{{ row.group }}]{% endif %}{{ row.name }}
+        {%- endfor -%}
+        "#,
+        rows => vec![
+            BTreeMap::from([("group", "x"), ("name", "1")]),
+            BTreeMap::from([("group", "x"), ("name", "2")]),
+            BTreeMap::from([("group", "y"), ("name", "3")]),
+            BTreeMap::from([("group", "x"), ("name", "4")]),
+        ],
+    );
+    assert_eq!(rv, "[x]12[y]3[x]4");
+}
+
 // ideally this would work, but unfortunately the way serde flatten works makes it
 // impossible for us to support with the internal optimizations in the value model.
 // see https://github.com/mitsuhiko/minijinja/issues/222
@@ -585,6 +609,58 @@ fn test_render_and_return_state() {
     }
 }
 
+#[test]
+fn test_observed_var_kinds() {
+    let source = "{% set x = 'a' %}{{ x ~ name }}{% set x = 1 %}{{ x + count }}\
+                  {{ range(1)|length }}{{ items|length }}{{ config.a }}";
+    let ctx =
+        context! { name => "b", count => 2, items => vec![1, 2], config => context! { a => 1 } };
+
+    // recording is opt-in
+    let env = Environment::new();
+    assert!(!env.record_var_kinds());
+    let tmpl = env.template_from_str(source, &[]).unwrap();
+    let (_, state) = tmpl.render_and_return_state(&ctx, &[]).unwrap();
+    assert!(state.observed_var_kinds().is_empty());
+
+    let mut env = Environment::new();
+    env.set_record_var_kinds(true);
+    let tmpl = env.template_from_str(source, &[]).unwrap();
+    let (rv, state) = tmpl.render_and_return_state(&ctx, &[]).unwrap();
+    assert_eq!(rv, "ab3121");
+
+    let kinds = state.observed_var_kinds();
+    assert_eq!(kinds.get("name"), Some(&Type::String(None)));
+    assert_eq!(kinds.get("count"), Some(&Type::Integer(None)));
+    // `x` held a string, then an integer
+    assert!(matches!(kinds.get("x"), Some(Type::Union(_))));
+    assert_eq!(kinds["x"].describe(), "string or integer");
+    // containers only record their top-level kind
+    assert!(matches!(kinds.get("items"), Some(Type::List(_))));
+    assert!(matches!(kinds.get("config"), Some(Type::Dict(_))));
+    // functions are not variables
+    assert!(!kinds.contains_key("range"));
+}
+
+#[test]
+fn test_observed_var_kinds_ignore_usage() {
+    // a string used in arithmetic and a number used in concatenation keep the
+    // kinds of their values: nothing is inferred from how they are used
+    let mut env = Environment::new();
+    env.set_record_var_kinds(true);
+    let tmpl = env
+        .template_from_str("{{ label * 2 }}{{ n ~ label }}{{ n + 1 }}", &[])
+        .unwrap();
+    let (rv, state) = tmpl
+        .render_and_return_state(context! { label => "ab", n => 1 }, &[])
+        .unwrap();
+    assert_eq!(rv, "abab1ab2");
+
+    let kinds = state.observed_var_kinds();
+    assert_eq!(kinds.get("label"), Some(&Type::String(None)));
+    assert_eq!(kinds.get("n"), Some(&Type::Integer(None)));
+}
+
 #[test]
 fn test_render_to_write_state() {
     let env = Environment::new();
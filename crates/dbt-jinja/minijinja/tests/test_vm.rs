@@ -145,6 +145,84 @@ fn test_error_info() {
     );
 }
 
+fn add_error_at(span: Span) -> Error {
+    let mut c = CodeGenerator::new("hello.html", "", CodeGenerationProfile::Render);
+    c.add(Instruction::Lookup("a_string", Span::default()));
+    c.add(Instruction::Lookup("an_int", Span::default()));
+    c.add(Instruction::Add(span));
+
+    let mut ctx = std::collections::BTreeMap::new();
+    ctx.insert("a_string", Value::from("foo"));
+    ctx.insert("an_int", Value::from(42));
+    ctx.insert(CURRENT_PATH, Value::from("hello.html"));
+    ctx.insert(CURRENT_SPAN, Value::from_serialize(Span::default()));
+    simple_eval(&c.finish().0, ctx).unwrap_err()
+}
+
+/// The snippet `display_with_source` appends after the error message.
+fn source_snippet(err: &Error, source: &str) -> String {
+    err.display_with_source(source)
+        .strip_prefix(&err.to_string())
+        .unwrap()
+        .to_string()
+}
+
+#[test]
+fn test_display_with_source_single_line() {
+    let err = add_error_at(Span {
+        start_line: 2,
+        start_col: 4,
+        start_offset: 18,
+        end_line: 2,
+        end_col: 21,
+        end_offset: 35,
+    });
+    let source = "<h1>Hello</h1>\n{{ a_string + an_int }}\n";
+    assert_eq!(
+        source_snippet(&err, source),
+        "\n  |\n2 | {{ a_string + an_int }}\n  |    ^^^^^^^^^^^^^^^^^"
+    );
+
+    // a span at the end of the source points right after the last character
+    let err = add_error_at(Span {
+        start_line: 1,
+        start_col: 15,
+        start_offset: 14,
+        end_line: 1,
+        end_col: 15,
+        end_offset: 14,
+    });
+    assert_eq!(
+        source_snippet(&err, "{{ a_string + "),
+        "\n  |\n1 | {{ a_string + \n  |               ^"
+    );
+}
+
+#[test]
+fn test_display_with_source_multi_line() {
+    let err = add_error_at(Span {
+        start_line: 1,
+        start_col: 12,
+        start_offset: 11,
+        end_line: 4,
+        end_col: 2,
+        end_offset: 34,
+    });
+    let source = "{% set x = [\n  a_string,\n  an_int\n] %}";
+    assert_eq!(
+        source_snippet(&err, source),
+        "\n  |\
+         \n1 | {% set x = [\
+         \n  |            ^\
+         \n2 |   a_string,\
+         \n  | ^^^^^^^^^^^\
+         \n3 |   an_int\
+         \n  | ^^^^^^^^\
+         \n4 | ] %}\
+         \n  | ^"
+    );
+}
+
 #[test]
 fn test_op_eq() {
     let mut c = CodeGenerator::new("hello.html", "", CodeGenerationProfile::Render);
@@ -358,3 +436,75 @@ fn test_deep_recursion() {
         .collect::<Vec<_>>();
     assert_eq!(pieces, (0..limit).collect::<Vec<_>>());
 }
+
+#[test]
+fn test_instruction_trace_callback() {
+    let executed = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+    let mut env = Environment::new();
+    let recorder = executed.clone();
+    env.set_instruction_trace_callback(move |pc, instr, depth| {
+        recorder
+            .lock()
+            .unwrap()
+            .push((pc, format!("{instr:?}"), depth));
+    });
+
+    let rv = env
+        .render_str(
+            "{% for x in items %}{{ x }}{% endfor %}",
+            minijinja::context! { items => vec![1, 2, 3] },
+            &[],
+        )
+        .unwrap();
+    assert_eq!(rv, "123");
+
+    let executed = executed.lock().unwrap();
+    assert!(!executed.is_empty());
+    // The loop body is executed once per item
+    let emits = executed
+        .iter()
+        .filter(|(_, instr, _)| instr.starts_with("Emit("))
+        .count();
+    assert_eq!(emits, 3);
+    // Emitting pops the value it was given
+    assert!(executed
+        .iter()
+        .filter(|(_, instr, _)| instr.starts_with("Emit("))
+        .all(|(_, _, depth)| *depth >= 1));
+}
+
+#[test]
+fn test_reset_span() {
+    let env = Environment::new();
+    let loc = ("models/schema.yml", 10, 5, 100, 10, 40, 135);
+    let err_at = |template: &str| {
+        let err = env
+            .render_str(template, minijinja::context! { loc }, &[])
+            .unwrap_err();
+        (
+            err.name().map(str::to_string),
+            err.span().map(|span| span.start_line),
+        )
+    };
+
+    // Compiled to a dedicated instruction
+    let tmpl = env
+        .template_from_str(
+            "{{ reset_span('models/schema.yml', 10, 5, 100, 10, 40, 135) }}",
+            &[],
+        )
+        .unwrap();
+    let compiled = minijinja::machinery::get_compiled_template(&tmpl);
+    assert!((0..compiled.instructions.len())
+        .filter_map(|idx| compiled.instructions.get(idx))
+        .any(|instr| matches!(instr, Instruction::SetCurrentSpan(_))));
+
+    // Errors after the call are reported relative to the overridden location
+    let expected = (Some("models/schema.yml".to_string()), Some(10));
+    assert_eq!(
+        err_at("{{ reset_span('models/schema.yml', 10, 5, 100, 10, 40, 135) }}\n{{ missing() }}"),
+        expected
+    );
+    // The deprecated string-named fallback behaves the same
+    assert_eq!(err_at("{{ reset_span(*loc) }}\n{{ missing() }}"), expected);
+}
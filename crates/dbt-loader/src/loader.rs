@@ -57,7 +57,7 @@ use dbt_common::tracing::ToTracingValue;
 #[tracing::instrument(
     skip_all,
     fields(
-        __event = TelemetryAttributes::Phase(BuildPhaseInfo::Loading { }).to_tracing_value(),
+        __event = TelemetryAttributes::Phase(BuildPhaseInfo::Loading { peak_rss_bytes: None }).to_tracing_value(),
     )
 )]
 pub async fn load(
@@ -17,7 +17,7 @@ use dbt_jinja_utils::{
 };
 use dbt_schemas::schemas::{
     packages::{DbtPackageEntry, DbtPackages},
-    profiles::{DbConfig, DbTargets, DbtProfilesIntermediate},
+    profiles::{DbConfig, DbTargets, DbtProfilesIntermediate, resolve_target_output},
 };
 use fs_deps::utils::get_local_package_full_path;
 use serde::{Serialize, de::DeserializeOwned};
@@ -175,23 +175,20 @@ pub fn read_profiles_and_extract_db_config<S: Serialize>(
         "No 'outputs' key found in dbt profiles.yml"
     ))?;
 
-    // filter the db_targets to only include the target we want to use
-    // 使用したいターゲットのみを含むように db_targets をフィルタリングする
-    let unrendered_outputs_filtered: BTreeMap<String, dbt_serde_yaml::Value> = unrendered_outputs
-        .as_mapping()
-        .unwrap()
-        .iter()
-        .filter(|(k, _)| k.as_str().unwrap() == rendered_target)
-        .map(|(k, v)| (k.as_str().unwrap().to_string(), v.clone()))
-        .collect();
-
-    if unrendered_outputs_filtered.is_empty() {
+    if unrendered_outputs.get(rendered_target.as_str()).is_none() {
         return err!(
             ErrorCode::InvalidConfig,
             "Target '{}' not found in profiles.yml",
             rendered_target
         );
     }
+
+    // filter the db_targets to only include the target we want to use, with `extends` resolved
+    // `extends` を解決したうえで、使用したいターゲットのみを含むように db_targets をフィルタリングする
+    let unrendered_output =
+        resolve_target_output(&rendered_target, |name| unrendered_outputs.get(name))?;
+    let unrendered_outputs_filtered: BTreeMap<String, dbt_serde_yaml::Value> =
+        BTreeMap::from([(rendered_target.clone(), unrendered_output)]);
     // render just the target output we want to use
     let rendered_db_target = into_typed_with_jinja(
         io_args,
@@ -7,7 +7,8 @@ use dbt_common::{
     node_selector::{IndirectSelection, SelectExpression},
 };
 use dbt_schemas::filter::RunFilter;
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::PathBuf;
 
 /// Args to be passed into the resolution phase
 #[derive(Clone, Default, Debug)]
@@ -34,6 +35,18 @@ pub struct ResolveArgs {
     pub replay: Option<dbt_common::io_args::ReplayMode>,
     /// Sample config
     pub sample_config: RunFilter,
+    /// Only render the sql of nodes matching this selector; all other nodes
+    /// are resolved from their configs without rendering (partial parsing)
+    pub only: Option<SelectExpression>,
+    /// Absolute paths of the sql files ref'd, transitively and across
+    /// packages, by the nodes matching [Self::only], which are rendered even if
+    /// not selected; computed by [resolve](crate::resolver::resolve)
+    pub rendered_sql_files: BTreeSet<PathBuf>,
+    /// Keep the raw and the rendered sql of each model on the node (for debugging)
+    pub keep_rendered: bool,
+    /// Unique ids of the nodes carried over from a previous resolve by
+    /// [resolve_incremental](crate::resolver::resolve_incremental)
+    pub carried_over: BTreeSet<String>,
 }
 
 impl ResolveArgs {
@@ -51,6 +64,10 @@ impl ResolveArgs {
             indirect_selection: arg.indirect_selection,
             replay: arg.replay.clone(),
             sample_config: RunFilter::try_from(arg.empty, arg.sample.clone())?,
+            only: None,
+            rendered_sql_files: BTreeSet::new(),
+            keep_rendered: false,
+            carried_over: BTreeSet::new(),
         })
     }
 }
@@ -9,6 +9,7 @@ use dbt_common::adapter::AdapterType;
 use dbt_common::cancellation::CancellationToken;
 use dbt_common::constants::PARSING;
 use dbt_common::io_args::IoArgs;
+use dbt_common::node_selector::{MethodName, SelectExpression};
 use dbt_common::tokiofs::read_to_string;
 use dbt_common::{
     ErrorCode, FsError, FsResult, fs_err, fsinfo, show_error, show_progress, show_warning,
@@ -30,16 +31,17 @@ use dbt_schemas::schemas::common::{DbtChecksum, DbtQuoting, Hooks};
 use dbt_schemas::schemas::project::DefaultTo;
 use dbt_schemas::schemas::properties::GetConfig;
 use dbt_schemas::schemas::{DbtModel, InternalDbtNode, IntrospectionKind, Nodes};
-use dbt_schemas::state::{DbtAsset, DbtRuntimeConfig, ModelStatus};
+use dbt_schemas::state::{DbtAsset, DbtPackage, DbtRuntimeConfig, ModelStatus};
 use std::fmt::Debug;
 
 use minijinja::constants::{TARGET_PACKAGE_NAME, TARGET_UNIQUE_ID};
 use minijinja::{MacroSpans, Value as MinijinjaValue};
-use std::collections::{BTreeMap, HashMap, HashSet};
-use std::path::PathBuf;
-use std::sync::Arc;
+use regex::Regex;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::path::{Path, PathBuf};
 use std::sync::Mutex;
 use std::sync::atomic::{self, AtomicBool};
+use std::sync::{Arc, LazyLock};
 
 /// Represents the result of rendering a single SQL file
 #[derive(Debug)]
@@ -52,6 +54,8 @@ pub struct SqlFileRenderResult<T: DefaultTo<T>, S> {
     pub sql_file_info: SqlFileInfo<T>,
     /// The rendered SQL
     pub rendered_sql: String,
+    /// The SQL as read from the file, only kept with [ResolveArgs::keep_rendered]
+    pub raw_sql: Option<String>,
     /// The macro spans for the rendered SQL
     pub macro_spans: MacroSpans,
     /// The macro calls made during rendering
@@ -128,6 +132,7 @@ pub async fn render_unresolved_sql_files_sequentially<
     render_ctx: &RenderCtx<T>,
     model_sql_files: &[DbtAsset],
     node_properties: &mut BTreeMap<String, MinimalPropertiesEntry>,
+    unselected_files: &HashSet<PathBuf>,
     token: &CancellationToken,
 ) -> FsResult<Vec<SqlFileRenderResult<T, S>>> {
     let RenderCtx {
@@ -224,6 +229,23 @@ pub async fn render_unresolved_sql_files_sequentially<
         let absolute_path = dbt_asset.base_path.join(&dbt_asset.path);
         let sql = read_to_string(&absolute_path).await.map_err(|e| *e)?;
 
+        if unselected_files.contains(&dbt_asset.path) {
+            let root_config = (root_project_name != package_name)
+                .then(|| root_project_config.get_config_for_fqn(&fqn).clone());
+            model_sql_resources_map.push(unrendered_result(
+                dbt_asset,
+                &sql,
+                properties_config,
+                root_config,
+                maybe_model,
+                node_properties
+                    .get(ref_name)
+                    .map(|mpe| mpe.relative_path.clone()),
+                args.keep_rendered.then(|| sql.clone()),
+            ));
+            continue;
+        }
+
         let sql_resources = Arc::new(Mutex::new(Vec::new()));
         let execute_exists = Arc::new(AtomicBool::new(false));
 
@@ -315,6 +337,7 @@ pub async fn render_unresolved_sql_files_sequentially<
                     asset: dbt_asset.clone(),
                     sql_file_info,
                     rendered_sql: rendered_sql_except_refs_and_sources,
+                    raw_sql: args.keep_rendered.then(|| sql.clone()),
                     macro_spans,
                     macro_calls,
                     properties: maybe_model,
@@ -358,6 +381,7 @@ pub async fn render_unresolved_sql_files_sequentially<
                     asset: dbt_asset.clone(),
                     sql_file_info,
                     rendered_sql: "".to_string(),
+                    raw_sql: args.keep_rendered.then(|| sql.clone()),
                     macro_spans: MacroSpans::default(),
                     macro_calls: HashSet::new(),
                     properties: maybe_model,
@@ -376,6 +400,241 @@ pub async fn render_unresolved_sql_files_sequentially<
     Ok(model_sql_resources_map)
 }
 
+/// Whether a node must be rendered when resolution is restricted to the nodes
+/// matching `only` (see [ResolveArgs::only]).
+///
+/// Only the criteria that can be decided from a node's location are evaluated
+/// here (`fqn`, `path`, `file` and `package`). Everything else, including graph
+/// operators whose parents and children are only known after rendering, is
+/// conservatively treated as selected.
+pub(crate) fn selected_for_rendering(
+    only: &SelectExpression,
+    fqn: &[String],
+    path: &Path,
+    package_name: &str,
+) -> bool {
+    match only {
+        SelectExpression::Atom(criteria) => {
+            if criteria.childrens_parents
+                || criteria.parents_depth.is_some()
+                || criteria.children_depth.is_some()
+                || criteria.value.contains('*')
+            {
+                return true;
+            }
+            let value = criteria.value.as_str();
+            let matched = match criteria.method {
+                MethodName::Fqn => {
+                    let parts: Vec<&str> = value.split('.').collect();
+                    fqn.last().is_some_and(|name| name == value)
+                        || (parts.len() <= fqn.len()
+                            && parts.iter().zip(fqn).all(|(part, f)| part == f))
+                }
+                MethodName::Path => path.starts_with(value),
+                MethodName::File => {
+                    path.file_name().is_some_and(|f| f == value)
+                        || path.file_stem().is_some_and(|f| f == value)
+                }
+                MethodName::Package => package_name == value,
+                _ => true,
+            };
+            matched
+                && !criteria
+                    .exclude
+                    .as_ref()
+                    .is_some_and(|exclude| selected_for_rendering(exclude, fqn, path, package_name))
+        }
+        SelectExpression::And(exprs) => exprs
+            .iter()
+            .all(|expr| selected_for_rendering(expr, fqn, path, package_name)),
+        SelectExpression::Or(exprs) => exprs
+            .iter()
+            .any(|expr| selected_for_rendering(expr, fqn, path, package_name)),
+        SelectExpression::Exclude(expr) => !selected_for_rendering(expr, fqn, path, package_name),
+    }
+}
+
+/// Matches a `ref` with literal arguments in raw sql, capturing the package
+/// (if any) and the model name, e.g. `ref('orders')` or `ref("package", "orders")`
+static RAW_REF_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"\bref\(\s*(?:['"]([^'"]*)['"]\s*,\s*)?['"]([^'"]+)['"]"#).expect("valid regex")
+});
+
+/// Absolute paths of the sql files to render when resolution is restricted to
+/// the nodes matching `only`: the selected models, analyses, snapshots and
+/// tests of all packages and the models they ref, transitively (see
+/// [ResolveArgs::rendered_sql_files]).
+///
+/// This is computed once across all packages, as a model may ref a model of
+/// another package. Refs are read from the raw sql, so a ref whose arguments
+/// are only known after rendering does not keep its parent.
+pub async fn selected_sql_files(
+    only: &SelectExpression,
+    packages: &[DbtPackage],
+) -> FsResult<BTreeSet<PathBuf>> {
+    let resources = packages.iter().flat_map(|package| {
+        let project = &package.dbt_project;
+        [
+            (true, &package.model_sql_files, &project.model_paths),
+            (false, &package.analysis_files, &project.analysis_paths),
+            (false, &package.snapshot_files, &project.snapshot_paths),
+            (false, &package.test_files, &project.test_paths),
+        ]
+        .map(|(is_model, assets, paths)| {
+            (
+                is_model,
+                assets.as_slice(),
+                paths.as_deref().unwrap_or_default(),
+            )
+        })
+    });
+    ref_closure(only, resources).await
+}
+
+/// See [selected_sql_files]; `resources` are the sql files of each resource
+/// type of each package, with whether they are models and their resource
+/// paths.
+pub(crate) async fn ref_closure<'a>(
+    only: &SelectExpression,
+    resources: impl IntoIterator<Item = (bool, &'a [DbtAsset], &'a [String])>,
+) -> FsResult<BTreeSet<PathBuf>> {
+    // model name -> (package name, absolute path) of the models with that name
+    let mut models_by_name: HashMap<String, Vec<(String, PathBuf)>> = HashMap::new();
+    // absolute path -> (package, model name) of the refs in the file
+    let mut refs_by_path: HashMap<PathBuf, Vec<(Option<String>, String)>> = HashMap::new();
+    let mut pending = Vec::new();
+    for (is_model, assets, resource_paths) in resources {
+        for dbt_asset in assets {
+            let name = dbt_asset
+                .path
+                .file_stem()
+                .unwrap()
+                .to_str()
+                .unwrap()
+                .to_string();
+            let fqn = get_node_fqn(
+                &dbt_asset.package_name,
+                dbt_asset.path.clone(),
+                vec![name.clone()],
+                resource_paths,
+            );
+            let absolute_path = dbt_asset.base_path.join(&dbt_asset.path);
+            if selected_for_rendering(only, &fqn, &dbt_asset.path, &dbt_asset.package_name) {
+                pending.push(absolute_path.clone());
+            }
+            let sql = read_to_string(&absolute_path).await.map_err(|e| *e)?;
+            let refs = RAW_REF_RE
+                .captures_iter(&sql)
+                .map(|captures| {
+                    (
+                        captures.get(1).map(|package| package.as_str().to_string()),
+                        captures[2].to_string(),
+                    )
+                })
+                .collect();
+            refs_by_path.insert(absolute_path.clone(), refs);
+            if is_model {
+                models_by_name
+                    .entry(name)
+                    .or_default()
+                    .push((dbt_asset.package_name.clone(), absolute_path));
+            }
+        }
+    }
+
+    let mut selected = BTreeSet::new();
+    while let Some(path) = pending.pop() {
+        if !selected.insert(path.clone()) {
+            continue;
+        }
+        for (package, name) in refs_by_path.get(&path).into_iter().flatten() {
+            let parents = models_by_name.get(name).into_iter().flatten();
+            pending.extend(
+                parents
+                    .filter(|(parent_package, _)| {
+                        package
+                            .as_ref()
+                            .is_none_or(|package| package == parent_package)
+                    })
+                    .map(|(_, path)| path.clone()),
+            );
+        }
+    }
+    Ok(selected)
+}
+
+/// Paths of the sql files that don't need to be rendered when resolution is
+/// restricted to the nodes matching [ResolveArgs::only]; empty when it is not.
+///
+/// A file is rendered if it is selected by [selected_for_rendering] or listed
+/// in [ResolveArgs::rendered_sql_files], i.e. ref'd by a selected node.
+fn unselected_sql_files<T: DefaultTo<T>>(
+    inner: &RenderCtxInner<T>,
+    model_sql_files: &[DbtAsset],
+) -> HashSet<PathBuf> {
+    let Some(only) = inner.args.only.as_ref() else {
+        return HashSet::new();
+    };
+
+    model_sql_files
+        .iter()
+        .filter(|dbt_asset| {
+            let name = dbt_asset.path.file_stem().unwrap().to_str().unwrap();
+            let fqn = get_node_fqn(
+                &inner.package_name,
+                dbt_asset.path.clone(),
+                vec![name.to_string()],
+                &inner.resource_paths,
+            );
+            !selected_for_rendering(only, &fqn, &dbt_asset.path, &inner.package_name)
+                && !inner
+                    .args
+                    .rendered_sql_files
+                    .contains(&dbt_asset.base_path.join(&dbt_asset.path))
+        })
+        .map(|dbt_asset| dbt_asset.path.clone())
+        .collect()
+}
+
+/// Builds the result for a sql file that is skipped because it is not selected
+/// for rendering: the node keeps its project and properties configs, but has
+/// no rendered sql and no refs, sources or macro calls.
+fn unrendered_result<T: DefaultTo<T>, S>(
+    dbt_asset: &DbtAsset,
+    sql: &str,
+    properties_config: T,
+    root_config: Option<T>,
+    properties: Option<S>,
+    patch_path: Option<PathBuf>,
+    raw_sql: Option<String>,
+) -> SqlFileRenderResult<T, S> {
+    let mut sql_resources = vec![SqlResource::Config(Box::new(properties_config))];
+    if let Some(root_config) = root_config {
+        sql_resources.push(SqlResource::Config(Box::new(root_config)));
+    }
+    let sql_file_info = SqlFileInfo::from_sql_resources(
+        sql_resources,
+        DbtChecksum::hash(sql.trim().as_bytes()),
+        false,
+    );
+    let status = if sql_file_info.config.get_enabled().unwrap_or(true) {
+        ModelStatus::Enabled
+    } else {
+        ModelStatus::Disabled
+    };
+    SqlFileRenderResult {
+        asset: dbt_asset.clone(),
+        sql_file_info,
+        rendered_sql: "".to_string(),
+        raw_sql,
+        macro_spans: MacroSpans::default(),
+        macro_calls: HashSet::new(),
+        properties,
+        status,
+        patch_path,
+    }
+}
+
 /// Inner context for rendering sql files
 #[derive(Clone)]
 pub struct RenderCtxInner<T: DefaultTo<T>> {
@@ -433,12 +692,15 @@ pub async fn render_unresolved_sql_files<
         return Ok(Vec::new());
     }
 
+    let unselected_files = Arc::new(unselected_sql_files(&render_ctx.inner, model_sql_files));
+
     if model_sql_files.len() < 50 || render_ctx.inner.args.num_threads == Some(1) {
         // if the number of files is less than 50 or the user has specified to use a single thread, use a single thread
         return render_unresolved_sql_files_sequentially(
             render_ctx,
             model_sql_files,
             node_properties,
+            &unselected_files,
             token,
         )
         .await;
@@ -470,6 +732,7 @@ pub async fn render_unresolved_sql_files<
 
     for (chunk, mut chunk_node_properties) in chunked_files.into_iter().zip(chunked_node_props) {
         let render_ctx = render_ctx.clone();
+        let unselected_files = unselected_files.clone();
         let token = token.clone();
         tasks.push(tokio::spawn(async move {
             let RenderCtx {
@@ -564,6 +827,25 @@ pub async fn render_unresolved_sql_files<
 
                 let absolute_path = dbt_asset.base_path.join(&dbt_asset.path);
                 let sql = read_to_string(&absolute_path).await.map_err(|e| *e)?;
+
+                if unselected_files.contains(&dbt_asset.path) {
+                    let root_config = (root_project_name != package_name)
+                        .then(|| root_project_config.get_config_for_fqn(&fqn).clone());
+                    let patch_path = chunk_node_properties
+                        .get(ref_name)
+                        .map(|mpe| mpe.relative_path.clone());
+                    local_results.push(unrendered_result(
+                        &dbt_asset,
+                        &sql,
+                        properties_config,
+                        root_config,
+                        maybe_model,
+                        patch_path,
+                        args.keep_rendered.then(|| sql.clone()),
+                    ));
+                    continue;
+                }
+
                 let sql_resources = Arc::new(Mutex::new(Vec::new()));
                 // when `load`, using `Ordering::Relax` is enough since no threads should be writing to it
                 // as long as the read is after `render_sql` is done within this scope
@@ -660,6 +942,7 @@ pub async fn render_unresolved_sql_files<
                             asset: dbt_asset.clone(),
                             sql_file_info,
                             rendered_sql: rendered_sql_except_refs_and_sources,
+                            raw_sql: args.keep_rendered.then(|| sql.clone()),
                             macro_spans,
                             macro_calls,
                             properties: maybe_model,
@@ -706,6 +989,7 @@ pub async fn render_unresolved_sql_files<
                             asset: dbt_asset.clone(),
                             sql_file_info,
                             rendered_sql: "".to_string(),
+                            raw_sql: args.keep_rendered.then(|| sql.clone()),
                             macro_spans: MacroSpans::default(),
                             macro_calls: HashSet::new(),
                             properties: maybe_model,
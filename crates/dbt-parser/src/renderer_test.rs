@@ -2,16 +2,18 @@
 mod tests {
     use crate::args::ResolveArgs;
     use crate::dbt_project_config::DbtProjectConfig;
-    use crate::renderer::{RenderCtx, RenderCtxInner, render_unresolved_sql_files};
+    use crate::renderer::{RenderCtx, RenderCtxInner, ref_closure, render_unresolved_sql_files};
     use dbt_common::adapter::AdapterType;
+    use dbt_common::cancellation::CancellationToken;
     use dbt_common::io_args::IoArgs;
+    use dbt_common::node_selector::parse_selector;
     use dbt_common::serde_utils::Omissible;
     use dbt_jinja_utils::jinja_environment::JinjaEnv;
     use dbt_schemas::filter::RunFilter;
     use dbt_schemas::schemas::common::DbtQuoting;
     use dbt_schemas::schemas::project::ModelConfig;
     use dbt_schemas::schemas::properties::ModelProperties;
-    use dbt_schemas::state::{DbtAsset, DbtRuntimeConfig};
+    use dbt_schemas::state::{DbtAsset, DbtRuntimeConfig, ModelStatus};
     use minijinja::Environment;
     use std::collections::{BTreeMap, HashMap};
     use std::path::PathBuf;
@@ -79,6 +81,10 @@ mod tests {
             exclude: None,
             replay: None,
             sample_config: RunFilter::default(),
+            only: None,
+            keep_rendered: false,
+            carried_over: Default::default(),
+            rendered_sql_files: Default::default(),
         };
 
         // Create base context with minimal required values
@@ -182,6 +188,184 @@ mod tests {
         );
     }
 
+    /// With `only` set, selected models and the models they ref are rendered,
+    /// other models are still returned with their configs but their sql is not
+    /// rendered
+    #[tokio::test]
+    async fn test_render_unresolved_sql_files_only_selected() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let base_path = temp_dir.path().to_path_buf();
+        let models_dir = base_path.join("models");
+        std::fs::create_dir_all(&models_dir).unwrap();
+
+        // `customers` would fail to render since `undefined_macro` does not exist
+        std::fs::write(
+            models_dir.join("orders.sql"),
+            "select id from {{ ref('stg_orders') }}",
+        )
+        .unwrap();
+        std::fs::write(models_dir.join("stg_orders.sql"), "select 1 as id").unwrap();
+        std::fs::write(
+            models_dir.join("customers.sql"),
+            "select {{ undefined_macro() }} as id",
+        )
+        .unwrap();
+        let assets: Vec<DbtAsset> = ["orders", "stg_orders", "customers"]
+            .iter()
+            .map(|name| DbtAsset {
+                base_path: base_path.clone(),
+                path: PathBuf::from(format!("models/{name}.sql")),
+                package_name: "test_package".to_string(),
+            })
+            .collect();
+
+        let config = DbtProjectConfig::<ModelConfig> {
+            config: ModelConfig {
+                enabled: Some(true),
+                schema: Omissible::Present(Some("package_schema".to_string())),
+                ..Default::default()
+            },
+            children: HashMap::new(),
+        };
+        let only = parse_selector("orders").unwrap();
+        let model_paths = vec!["models".to_string()];
+        let rendered_sql_files =
+            ref_closure(&only, [(true, assets.as_slice(), model_paths.as_slice())])
+                .await
+                .unwrap();
+        let args = ResolveArgs {
+            io: IoArgs {
+                in_dir: base_path.clone(),
+                out_dir: base_path.clone(),
+                ..Default::default()
+            },
+            num_threads: Some(1),
+            only: Some(only),
+            rendered_sql_files,
+            ..Default::default()
+        };
+        let render_ctx = RenderCtx {
+            inner: Arc::new(RenderCtxInner {
+                args,
+                base_ctx: BTreeMap::new(),
+                root_project_name: "test_package".to_string(),
+                package_name: "test_package".to_string(),
+                adapter_type: AdapterType::Postgres,
+                database: "test_db".to_string(),
+                schema: "default_schema".to_string(),
+                local_project_config: config.clone(),
+                root_project_config: config,
+                resource_paths: vec!["models".to_string()],
+                package_quoting: DbtQuoting::default(),
+            }),
+            jinja_env: Arc::new(JinjaEnv::new(Environment::new())),
+            runtime_config: Arc::new(DbtRuntimeConfig::default()),
+        };
+
+        let results = render_unresolved_sql_files::<ModelConfig, ModelProperties>(
+            &render_ctx,
+            &assets,
+            &mut BTreeMap::new(),
+            &CancellationToken::never_cancels(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(results.len(), 3);
+
+        let orders = results
+            .iter()
+            .find(|r| r.asset.path.ends_with("models/orders.sql"))
+            .unwrap();
+        assert!(orders.rendered_sql.starts_with("select id from"));
+        assert_eq!(orders.sql_file_info.refs.len(), 1);
+
+        // `stg_orders` is not selected, but is rendered as a parent of `orders`
+        let stg_orders = results
+            .iter()
+            .find(|r| r.asset.path.ends_with("stg_orders.sql"))
+            .unwrap();
+        assert!(stg_orders.rendered_sql.contains("select 1 as id"));
+
+        let customers = results
+            .iter()
+            .find(|r| r.asset.path.ends_with("customers.sql"))
+            .unwrap();
+        assert_eq!(customers.status, ModelStatus::Enabled);
+        assert_eq!(customers.rendered_sql, "");
+        assert!(customers.macro_calls.is_empty());
+        assert_eq!(
+            customers.sql_file_info.config.schema,
+            Omissible::Present(Some("package_schema".to_string()))
+        );
+    }
+
+    /// The models ref'd by a selected model are rendered whatever package
+    /// they are in, transitively
+    #[tokio::test]
+    async fn test_ref_closure_across_packages() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let write_models = |package: &str, models: &[(&str, &str)]| -> Vec<DbtAsset> {
+            let base_path = temp_dir.path().join(package);
+            std::fs::create_dir_all(base_path.join("models")).unwrap();
+            models
+                .iter()
+                .map(|(name, sql)| {
+                    let path = PathBuf::from(format!("models/{name}.sql"));
+                    std::fs::write(base_path.join(&path), sql).unwrap();
+                    DbtAsset {
+                        base_path: base_path.clone(),
+                        path,
+                        package_name: package.to_string(),
+                    }
+                })
+                .collect()
+        };
+        let analytics = write_models(
+            "analytics",
+            &[
+                ("orders", "select * from {{ ref('staging', 'stg_orders') }}"),
+                ("customers", "select 1 as id"),
+            ],
+        );
+        let staging = write_models(
+            "staging",
+            &[
+                ("stg_orders", "select * from {{ ref(\"raw_orders\") }}"),
+                ("raw_orders", "select 1 as id"),
+                ("stg_customers", "select 1 as id"),
+            ],
+        );
+        // same name as the parent in `staging`, but in another package
+        let other = write_models("other", &[("stg_orders", "select 1 as id")]);
+
+        let model_paths = vec!["models".to_string()];
+        let rendered = ref_closure(
+            &parse_selector("orders").unwrap(),
+            [&analytics, &staging, &other]
+                .map(|assets| (true, assets.as_slice(), model_paths.as_slice())),
+        )
+        .await
+        .unwrap();
+
+        let rendered_names = |assets: &[DbtAsset]| -> Vec<String> {
+            assets
+                .iter()
+                .filter(|asset| rendered.contains(&asset.base_path.join(&asset.path)))
+                .map(|asset| {
+                    asset
+                        .path
+                        .file_stem()
+                        .unwrap()
+                        .to_string_lossy()
+                        .to_string()
+                })
+                .collect()
+        };
+        assert_eq!(rendered_names(&analytics), vec!["orders"]);
+        assert_eq!(rendered_names(&staging), vec!["stg_orders", "raw_orders"]);
+        assert!(rendered_names(&other).is_empty());
+    }
+
     /// Simple unit test to verify config override ordering behavior
     #[test]
     fn test_config_override_order() {
@@ -123,6 +123,7 @@ pub async fn resolve_analyses(
         asset: dbt_asset,
         sql_file_info,
         rendered_sql,
+        raw_sql: _,
         macro_spans,
         macro_calls,
         properties: maybe_properties,
@@ -248,6 +249,7 @@ pub async fn resolve_analyses(
                 incremental_strategy: None,
                 freshness: None,
                 event_time: None,
+                rendered_code: None,
             },
             __other__: BTreeMap::new(),
         };
@@ -14,6 +14,7 @@ use crate::utils::get_unique_id;
 use crate::utils::update_node_relation_components;
 
 use dbt_common::ErrorCode;
+use dbt_common::FsError;
 use dbt_common::FsResult;
 use dbt_common::adapter::AdapterType;
 use dbt_common::cancellation::CancellationToken;
@@ -58,6 +59,29 @@ use std::sync::Arc;
 use super::resolve_properties::MinimalPropertiesEntry;
 use super::resolve_tests::persist_generic_data_tests::TestableNodeTrait;
 
+/// Error for a model whose name (and version) is already taken by another
+/// model of the same package, naming the files of both.
+pub(crate) fn duplicate_model_error(
+    model_name: &str,
+    maybe_version: Option<&str>,
+    existing_path: &Path,
+    duplicate_path: &Path,
+    loc: &Path,
+) -> Box<FsError> {
+    let model = match maybe_version {
+        Some(version) => format!("'{model_name}' with version '{version}'"),
+        None => format!("'{model_name}'"),
+    };
+    fs_err!(
+        code => ErrorCode::InvalidConfig,
+        loc => loc.to_path_buf(),
+        "Found duplicate model {}: defined in both '{}' and '{}'",
+        model,
+        existing_path.display(),
+        duplicate_path.display(),
+    )
+}
+
 #[allow(clippy::cognitive_complexity)]
 #[allow(clippy::too_many_arguments)]
 pub async fn resolve_models(
@@ -83,7 +107,7 @@ pub async fn resolve_models(
     HashMap<String, Arc<DbtModel>>,
 )> {
     let mut models: HashMap<String, Arc<DbtModel>> = HashMap::new();
-    let mut models_with_execute = HashMap::new();
+    let mut models_with_execute: HashMap<String, Arc<DbtModel>> = HashMap::new();
     let mut disabled_models: HashMap<String, Arc<DbtModel>> = HashMap::new();
     let mut node_names = HashSet::new();
     let mut rendering_results: HashMap<String, (String, MacroSpans)> = HashMap::new();
@@ -171,6 +195,7 @@ pub async fn resolve_models(
         asset: dbt_asset,
         sql_file_info,
         rendered_sql,
+        raw_sql,
         macro_spans,
         macro_calls,
         properties: maybe_properties,
@@ -213,22 +238,25 @@ pub async fn resolve_models(
             })?;
         }
 
+        let original_file_path =
+            get_original_file_path(&dbt_asset.base_path, &arg.io.in_dir, &dbt_asset.path);
+
         // Keep track of duplicates (often happens with versioned models)
-        if (models.contains_key(&unique_id) || models_with_execute.contains_key(&unique_id))
-            && !(status == ModelStatus::Disabled)
+        if let Some(existing) = models
+            .get(&unique_id)
+            .or_else(|| models_with_execute.get(&unique_id))
+            .filter(|_| status != ModelStatus::Disabled)
         {
-            duplicates.push((
-                unique_id.clone(),
-                model_name.clone(),
-                maybe_version.clone(),
-                dbt_asset.path.clone(),
+            duplicates.push(duplicate_model_error(
+                &model_name,
+                maybe_version.as_deref(),
+                &existing.__common_attr__.original_file_path,
+                &original_file_path,
+                &dbt_asset.path,
             ));
             continue;
         }
 
-        let original_file_path =
-            get_original_file_path(&dbt_asset.base_path, &arg.io.in_dir, &dbt_asset.path);
-
         // Model fqn includes v{version} for versioned models
         let fqn_components = if let Some(version) = &maybe_version {
             vec![model_name.to_owned(), format!("v{}", version)]
@@ -370,6 +398,7 @@ pub async fn resolve_models(
                 incremental_strategy: model_config.incremental_strategy.clone(),
                 freshness: model_config.freshness.clone(),
                 event_time: model_config.event_time.clone(),
+                rendered_code: None,
             },
             __adapter_attr__: AdapterAttr::from_config_and_dialect(
                 &model_config.__warehouse_specific_config__,
@@ -379,6 +408,7 @@ pub async fn resolve_models(
             deprecated_config: model_config.clone(),
             __other__: BTreeMap::new(),
         };
+        keep_rendered_code(&mut dbt_model, raw_sql, &rendered_sql);
 
         let components = RelationComponents {
             database: model_config.database.into_inner().unwrap_or(None),
@@ -437,6 +467,13 @@ pub async fn resolve_models(
         if mpe.version_info.is_some() {
             continue;
         }
+        // Models carried over by an incremental resolve are not re-rendered
+        if arg
+            .carried_over
+            .contains(&get_unique_id(model_name, package_name, None, "model"))
+        {
+            continue;
+        }
         if !mpe.schema_value.is_null() {
             // Validate that the model is not latest and flattened
             let err = fs_err!(
@@ -450,28 +487,11 @@ pub async fn resolve_models(
     }
 
     // Report duplicates
-    if !duplicates.is_empty() {
-        let mut errs = Vec::new();
-        for (_, model_name, maybe_version, path) in duplicates {
-            let msg = if let Some(version) = maybe_version {
-                format!("Found duplicate model '{model_name}' with version '{version}'")
-            } else {
-                format!("Found duplicate model '{model_name}'")
-            };
-            let err = fs_err!(
-                code => ErrorCode::InvalidConfig,
-                loc => path.clone(),
-                "{}",
-                msg,
-            );
-            errs.push(err);
-        }
-        while let Some(err) = errs.pop() {
-            if errs.is_empty() {
-                return Err(err);
-            }
-            show_error!(&arg.io, err);
+    while let Some(err) = duplicates.pop() {
+        if duplicates.is_empty() {
+            return Err(err);
         }
+        show_error!(&arg.io, err);
     }
 
     // Second pass to capture all identifiers with the appropriate context
@@ -552,3 +572,22 @@ pub fn validate_merge_update_columns_xor(model_config: &ModelConfig, path: &Path
     }
     Ok(())
 }
+
+/// Retains the raw and the rendered sql on the model when the renderer kept the
+/// raw sql, i.e. with [ResolveArgs::keep_rendered]. Otherwise `raw_code` stays
+/// the `--placeholder--` set at resolution and `rendered_code` stays unset, to
+/// save memory.
+///
+/// Models that were not rendered (unselected, or failed to render) come with an
+/// empty rendered sql: they keep their raw sql but no `rendered_code`.
+pub(crate) fn keep_rendered_code(
+    dbt_model: &mut DbtModel,
+    raw_sql: Option<String>,
+    rendered_sql: &str,
+) {
+    if let Some(raw_sql) = raw_sql {
+        dbt_model.__common_attr__.raw_code = Some(raw_sql);
+        dbt_model.__model_attr__.rendered_code =
+            (!rendered_sql.is_empty()).then(|| rendered_sql.to_string());
+    }
+}
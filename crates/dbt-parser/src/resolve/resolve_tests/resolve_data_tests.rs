@@ -162,6 +162,7 @@ pub async fn resolve_data_tests(
         asset: dbt_asset,
         sql_file_info,
         rendered_sql,
+        raw_sql: _,
         macro_spans: _macro_spans,
         macro_calls,
         properties: maybe_properties,
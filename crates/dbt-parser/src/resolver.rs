@@ -16,16 +16,19 @@ use dbt_jinja_utils::serde::{into_typed_with_error, into_typed_with_jinja};
 use dbt_jinja_utils::utils::dependency_package_name_from_ctx;
 use dbt_schemas::dbt_utils::resolve_package_quoting;
 use dbt_schemas::schemas::common::Access;
-use dbt_schemas::schemas::macros::build_macro_units;
+use dbt_schemas::schemas::macros::{DbtMacro, build_macro_units};
 use dbt_schemas::schemas::properties::{MetricsProperties, ModelProperties};
 use dbt_schemas::schemas::{InternalDbtNode, Nodes};
 
 use dbt_jinja_utils::jinja_environment::JinjaEnv;
-use dbt_schemas::state::{DbtPackage, GenericTestAsset, Macros, RenderResults};
+use dbt_schemas::state::{DbtAsset, DbtPackage, GenericTestAsset, Macros, RenderResults};
 use dbt_schemas::state::{DbtRuntimeConfig, Operations};
+use minijinja::dispatch_object::get_internal_packages;
+use regex::Regex;
 
 use crate::args::ResolveArgs;
 use crate::dbt_project_config::{RootProjectConfigs, build_root_project_configs};
+use crate::renderer::selected_sql_files;
 use crate::resolve::resolve_groups::resolve_groups;
 use crate::resolve::resolve_operations::resolve_operations;
 use crate::utils::{self, clear_package_diagnostics};
@@ -33,8 +36,9 @@ use dbt_schemas::schemas::telemetry::BuildPhaseInfo;
 use dbt_schemas::schemas::telemetry::TelemetryAttributes;
 use dbt_schemas::state::DbtState;
 use dbt_schemas::state::ResolverState;
-use std::collections::{BTreeMap, HashMap};
-use std::sync::Arc;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, LazyLock};
 
 use crate::resolve::resolve_analyses::resolve_analyses;
 use crate::resolve::resolve_exposures::resolve_exposures;
@@ -74,7 +78,7 @@ type YmlValue = dbt_serde_yaml::Value;
 #[tracing::instrument(
     skip_all,
     fields(
-        __event = TelemetryAttributes::Phase(BuildPhaseInfo::Parsing { }).to_tracing_value(),
+        __event = TelemetryAttributes::Phase(BuildPhaseInfo::Parsing { peak_rss_bytes: None }).to_tracing_value(),
     )
 )]
 pub async fn resolve(
@@ -96,6 +100,16 @@ pub async fn resolve(
     let mut macros = macros;
     let mut nodes = nodes;
 
+    // A selected model may ref a model of another package, so the files to
+    // render with `only` are decided once for all packages
+    let arg = &match &arg.only {
+        Some(only) => ResolveArgs {
+            rendered_sql_files: selected_sql_files(only, &dbt_state.packages).await?,
+            ..arg.clone()
+        },
+        None => arg.clone(),
+    };
+
     // First, resolve all of the macros from each package
     // まず、各パッケージのすべてのマクロを解決します
     for package in &dbt_state.packages {
@@ -233,8 +247,9 @@ pub async fn resolve(
             .rendering_results
             .extend(resolved_collector.rendering_results);
     }
-    // Ensure that there are no duplicate relations
-    // 重複した関係がないことを確認する
+    // Ensure that there are no duplicate names within a package, nor duplicate relations
+    // パッケージ内で重複した名前、および重複した関係がないことを確認する
+    check_node_name_uniqueness(&nodes)?;
     check_relation_uniqueness(&nodes)?;
 
     match nodes.warn_on_microbatch() {
@@ -288,6 +303,169 @@ pub async fn resolve(
     ))
 }
 
+/// Computes the unique ids of the nodes that must be re-resolved after the
+/// files in `changed` (relative to the project root) were edited.
+///
+/// The invalidation rule is:
+/// 1. a node is invalidated if its `original_file_path` or `patch_path` is
+///    one of the changed files;
+/// 2. a node is invalidated if any entry of its `depends_on.nodes` is
+///    invalidated, applied transitively until a fixed point is reached.
+///
+/// Data tests are only invalidated by rule 1: generic tests are regenerated
+/// together with the node they are attached to, so pulling them in through
+/// their dependencies would only drop tests that are not re-emitted.
+pub fn invalidated_nodes(nodes: &Nodes, changed: &[PathBuf]) -> BTreeSet<String> {
+    let changed: HashSet<&Path> = changed.iter().map(PathBuf::as_path).collect();
+
+    let mut dependents: HashMap<&str, Vec<&str>> = HashMap::new();
+    let mut invalidated = BTreeSet::new();
+    let mut queue = VecDeque::new();
+    for (unique_id, node) in nodes.iter() {
+        let common = node.common();
+        if changed.contains(common.original_file_path.as_path())
+            || common
+                .patch_path
+                .as_deref()
+                .is_some_and(|p| changed.contains(p))
+        {
+            invalidated.insert(unique_id.clone());
+            queue.push_back(unique_id.as_str());
+        }
+        if nodes.tests.contains_key(unique_id) {
+            continue;
+        }
+        for dependency in &node.base().depends_on.nodes {
+            dependents
+                .entry(dependency.as_str())
+                .or_default()
+                .push(unique_id.as_str());
+        }
+    }
+
+    while let Some(unique_id) = queue.pop_front() {
+        for dependent in dependents.get(unique_id).into_iter().flatten() {
+            if invalidated.insert(dependent.to_string()) {
+                queue.push_back(*dependent);
+            }
+        }
+    }
+    invalidated
+}
+
+/// Re-resolves the project after the files in `changed` were edited, reusing
+/// everything in `prev` that is not affected by the change.
+///
+/// Only the nodes returned by [invalidated_nodes] (plus any new files among
+/// `changed`) are re-rendered; all other nodes are carried over from `prev`.
+/// Properties files are always re-read, since they are cheap to resolve and
+/// provide the configs of the re-rendered nodes. A change to a macro file can
+/// affect any node, so it falls back to a full [resolve].
+#[allow(clippy::too_many_arguments)]
+pub async fn resolve_incremental(
+    prev: &ResolverState,
+    changed: &[PathBuf],
+    arg: &ResolveArgs,
+    invocation_args: &InvocationArgs,
+    dbt_state: Arc<DbtState>,
+    listener_factory: Option<Arc<dyn dbt_jinja_utils::listener::RenderingEventListenerFactory>>,
+    token: &CancellationToken,
+) -> FsResult<(ResolverState, Arc<JinjaEnv>)> {
+    let in_dir = &arg.io.in_dir;
+    let changed: Vec<PathBuf> = changed
+        .iter()
+        .map(|path| {
+            path.strip_prefix(in_dir)
+                .unwrap_or(path.as_path())
+                .to_path_buf()
+        })
+        .collect();
+    let relative_path =
+        |asset: &DbtAsset| utils::get_original_file_path(&asset.base_path, in_dir, &asset.path);
+
+    let macro_changed = dbt_state
+        .packages
+        .iter()
+        .flat_map(|package| &package.macro_files)
+        .any(|asset| changed.contains(&relative_path(asset)));
+    if macro_changed {
+        return resolve(
+            arg,
+            invocation_args,
+            dbt_state,
+            Macros::default(),
+            Nodes::default(),
+            listener_factory,
+            token,
+        )
+        .await;
+    }
+
+    let invalidated = invalidated_nodes(&prev.nodes, &changed);
+    let mut rerender: HashSet<PathBuf> = changed.iter().cloned().collect();
+    for (unique_id, node) in prev.nodes.iter() {
+        if invalidated.contains(unique_id) {
+            rerender.insert(node.common().original_file_path.clone());
+        }
+    }
+
+    // Restrict every package to the files that need to be re-rendered. Macros
+    // are carried over from `prev`, so macro files are not re-read.
+    let mut pruned_state = (*dbt_state).clone();
+    for package in &mut pruned_state.packages {
+        package.macro_files.clear();
+        for files in [
+            &mut package.model_sql_files,
+            &mut package.analysis_files,
+            &mut package.seed_files,
+            &mut package.snapshot_files,
+            &mut package.test_files,
+        ] {
+            files.retain(|asset| rerender.contains(&relative_path(asset)));
+        }
+    }
+
+    let mut nodes = prev.nodes.clone();
+    nodes.retain(|unique_id| !invalidated.contains(unique_id));
+    let arg = &ResolveArgs {
+        carried_over: nodes
+            .iter()
+            .map(|(unique_id, _)| unique_id.clone())
+            .collect(),
+        ..arg.clone()
+    };
+
+    let (mut state, jinja_env) = resolve(
+        arg,
+        invocation_args,
+        Arc::new(pruned_state),
+        prev.macros.clone(),
+        nodes,
+        listener_factory,
+        token,
+    )
+    .await?;
+
+    // Carry over the disabled nodes and rendering results of untouched files
+    let stale_disabled: HashSet<String> = prev
+        .disabled_nodes
+        .iter()
+        .filter(|(_, node)| rerender.contains(&node.common().original_file_path))
+        .map(|(unique_id, _)| unique_id.clone())
+        .collect();
+    let mut disabled_nodes = prev.disabled_nodes.clone();
+    disabled_nodes.retain(|unique_id| !stale_disabled.contains(unique_id));
+    disabled_nodes.extend(state.disabled_nodes);
+    state.disabled_nodes = disabled_nodes;
+
+    let mut rendering_results = prev.render_results.rendering_results.clone();
+    rendering_results.retain(|unique_id, _| !invalidated.contains(unique_id));
+    rendering_results.extend(state.render_results.rendering_results);
+    state.render_results.rendering_results = rendering_results;
+
+    Ok((state, jinja_env))
+}
+
 // Check that models accessing other models (dependecies) can do so.
 fn check_access(
     arg: &ResolveArgs,
@@ -684,6 +862,151 @@ pub async fn resolve_inner(
     Ok((nodes, disabled_nodes, collector, refs_and_sources.clone()))
 }
 
+/// Reports the macros of the root project that are defined but never
+/// referenced, see [unused_macros].
+pub fn find_unused_macros(state: &ResolverState) -> Vec<String> {
+    unused_macros(
+        &state.root_project_name,
+        state.adapter_type,
+        &state.macros,
+        &state.nodes,
+        &state.operations,
+    )
+}
+
+/// Returns the unique ids of the root project macros that no node refers to.
+///
+/// A macro counts as referenced if it is in the `depends_on.macros` of a node
+/// (as recorded while rendering), transitively through the `depends_on` of
+/// referenced macros, or if it is called in an `on-run-start`/`on-run-end`
+/// hook, since hooks are not rendered during resolution.
+///
+/// Macros that the framework invokes on its own are never reported: overrides
+/// of macros defined in the internal packages (including adapter dispatch
+/// variants such as `postgres__create_table_as`), materializations and
+/// generic tests (`{% test %}` blocks).
+pub(crate) fn unused_macros(
+    root_project_name: &str,
+    adapter_type: AdapterType,
+    macros: &Macros,
+    nodes: &Nodes,
+    operations: &Operations,
+) -> Vec<String> {
+    let internal_packages = get_internal_packages(adapter_type.as_ref());
+    let builtin_names: HashSet<&str> = macros
+        .macros
+        .values()
+        .filter(|m| internal_packages.contains(&m.package_name))
+        .map(|m| m.name.as_str())
+        .collect();
+    let overrides_builtin = |name: &str| {
+        builtin_names.contains(name)
+            || name.split_once("__").is_some_and(|(_, dispatched)| {
+                builtin_names.contains(dispatched)
+                    || builtin_names.contains(format!("default__{dispatched}").as_str())
+            })
+    };
+
+    let mut referenced: HashSet<&str> = HashSet::new();
+    let mut queue: VecDeque<&str> = nodes
+        .iter()
+        .flat_map(|(_, node)| &node.base().depends_on.macros)
+        .map(String::as_str)
+        .collect();
+    while let Some(unique_id) = queue.pop_front() {
+        if referenced.insert(unique_id) {
+            if let Some(called) = macros.macros.get(unique_id) {
+                queue.extend(called.depends_on.macros.iter().map(String::as_str));
+            }
+        }
+    }
+
+    let hooks: Vec<&str> = operations
+        .on_run_start
+        .iter()
+        .chain(&operations.on_run_end)
+        .filter_map(|op| op.__common_attr__.raw_code.as_deref())
+        .collect();
+    let called_in_hooks = |name: &str| {
+        let call = Regex::new(&format!(r"\b{}\s*\(", regex::escape(name))).expect("valid regex");
+        hooks.iter().any(|hook| call.is_match(hook))
+    };
+
+    macros
+        .macros
+        .iter()
+        .filter(|(unique_id, m)| {
+            m.package_name == root_project_name
+                && !referenced.contains(unique_id.as_str())
+                && !m.name.starts_with("materialization_")
+                && !is_generic_test(m)
+                && !overrides_builtin(&m.name)
+                && !called_in_hooks(&m.name)
+        })
+        .map(|(unique_id, _)| unique_id.clone())
+        .collect()
+}
+
+/// Whether the macro was defined by a `{% test %}` block, i.e. is a generic test
+fn is_generic_test(m: &DbtMacro) -> bool {
+    static TEST_BLOCK: LazyLock<Regex> =
+        LazyLock::new(|| Regex::new(r"^\{%[-+]?\s*test\s").expect("valid regex"));
+    TEST_BLOCK.is_match(&m.macro_sql)
+}
+
+/// Function to check that no two refable resources of different types (a model, seed
+/// or snapshot) in the same package share a name (and version), since `ref` could not
+/// tell them apart. Two resources of the same type already collapse on their unique_id
+/// (duplicate models are reported while resolving them), so this only catches conflicts
+/// across resource types. The same name in different packages is allowed, as those are
+/// disambiguated by the package name.
+pub fn check_node_name_uniqueness(nodes: &Nodes) -> FsResult<()> {
+    let refable_nodes = nodes
+        .models
+        .values()
+        .map(|node| node.as_ref() as &dyn InternalDbtNode)
+        .chain(
+            nodes
+                .seeds
+                .values()
+                .map(|node| node.as_ref() as &dyn InternalDbtNode),
+        )
+        .chain(
+            nodes
+                .snapshots
+                .values()
+                .map(|node| node.as_ref() as &dyn InternalDbtNode),
+        );
+
+    let mut named_resources: BTreeMap<(&str, &str, Option<String>), &dyn InternalDbtNode> =
+        BTreeMap::new();
+    for node in refable_nodes {
+        let common = node.common();
+        let key = (
+            common.package_name.as_str(),
+            common.name.as_str(),
+            node.version().map(|version| version.to_string()),
+        );
+        if let Some(existing_node) = named_resources.get(&key) {
+            let existing = existing_node.common();
+            return err!(
+                code => ErrorCode::InvalidConfig,
+                loc => common.original_file_path.clone(),
+                "dbt found two resources named '{}' in package '{}': {} ('{}') and {} ('{}'). Resource names must be unique within a package",
+                common.name,
+                common.package_name,
+                existing.unique_id,
+                existing.original_file_path.display(),
+                common.unique_id,
+                common.original_file_path.display()
+            );
+        }
+        named_resources.insert(key, node);
+    }
+
+    Ok(())
+}
+
 /// Function to check models, seeds, and snapshots for relation uniqueness
 pub fn check_relation_uniqueness(nodes: &Nodes) -> FsResult<()> {
     let mut alias_resources: HashMap<String, &dyn InternalDbtNode> = HashMap::new();
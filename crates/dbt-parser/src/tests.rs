@@ -6,6 +6,18 @@ mod test_macro_dependency_integration;
 #[cfg(test)]
 mod test_namespaced_macro_tracking;
 
+#[cfg(test)]
+mod test_incremental_resolve;
+
+#[cfg(test)]
+mod test_unused_macros;
+
+#[cfg(test)]
+mod test_node_name_uniqueness;
+
+#[cfg(test)]
+mod test_keep_rendered;
+
 #[cfg(test)]
 #[allow(clippy::module_inception)]
 mod tests {
@@ -0,0 +1,238 @@
+//! Tests for incremental resolution and its invalidation rule
+
+#[cfg(test)]
+mod tests {
+    use crate::resolver::invalidated_nodes;
+    use dbt_schemas::schemas::Nodes;
+    use dbt_schemas::schemas::nodes::{DbtModel, DbtTest};
+    use std::collections::BTreeSet;
+    use std::path::PathBuf;
+    use std::sync::Arc;
+
+    fn model(name: &str, depends_on: &[&str]) -> (String, Arc<DbtModel>) {
+        let unique_id = format!("model.test.{name}");
+        let mut model = DbtModel::default();
+        model.__common_attr__.unique_id = unique_id.clone();
+        model.__common_attr__.name = name.to_string();
+        model.__common_attr__.original_file_path = PathBuf::from(format!("models/{name}.sql"));
+        model.__common_attr__.patch_path = Some(PathBuf::from("models/schema.yml"));
+        model.__base_attr__.depends_on.nodes = depends_on
+            .iter()
+            .map(|dep| format!("model.test.{dep}"))
+            .collect();
+        (unique_id, Arc::new(model))
+    }
+
+    /// a <- b <- d, c is independent, and a generic test is attached to a
+    fn project() -> Nodes {
+        let mut nodes = Nodes::default();
+        nodes.models.extend([
+            model("a", &[]),
+            model("b", &["a"]),
+            model("c", &[]),
+            model("d", &["b"]),
+        ]);
+        let mut test = DbtTest::default();
+        test.__common_attr__.unique_id = "test.test.not_null_a_id".to_string();
+        test.__common_attr__.original_file_path = PathBuf::from("models/tests.yml");
+        test.__base_attr__.depends_on.nodes = vec!["model.test.a".to_string()];
+        nodes
+            .tests
+            .insert(test.__common_attr__.unique_id.clone(), Arc::new(test));
+        nodes
+    }
+
+    fn ids(ids: &[&str]) -> BTreeSet<String> {
+        ids.iter().map(|id| id.to_string()).collect()
+    }
+
+    #[test]
+    fn test_editing_sql_invalidates_model_and_children() {
+        let invalidated = invalidated_nodes(&project(), &[PathBuf::from("models/a.sql")]);
+        assert_eq!(
+            invalidated,
+            ids(&["model.test.a", "model.test.b", "model.test.d"])
+        );
+    }
+
+    #[test]
+    fn test_editing_leaf_invalidates_only_itself() {
+        let invalidated = invalidated_nodes(&project(), &[PathBuf::from("models/c.sql")]);
+        assert_eq!(invalidated, ids(&["model.test.c"]));
+    }
+
+    #[test]
+    fn test_editing_properties_invalidates_patched_nodes() {
+        let invalidated = invalidated_nodes(
+            &project(),
+            &[
+                PathBuf::from("models/schema.yml"),
+                PathBuf::from("models/tests.yml"),
+            ],
+        );
+        assert_eq!(
+            invalidated,
+            ids(&[
+                "model.test.a",
+                "model.test.b",
+                "model.test.c",
+                "model.test.d",
+                "test.test.not_null_a_id",
+            ])
+        );
+    }
+
+    mod resolve {
+        use crate::args::ResolveArgs;
+        use crate::resolver::{resolve, resolve_incremental};
+        use dbt_common::cancellation::never_cancels;
+        use dbt_common::io_args::IoArgs;
+        use dbt_frontend_common::error::CodeLocation;
+        use dbt_jinja_utils::invocation_args::InvocationArgs;
+        use dbt_jinja_utils::listener::{
+            DefaultRenderingEventListenerFactory, RenderingEventListenerFactory,
+        };
+        use dbt_loader::args::LoadArgs;
+        use dbt_schemas::schemas::Nodes;
+        use dbt_schemas::state::Macros;
+        use minijinja::MacroSpans;
+        use minijinja::listener::RenderingEventListener;
+        use std::collections::{BTreeSet, HashSet};
+        use std::path::{Path, PathBuf};
+        use std::rc::Rc;
+        use std::sync::{Arc, Mutex};
+
+        /// Records the names of the model files that were rendered
+        #[derive(Default)]
+        struct RecordingListenerFactory {
+            inner: DefaultRenderingEventListenerFactory,
+            rendered: Mutex<BTreeSet<String>>,
+        }
+
+        impl RecordingListenerFactory {
+            fn rendered(&self) -> BTreeSet<String> {
+                self.rendered.lock().unwrap().clone()
+            }
+        }
+
+        impl RenderingEventListenerFactory for RecordingListenerFactory {
+            fn create_listeners(
+                &self,
+                filename: &Path,
+                offset: &CodeLocation,
+            ) -> Vec<Rc<dyn RenderingEventListener>> {
+                if filename.components().any(|c| c.as_os_str() == "models")
+                    && filename.extension().is_some_and(|ext| ext == "sql")
+                {
+                    if let Some(stem) = filename.file_stem() {
+                        self.rendered
+                            .lock()
+                            .unwrap()
+                            .insert(stem.to_string_lossy().into_owned());
+                    }
+                }
+                self.inner.create_listeners(filename, offset)
+            }
+
+            fn destroy_listener(&self, filename: &Path, listener: Rc<dyn RenderingEventListener>) {
+                self.inner.destroy_listener(filename, listener)
+            }
+
+            fn drain_macro_spans(&self, filename: &Path) -> MacroSpans {
+                self.inner.drain_macro_spans(filename)
+            }
+
+            fn drain_macro_calls(&self, filename: &Path) -> HashSet<String> {
+                self.inner.drain_macro_calls(filename)
+            }
+        }
+
+        /// a <- b, and c is independent
+        fn write_project(dir: &Path) {
+            std::fs::create_dir_all(dir.join("models")).unwrap();
+            std::fs::write(
+                dir.join("dbt_project.yml"),
+                "name: test\nversion: '1.0'\nprofile: hello_world\nmodel-paths: [\"models\"]\n",
+            )
+            .unwrap();
+            std::fs::copy(
+                Path::new(env!("CARGO_MANIFEST_DIR"))
+                    .join("../dbt-test-utils/tests/data/hello/profiles.yml"),
+                dir.join("profiles.yml"),
+            )
+            .unwrap();
+            std::fs::write(dir.join("models/a.sql"), "select 1 as id").unwrap();
+            std::fs::write(dir.join("models/b.sql"), "select * from {{ ref('a') }}").unwrap();
+            std::fs::write(dir.join("models/c.sql"), "select 2 as id").unwrap();
+        }
+
+        fn names(names: &[&str]) -> BTreeSet<String> {
+            names.iter().map(|name| name.to_string()).collect()
+        }
+
+        #[tokio::test]
+        async fn test_editing_model_rerenders_only_it_and_its_children() {
+            let temp_dir = tempfile::TempDir::new().unwrap();
+            let project_dir = temp_dir.path().to_path_buf();
+            write_project(&project_dir);
+
+            let io = IoArgs {
+                in_dir: project_dir.clone(),
+                out_dir: project_dir.join("target"),
+                ..Default::default()
+            };
+            let load_args = LoadArgs {
+                command: "parse".to_string(),
+                io: io.clone(),
+                profiles_dir: Some(project_dir.clone()),
+                ..Default::default()
+            };
+            let invocation_args = InvocationArgs::default();
+            let token = never_cancels();
+            let (dbt_state, _, _) = dbt_loader::load(&load_args, &invocation_args, &token)
+                .await
+                .unwrap();
+            let dbt_state = Arc::new(dbt_state);
+            let resolve_args = ResolveArgs {
+                command: "parse".to_string(),
+                io,
+                ..Default::default()
+            };
+
+            let factory = Arc::new(RecordingListenerFactory::default());
+            let (prev, _) = resolve(
+                &resolve_args,
+                &invocation_args,
+                dbt_state.clone(),
+                Macros::default(),
+                Nodes::default(),
+                Some(factory.clone()),
+                &token,
+            )
+            .await
+            .unwrap();
+            assert_eq!(factory.rendered(), names(&["a", "b", "c"]));
+
+            std::fs::write(project_dir.join("models/a.sql"), "select 3 as id").unwrap();
+            let factory = Arc::new(RecordingListenerFactory::default());
+            let (state, _) = resolve_incremental(
+                &prev,
+                &[PathBuf::from("models/a.sql")],
+                &resolve_args,
+                &invocation_args,
+                dbt_state,
+                Some(factory.clone()),
+                &token,
+            )
+            .await
+            .unwrap();
+            assert_eq!(factory.rendered(), names(&["a", "b"]));
+
+            let models = state.nodes.models.keys().cloned().collect::<BTreeSet<_>>();
+            assert_eq!(
+                models,
+                names(&["model.test.a", "model.test.b", "model.test.c"])
+            );
+        }
+    }
+}
@@ -0,0 +1,52 @@
+//! Tests for retaining the raw and rendered sql of models with `keep_rendered`
+
+#[cfg(test)]
+mod tests {
+    use crate::resolve::resolve_models::keep_rendered_code;
+    use dbt_schemas::schemas::DbtModel;
+
+    const RAW_SQL: &str = "select * from {{ ref('customers') }}";
+    const RENDERED_SQL: &str = "select * from \"db\".\"schema\".\"customers\"";
+
+    fn placeholder_model() -> DbtModel {
+        let mut model = DbtModel::default();
+        model.__common_attr__.raw_code = Some("--placeholder--".to_string());
+        model
+    }
+
+    #[test]
+    fn test_keep_rendered_enabled() {
+        let mut model = placeholder_model();
+
+        keep_rendered_code(&mut model, Some(RAW_SQL.to_string()), RENDERED_SQL);
+
+        assert_eq!(model.__common_attr__.raw_code.as_deref(), Some(RAW_SQL));
+        assert_eq!(
+            model.__model_attr__.rendered_code.as_deref(),
+            Some(RENDERED_SQL)
+        );
+    }
+
+    #[test]
+    fn test_keep_rendered_disabled() {
+        let mut model = placeholder_model();
+
+        keep_rendered_code(&mut model, None, RENDERED_SQL);
+
+        assert_eq!(
+            model.__common_attr__.raw_code.as_deref(),
+            Some("--placeholder--")
+        );
+        assert_eq!(model.__model_attr__.rendered_code, None);
+    }
+
+    #[test]
+    fn test_keep_rendered_unrendered_model() {
+        let mut model = placeholder_model();
+
+        keep_rendered_code(&mut model, Some(RAW_SQL.to_string()), "");
+
+        assert_eq!(model.__common_attr__.raw_code.as_deref(), Some(RAW_SQL));
+        assert_eq!(model.__model_attr__.rendered_code, None);
+    }
+}
@@ -0,0 +1,125 @@
+//! Tests for the per-package resource name uniqueness check
+
+#[cfg(test)]
+mod tests {
+    use crate::resolve::resolve_models::duplicate_model_error;
+    use crate::resolver::check_node_name_uniqueness;
+    use dbt_schemas::schemas::Nodes;
+    use dbt_schemas::schemas::nodes::{DbtModel, DbtSeed, DbtSnapshot};
+    use std::path::{Path, PathBuf};
+    use std::sync::Arc;
+
+    fn model(package_name: &str, name: &str, path: &str) -> (String, Arc<DbtModel>) {
+        let unique_id = format!("model.{package_name}.{name}");
+        let mut model = DbtModel::default();
+        model.__common_attr__.unique_id = unique_id.clone();
+        model.__common_attr__.name = name.to_string();
+        model.__common_attr__.package_name = package_name.to_string();
+        model.__common_attr__.original_file_path = PathBuf::from(path);
+        (unique_id, Arc::new(model))
+    }
+
+    fn seed(package_name: &str, name: &str, path: &str) -> (String, Arc<DbtSeed>) {
+        let unique_id = format!("seed.{package_name}.{name}");
+        let mut seed = DbtSeed::default();
+        seed.__common_attr__.unique_id = unique_id.clone();
+        seed.__common_attr__.name = name.to_string();
+        seed.__common_attr__.package_name = package_name.to_string();
+        seed.__common_attr__.original_file_path = PathBuf::from(path);
+        (unique_id, Arc::new(seed))
+    }
+
+    fn snapshot(package_name: &str, name: &str, path: &str) -> (String, Arc<DbtSnapshot>) {
+        let unique_id = format!("snapshot.{package_name}.{name}");
+        let mut snapshot = DbtSnapshot::default();
+        snapshot.__common_attr__.unique_id = unique_id.clone();
+        snapshot.__common_attr__.name = name.to_string();
+        snapshot.__common_attr__.package_name = package_name.to_string();
+        snapshot.__common_attr__.original_file_path = PathBuf::from(path);
+        (unique_id, Arc::new(snapshot))
+    }
+
+    #[test]
+    fn test_seed_and_model_with_same_name_conflict() {
+        let mut nodes = Nodes::default();
+        nodes
+            .models
+            .extend([model("my_project", "orders", "models/orders.sql")]);
+        nodes
+            .seeds
+            .extend([seed("my_project", "orders", "seeds/orders.csv")]);
+
+        let err = check_node_name_uniqueness(&nodes).unwrap_err();
+        let message = err.to_string();
+        assert!(message.contains("'orders'"), "{message}");
+        assert!(message.contains("'my_project'"), "{message}");
+        assert!(message.contains("models/orders.sql"), "{message}");
+        assert!(message.contains("seeds/orders.csv"), "{message}");
+    }
+
+    #[test]
+    fn test_same_name_in_different_packages_is_allowed() {
+        let mut nodes = Nodes::default();
+        nodes.models.extend([
+            model("my_project", "orders", "models/orders.sql"),
+            model("other_package", "orders", "models/orders.sql"),
+        ]);
+        nodes
+            .seeds
+            .extend([seed("third_package", "orders", "seeds/orders.csv")]);
+
+        assert!(check_node_name_uniqueness(&nodes).is_ok());
+    }
+
+    #[test]
+    fn test_snapshot_and_model_with_same_name_conflict() {
+        let mut nodes = Nodes::default();
+        nodes
+            .models
+            .extend([model("my_project", "customers", "models/customers.sql")]);
+        nodes.snapshots.extend([snapshot(
+            "my_project",
+            "customers",
+            "snapshots/customers.sql",
+        )]);
+
+        let err = check_node_name_uniqueness(&nodes).unwrap_err();
+        let message = err.to_string();
+        assert!(message.contains("'customers'"), "{message}");
+        assert!(message.contains("model.my_project.customers"), "{message}");
+        assert!(
+            message.contains("snapshot.my_project.customers"),
+            "{message}"
+        );
+    }
+
+    #[test]
+    fn test_duplicate_models_in_one_package_name_both_files() {
+        let err = duplicate_model_error(
+            "orders",
+            None,
+            Path::new("models/orders.sql"),
+            Path::new("models/staging/orders.sql"),
+            Path::new("models/staging/orders.sql"),
+        );
+        let message = err.to_string();
+        assert!(message.contains("duplicate model 'orders'"), "{message}");
+        assert!(message.contains("'models/orders.sql'"), "{message}");
+        assert!(message.contains("'models/staging/orders.sql'"), "{message}");
+
+        let err = duplicate_model_error(
+            "orders",
+            Some("2"),
+            Path::new("models/orders_v2.sql"),
+            Path::new("models/legacy/orders_v2.sql"),
+            Path::new("models/legacy/orders_v2.sql"),
+        );
+        let message = err.to_string();
+        assert!(message.contains("with version '2'"), "{message}");
+        assert!(message.contains("'models/orders_v2.sql'"), "{message}");
+        assert!(
+            message.contains("'models/legacy/orders_v2.sql'"),
+            "{message}"
+        );
+    }
+}
@@ -0,0 +1,147 @@
+//! Tests for the unused macro diagnostics pass
+
+#[cfg(test)]
+mod tests {
+    use crate::resolver::unused_macros;
+    use dbt_common::adapter::AdapterType;
+    use dbt_schemas::schemas::Nodes;
+    use dbt_schemas::schemas::macros::DbtMacro;
+    use dbt_schemas::schemas::manifest::DbtOperation;
+    use dbt_schemas::schemas::nodes::DbtModel;
+    use dbt_schemas::state::{Macros, Operations};
+    use dbt_serde_yaml::Spanned;
+    use std::sync::Arc;
+
+    fn add_macro(macros: &mut Macros, package_name: &str, name: &str) {
+        add_macro_sql(
+            macros,
+            package_name,
+            name,
+            &format!("{{% macro {name}() %}}{{% endmacro %}}"),
+        );
+    }
+
+    fn add_macro_sql(macros: &mut Macros, package_name: &str, name: &str, macro_sql: &str) {
+        let unique_id = format!("macro.{package_name}.{name}");
+        macros.macros.insert(
+            unique_id.clone(),
+            DbtMacro {
+                name: name.to_string(),
+                package_name: package_name.to_string(),
+                unique_id,
+                macro_sql: macro_sql.to_string(),
+                ..Default::default()
+            },
+        );
+    }
+
+    fn hook(raw_code: &str) -> Spanned<DbtOperation> {
+        let mut operation = DbtOperation::default();
+        operation.__common_attr__.raw_code = Some(raw_code.to_string());
+        Spanned::new(operation)
+    }
+
+    fn find(macros: &Macros, nodes: &Nodes) -> Vec<String> {
+        unused_macros(
+            "my_project",
+            AdapterType::Postgres,
+            macros,
+            nodes,
+            &Operations::default(),
+        )
+    }
+
+    #[test]
+    fn test_reports_only_unreferenced_macros() {
+        let mut macros = Macros::default();
+        add_macro(&mut macros, "my_project", "cents_to_dollars");
+        add_macro(&mut macros, "my_project", "dead_code");
+
+        let mut model = DbtModel::default();
+        model.__base_attr__.depends_on.macros =
+            vec!["macro.my_project.cents_to_dollars".to_string()];
+        let mut nodes = Nodes::default();
+        nodes
+            .models
+            .insert("model.my_project.orders".to_string(), Arc::new(model));
+
+        assert_eq!(
+            find(&macros, &nodes),
+            vec!["macro.my_project.dead_code".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_ignores_dispatch_overrides_of_builtins() {
+        let mut macros = Macros::default();
+        add_macro(&mut macros, "dbt", "default__create_table_as");
+        add_macro(&mut macros, "dbt", "generate_schema_name");
+        add_macro(&mut macros, "my_project", "postgres__create_table_as");
+        add_macro(&mut macros, "my_project", "generate_schema_name");
+
+        assert!(find(&macros, &Nodes::default()).is_empty());
+    }
+
+    #[test]
+    fn test_follows_macro_dependencies() {
+        let mut macros = Macros::default();
+        add_macro(&mut macros, "my_project", "outer");
+        add_macro(&mut macros, "my_project", "inner");
+        macros
+            .macros
+            .get_mut("macro.my_project.outer")
+            .unwrap()
+            .depends_on
+            .macros = vec!["macro.my_project.inner".to_string()];
+
+        let mut model = DbtModel::default();
+        model.__base_attr__.depends_on.macros = vec!["macro.my_project.outer".to_string()];
+        let mut nodes = Nodes::default();
+        nodes
+            .models
+            .insert("model.my_project.orders".to_string(), Arc::new(model));
+
+        assert!(find(&macros, &nodes).is_empty());
+    }
+
+    #[test]
+    fn test_ignores_only_generic_test_blocks() {
+        let mut macros = Macros::default();
+        add_macro_sql(
+            &mut macros,
+            "my_project",
+            "test_positive_value",
+            "{% test positive_value(model, column_name) %}select 1{% endtest %}",
+        );
+        add_macro(&mut macros, "my_project", "test_helper");
+
+        assert_eq!(
+            find(&macros, &Nodes::default()),
+            vec!["macro.my_project.test_helper".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_hooks_reference_whole_macro_names() {
+        let mut macros = Macros::default();
+        add_macro(&mut macros, "my_project", "grant_select");
+        add_macro(&mut macros, "my_project", "grant");
+        add_macro(&mut macros, "my_project", "vacuum");
+        let operations = Operations {
+            on_run_start: vec![hook("{{ grant_select('reporter') }}")],
+            on_run_end: vec![hook("{{ my_project.vacuum ('orders') }}")],
+        };
+
+        // `grant` only appears as a prefix of `grant_select`
+        assert_eq!(
+            unused_macros(
+                "my_project",
+                AdapterType::Postgres,
+                &macros,
+                &Nodes::default(),
+                &operations,
+            ),
+            vec!["macro.my_project.grant".to_string()]
+        );
+    }
+}
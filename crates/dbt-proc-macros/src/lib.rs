@@ -2,6 +2,8 @@ use syn::{Ident, Variant};
 
 extern crate proc_macro;
 
+// Keep in sync with the `include_str!` below, which only accepts a literal.
+const FRONTEND_ERROR_CODES_PATH: &str = "dbt-frontend-common/src/error/codes.rs";
 const FRONTEND_ERROR_CODES: &str = include_str!("../../dbt-frontend-common/src/error/codes.rs");
 
 /// This macro is used to include the error codes from the frontend crate into
@@ -11,58 +13,114 @@ pub fn include_frontend_error_codes(
     _args: proc_macro::TokenStream,
     item: proc_macro::TokenStream,
 ) -> proc_macro::TokenStream {
-    let ast = syn::parse_file(FRONTEND_ERROR_CODES).expect("Could not parse error codes file");
-    let frontend_err_code_def = ast
-        .items
-        .into_iter()
-        .find(|item| {
-            if let syn::Item::Enum(err_def) = item {
-                err_def.ident == "ErrorCode"
-            } else {
-                false
+    let frontend_variants = match frontend_error_code_variants(FRONTEND_ERROR_CODES) {
+        Ok(variants) => variants,
+        Err(message) => {
+            return quote::quote! {
+                compile_error!(#message);
             }
-        })
-        .map(|item| {
-            if let syn::Item::Enum(err_def) = item {
-                err_def
-            } else {
-                unreachable!()
-            }
-        })
-        .expect("Could not find ErrorCode enum definition");
+            .into();
+        }
+    };
     let mut err_code_def = syn::parse_macro_input!(item as syn::ItemEnum);
-    err_code_def
-        .variants
-        .extend(
-            frontend_err_code_def
-                .variants
-                .into_iter()
-                .filter_map(|variant| {
-                    if let Some((eq, syn::Expr::Lit(lit))) = &variant.discriminant {
-                        if let syn::Lit::Int(int) = &lit.lit {
-                            let code = int.base10_parse::<u16>().expect("Invalid error code");
-                            if code < 900 {
-                                // Regular errors just map to the same code
-                                return Some(variant);
-                            } else {
-                                // Internal errors map to the 9k range
-                                return Some(Variant {
-                                    ident: Ident::new(
-                                        &format!("Frontend{}", variant.ident),
-                                        variant.ident.span(),
-                                    ),
-                                    discriminant: Some((*eq, syn::parse_quote!(#code + 9000))),
-                                    ..variant
-                                });
-                            }
-                        }
-                    };
-                    None
-                }),
-        );
+    err_code_def.variants.extend(frontend_variants);
 
     let output = quote::quote! {
         #err_code_def
     };
     output.into()
 }
+
+/// Extracts the `ErrorCode` variants from the frontend error codes source,
+/// remapping internal errors (>= 900) to the 9k range.
+fn frontend_error_code_variants(source: &str) -> Result<Vec<Variant>, String> {
+    let ast = syn::parse_file(source).map_err(|err| {
+        format!("Could not parse frontend error codes file `{FRONTEND_ERROR_CODES_PATH}`: {err}")
+    })?;
+    let frontend_err_code_def = ast
+        .items
+        .into_iter()
+        .find_map(|item| match item {
+            syn::Item::Enum(err_def) if err_def.ident == "ErrorCode" => Some(err_def),
+            _ => None,
+        })
+        .ok_or_else(|| {
+            format!(
+                "Could not find `ErrorCode` enum definition in `{FRONTEND_ERROR_CODES_PATH}`; \
+                 update `include_frontend_error_codes` if the frontend error codes moved"
+            )
+        })?;
+
+    let mut variants = vec![];
+    for variant in frontend_err_code_def.variants {
+        let Some((eq, syn::Expr::Lit(lit))) = &variant.discriminant else {
+            continue;
+        };
+        let syn::Lit::Int(int) = &lit.lit else {
+            continue;
+        };
+        let code = int.base10_parse::<u16>().map_err(|err| {
+            format!(
+                "Invalid error code for `{}` in `{FRONTEND_ERROR_CODES_PATH}`: {err}",
+                variant.ident
+            )
+        })?;
+        if code < 900 {
+            // Regular errors just map to the same code
+            variants.push(variant);
+        } else {
+            // Internal errors map to the 9k range
+            let eq = *eq;
+            variants.push(Variant {
+                ident: Ident::new(&format!("Frontend{}", variant.ident), variant.ident.span()),
+                discriminant: Some((eq, syn::parse_quote!(#code + 9000))),
+                ..variant
+            });
+        }
+    }
+    Ok(variants)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_frontend_error_codes_are_found() {
+        let variants = frontend_error_code_variants(FRONTEND_ERROR_CODES).unwrap();
+        assert!(!variants.is_empty());
+    }
+
+    #[test]
+    fn test_internal_error_codes_are_remapped() {
+        let variants =
+            frontend_error_code_variants("pub enum ErrorCode { Syntax = 101, Internal = 901 }")
+                .unwrap();
+        assert_eq!(variants[0].ident, "Syntax");
+        assert_eq!(variants[1].ident, "FrontendInternal");
+    }
+
+    #[test]
+    fn test_missing_error_code_enum() {
+        let err = frontend_error_code_variants("pub enum OtherCode { A = 1 }").unwrap_err();
+        assert!(
+            err.starts_with(
+                "Could not find `ErrorCode` enum definition in \
+                 `dbt-frontend-common/src/error/codes.rs`"
+            ),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn test_unparseable_error_codes_file() {
+        let err = frontend_error_code_variants("pub enum ErrorCode {").unwrap_err();
+        assert!(
+            err.starts_with(
+                "Could not parse frontend error codes file \
+                 `dbt-frontend-common/src/error/codes.rs`"
+            ),
+            "{err}"
+        );
+    }
+}
@@ -0,0 +1,76 @@
+use dbt_common::cancellation::CancellationTokenSource;
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Exit code of a run interrupted with Ctrl-C (128 + SIGINT)
+pub const EXIT_CODE_INTERRUPTED: u8 = 130;
+
+/// What to do after a Ctrl-C
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CtrlCAction {
+    /// The run was cancelled and in-flight work is winding down
+    Cancel,
+    /// Ctrl-C was pressed again, exit right away
+    ForceExit,
+}
+
+/// Cancels the run on the first Ctrl-C and forces an exit on the second.
+#[derive(Debug, Clone)]
+pub struct CtrlCHandler {
+    cst: CancellationTokenSource,
+    pressed: Arc<AtomicBool>,
+}
+
+impl CtrlCHandler {
+    pub fn new(cst: CancellationTokenSource) -> Self {
+        Self {
+            cst,
+            pressed: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    /// Handle a single Ctrl-C.
+    pub fn handle(&self) -> CtrlCAction {
+        if self.pressed.swap(true, Ordering::SeqCst) {
+            CtrlCAction::ForceExit
+        } else {
+            self.cst.cancel();
+            CtrlCAction::Cancel
+        }
+    }
+
+    /// Listen for Ctrl-C on the given runtime for as long as it runs.
+    pub fn install(self, rt: &tokio::runtime::Runtime) {
+        rt.spawn(async move {
+            while tokio::signal::ctrl_c().await.is_ok() {
+                match self.handle() {
+                    CtrlCAction::Cancel => {
+                        eprintln!("Cancelling... press Ctrl-C again to exit immediately");
+                    }
+                    CtrlCAction::ForceExit => {
+                        std::process::exit(EXIT_CODE_INTERRUPTED as i32);
+                    }
+                }
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_first_ctrl_c_cancels_second_forces_exit() {
+        let cst = CancellationTokenSource::new();
+        let token = cst.token();
+        let handler = CtrlCHandler::new(cst);
+        assert!(!token.is_cancelled());
+
+        assert_eq!(handler.handle(), CtrlCAction::Cancel);
+        assert!(token.is_cancelled());
+
+        assert_eq!(handler.clone().handle(), CtrlCAction::ForceExit);
+    }
+}
@@ -245,6 +245,14 @@ pub struct CommonArgs {
     #[arg(global = true,long,action = ArgAction::SetTrue,  default_value_t=false, value_parser = BoolishValueParser::new(),hide = true)]
     pub no_write_json: bool,
 
+    /// Write a JSON summary of the resolved nodes and parse timing to this path
+    #[arg(global = true, long)]
+    pub summary_json: Option<PathBuf>,
+
+    /// Fail if the resolved nodes' dependencies contain a cycle, reporting the cycle path
+    #[arg(global = true, long, action = ArgAction::SetTrue, env = "DBT_CHECK_CYCLES", value_parser = BoolishValueParser::new())]
+    pub check_cycles: bool,
+
     /// Set 'log-path' for the current run, overriding 'DBT_LOG_PATH'.
     #[arg(global = true, long, env = "DBT_LOG_PATH")]
     pub log_path: Option<PathBuf>,
@@ -254,6 +262,16 @@ pub struct CommonArgs {
     #[arg(global = true, long, env = "DBT_OTM_FILE_NAME", hide = true)]
     pub otm_file_name: Option<String>,
 
+    /// Continue the OTEL telemetry in a new numbered file once the current
+    /// one would exceed this many bytes.
+    #[arg(global = true, long, env = "DBT_OTM_FILE_MAX_BYTES", hide = true)]
+    pub otm_file_max_bytes: Option<u64>,
+
+    /// Keep only this share (0.0 to 1.0) of the spans below warning level in
+    /// the OTEL telemetry files. Spans that fail are always kept.
+    #[arg(global = true, long, env = "DBT_TRACE_SAMPLE_RATIO", hide = true)]
+    pub trace_sample_ratio: Option<f64>,
+
     /// Set logging format; use --log-format-file to override.
     #[arg(global = true, long, env = "DBT_LOG_FORMAT", default_value_t = LogFormat::Default,)]
     pub log_format: LogFormat,
@@ -293,6 +311,8 @@ pub struct CommonArgs {
 // Arg processing
 impl Cli {
     pub fn to_eval_args(&self, system_arg: SystemArgs) -> FsResult<EvalArgs> {
+        self.common_args().check_log_options()?;
+
         // Determine the input and output directories based on the command.
         // Some commands operate without project context, while others must be run in a project directory.
         // コマンドに基づいて入力ディレクトリと出力ディレクトリを決定します。
@@ -370,6 +390,14 @@ impl Cli {
         self.common_args().target_path
     }
 
+    pub fn summary_json(&self) -> Option<PathBuf> {
+        self.common_args().summary_json
+    }
+
+    pub fn check_cycles(&self) -> bool {
+        self.common_args().check_cycles
+    }
+
     pub fn get_command_str(&self) -> &str {
         // generate the command string
         match &self.command {
@@ -460,7 +488,9 @@ impl InitArgs {
                 log_level_file: self.common_args.log_level_file,
                 log_path: self.common_args.log_path.clone(),
                 otm_file_name: self.common_args.otm_file_name.clone(),
+                otm_file_max_bytes: self.common_args.otm_file_max_bytes,
                 otm_parquet_file_name: None,
+                trace_sample_ratio: self.common_args.trace_sample_ratio,
                 export_to_otlp: false,
                 show_all_deprecations: self.common_args.show_all_deprecations,
                 show_timings: arg.from_main,
@@ -545,7 +575,9 @@ impl CommonArgs {
                 log_level_file: self.log_level_file,
                 log_path: self.log_path.clone(),
                 otm_file_name: self.otm_file_name.clone(),
+                otm_file_max_bytes: self.otm_file_max_bytes,
                 otm_parquet_file_name: None,
+                trace_sample_ratio: self.trace_sample_ratio,
                 export_to_otlp: false,
                 show_all_deprecations: arg.io.show_all_deprecations,
                 show_timings: arg.from_main,
@@ -602,6 +634,29 @@ impl CommonArgs {
         }
     }
 
+    /// Rejects logging options that contradict each other instead of silently
+    /// letting one of them win.
+    pub fn check_log_options(&self) -> FsResult<()> {
+        if self.quiet && self.debug {
+            return Err(fs_err!(
+                ErrorCode::InvalidArgument,
+                "--quiet suppresses all non-error output and cannot be combined with \
+                 --debug. Remove one of the two flags."
+            ));
+        }
+        if self.quiet
+            && let Some(level @ (LevelFilter::Debug | LevelFilter::Trace)) = self.log_level
+        {
+            let level = level.as_str().to_lowercase();
+            return Err(fs_err!(
+                ErrorCode::InvalidArgument,
+                "--quiet suppresses all non-error output and cannot be combined with \
+                 --log-level {level}. Remove --quiet to see {level} logs, or lower the log level."
+            ));
+        }
+        Ok(())
+    }
+
     pub fn get_send_anonymous_usage_stats(&self) -> bool {
         if self.no_send_anonymous_usage_stats {
             false
@@ -638,7 +693,9 @@ pub fn from_main(cli: &Cli) -> SystemArgs {
             },
             log_path: cli.common_args().log_path,
             otm_file_name: cli.common_args().otm_file_name,
+            otm_file_max_bytes: cli.common_args().otm_file_max_bytes,
             otm_parquet_file_name: None,
+            trace_sample_ratio: cli.common_args().trace_sample_ratio,
             export_to_otlp: false,
             show_all_deprecations: cli.common_args().show_all_deprecations,
             show_timings: true, // always true for main
@@ -672,7 +729,9 @@ pub fn from_lib(cli: &Cli) -> SystemArgs {
             log_level_file: cli.common_args().log_level_file,
             log_path: cli.common_args().log_path,
             otm_file_name: cli.common_args().otm_file_name,
+            otm_file_max_bytes: cli.common_args().otm_file_max_bytes,
             otm_parquet_file_name: None,
+            trace_sample_ratio: cli.common_args().trace_sample_ratio,
             export_to_otlp: false,
             show_all_deprecations: cli.common_args().show_all_deprecations,
             show_timings: false, // always false for lib
@@ -685,3 +744,73 @@ pub fn from_lib(cli: &Cli) -> SystemArgs {
         num_threads: cli.common_args().threads,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn eval_args(flags: &[&str]) -> FsResult<EvalArgs> {
+        let dir = tempfile::tempdir().unwrap();
+        let project_dir = dir.path().to_str().unwrap();
+        let cli = Cli::try_parse_from(
+            ["dbt-sa-cli", "parse", "--project-dir", project_dir]
+                .iter()
+                .chain(flags),
+        )
+        .unwrap();
+        cli.to_eval_args(from_lib(&cli))
+    }
+
+    #[test]
+    fn test_quiet_conflicts_with_verbose_log_level() {
+        let err = eval_args(&["--quiet", "--log-level", "debug"]).unwrap_err();
+        assert_eq!(err.code, ErrorCode::InvalidArgument);
+        assert!(err.to_string().contains("--log-level debug"));
+
+        assert!(eval_args(&["-q", "--log-level", "trace"]).is_err());
+    }
+
+    #[test]
+    fn test_quiet_conflicts_with_debug() {
+        let err = eval_args(&["--quiet", "--debug"]).unwrap_err();
+        assert_eq!(err.code, ErrorCode::InvalidArgument);
+        assert!(err.to_string().contains("--debug"));
+
+        assert!(eval_args(&["-q", "-d", "--log-level", "error"]).is_err());
+    }
+
+    #[test]
+    fn test_quiet_or_log_level_alone_is_accepted() {
+        assert!(eval_args(&["--quiet"]).unwrap().quiet);
+        assert_eq!(
+            eval_args(&["--log-level", "debug"]).unwrap().log_level,
+            Some(LevelFilter::Debug)
+        );
+        // a non-verbose level does not contradict --quiet
+        assert!(eval_args(&["--quiet", "--log-level", "error"]).is_ok());
+    }
+
+    #[test]
+    fn test_trace_sample_ratio_reaches_io_args() {
+        assert_eq!(eval_args(&[]).unwrap().io.trace_sample_ratio, None);
+        assert_eq!(
+            eval_args(&["--trace-sample-ratio", "0.25"])
+                .unwrap()
+                .io
+                .trace_sample_ratio,
+            Some(0.25)
+        );
+    }
+
+    #[test]
+    fn test_otm_file_max_bytes_reaches_io_args() {
+        assert_eq!(eval_args(&[]).unwrap().io.otm_file_max_bytes, None);
+        assert_eq!(
+            eval_args(&["--otm-file-max-bytes", "1048576"])
+                .unwrap()
+                .io
+                .otm_file_max_bytes,
+            Some(1048576)
+        );
+    }
+}
@@ -1,4 +1,5 @@
 use crate::dbt_sa_clap::{Cli, Commands};
+use crate::summary::ParseSummary;
 use dbt_common::cancellation::CancellationToken;
 use dbt_common::create_root_info_span;
 use dbt_common::tracing::create_invocation_attributes;
@@ -23,7 +24,7 @@ use dbt_schemas::state::Macros;
 #[allow(unused_imports)]
 use git_version::git_version;
 
-use dbt_schemas::schemas::manifest::build_manifest;
+use dbt_schemas::schemas::manifest::{build_manifest, write_manifest};
 use tracing::Instrument;
 
 use std::{sync::Arc, time::SystemTime};
@@ -193,11 +194,7 @@ async fn execute_setup_and_all_phases(
 }
 
 #[allow(clippy::cognitive_complexity)]
-async fn execute_all_phases(
-    arg: &EvalArgs,
-    _cli: &Cli,
-    token: &CancellationToken,
-) -> FsResult<i32> {
+async fn execute_all_phases(arg: &EvalArgs, cli: &Cli, token: &CancellationToken) -> FsResult<i32> {
     let start = SystemTime::now();
 
     // Loads all .yml files + collects all included files
@@ -236,10 +233,31 @@ async fn execute_all_phases(
 
     let dbt_manifest = build_manifest(&arg.io.invocation_id.to_string(), &resolved_state);
 
+    if cli.check_cycles()
+        && let Err(cycle) = resolved_state.nodes.detect_cycles()
+    {
+        return Err(fs_err!(
+            ErrorCode::CyclicDependency,
+            "Found a cycle: {} -> {}",
+            cycle.join(" -> "),
+            cycle[0]
+        ));
+    }
+
     if arg.write_json {
         let dbt_manifest_path = arg.io.out_dir.join(DBT_MANIFEST_JSON);
         stdfs::create_dir_all(dbt_manifest_path.parent().unwrap())?;
-        stdfs::write(dbt_manifest_path, serde_json::to_string(&dbt_manifest)?)?;
+        write_manifest(&dbt_manifest, &dbt_manifest_path)?;
+    }
+
+    if let Some(summary_path) = cli.summary_json() {
+        let summary_path = if summary_path.is_relative() {
+            arg.io.in_dir.join(summary_path)
+        } else {
+            summary_path
+        };
+        let elapsed = start.elapsed().unwrap_or_default();
+        ParseSummary::new(&resolved_state.nodes, elapsed).write(&summary_path)?;
     }
 
     show_result_with_default_title!(
@@ -250,3 +268,97 @@ async fn execute_all_phases(
 
     show_progress_exit!(&arg, start)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dbt_sa_clap::from_lib;
+    use crate::summary::PARSE_SUMMARY_VERSION;
+    use clap::Parser;
+    use dbt_common::cancellation::never_cancels;
+    use std::path::Path;
+
+    const SCHEMA_YML: &str = r#"
+version: 2
+sources:
+  - name: raw
+    tables:
+      - name: payments
+models:
+  - name: orders
+    columns:
+      - name: id
+        data_tests:
+          - not_null
+          - unique
+"#;
+
+    /// Two models, one seed, one source and two tests
+    fn write_project(dir: &Path) {
+        std::fs::create_dir_all(dir.join("models")).unwrap();
+        std::fs::create_dir_all(dir.join("seeds")).unwrap();
+        std::fs::write(
+            dir.join("dbt_project.yml"),
+            "name: test\nversion: '1.0'\nprofile: hello_world\n",
+        )
+        .unwrap();
+        std::fs::copy(
+            Path::new(env!("CARGO_MANIFEST_DIR"))
+                .join("../dbt-test-utils/tests/data/hello/profiles.yml"),
+            dir.join("profiles.yml"),
+        )
+        .unwrap();
+        std::fs::write(dir.join("models/schema.yml"), SCHEMA_YML).unwrap();
+        std::fs::write(
+            dir.join("models/stg_payments.sql"),
+            "select * from {{ source('raw', 'payments') }}",
+        )
+        .unwrap();
+        std::fs::write(
+            dir.join("models/orders.sql"),
+            "select 1 as id from {{ ref('stg_payments') }}",
+        )
+        .unwrap();
+        std::fs::write(dir.join("seeds/raw_orders.csv"), "id,amount\n1,10\n").unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_summary_json_counts_match_fixture_project() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let project_dir = temp_dir.path();
+        write_project(project_dir);
+
+        let project_dir = project_dir.to_str().unwrap();
+        let cli = Cli::try_parse_from([
+            "dbt-sa-cli",
+            "parse",
+            "--project-dir",
+            project_dir,
+            "--profiles-dir",
+            project_dir,
+            "--summary-json",
+            "target/summary.json",
+        ])
+        .unwrap();
+        let arg = cli.to_eval_args(from_lib(&cli)).unwrap();
+
+        execute_all_phases(&arg, &cli, &never_cancels())
+            .await
+            .unwrap();
+
+        let summary: ParseSummary = serde_json::from_str(
+            &std::fs::read_to_string(temp_dir.path().join("target/summary.json")).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(summary.version, PARSE_SUMMARY_VERSION);
+        assert_eq!(
+            (
+                summary.models,
+                summary.seeds,
+                summary.tests,
+                summary.sources
+            ),
+            (2, 1, 2, 1)
+        );
+    }
+}
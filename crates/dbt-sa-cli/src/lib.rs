@@ -1,3 +1,6 @@
 // Re-export the main library functionality
+pub mod ctrl_c;
 pub mod dbt_sa_clap;
 pub mod dbt_sa_lib;
+pub mod runtime;
+pub mod summary;
@@ -4,29 +4,16 @@ use clap::error::ErrorKind;
 use dbt_common::cancellation::CancellationTokenSource;
 use dbt_common::tracing::{FsTraceConfig, init_tracing};
 use dbt_common::{constants::PANIC, pretty_string::GREEN, pretty_string::RED};
+use dbt_sa_lib::ctrl_c::{CtrlCHandler, EXIT_CODE_INTERRUPTED};
 use dbt_sa_lib::dbt_sa_clap::Cli;
 use dbt_sa_lib::dbt_sa_clap::from_main;
 use dbt_sa_lib::dbt_sa_lib::execute_fs;
+use dbt_sa_lib::runtime::build_runtime;
 use std::io::{self, Write};
 use std::process::ExitCode;
 
-const FS_DEFAULT_STACK_SIZE: usize = 8 * 1024 * 1024;
-
-/// Maximum number of threads used for running blocking operations (based on the tokio runtime
-/// default).
-///
-/// These threads are used mostly for blocking I/O operations, so they don't really
-/// consume CPU resources. That's why we can afford and should have a lot of them.
-/// 
-/// ブロッキング操作の実行に使用されるスレッドの最大数 (tokio ランタイムのデフォルトに基づく)。
-/// 
-/// これらのスレッドは主にI/O操作のブロッキングに使用されるため、CPUリソースをあまり消費しません。
-/// だからこそ、これらのスレッドを大量に用意しておくべきなのです。
-const FS_DEFAULT_MAX_BLOCKING_THREADS: usize = 512;
-
 fn main() -> ExitCode {
     let cst = CancellationTokenSource::new();
-    // TODO(felipecrv): cancel the token (through the cst) on Ctrl-C
     let token = cst.token();
 
     let cli = match Cli::try_parse() {
@@ -78,42 +65,11 @@ fn main() -> ExitCode {
     // DO NOT USE Rayon, it is not compatible with Tokio
     // tokioランタイムをセットアップし、スタックサイズを8MBに設定します。
     // RayonはTokioと互換性がないため使用しないでください。
+    let tokio_rt = build_runtime(arg.num_threads);
 
-    let tokio_rt = match arg.num_threads {
-        Some(1) => {
-            // Simiulate single-threaded runtime
-            // シングルスレッドランタイムをシミュレートする
-            tokio::runtime::Builder::new_multi_thread()
-                .enable_all()
-                .thread_stack_size(FS_DEFAULT_STACK_SIZE)
-                .worker_threads(1)
-                .max_blocking_threads(1)
-                .build()
-                .expect("failed to initialize 'single-threaded' tokio runtime")
-        }
-        // Uncomment this if you want to limit the number of threads in multi-threaded runtime
-        // マルチスレッドランタイムでスレッド数を制限したい場合は、これをコメント解除してください。
-        // Some(num_threads) if num_threads > 1 => {
-        //     // Multi-threaded runtime: limit to num_threads
-        //     tokio::runtime::Builder::new_multi_thread()
-        //         .enable_all()
-        //         .worker_threads(num_threads)
-        //         .max_blocking_threads(FS_DEFAULT_MAX_BLOCKING_THREADS)
-        //         .thread_stack_size(FS_DEFAULT_STACK_SIZE)
-        //         .build()
-        //         .expect("failed to initialize multi-threaded tokio runtime")
-        // }
-        _ => {
-            // Multi-threaded runtime: use default (max parallelism)
-            // マルチスレッドランタイム: デフォルト (最大並列処理) を使用
-            tokio::runtime::Builder::new_multi_thread()
-                .enable_all()
-                .max_blocking_threads(FS_DEFAULT_MAX_BLOCKING_THREADS)
-                .thread_stack_size(FS_DEFAULT_STACK_SIZE)
-                .build()
-                .expect("failed to initialize default multi-threaded tokio runtime")
-        }
-    };
+    // The first Ctrl-C cancels the token, the second one exits right away
+    // 最初の Ctrl-C でトークンをキャンセルし、2 回目で即座に終了します
+    CtrlCHandler::new(cst).install(&tokio_rt);
 
     // If execution panics, exit with a status 2 (but not if RUST_BACKTRACE is
     // set to 1, in which case we want to see the backtrace):
@@ -131,7 +87,7 @@ fn main() -> ExitCode {
 
     // Run within the process span
     // プロセス範囲内で実行
-    let future = Box::pin(execute_fs(arg, cli, token));
+    let future = Box::pin(execute_fs(arg, cli, token.clone()));
 
     let result = tokio_rt.block_on(async { tokio_rt.spawn(future).await.unwrap() });
 
@@ -145,6 +101,12 @@ fn main() -> ExitCode {
     // パニックフックを外す
     let _ = std::panic::take_hook();
 
+    // An interrupted run exits with its own status
+    // 中断された実行は独自のステータスで終了します
+    if token.is_cancelled() {
+        return ExitCode::from(EXIT_CODE_INTERRUPTED);
+    }
+
     // Handle regular execution
     match result {
         Ok(code) => {
@@ -0,0 +1,76 @@
+/// Stack size of the tokio worker threads
+pub const FS_DEFAULT_STACK_SIZE: usize = 8 * 1024 * 1024;
+
+/// Maximum number of threads used for running blocking operations (based on the tokio runtime
+/// default).
+///
+/// These threads are used mostly for blocking I/O operations, so they don't really
+/// consume CPU resources. That's why we can afford and should have a lot of them.
+/// 
+/// ブロッキング操作の実行に使用されるスレッドの最大数 (tokio ランタイムのデフォルトに基づく)。
+/// 
+/// これらのスレッドは主にI/O操作のブロッキングに使用されるため、CPUリソースをあまり消費しません。
+/// だからこそ、これらのスレッドを大量に用意しておくべきなのです。
+pub const FS_DEFAULT_MAX_BLOCKING_THREADS: usize = 512;
+
+/// Setup tokio runtime with `num_threads` worker threads (all available
+/// cores when unset) and set stack-size to 8MB
+///
+/// DO NOT USE Rayon, it is not compatible with Tokio
+pub fn build_runtime(num_threads: Option<usize>) -> tokio::runtime::Runtime {
+    match num_threads {
+        Some(1) => {
+            // Simiulate single-threaded runtime
+            // シングルスレッドランタイムをシミュレートする
+            tokio::runtime::Builder::new_multi_thread()
+                .enable_all()
+                .thread_stack_size(FS_DEFAULT_STACK_SIZE)
+                .worker_threads(1)
+                .max_blocking_threads(1)
+                .build()
+                .expect("failed to initialize 'single-threaded' tokio runtime")
+        }
+        Some(num_threads) if num_threads > 1 => {
+            // Multi-threaded runtime: limit to num_threads
+            // マルチスレッドランタイム: num_threads に制限
+            tokio::runtime::Builder::new_multi_thread()
+                .enable_all()
+                .worker_threads(num_threads)
+                .max_blocking_threads(FS_DEFAULT_MAX_BLOCKING_THREADS)
+                .thread_stack_size(FS_DEFAULT_STACK_SIZE)
+                .build()
+                .expect("failed to initialize multi-threaded tokio runtime")
+        }
+        _ => {
+            // Multi-threaded runtime: use default (max parallelism)
+            // マルチスレッドランタイム: デフォルト (最大並列処理) を使用
+            tokio::runtime::Builder::new_multi_thread()
+                .enable_all()
+                .max_blocking_threads(FS_DEFAULT_MAX_BLOCKING_THREADS)
+                .thread_stack_size(FS_DEFAULT_STACK_SIZE)
+                .build()
+                .expect("failed to initialize default multi-threaded tokio runtime")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn num_workers(num_threads: Option<usize>) -> usize {
+        build_runtime(num_threads).metrics().num_workers()
+    }
+
+    #[test]
+    fn test_worker_threads_match_argument() {
+        assert_eq!(num_workers(Some(1)), 1);
+        assert_eq!(num_workers(Some(4)), 4);
+        // Unless overridden through TOKIO_WORKER_THREADS, tokio defaults to
+        // one worker per core
+        if std::env::var_os("TOKIO_WORKER_THREADS").is_none() {
+            let cores = std::thread::available_parallelism().unwrap().get();
+            assert_eq!(num_workers(None), cores);
+        }
+    }
+}
@@ -0,0 +1,94 @@
+use dbt_common::{FsResult, stdfs};
+use dbt_schemas::schemas::Nodes;
+use serde::{Deserialize, Serialize};
+
+use std::path::Path;
+use std::time::Duration;
+
+/// Version of the [ParseSummary] layout; bump it when fields change meaning
+/// or are removed
+pub const PARSE_SUMMARY_VERSION: u32 = 1;
+
+/// Counts of the resolved nodes of a project, written by `--summary-json`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ParseSummary {
+    pub version: u32,
+    pub models: usize,
+    pub seeds: usize,
+    pub tests: usize,
+    pub sources: usize,
+    /// Time spent loading and resolving the project
+    pub elapsed_ms: u64,
+}
+
+impl ParseSummary {
+    pub fn new(nodes: &Nodes, elapsed: Duration) -> Self {
+        Self {
+            version: PARSE_SUMMARY_VERSION,
+            models: nodes.models.len(),
+            seeds: nodes.seeds.len(),
+            tests: nodes.tests.len(),
+            sources: nodes.sources.len(),
+            elapsed_ms: elapsed.as_millis() as u64,
+        }
+    }
+
+    pub fn write(&self, path: &Path) -> FsResult<()> {
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            stdfs::create_dir_all(parent)?;
+        }
+        stdfs::write(path, serde_json::to_string_pretty(self)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dbt_schemas::schemas::{DbtModel, DbtSeed, DbtSource, DbtTest};
+    use std::sync::Arc;
+
+    #[test]
+    fn test_summary_counts_match_nodes() {
+        let mut nodes = Nodes::default();
+        for name in ["stg_orders", "stg_customers", "orders"] {
+            nodes.models.insert(
+                format!("model.jaffle_shop.{name}"),
+                Arc::new(DbtModel::default()),
+            );
+        }
+        nodes.seeds.insert(
+            "seed.jaffle_shop.raw_orders".to_string(),
+            Arc::new(DbtSeed::default()),
+        );
+        for name in ["not_null_orders_id", "unique_orders_id"] {
+            nodes.tests.insert(
+                format!("test.jaffle_shop.{name}"),
+                Arc::new(DbtTest::default()),
+            );
+        }
+        nodes.sources.insert(
+            "source.jaffle_shop.raw.orders".to_string(),
+            Arc::new(DbtSource::default()),
+        );
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("target").join("summary.json");
+        ParseSummary::new(&nodes, Duration::from_millis(42))
+            .write(&path)
+            .unwrap();
+
+        let written: ParseSummary =
+            serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(
+            written,
+            ParseSummary {
+                version: PARSE_SUMMARY_VERSION,
+                models: 3,
+                seeds: 1,
+                tests: 2,
+                sources: 1,
+                elapsed_ms: 42,
+            }
+        );
+    }
+}
@@ -22,10 +22,12 @@ pub mod schemas {
     pub mod selectors;
     pub mod serde;
     mod sources;
+    #[cfg(any(test, feature = "test-util"))]
+    pub mod test_utils;
     pub use prev_state::{ModificationType, PreviousState};
     pub use run_results::{
-        ContextRunResult, RunResultOutput, RunResultsArgs, RunResultsArtifact, RunResultsMetadata,
-        TimingInfo,
+        ContextRunResult, ResultCounts, RunResultOutput, RunResultsArgs, RunResultsArtifact,
+        RunResultsMetadata, RunStats, TimingInfo,
     };
 
     // Add re-exports from relation_configs
@@ -78,7 +80,7 @@ pub mod schemas {
         pub use group::ManifestGroup;
         pub use manifest::{
             BaseMetadata, DbtManifest, DbtNode, ManifestMetadata, build_manifest,
-            nodes_from_dbt_manifest,
+            nodes_from_dbt_manifest, write_manifest,
         };
         pub use manifest_nodes::{
             ManifestDataTest, ManifestExposure, ManifestMetric, ManifestModel, ManifestSavedQuery,
@@ -92,7 +94,7 @@ pub mod schemas {
         pub use semantic_model::DbtSemanticModel;
         pub use v10::DbtManifestV10;
         pub use v11::DbtManifestV11;
-        pub use v12::DbtManifestV12;
+        pub use v12::{DbtManifestV12, load_manifest_any_version};
     }
     mod dbt_cloud;
     pub use dbt_cloud::{DbtCloudConfig, DbtCloudContext, DbtCloudProject};
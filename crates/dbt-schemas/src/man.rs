@@ -41,11 +41,7 @@ pub async fn execute_man_command(arg: &EvalArgs) -> FsResult<i32> {
                 log::info!("{}", to_string_pretty(&schema)?);
             }
             JsonSchemaTypes::Project => {
-                let settings = SchemaSettings::default();
-                let generator = settings.into_generator();
-                let mut schema = generator.into_root_schema_for::<DbtProject>();
-                deny_additional_properties_in_root(&mut schema);
-                log::info!("{}", to_string_pretty(&schema)?);
+                log::info!("{}", to_string_pretty(&dbt_project_json_schema())?);
             }
             JsonSchemaTypes::Selector => {
                 let settings = SchemaSettings::default();
@@ -73,6 +69,16 @@ pub async fn execute_man_command(arg: &EvalArgs) -> FsResult<i32> {
     Ok(0)
 }
 
+/// JSON Schema of `dbt_project.yml`, derived from [DbtProject]. Unknown keys
+/// are rejected, except within free-form maps such as `meta`.
+pub fn dbt_project_json_schema() -> serde_json::Value {
+    let settings = SchemaSettings::default();
+    let generator = settings.into_generator();
+    let mut schema = generator.into_root_schema_for::<DbtProject>();
+    deny_additional_properties_in_root(&mut schema);
+    serde_json::to_value(schema).expect("a JSON schema always serializes to JSON")
+}
+
 /// Recursively modifies all object schemas in a `RootSchema`
 /// to set "additionalProperties": false, unless the current path includes "meta"
 pub fn deny_additional_properties_in_root(root: &mut RootSchema) {
@@ -156,3 +162,41 @@ fn deny_additional_properties(schema: &mut Schema, path: &mut Vec<String>) {
         _ => {}
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn is_valid_project(project: &serde_json::Value) -> bool {
+        let schema = dbt_project_json_schema();
+        let config = jsonschema_valid::Config::from_schema(&schema, None).unwrap();
+        config.validate(project).is_ok()
+    }
+
+    #[test]
+    fn test_dbt_project_json_schema() {
+        let project = json!({
+            "name": "jaffle_shop",
+            "version": "1.0.0",
+            "profile": "jaffle_shop",
+            "config-version": 2,
+            "model-paths": ["models"],
+            "seed-paths": ["seeds"],
+            "clean-targets": ["target", "dbt_packages"],
+            "require-dbt-version": ">=1.5.0",
+            "models": {
+                "+materialized": "view",
+                "+schema": "analytics",
+            },
+            "seeds": {
+                "+enabled": true,
+            },
+        });
+        assert!(is_valid_project(&project));
+
+        let mut bad_materialization = project.clone();
+        bad_materialization["models"]["+materialized"] = json!(42);
+        assert!(!is_valid_project(&bad_materialization));
+    }
+}
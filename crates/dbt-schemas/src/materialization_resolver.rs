@@ -144,6 +144,9 @@ pub struct MaterializationResolver {
     pub root_project_name: String,
     /// Cache mapping materialization name -> fully qualified macro name
     pub cache: Mutex<HashMap<String, String>>,
+    /// Explicitly registered materializations, keyed by (materialization name, adapter prefix).
+    /// Default registrations use the `default` prefix.
+    pub registered: BTreeMap<(String, String), String>,
 }
 
 impl MaterializationResolver {
@@ -169,9 +172,42 @@ impl MaterializationResolver {
             adapter_type: adapter_type.to_string(),
             root_project_name: root_project_name.to_string(),
             cache: Mutex::new(HashMap::new()),
+            registered: BTreeMap::new(),
         }
     }
 
+    /// Register a materialization macro that resolution falls back to when no
+    /// macro named `materialization_<name>_<adapter>` is found
+    ///
+    /// * `name` - Materialization name (e.g., "my_custom_table")
+    /// * `adapter` - Adapter the macro applies to, or `None` for the default registration
+    /// * `macro_ref` - Fully qualified macro name (e.g., "my_pkg.my_custom_table_macro")
+    ///
+    /// Registrations follow the same adapter specificity as macro lookup: an entry for the
+    /// active adapter beats one for a parent adapter, which beats the default registration.
+    /// Re-registering the same (name, adapter) pair replaces the previous entry.
+    pub fn register_materialization(&mut self, name: &str, adapter: Option<&str>, macro_ref: &str) {
+        let adapter = adapter.unwrap_or("default");
+        self.registered.insert(
+            (name.to_string(), adapter.to_string()),
+            macro_ref.to_string(),
+        );
+        // Previously resolved names may now resolve differently
+        if let Ok(cache) = self.cache.get_mut() {
+            cache.remove(name);
+        }
+    }
+
+    fn find_registered_materialization(&self, materialization_name: &str) -> Option<String> {
+        get_adapter_prefixes(&self.adapter_type)
+            .into_iter()
+            .find_map(|prefix| {
+                self.registered
+                    .get(&(materialization_name.to_string(), prefix))
+                    .cloned()
+            })
+    }
+
     fn classify_macro_locality(&self, package_name: &str) -> MacroLocality {
         let internal_packages = get_internal_packages(&self.adapter_type);
         if internal_packages.contains(&package_name.to_string()) {
@@ -274,6 +310,14 @@ impl MaterializationResolver {
             return Ok(result);
         }
 
+        // Fall back to explicitly registered materializations
+        if let Some(result) = self.find_registered_materialization(materialization_name) {
+            if let Ok(mut cache) = self.cache.lock() {
+                cache.insert(materialization_name.to_string(), result.clone());
+            }
+            return Ok(result);
+        }
+
         Err(fs_err!(
             ErrorCode::Unexpected,
             "Materialization macro not found for materialization: {}, adapter: {}",
@@ -533,4 +577,52 @@ mod tests {
             .expect("should resolve from cache");
         assert_eq!(first, second);
     }
+
+    #[test]
+    fn registered_custom_materialization_resolves() {
+        let root = "root";
+        let adapter = AdapterType::Postgres;
+        let macros = vec![build_macro("materialization_view_default", "dbt")];
+        let mut resolver = resolver_with(macros, adapter, root);
+        assert!(
+            resolver
+                .find_materialization_macro_by_name("my_custom")
+                .is_err()
+        );
+
+        resolver.register_materialization("my_custom", None, "my_pkg.my_custom_materialization");
+        let got = resolver
+            .find_materialization_macro_by_name("my_custom")
+            .expect("should resolve registered materialization");
+        assert_eq!(got, "my_pkg.my_custom_materialization".to_string());
+    }
+
+    #[test]
+    fn registered_adapter_specific_beats_default() {
+        let root = "root";
+        let adapter = AdapterType::Redshift; // prefixes: redshift, postgres, default
+        let mut resolver = resolver_with(vec![], adapter, root);
+        resolver.register_materialization("my_custom", None, "my_pkg.my_custom_default");
+        resolver.register_materialization(
+            "my_custom",
+            Some("postgres"),
+            "my_pkg.my_custom_postgres",
+        );
+        resolver.register_materialization("my_custom", Some("snowflake"), "my_pkg.my_custom_sf");
+        let got = resolver
+            .find_materialization_macro_by_name("my_custom")
+            .expect("should resolve");
+        assert_eq!(got, "my_pkg.my_custom_postgres".to_string());
+
+        // Registering for the exact adapter takes over, even after a cached lookup
+        resolver.register_materialization(
+            "my_custom",
+            Some("redshift"),
+            "my_pkg.my_custom_redshift",
+        );
+        let got = resolver
+            .find_materialization_macro_by_name("my_custom")
+            .expect("should resolve");
+        assert_eq!(got, "my_pkg.my_custom_redshift".to_string());
+    }
 }
@@ -524,4 +524,80 @@ mod tests {
             assert_eq!(expected, actual);
         }
     }
+
+    #[derive(Clone, Debug, Default, BaseColumnObject)]
+    #[base_column(extra_methods(is_geography))]
+    struct GeoColumn {
+        name: String,
+        dtype: String,
+    }
+
+    impl GeoColumn {
+        fn is_geography(&self, _args: &[Value]) -> Result<Value, MinijinjaError> {
+            Ok(Value::from(self.dtype.eq_ignore_ascii_case("geography")))
+        }
+    }
+
+    impl BaseColumn for GeoColumn {
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+
+        fn as_value(&self) -> Value {
+            Value::from_object(self.clone())
+        }
+    }
+
+    impl BaseColumnProperties for GeoColumn {
+        fn name_prop(&self) -> &str {
+            &self.name
+        }
+
+        fn dtype_prop(&self) -> &str {
+            &self.dtype
+        }
+
+        fn char_size_prop(&self) -> Option<u32> {
+            None
+        }
+
+        fn numeric_precision_prop(&self) -> Option<u64> {
+            None
+        }
+
+        fn numeric_scale_prop(&self) -> Option<u64> {
+            None
+        }
+    }
+
+    #[test]
+    fn test_base_column_extra_methods() {
+        use minijinja::value::Object;
+        use std::sync::Arc;
+
+        let env = minijinja::Environment::new();
+        let state = env.empty_state();
+        let column = Arc::new(GeoColumn {
+            name: "location".to_string(),
+            dtype: "GEOGRAPHY".to_string(),
+        });
+
+        let result = column
+            .call_method(&state, "is_geography", &[], &[])
+            .unwrap();
+        assert_eq!(result, Value::from(true));
+
+        // the builtin methods are still dispatched
+        let result = column.call_method(&state, "is_string", &[], &[]).unwrap();
+        assert_eq!(result, Value::from(false));
+
+        let err = column
+            .call_method(&state, "is_raster", &[], &[])
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidOperation);
+        assert!(
+            err.to_string()
+                .contains("Unknown method on BaseColumnObject: 'is_raster'")
+        );
+    }
 }
@@ -67,6 +67,52 @@ pub struct DbtCatalog {
     pub errors: Option<Vec<String>>,
 }
 
+impl DbtCatalog {
+    /// Merges the tables of `other` into this catalog without rebuilding it.
+    ///
+    /// When a table (keyed by unique id) is in both catalogs, the one from the
+    /// catalog with the later `generated_at` wins, with `other` winning ties.
+    /// Stats the winning table lacks are kept from the losing one, so a table
+    /// introspected without stats does not drop the stats known so far. The
+    /// metadata is taken from the newer catalog and errors are concatenated.
+    pub fn merge(&mut self, other: DbtCatalog) {
+        let other_is_newer = other.metadata.generated_at >= self.metadata.generated_at;
+        merge_tables(&mut self.nodes, other.nodes, other_is_newer);
+        merge_tables(&mut self.sources, other.sources, other_is_newer);
+        if let Some(errors) = other.errors {
+            self.errors.get_or_insert_with(Vec::new).extend(errors);
+        }
+        if other_is_newer {
+            self.metadata = other.metadata;
+        }
+    }
+}
+
+fn merge_tables(
+    tables: &mut BTreeMap<String, CatalogTable>,
+    other: BTreeMap<String, CatalogTable>,
+    other_is_newer: bool,
+) {
+    for (id, table) in other {
+        match tables.remove(&id) {
+            Some(existing) => {
+                let (mut newer, older) = if other_is_newer {
+                    (table, existing)
+                } else {
+                    (existing, table)
+                };
+                for (stat_id, stat) in older.stats {
+                    newer.stats.entry(stat_id).or_insert(stat);
+                }
+                tables.insert(id, newer);
+            }
+            None => {
+                tables.insert(id, table);
+            }
+        }
+    }
+}
+
 // TODO: dedupe code below
 pub fn build_catalog(
     invocation_id: &str,
@@ -181,3 +227,127 @@ pub fn build_catalog(
         errors: None, // TODO: look into errors and what this should look like
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn table(name: &str, columns: &[&str], stats: &[(&str, i64)]) -> CatalogTable {
+        CatalogTable {
+            metadata: TableMetadata {
+                materialization_type: "table".to_string(),
+                schema: "analytics".to_string(),
+                name: name.to_string(),
+                ..Default::default()
+            },
+            columns: columns
+                .iter()
+                .enumerate()
+                .map(|(index, column)| {
+                    (
+                        column.to_string(),
+                        ColumnMetadata {
+                            data_type: "integer".to_string(),
+                            index: index as i128,
+                            name: column.to_string(),
+                            comment: None,
+                        },
+                    )
+                })
+                .collect(),
+            stats: stats
+                .iter()
+                .map(|(id, value)| {
+                    (
+                        id.to_string(),
+                        CatalogNodeStats {
+                            id: id.to_string(),
+                            label: id.to_string(),
+                            value: Value::from(*value),
+                            include: true,
+                            description: None,
+                        },
+                    )
+                })
+                .collect(),
+            unique_id: Some(format!("model.test.{name}")),
+        }
+    }
+
+    fn catalog(generated_at: &str, tables: Vec<CatalogTable>) -> DbtCatalog {
+        DbtCatalog {
+            metadata: CatalogMetadata {
+                generated_at: generated_at.parse().unwrap(),
+                ..Default::default()
+            },
+            nodes: tables
+                .into_iter()
+                .map(|table| (table.unique_id.clone().unwrap(), table))
+                .collect(),
+            sources: BTreeMap::new(),
+            errors: None,
+        }
+    }
+
+    #[test]
+    fn test_merge_newer_table_wins_and_keeps_stats() {
+        let mut existing = catalog(
+            "2024-01-01T00:00:00Z",
+            vec![
+                table("orders", &["id"], &[("row_count", 10), ("bytes", 100)]),
+                table("customers", &["id"], &[]),
+            ],
+        );
+        let introspected = catalog(
+            "2024-02-01T00:00:00Z",
+            vec![
+                table("orders", &["id", "amount"], &[("row_count", 20)]),
+                table("payments", &["id"], &[]),
+            ],
+        );
+        existing.merge(introspected);
+
+        assert_eq!(
+            existing.nodes.keys().collect::<Vec<_>>(),
+            [
+                "model.test.customers",
+                "model.test.orders",
+                "model.test.payments"
+            ]
+        );
+        let orders = &existing.nodes["model.test.orders"];
+        assert_eq!(orders.columns.keys().collect::<Vec<_>>(), ["amount", "id"]);
+        assert_eq!(orders.stats["row_count"].value, Value::from(20));
+        assert_eq!(orders.stats["bytes"].value, Value::from(100));
+        assert_eq!(
+            existing.metadata.generated_at,
+            "2024-02-01T00:00:00Z".parse::<DateTime<Utc>>().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_merge_older_catalog_does_not_override() {
+        let mut existing = catalog(
+            "2024-02-01T00:00:00Z",
+            vec![table("orders", &["id", "amount"], &[("row_count", 20)])],
+        );
+        let stale = catalog(
+            "2024-01-01T00:00:00Z",
+            vec![table(
+                "orders",
+                &["id"],
+                &[("row_count", 10), ("bytes", 100)],
+            )],
+        );
+        existing.merge(stale);
+
+        let orders = &existing.nodes["model.test.orders"];
+        assert_eq!(orders.columns.keys().collect::<Vec<_>>(), ["amount", "id"]);
+        assert_eq!(orders.stats["row_count"].value, Value::from(20));
+        assert_eq!(orders.stats["bytes"].value, Value::from(100));
+        assert_eq!(
+            existing.metadata.generated_at,
+            "2024-02-01T00:00:00Z".parse::<DateTime<Utc>>().unwrap()
+        );
+    }
+}
@@ -1,8 +1,16 @@
 use chrono::{DateTime, Utc};
-use dbt_common::{Span, adapter::AdapterType, io_args::StaticAnalysisKind};
+use dbt_common::{
+    ErrorCode, FsResult, Span, adapter::AdapterType, fs_err, io_args::StaticAnalysisKind, stdfs,
+};
 use dbt_serde_yaml::UntaggedEnumDeserialize;
 use serde::{Deserialize, Serialize};
-use std::{collections::BTreeMap, str::FromStr as _, sync::Arc};
+use std::{
+    collections::BTreeMap,
+    io::{BufWriter, Write as _},
+    path::Path,
+    str::FromStr as _,
+    sync::Arc,
+};
 
 // Type aliases for clarity
 type YmlValue = dbt_serde_yaml::Value;
@@ -99,6 +107,31 @@ pub fn serialize_with_resource_type(mut value: YmlValue, resource_type: &str) ->
     value
 }
 
+/// Writes `manifest` as JSON to `path`, serializing straight into a buffered
+/// file rather than building the whole document in memory first. The file is
+/// replaced atomically, so a crash mid-write never leaves a truncated manifest.
+pub fn write_manifest(manifest: &DbtManifest, path: &Path) -> FsResult<()> {
+    stdfs::write_atomic_with(path, |file| {
+        let mut writer = BufWriter::new(file);
+        serde_json::to_writer(&mut writer, manifest).map_err(|e| {
+            fs_err!(
+                ErrorCode::SerializationError,
+                "Failed to write manifest to {}: {}",
+                path.display(),
+                e
+            )
+        })?;
+        writer.flush().map_err(|e| {
+            fs_err!(
+                ErrorCode::IoError,
+                "Failed to write manifest to {}: {}",
+                path.display(),
+                e
+            )
+        })
+    })
+}
+
 pub fn build_manifest(invocation_id: &str, resolver_state: &ResolverState) -> DbtManifest {
     let (parent_map, child_map) = build_parent_and_child_maps(&resolver_state.nodes);
     let group_map = build_group_map(&resolver_state.nodes);
@@ -541,6 +574,7 @@ pub fn nodes_from_dbt_manifest(manifest: DbtManifest, dbt_quoting: DbtQuoting) -
                             primary_key: model.primary_key.unwrap_or_default(),
                             time_spine: model.time_spine,
                             event_time: model.config.event_time.clone(),
+                            rendered_code: None,
                         },
                         __adapter_attr__: AdapterAttr::from_config_and_dialect(
                             &model.config.__warehouse_specific_config__,
@@ -820,6 +854,7 @@ pub fn nodes_from_dbt_manifest(manifest: DbtManifest, dbt_quoting: DbtQuoting) -
                             primary_key: analysis.primary_key.unwrap_or_default(),
                             time_spine: analysis.time_spine,
                             event_time: analysis.config.event_time.clone(),
+                            rendered_code: None,
                         },
                         __adapter_attr__: AdapterAttr::from_config_and_dialect(
                             &analysis.config.__warehouse_specific_config__,
@@ -1141,6 +1176,41 @@ mod tests {
         })
     }
 
+    #[test]
+    fn test_write_manifest_matches_to_string() {
+        let mut nodes = create_test_nodes();
+        nodes.models.insert(
+            "model.test.model_a".to_string(),
+            create_test_model("model.test.model_a", vec![]),
+        );
+        nodes.models.insert(
+            "model.test.model_b".to_string(),
+            create_test_model("model.test.model_b", vec!["model.test.model_a".to_string()]),
+        );
+        let (parent_map, child_map) = build_parent_and_child_maps(&nodes);
+        let manifest = DbtManifest {
+            metadata: ManifestMetadata {
+                project_name: "test".to_string(),
+                adapter_type: "postgres".to_string(),
+                ..Default::default()
+            },
+            parent_map,
+            child_map,
+            ..Default::default()
+        };
+
+        let path =
+            std::env::temp_dir().join(format!("dbt_write_manifest_{}.json", std::process::id()));
+        write_manifest(&manifest, &path).unwrap();
+        let written = std::fs::read(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(
+            written,
+            serde_json::to_string(&manifest).unwrap().into_bytes()
+        );
+    }
+
     #[test]
     fn test_build_parent_and_child_maps_empty_nodes() {
         let nodes = create_test_nodes();
@@ -1,3 +1,4 @@
+use dbt_common::{ErrorCode, FsResult, fs_err};
 use serde::{Deserialize, Serialize};
 use std::collections::{BTreeMap, HashMap};
 
@@ -15,9 +16,10 @@ use crate::schemas::{
             ManifestSource, ManifestUnitTest,
         },
     },
+    serde::yaml_to_fs_error,
 };
 
-use super::{DbtSelector, ManifestGroup};
+use super::{DbtManifestV10, DbtManifestV11, DbtSelector, ManifestGroup};
 
 #[derive(Debug, Default, Deserialize, Clone)]
 pub struct DbtManifestV12 {
@@ -40,6 +42,66 @@ pub struct DbtManifestV12 {
 }
 
 impl DbtManifestV12 {
+    /// Upcasts a v11 manifest, which has no unit tests or saved queries.
+    pub fn from_v11(manifest: DbtManifestV11) -> Self {
+        Self {
+            metadata: manifest.metadata,
+            nodes: manifest.nodes,
+            sources: manifest.sources,
+            macros: manifest.macros,
+            unit_tests: BTreeMap::new(),
+            docs: manifest.docs,
+            semantic_models: manifest
+                .semantic_models
+                .into_iter()
+                .map(|(id, semantic_model)| (id, semantic_model.into()))
+                .collect(),
+            saved_queries: BTreeMap::new(),
+            exposures: manifest.exposures,
+            metrics: manifest.metrics,
+            child_map: manifest.child_map,
+            parent_map: manifest.parent_map,
+            group_map: manifest.group_map,
+            disabled: manifest.disabled,
+            selectors: manifest.selectors,
+            groups: manifest.groups,
+        }
+    }
+
+    /// Upcasts a v10 manifest, which has no unit tests or saved queries.
+    ///
+    /// Fails if the manifest has semantic models or metrics: their v10
+    /// representation lacks fields that v12 requires (e.g. the metric type and
+    /// config), so they cannot be converted without losing them.
+    pub fn from_v10(manifest: DbtManifestV10) -> FsResult<Self> {
+        if !manifest.semantic_models.is_empty() || !manifest.metrics.is_empty() {
+            return Err(fs_err!(
+                ErrorCode::SerializationError,
+                "Cannot upcast manifest v10 with {} semantic model(s) and {} metric(s) to v12; regenerate the manifest with dbt 1.7 or later",
+                manifest.semantic_models.len(),
+                manifest.metrics.len()
+            ));
+        }
+        Ok(Self {
+            metadata: manifest.metadata,
+            nodes: manifest.nodes,
+            sources: manifest.sources,
+            macros: manifest.macros,
+            unit_tests: BTreeMap::new(),
+            docs: manifest.docs,
+            semantic_models: BTreeMap::new(),
+            saved_queries: BTreeMap::new(),
+            exposures: manifest.exposures,
+            metrics: BTreeMap::new(),
+            child_map: manifest.child_map,
+            parent_map: manifest.parent_map,
+            group_map: manifest.group_map,
+            disabled: manifest.disabled,
+            selectors: manifest.selectors,
+            groups: manifest.groups,
+        })
+    }
+
     pub fn into_map_compiled_sql(self) -> HashMap<String, Option<String>> {
         self.nodes
             .into_iter()
@@ -55,6 +117,43 @@ impl DbtManifestV12 {
     }
 }
 
+/// Extracts the version number from a manifest `dbt_schema_version` URL,
+/// e.g. `https://schemas.getdbt.com/dbt/manifest/v10.json`.
+fn manifest_schema_version(url: &str) -> Option<u32> {
+    url.rsplit('/')
+        .next()?
+        .strip_prefix('v')?
+        .strip_suffix(".json")?
+        .parse()
+        .ok()
+}
+
+/// Loads a `manifest.json` of any supported version (v10 and later),
+/// upcasting older versions to [DbtManifestV12].
+pub fn load_manifest_any_version(json: &str) -> FsResult<DbtManifestV12> {
+    let yml_val: YmlValue = serde_json::from_str(json)
+        .map_err(|e| fs_err!(ErrorCode::SerializationError, "Failed to parse JSON: {}", e))?;
+    let version = yml_val
+        .get("metadata")
+        .and_then(|metadata| metadata.get("dbt_schema_version"))
+        .and_then(|version| version.as_str())
+        .and_then(manifest_schema_version);
+    match version {
+        Some(version) if version < 10 => Err(fs_err!(
+            ErrorCode::SerializationError,
+            "Unsupported manifest version v{}: only v10 and later can be loaded",
+            version
+        )),
+        Some(10) => DbtManifestV10::deserialize(yml_val)
+            .map_err(|e| yaml_to_fs_error(e, None))
+            .and_then(DbtManifestV12::from_v10),
+        Some(11) => DbtManifestV11::deserialize(yml_val)
+            .map(DbtManifestV12::from_v11)
+            .map_err(|e| yaml_to_fs_error(e, None)),
+        _ => DbtManifestV12::deserialize(yml_val).map_err(|e| yaml_to_fs_error(e, None)),
+    }
+}
+
 impl Serialize for DbtManifestV12 {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -244,3 +343,120 @@ impl Serialize for DbtManifestV12 {
         map.serialize(serializer)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn minimal_manifest(version: u32, extra_sections: &str) -> String {
+        manifest_with_metrics(version, "{}", extra_sections)
+    }
+
+    fn manifest_with_metrics(version: u32, metrics: &str, extra_sections: &str) -> String {
+        format!(
+            r#"{{
+                "metadata": {{
+                    "dbt_schema_version": "https://schemas.getdbt.com/dbt/manifest/v{version}.json",
+                    "dbt_version": "1.6.0",
+                    "generated_at": "2024-01-01T00:00:00Z",
+                    "invocation_id": "abc",
+                    "env": {{}},
+                    "project_name": "jaffle_shop",
+                    "adapter_type": "postgres"
+                }},
+                "nodes": {{}},
+                "sources": {{}},
+                "macros": {{}},
+                "docs": {{}},
+                "semantic_models": {{}},
+                "exposures": {{}},
+                "metrics": {metrics},
+                "child_map": {{"model.jaffle_shop.orders": []}},
+                "parent_map": {{"model.jaffle_shop.orders": []}},
+                "group_map": {{}},
+                "disabled": {{}},
+                "selectors": {{}},
+                "groups": {{}}{extra_sections}
+            }}"#
+        )
+    }
+
+    #[test]
+    fn test_manifest_schema_version() {
+        assert_eq!(
+            manifest_schema_version("https://schemas.getdbt.com/dbt/manifest/v10.json"),
+            Some(10)
+        );
+        assert_eq!(manifest_schema_version("manifest.json"), None);
+    }
+
+    #[test]
+    fn test_load_manifest_v10() {
+        let manifest = load_manifest_any_version(&minimal_manifest(10, "")).unwrap();
+        assert_eq!(manifest.metadata.project_name, "jaffle_shop");
+        assert_eq!(manifest.metadata.adapter_type, "postgres");
+        assert!(manifest.child_map.contains_key("model.jaffle_shop.orders"));
+        assert!(manifest.unit_tests.is_empty());
+        assert!(manifest.saved_queries.is_empty());
+        assert!(manifest.semantic_models.is_empty());
+        assert!(manifest.metrics.is_empty());
+    }
+
+    #[test]
+    fn test_load_manifest_v10_with_metrics_fails() {
+        let metrics = r#"{
+            "metric.jaffle_shop.revenue": {
+                "name": "revenue",
+                "package_name": "jaffle_shop",
+                "path": "metrics.yml",
+                "original_file_path": "models/metrics.yml",
+                "unique_id": "metric.jaffle_shop.revenue",
+                "fqn": ["jaffle_shop", "revenue"],
+                "description": "",
+                "type_params": {},
+                "unrendered_config": {},
+                "sources": [],
+                "depends_on": {"macros": [], "nodes": []},
+                "refs": [],
+                "metrics": []
+            }
+        }"#;
+        let err = load_manifest_any_version(&manifest_with_metrics(10, metrics, "")).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("with 0 semantic model(s) and 1 metric(s)"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn test_load_manifest_v11() {
+        let manifest = load_manifest_any_version(&minimal_manifest(11, "")).unwrap();
+        assert_eq!(
+            manifest.metadata.__base__.dbt_schema_version,
+            "https://schemas.getdbt.com/dbt/manifest/v11.json"
+        );
+        assert!(manifest.parent_map.contains_key("model.jaffle_shop.orders"));
+        assert!(manifest.unit_tests.is_empty());
+        assert!(manifest.saved_queries.is_empty());
+    }
+
+    #[test]
+    fn test_load_manifest_v12() {
+        let manifest = load_manifest_any_version(&minimal_manifest(
+            12,
+            r#", "unit_tests": {}, "saved_queries": {}"#,
+        ))
+        .unwrap();
+        assert_eq!(manifest.metadata.project_name, "jaffle_shop");
+    }
+
+    #[test]
+    fn test_load_manifest_unsupported_version() {
+        let err = load_manifest_any_version(&minimal_manifest(9, "")).unwrap_err();
+        assert!(
+            err.to_string().contains("Unsupported manifest version v9"),
+            "{err}"
+        );
+    }
+}
@@ -1,6 +1,12 @@
 use core::fmt;
 use std::str::FromStr;
-use std::{any::Any, collections::BTreeMap, fmt::Display, path::PathBuf, sync::Arc};
+use std::{
+    any::Any,
+    collections::{BTreeMap, BTreeSet, HashMap},
+    fmt::Display,
+    path::PathBuf,
+    sync::Arc,
+};
 
 use chrono::{DateTime, Utc};
 use dbt_common::adapter::AdapterType;
@@ -133,6 +139,84 @@ impl InternalDbtNodeWrapper {
     }
 }
 
+/// Serialized node fields that [InternalDbtNode::content_checksum] ignores:
+/// identity and location (so a renamed or moved node keeps its checksum) and
+/// volatile timestamps. The file `checksum` is kept: resolved models and
+/// snapshots carry a placeholder `raw_code`, so it is their only SQL signal.
+const CONTENT_CHECKSUM_EXCLUDED_KEYS: &[&str] = &[
+    "unique_id",
+    "name",
+    "fqn",
+    "path",
+    "original_file_path",
+    "patch_path",
+    "name_span",
+    "alias",
+    "relation_name",
+    "created_at",
+];
+
+/// Serialized node fields that [InternalDbtNode::is_unchanged_from] ignores on
+/// top of [CONTENT_CHECKSUM_EXCLUDED_KEYS]: compilation output, which is
+/// derived from the SQL and the environment, the relation location (`database`,
+/// `schema`, `quoting`), which differs between the target and the prior state's
+/// environment, and `depends_on`, which is compared as a set since its order and
+/// ref locations carry no meaning.
+const STATE_COMPARISON_EXCLUDED_KEYS: &[&str] = &[
+    "compiled",
+    "compiled_code",
+    "rendered_code",
+    "database",
+    "schema",
+    "quoting",
+    "depends_on",
+];
+
+/// Hashes the serialized node `value`, leaving out [CONTENT_CHECKSUM_EXCLUDED_KEYS]
+/// and `excluded`.
+fn checksum_excluding(value: YmlValue, excluded: &[&str]) -> String {
+    let content = match value {
+        YmlValue::Mapping(map, span) => YmlValue::Mapping(
+            map.into_iter()
+                .filter(|(k, _)| {
+                    !k.as_str().is_some_and(|k| {
+                        CONTENT_CHECKSUM_EXCLUDED_KEYS.contains(&k) || excluded.contains(&k)
+                    })
+                })
+                .collect(),
+            span,
+        ),
+        other => other,
+    };
+    blake3::hash(canonical_json(&content).as_bytes())
+        .to_hex()
+        .to_string()
+}
+
+/// Renders `value` as JSON with mapping keys sorted, so the output does not
+/// depend on map iteration order.
+fn canonical_json(value: &YmlValue) -> String {
+    match value {
+        YmlValue::Mapping(map, _) => {
+            let mut entries = map
+                .iter()
+                .map(|(k, v)| (canonical_json(k), canonical_json(v)))
+                .collect::<Vec<_>>();
+            entries.sort();
+            let entries = entries
+                .into_iter()
+                .map(|(k, v)| format!("{k}:{v}"))
+                .collect::<Vec<_>>();
+            format!("{{{}}}", entries.join(","))
+        }
+        YmlValue::Sequence(items, _) => {
+            let items = items.iter().map(canonical_json).collect::<Vec<_>>();
+            format!("[{}]", items.join(","))
+        }
+        _ => serde_json::to_string(value).expect("Failed to serialize to JSON"),
+    }
+}
+
 pub trait InternalDbtNode: Any + Send + Sync + fmt::Debug {
     fn common(&self) -> &CommonAttributes;
     fn base(&self) -> &NodeBaseAttributes;
@@ -170,6 +254,29 @@ pub trait InternalDbtNode: Any + Send + Sync + fmt::Debug {
     }
     fn serialize_inner(&self) -> YmlValue;
 
+    /// Deterministic blake3 hash (hex) of the node's content, for change
+    /// detection across runs. See [CONTENT_CHECKSUM_EXCLUDED_KEYS] for the
+    /// fields that do not contribute.
+    fn content_checksum(&self) -> String {
+        checksum_excluding(self.serialize(), &[])
+    }
+
+    /// Whether this node is unchanged from `prev`, its counterpart in a prior
+    /// state: same file checksum, same content checksum ignoring
+    /// [STATE_COMPARISON_EXCLUDED_KEYS] as well, and the same set of upstream
+    /// nodes and macros.
+    fn is_unchanged_from(&self, prev: &dyn InternalDbtNode) -> bool {
+        let (deps, prev_deps) = (&self.base().depends_on, &prev.base().depends_on);
+        self.resource_type() == prev.resource_type()
+            && self.common().checksum == prev.common().checksum
+            && deps.nodes.iter().collect::<BTreeSet<_>>()
+                == prev_deps.nodes.iter().collect::<BTreeSet<_>>()
+            && deps.macros.iter().collect::<BTreeSet<_>>()
+                == prev_deps.macros.iter().collect::<BTreeSet<_>>()
+            && checksum_excluding(self.serialize(), STATE_COMPARISON_EXCLUDED_KEYS)
+                == checksum_excluding(prev.serialize(), STATE_COMPARISON_EXCLUDED_KEYS)
+    }
+
     // Selector functions
     fn has_same_config(&self, other: &dyn InternalDbtNode) -> bool;
     fn has_same_content(&self, other: &dyn InternalDbtNode) -> bool;
@@ -1472,6 +1579,9 @@ impl InternalDbtNode for DbtMacro {
     }
 }
 
+/// The unique id of a node
+pub type NodeId = String;
+
 #[derive(Debug, Default, Clone)]
 pub struct Nodes {
     pub models: BTreeMap<String, Arc<DbtModel>>,
@@ -1925,6 +2035,22 @@ impl Nodes {
         self.groups.extend(other.groups);
     }
 
+    /// Retain only the nodes whose unique id satisfies `keep`.
+    pub fn retain(&mut self, keep: impl Fn(&str) -> bool) {
+        self.models.retain(|id, _| keep(id));
+        self.seeds.retain(|id, _| keep(id));
+        self.tests.retain(|id, _| keep(id));
+        self.unit_tests.retain(|id, _| keep(id));
+        self.sources.retain(|id, _| keep(id));
+        self.snapshots.retain(|id, _| keep(id));
+        self.analyses.retain(|id, _| keep(id));
+        self.exposures.retain(|id, _| keep(id));
+        self.semantic_models.retain(|id, _| keep(id));
+        self.metrics.retain(|id, _| keep(id));
+        self.saved_queries.retain(|id, _| keep(id));
+        self.groups.retain(|id, _| keep(id));
+    }
+
     pub fn warn_on_custom_materializations(&self) -> FsResult<()> {
         let mut custom_materializations: Vec<(String, String)> = Vec::new();
 
@@ -1953,6 +2079,57 @@ impl Nodes {
         }
         Ok(())
     }
+
+    /// Checks that the `depends_on.nodes` edges between nodes form a DAG.
+    ///
+    /// On failure, returns the unique ids of the first cycle found by a
+    /// depth-first search in unique id order, starting with the node the cycle
+    /// was entered from; each node depends on the next, and the last one on
+    /// the first.
+    pub fn detect_cycles(&self) -> Result<(), Vec<NodeId>> {
+        let deps: BTreeMap<&str, &[String]> = self
+            .iter()
+            .map(|(id, node)| (id.as_str(), node.base().depends_on.nodes.as_slice()))
+            .collect();
+
+        // true while a node is on the current path, false once it is finished
+        let mut on_path: HashMap<&str, bool> = HashMap::new();
+        for (&root, root_deps) in &deps {
+            if on_path.contains_key(root) {
+                continue;
+            }
+            on_path.insert(root, true);
+            let mut path = vec![root];
+            let mut stack = vec![root_deps.iter()];
+            while let Some(parents) = stack.last_mut() {
+                let Some(parent) = parents.next() else {
+                    stack.pop();
+                    on_path.insert(path.pop().unwrap(), false);
+                    continue;
+                };
+                let parent = parent.as_str();
+                match on_path.get(parent) {
+                    Some(true) => {
+                        let start = path.iter().position(|id| *id == parent).unwrap();
+                        return Err(path[start..].iter().map(|id| id.to_string()).collect());
+                    }
+                    Some(false) => {}
+                    // Dependencies outside of these nodes cannot close a cycle
+                    None => match deps.get(parent) {
+                        Some(parent_deps) => {
+                            on_path.insert(parent, true);
+                            path.push(parent);
+                            stack.push(parent_deps.iter());
+                        }
+                        None => {
+                            on_path.insert(parent, false);
+                        }
+                    },
+                }
+            }
+        }
+        Ok(())
+    }
 }
 
 fn upcast<T: InternalDbtNodeAttributes + 'static>(
@@ -2929,6 +3106,9 @@ pub struct DbtModelAttr {
     pub primary_key: Vec<String>,
     pub time_spine: Option<YmlValue>,
     pub event_time: Option<String>,
+    /// The jinja-expanded sql, only retained when resolving with `keep_rendered`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rendered_code: Option<String>,
 }
 
 fn default_introspection() -> IntrospectionKind {
@@ -2939,10 +3119,112 @@ fn default_introspection() -> IntrospectionKind {
 mod tests {
     use serde::Deserialize;
 
-    use super::ModelConfig;
+    use std::sync::Arc;
+
+    use super::{DbtChecksum, DbtModel, InternalDbtNode, ModelConfig, Nodes};
 
     type YmlValue = dbt_serde_yaml::Value;
 
+    fn model_with(name: &str, raw_code: &str, meta_value: &str) -> DbtModel {
+        let mut model = DbtModel::default();
+        model.__common_attr__.unique_id = format!("model.test.{name}");
+        model.__common_attr__.name = name.to_string();
+        model.__common_attr__.fqn = vec!["test".to_string(), name.to_string()];
+        model.__common_attr__.raw_code = Some(raw_code.to_string());
+        model.__common_attr__.meta.insert(
+            "settings".to_string(),
+            dbt_serde_yaml::from_str(meta_value).unwrap(),
+        );
+        model
+    }
+
+    #[test]
+    fn test_content_checksum_ignores_map_order() {
+        let a = model_with("orders", "select 1", "{owner: data, tier: 1}");
+        let b = model_with("orders", "select 1", "{tier: 1, owner: data}");
+        assert_eq!(a.content_checksum(), b.content_checksum());
+    }
+
+    #[test]
+    fn test_content_checksum_changes_with_sql() {
+        let a = model_with("orders", "select 1", "{owner: data}");
+        let b = model_with("orders", "select 2", "{owner: data}");
+        assert_ne!(a.content_checksum(), b.content_checksum());
+    }
+
+    #[test]
+    fn test_content_checksum_changes_with_file_checksum() {
+        // resolved models carry a placeholder instead of their SQL
+        let mut a = model_with("orders", "--placeholder--", "{owner: data}");
+        let mut b = model_with("orders", "--placeholder--", "{owner: data}");
+        a.__common_attr__.checksum = DbtChecksum::hash(b"select 1");
+        b.__common_attr__.checksum = DbtChecksum::hash(b"select 2");
+        assert_ne!(a.content_checksum(), b.content_checksum());
+    }
+
+    #[test]
+    fn test_content_checksum_ignores_identity() {
+        let a = model_with("orders", "select 1", "{owner: data}");
+        let b = model_with("customer_orders", "select 1", "{owner: data}");
+        assert_eq!(a.content_checksum(), b.content_checksum());
+    }
+
+    #[test]
+    fn test_is_unchanged_from_identical_node() {
+        let prev = model_with("orders", "select 1", "{owner: data}");
+        let mut curr = model_with("orders", "select 1", "{owner: data}");
+        assert!(curr.is_unchanged_from(&prev));
+
+        // dependency order and compilation output are not part of the comparison
+        let mut prev = prev;
+        prev.__base_attr__.depends_on.nodes = vec!["model.test.a".into(), "model.test.b".into()];
+        curr.__base_attr__.depends_on.nodes = vec!["model.test.b".into(), "model.test.a".into()];
+        curr.__model_attr__.rendered_code = Some("select 1".to_string());
+        assert!(curr.is_unchanged_from(&prev));
+    }
+
+    #[test]
+    fn test_is_unchanged_from_sql_change() {
+        let prev = model_with("orders", "select 1", "{owner: data}");
+        let curr = model_with("orders", "select 2", "{owner: data}");
+        assert!(!curr.is_unchanged_from(&prev));
+    }
+
+    #[test]
+    fn test_is_unchanged_from_file_checksum_change() {
+        // resolved models carry a placeholder instead of their SQL
+        let mut prev = model_with("orders", "--placeholder--", "{owner: data}");
+        let mut curr = model_with("orders", "--placeholder--", "{owner: data}");
+        prev.__common_attr__.checksum = DbtChecksum::hash(b"select 1");
+        curr.__common_attr__.checksum = DbtChecksum::hash(b"select 1");
+        assert!(curr.is_unchanged_from(&prev));
+
+        curr.__common_attr__.checksum = DbtChecksum::hash(b"select 2");
+        assert!(!curr.is_unchanged_from(&prev));
+    }
+
+    #[test]
+    fn test_is_unchanged_from_ignores_relation_location() {
+        let mut prev = model_with("orders", "select 1", "{owner: data}");
+        let mut curr = model_with("orders", "select 1", "{owner: data}");
+        prev.__base_attr__.database = "prod".to_string();
+        prev.__base_attr__.schema = "analytics".to_string();
+        prev.__base_attr__.quoting.identifier = true;
+        curr.__base_attr__.database = "dev".to_string();
+        curr.__base_attr__.schema = "dbt_alice".to_string();
+        curr.__base_attr__.quoting.identifier = false;
+        assert!(curr.is_unchanged_from(&prev));
+    }
+
+    #[test]
+    fn test_is_unchanged_from_dependency_change() {
+        let mut prev = model_with("orders", "select 1", "{owner: data}");
+        let mut curr = model_with("orders", "select 1", "{owner: data}");
+        prev.__base_attr__.depends_on.nodes = vec!["model.test.a".into()];
+        curr.__base_attr__.depends_on.nodes = vec!["model.test.b".into()];
+        assert!(!curr.is_unchanged_from(&prev));
+    }
+
     #[test]
     fn test_deserialize_wo_meta() {
         let config: YmlValue = dbt_serde_yaml::from_str(
@@ -2957,4 +3239,46 @@ mod tests {
             panic!("Could not deserialize and failed with the following error: {err}");
         }
     }
+
+    fn nodes_with_deps(edges: &[(&str, &[&str])]) -> Nodes {
+        let mut nodes = Nodes::default();
+        for (name, depends_on) in edges {
+            let mut model = DbtModel::default();
+            model.__common_attr__.unique_id = format!("model.test.{name}");
+            model.__base_attr__.depends_on.nodes = depends_on
+                .iter()
+                .map(|dep| format!("model.test.{dep}"))
+                .collect();
+            nodes
+                .models
+                .insert(model.__common_attr__.unique_id.clone(), Arc::new(model));
+        }
+        nodes
+    }
+
+    #[test]
+    fn test_detect_cycles_reports_path() {
+        // a refs b, b refs c, c refs a; d refs a but is not part of the cycle
+        let nodes = nodes_with_deps(&[("a", &["b"]), ("b", &["c"]), ("c", &["a"]), ("d", &["a"])]);
+        assert_eq!(
+            nodes.detect_cycles(),
+            Err(vec![
+                "model.test.a".to_string(),
+                "model.test.b".to_string(),
+                "model.test.c".to_string(),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_detect_cycles_dag() {
+        // a diamond plus a dependency on a node outside of the graph
+        let nodes = nodes_with_deps(&[
+            ("a", &["source"]),
+            ("b", &["a"]),
+            ("c", &["a"]),
+            ("d", &["b", "c"]),
+        ]);
+        assert_eq!(nodes.detect_cycles(), Ok(()));
+    }
 }
@@ -4,6 +4,7 @@ use crate::schemas::manifest::nodes_from_dbt_manifest;
 use crate::schemas::serde::typed_struct_from_json_file;
 use crate::schemas::{InternalDbtNode, Nodes, nodes::DbtModel};
 use dbt_common::{FsResult, constants::DBT_MANIFEST_JSON};
+use std::collections::BTreeMap;
 use std::fmt;
 use std::path::{Path, PathBuf};
 
@@ -25,6 +26,20 @@ pub enum ModificationType {
     Any,
 }
 
+/// Node-level differences between the previous state and the current nodes.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StateDiff {
+    /// unique_ids only present in the current nodes
+    pub added: Vec<String>,
+    /// unique_ids only present in the previous state
+    pub removed: Vec<String>,
+    /// (previous unique_id, current unique_id) of nodes that were renamed
+    /// without changing their content
+    pub renamed: Vec<(String, String)>,
+    /// Current unique_id -> how it differs from the previous state
+    pub modified: BTreeMap<String, ModificationType>,
+}
+
 impl fmt::Display for PreviousState {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "PreviousState from {}", self.state_path.display())
@@ -102,6 +117,42 @@ impl PreviousState {
         }
     }
 
+    // Compute the added, removed and modified nodes relative to the previous state.
+    // A removed node and an added node of the same resource type with the same
+    // content checksum are reported as a single rename instead.
+    pub fn diff(&self, current: &Nodes) -> StateDiff {
+        let mut diff = StateDiff::default();
+
+        let mut removed_by_checksum: BTreeMap<(String, String), Vec<String>> = BTreeMap::new();
+        for (unique_id, node) in self.nodes.iter() {
+            if !current.contains(unique_id) {
+                removed_by_checksum
+                    .entry((node.resource_type().to_string(), node.content_checksum()))
+                    .or_default()
+                    .push(unique_id.clone());
+            }
+        }
+
+        for (unique_id, node) in current.iter() {
+            if self.nodes.contains(unique_id) {
+                if self.is_modified(node, None) {
+                    diff.modified
+                        .insert(unique_id.clone(), ModificationType::Any);
+                }
+                continue;
+            }
+            let key = (node.resource_type().to_string(), node.content_checksum());
+            match removed_by_checksum.get_mut(&key).and_then(|ids| ids.pop()) {
+                Some(from) => diff.renamed.push((from, unique_id.clone())),
+                None => diff.added.push(unique_id.clone()),
+            }
+        }
+
+        diff.removed = removed_by_checksum.into_values().flatten().collect();
+        diff.removed.sort();
+        diff
+    }
+
     // Private helper methods to check specific types of modifications
     fn check_modified_content(&self, current_node: &dyn InternalDbtNode) -> bool {
         // Get the previous node from the manifest
@@ -199,3 +250,71 @@ impl PreviousState {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    fn model(name: &str, raw_code: &str) -> (String, Arc<DbtModel>) {
+        let mut model = DbtModel::default();
+        model.__common_attr__.unique_id = format!("model.test.{name}");
+        model.__common_attr__.name = name.to_string();
+        model.__common_attr__.fqn = vec!["test".to_string(), name.to_string()];
+        model.__common_attr__.path = PathBuf::from(format!("models/{name}.sql"));
+        model.__common_attr__.raw_code = Some(raw_code.to_string());
+        model.__base_attr__.alias = name.to_string();
+        (model.__common_attr__.unique_id.clone(), Arc::new(model))
+    }
+
+    fn nodes(models: Vec<(String, Arc<DbtModel>)>) -> Nodes {
+        Nodes {
+            models: models.into_iter().collect(),
+            ..Default::default()
+        }
+    }
+
+    fn previous_state(models: Vec<(String, Arc<DbtModel>)>) -> PreviousState {
+        PreviousState {
+            nodes: nodes(models),
+            run_results: None,
+            state_path: PathBuf::from("target"),
+        }
+    }
+
+    #[test]
+    fn test_diff_reports_rename_with_identical_sql() {
+        let state = previous_state(vec![
+            model("orders", "select 1 as id"),
+            model("customers", "select 2 as id"),
+        ]);
+        let current = nodes(vec![
+            model("customer_orders", "select 1 as id"),
+            model("customers", "select 2 as id"),
+        ]);
+
+        let diff = state.diff(&current);
+        assert!(diff.added.is_empty(), "{diff:?}");
+        assert!(diff.removed.is_empty(), "{diff:?}");
+        assert!(diff.modified.is_empty(), "{diff:?}");
+        assert_eq!(
+            diff.renamed,
+            vec![(
+                "model.test.orders".to_string(),
+                "model.test.customer_orders".to_string(),
+            )]
+        );
+    }
+
+    #[test]
+    fn test_diff_rename_with_changed_sql_is_added_and_removed() {
+        let state = previous_state(vec![model("orders", "select 1 as id")]);
+        let current = nodes(vec![model("customer_orders", "select 2 as id")]);
+
+        let diff = state.diff(&current);
+        assert_eq!(diff.added, vec!["model.test.customer_orders".to_string()]);
+        assert_eq!(diff.removed, vec!["model.test.orders".to_string()]);
+        assert!(diff.modified.is_empty(), "{diff:?}");
+        assert!(diff.renamed.is_empty(), "{diff:?}");
+    }
+}
@@ -4,6 +4,7 @@ use crate::schemas::relations::DEFAULT_DATABRICKS_DATABASE;
 use crate::schemas::serde::{StringOrInteger, StringOrMap};
 
 use dbt_common::adapter::AdapterType;
+use dbt_common::{ErrorCode, FsResult, fs_err};
 use dbt_serde_yaml::JsonSchema;
 use dbt_serde_yaml::UntaggedEnumDeserialize;
 use merge::Merge;
@@ -409,6 +410,83 @@ fn default_target() -> String {
     "default".to_string()
 }
 
+/// Key under a target output naming another output of the same profile to inherit from.
+pub const TARGET_EXTENDS_KEY: &str = "extends";
+
+impl DbTargets {
+    /// Returns the effective config of `target_name` with `extends` resolved.
+    pub fn resolve_output(&self, target_name: &str) -> FsResult<YmlValue> {
+        resolve_target_output(target_name, |name| self.outputs.get(name))
+    }
+}
+
+/// Resolves the `extends` chain of `target_name`, looking up outputs through `lookup`.
+///
+/// Keys are merged shallowly from the root ancestor down, so a child's keys win over
+/// its parent's. The `extends` key itself is dropped from the effective config.
+pub fn resolve_target_output<'a>(
+    target_name: &str,
+    lookup: impl Fn(&str) -> Option<&'a YmlValue>,
+) -> FsResult<YmlValue> {
+    let mut chain: Vec<(&str, &YmlValue)> = Vec::new();
+    let mut current = target_name;
+    loop {
+        if let Some(pos) = chain.iter().position(|(name, _)| *name == current) {
+            let cycle = chain[pos..]
+                .iter()
+                .map(|(name, _)| *name)
+                .chain(std::iter::once(current))
+                .collect::<Vec<_>>()
+                .join(" -> ");
+            return Err(fs_err!(
+                ErrorCode::InvalidConfig,
+                "Cycle in target inheritance in profiles.yml: {}",
+                cycle
+            ));
+        }
+        let output = lookup(current).ok_or_else(|| {
+            fs_err!(
+                ErrorCode::InvalidConfig,
+                "Could not find target {} in profiles.yml",
+                current
+            )
+        })?;
+        let mapping = output.as_mapping().ok_or_else(|| {
+            fs_err!(
+                ErrorCode::InvalidConfig,
+                "Target {} in profiles.yml must be a mapping",
+                current
+            )
+        })?;
+        chain.push((current, output));
+        match mapping.get(TARGET_EXTENDS_KEY) {
+            None => break,
+            Some(parent) => {
+                current = parent.as_str().ok_or_else(|| {
+                    fs_err!(
+                        ErrorCode::InvalidConfig,
+                        "'{}' of target {} in profiles.yml must be a target name",
+                        TARGET_EXTENDS_KEY,
+                        current
+                    )
+                })?;
+            }
+        }
+    }
+
+    let mut outputs = chain.into_iter().rev().map(|(_, output)| output);
+    // The chain holds at least the requested target itself
+    let mut effective = outputs.next().unwrap().clone();
+    let merged = effective.as_mapping_mut().unwrap();
+    for output in outputs {
+        for (key, value) in output.as_mapping().unwrap() {
+            merged.insert(key.clone(), value.clone());
+        }
+    }
+    merged.remove(TARGET_EXTENDS_KEY);
+    Ok(effective)
+}
+
 /// Extend merge_strategies from `merge` crate
 mod merge_strategies_extend {
     pub fn overwrite_always<T>(left: &mut T, right: T) {
@@ -447,6 +525,8 @@ pub struct RedshiftDbConfig {
     pub autocommit: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub retries: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query_retries: Option<StringOrInteger>,
     // Authentication Parameters (Password)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub method: Option<String>,
@@ -485,6 +565,8 @@ pub struct SnowflakeDbConfig {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub connect_retries: Option<StringOrInteger>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub query_retries: Option<StringOrInteger>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub connect_timeout: Option<StringOrInteger>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reuse_connections: Option<bool>,
@@ -551,6 +633,8 @@ pub struct PostgresDbConfig {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub retries: Option<StringOrInteger>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub query_retries: Option<StringOrInteger>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub search_path: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub role: Option<String>,
@@ -634,6 +718,8 @@ pub struct BigqueryDbConfig {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub job_retries: Option<i64>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub query_retries: Option<StringOrInteger>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub job_retry_deadline_seconds: Option<i64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub target_name: Option<String>,
@@ -708,6 +794,8 @@ pub struct DatabricksDbConfig {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub connect_retries: Option<i32>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub query_retries: Option<StringOrInteger>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub connect_timeout: Option<i32>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub retry_all: Option<bool>,
@@ -1070,4 +1158,66 @@ mod tests {
             panic!("Expected DbConfig::Bigquery, got {config:?}",);
         }
     }
+
+    fn targets(yaml: &str) -> DbTargets {
+        dbt_serde_yaml::from_str(yaml).unwrap()
+    }
+
+    #[test]
+    fn test_resolve_output_two_level_extends() {
+        let db_targets = targets(
+            "target: dev\n\
+             outputs:\n  \
+               base:\n    \
+                 type: postgres\n    \
+                 host: localhost\n    \
+                 port: 5432\n    \
+                 schema: base\n  \
+               prod:\n    \
+                 extends: base\n    \
+                 host: prod.example.com\n    \
+                 schema: analytics\n  \
+               dev:\n    \
+                 extends: prod\n    \
+                 schema: dev\n",
+        );
+
+        let dev = db_targets.resolve_output("dev").unwrap();
+        assert_eq!(dev.get("type").and_then(|v| v.as_str()), Some("postgres"));
+        assert_eq!(
+            dev.get("host").and_then(|v| v.as_str()),
+            Some("prod.example.com")
+        );
+        assert_eq!(dev.get("port").and_then(|v| v.as_i64()), Some(5432));
+        assert_eq!(dev.get("schema").and_then(|v| v.as_str()), Some("dev"));
+        assert!(dev.get(TARGET_EXTENDS_KEY).is_none());
+
+        let config: DbConfig = dbt_serde_yaml::from_value(dev).unwrap();
+        assert_eq!(config.get_schema().map(String::as_str), Some("dev"));
+
+        let base = db_targets.resolve_output("base").unwrap();
+        assert_eq!(base.get("schema").and_then(|v| v.as_str()), Some("base"));
+    }
+
+    #[test]
+    fn test_resolve_output_extends_cycle() {
+        let db_targets = targets(
+            "outputs:\n  \
+               a:\n    \
+                 extends: b\n    \
+                 type: postgres\n  \
+               b:\n    \
+                 extends: c\n  \
+               c:\n    \
+                 extends: a\n",
+        );
+
+        let err = db_targets.resolve_output("a").unwrap_err();
+        assert!(err.to_string().contains("a -> b -> c -> a"), "{err}");
+
+        let err = targets("outputs:\n  dev:\n    extends: missing\n")
+            .resolve_output("dev")
+            .unwrap_err();
+        assert!(err.to_string().contains("missing"), "{err}");
+    }
 }
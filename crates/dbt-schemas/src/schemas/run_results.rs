@@ -181,4 +181,164 @@ impl RunResultsArtifact {
     pub fn from_file(path: &Path) -> FsResult<Self> {
         typed_struct_from_json_file(path)
     }
+
+    /// Rolls the results up into counts and execution time, overall and per
+    /// resource type.
+    pub fn aggregate_stats(&self) -> RunStats {
+        let mut stats = RunStats::default();
+        for result in &self.results {
+            // unique ids are `<resource_type>.<package>.<name>...`
+            let resource_type = result
+                .unique_id
+                .split_once('.')
+                .map_or(result.unique_id.as_str(), |(resource_type, _)| {
+                    resource_type
+                });
+            stats.total.add(result);
+            stats
+                .by_resource_type
+                .entry(resource_type.to_string())
+                .or_default()
+                .add(result);
+        }
+        stats
+    }
+}
+
+/// Result counts and summed execution time of a set of [RunResultOutput]s.
+///
+/// `success` counts `success`, `pass`, `warn` and `reused` results, `error`
+/// counts `error`, `fail` and `runtime error` results. Results with any
+/// other status only count towards `total`.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ResultCounts {
+    pub total: usize,
+    pub success: usize,
+    pub error: usize,
+    pub skipped: usize,
+    /// Summed execution time in seconds
+    pub execution_time: f64,
+}
+
+impl ResultCounts {
+    fn add(&mut self, result: &RunResultOutput) {
+        self.total += 1;
+        match result.status.as_str() {
+            "success" | "pass" | "warn" | "reused" => self.success += 1,
+            "error" | "fail" | "runtime error" => self.error += 1,
+            "skipped" => self.skipped += 1,
+            _ => {}
+        }
+        self.execution_time += result.execution_seconds();
+    }
+}
+
+/// Rollup of a [RunResultsArtifact], see [RunResultsArtifact::aggregate_stats].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RunStats {
+    pub total: ResultCounts,
+    /// Keyed by resource type, e.g. `model` or `test`
+    pub by_resource_type: BTreeMap<String, ResultCounts>,
+}
+
+impl RunResultOutput {
+    /// The execution time in seconds, falling back to the span of the timing
+    /// entries when `execution_time` is unset, and to zero without either.
+    fn execution_seconds(&self) -> f64 {
+        if self.execution_time.is_finite() && self.execution_time > 0.0 {
+            return self.execution_time;
+        }
+        self.timing
+            .iter()
+            .filter_map(|timing| Some(timing.completed_at? - timing.started_at?))
+            .map(|elapsed| elapsed.as_seconds_f64().max(0.0))
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn result(unique_id: &str, status: &str, execution_time: f64) -> RunResultOutput {
+        RunResultOutput {
+            status: status.to_string(),
+            timing: vec![],
+            thread_id: "Thread-1".to_string(),
+            execution_time,
+            adapter_response: BTreeMap::new(),
+            message: None,
+            failures: None,
+            unique_id: unique_id.to_string(),
+            compiled: None,
+            compiled_code: None,
+            relation_name: None,
+            batch_results: None,
+        }
+    }
+
+    #[test]
+    fn test_aggregate_stats() {
+        let mut timed = result("model.pkg.timed", "success", 0.0);
+        timed.timing = vec![
+            TimingInfo {
+                name: "compile".to_string(),
+                started_at: Some(Utc.timestamp_opt(100, 0).unwrap()),
+                completed_at: Some(Utc.timestamp_opt(101, 0).unwrap()),
+            },
+            TimingInfo {
+                name: "execute".to_string(),
+                started_at: Some(Utc.timestamp_opt(101, 0).unwrap()),
+                completed_at: None,
+            },
+        ];
+        let artifact = RunResultsArtifact {
+            metadata: RunResultsMetadata::default(),
+            results: vec![
+                result("model.pkg.a", "success", 1.5),
+                result("model.pkg.b", "error", 2.0),
+                result("model.pkg.c", "skipped", 0.0),
+                timed,
+                result("test.pkg.not_null", "pass", 0.25),
+                result("test.pkg.unique", "fail", 0.25),
+                result("seed.pkg.s", "success", 3.0),
+            ],
+            elapsed_time: 10.0,
+            args: RunResultsArgs {
+                command: "build".to_string(),
+                which: "build".to_string(),
+                __other__: BTreeMap::new(),
+            },
+        };
+
+        let stats = artifact.aggregate_stats();
+        assert_eq!(
+            stats.total,
+            ResultCounts {
+                total: 7,
+                success: 4,
+                error: 2,
+                skipped: 1,
+                execution_time: 8.0,
+            }
+        );
+        assert_eq!(
+            stats.by_resource_type.keys().collect::<Vec<_>>(),
+            ["model", "seed", "test"]
+        );
+        assert_eq!(
+            stats.by_resource_type["model"],
+            ResultCounts {
+                total: 4,
+                success: 2,
+                error: 1,
+                skipped: 1,
+                execution_time: 4.5,
+            }
+        );
+        assert_eq!(stats.by_resource_type["test"].success, 1);
+        assert_eq!(stats.by_resource_type["test"].error, 1);
+        assert_eq!(stats.by_resource_type["test"].execution_time, 0.5);
+    }
 }
@@ -0,0 +1,99 @@
+//! Helpers for constructing nodes in tests. Only compiled for this crate's
+//! tests or with the `test-util` feature.
+
+use crate::schemas::common::DbtMaterialization;
+use crate::schemas::nodes::NodeBaseAttributes;
+
+/// Builds a [NodeBaseAttributes] with defaults matching a freshly resolved,
+/// enabled view:
+///
+/// * `database`, `schema` and `alias` are empty, `relation_name` is `None`
+/// * `materialized` is [DbtMaterialization::View]
+/// * `enabled` is `true`
+/// * every other field takes its [Default] value (quoting follows dbt's
+///   all-quoted default)
+#[derive(Debug, Clone)]
+pub struct NodeBaseAttributesBuilder {
+    attrs: NodeBaseAttributes,
+}
+
+impl Default for NodeBaseAttributesBuilder {
+    fn default() -> Self {
+        Self {
+            attrs: NodeBaseAttributes {
+                materialized: DbtMaterialization::View,
+                enabled: true,
+                ..Default::default()
+            },
+        }
+    }
+}
+
+impl NodeBaseAttributesBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the relation identifier (`alias`).
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.attrs.alias = name.into();
+        self
+    }
+
+    pub fn database(mut self, database: impl Into<String>) -> Self {
+        self.attrs.database = database.into();
+        self
+    }
+
+    pub fn schema(mut self, schema: impl Into<String>) -> Self {
+        self.attrs.schema = schema.into();
+        self
+    }
+
+    pub fn materialized(mut self, materialized: DbtMaterialization) -> Self {
+        self.attrs.materialized = materialized;
+        self
+    }
+
+    pub fn build(self) -> NodeBaseAttributes {
+        self.attrs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schemas::common::ResolvedQuoting;
+    use crate::schemas::{DbtModel, InternalDbtNodeAttributes};
+
+    #[test]
+    fn test_build_minimal_model() {
+        let model = DbtModel {
+            __base_attr__: NodeBaseAttributesBuilder::new()
+                .name("orders")
+                .database("analytics")
+                .schema("marts")
+                .materialized(DbtMaterialization::Table)
+                .build(),
+            ..Default::default()
+        };
+        assert_eq!(model.database(), "analytics");
+        assert_eq!(model.schema(), "marts");
+        assert_eq!(model.alias(), "orders");
+        assert_eq!(model.materialized(), DbtMaterialization::Table);
+    }
+
+    #[test]
+    fn test_omitted_fields_take_defaults() {
+        let attrs = NodeBaseAttributesBuilder::new().build();
+        assert_eq!(attrs.database, "");
+        assert_eq!(attrs.schema, "");
+        assert_eq!(attrs.alias, "");
+        assert_eq!(attrs.relation_name, None);
+        assert_eq!(attrs.materialized, DbtMaterialization::View);
+        assert!(attrs.enabled);
+        assert_eq!(attrs.quoting, ResolvedQuoting::default());
+        assert!(attrs.refs.is_empty());
+        assert!(attrs.columns.is_empty());
+    }
+}
@@ -1,3 +1,5 @@
+use std::collections::BTreeMap;
+
 use dbt_serde_yaml::JsonSchema;
 #[cfg(test)]
 use fake::Dummy;
@@ -25,6 +27,11 @@ pub struct LogEventInfo {
     /// This is the original severity before user up/down-grade configuration applied
     pub original_severity_text: String,
 
+    /// Key/value context attached to the originating error (e.g. the file
+    /// path or relation being processed when it was raised)
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub context: BTreeMap<String, String>,
+
     #[serde(flatten)]
     pub location: RecordCodeLocation,
 }
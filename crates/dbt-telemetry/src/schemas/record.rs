@@ -375,12 +375,14 @@ impl TelemetryAttributes {
                 dbt_core_code,
                 original_severity_number,
                 original_severity_text,
+                context,
                 ..
             }) => TelemetryAttributes::Log(LogEventInfo {
                 code,
                 dbt_core_code,
                 original_severity_number,
                 original_severity_text,
+                context,
                 location,
             }),
             TelemetryAttributes::LegacyLog(LegacyLogEventInfo {
@@ -2,10 +2,12 @@ use dbt_serde_yaml::JsonSchema;
 #[cfg(test)]
 use fake::Dummy;
 use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
 #[cfg(test)]
 use strum::EnumIter;
 use strum::{EnumDiscriminants, IntoStaticStr};
 
+#[skip_serializing_none]
 #[cfg_attr(test, derive(Dummy))]
 #[derive(
     Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, EnumDiscriminants, strum::Display,
@@ -32,41 +34,86 @@ use strum::{EnumDiscriminants, IntoStaticStr};
 pub enum BuildPhaseInfo {
     /// # File Discovery
     /// Analyzing dbt_project, profiles.yml and scanning files
-    Loading {},
+    Loading { peak_rss_bytes: Option<u64> },
 
     /// # Dependency Loading
     /// Check that dependencies are met
-    DependencyLoading {},
+    DependencyLoading { peak_rss_bytes: Option<u64> },
 
     /// # Parsing
     /// Parsing and macro name resolution of all dbt files
-    Parsing {},
+    Parsing { peak_rss_bytes: Option<u64> },
 
     /// # Scheduling
     /// Graph construction and graph slicing
-    Scheduling {},
+    Scheduling { peak_rss_bytes: Option<u64> },
 
     /// # Freshness Analysis
     /// Freshness analysis of sources and models
-    FreshnessAnalysis {},
+    FreshnessAnalysis { peak_rss_bytes: Option<u64> },
 
     /// # Lineage
     /// Analysis of individual node lineages
-    Lineage {},
+    Lineage { peak_rss_bytes: Option<u64> },
 
     /// # Compiling
     /// Dbt compile (called render) and Sql analysis
-    Compiling { node_count: u64 },
+    Compiling {
+        node_count: u64,
+        peak_rss_bytes: Option<u64>,
+    },
 
     /// # Hydrating
     /// Hydration of models, seeds, snapshots and sources
-    Hydrating { node_count: u64 },
+    Hydrating {
+        node_count: u64,
+        peak_rss_bytes: Option<u64>,
+    },
 
     /// # Analyzing
     /// Dbt compile (called render) and Sql analysis
-    Analyzing { node_count: u64 },
+    Analyzing {
+        node_count: u64,
+        peak_rss_bytes: Option<u64>,
+    },
 
     /// # Executing
     /// Execution against the target database
-    Executing { node_count: u64 },
+    Executing {
+        node_count: u64,
+        peak_rss_bytes: Option<u64>,
+    },
+}
+
+impl BuildPhaseInfo {
+    /// Peak resident set size of the process when the phase ended, if recorded
+    pub fn peak_rss_bytes(&self) -> Option<u64> {
+        match self {
+            Self::Loading { peak_rss_bytes }
+            | Self::DependencyLoading { peak_rss_bytes }
+            | Self::Parsing { peak_rss_bytes }
+            | Self::Scheduling { peak_rss_bytes }
+            | Self::FreshnessAnalysis { peak_rss_bytes }
+            | Self::Lineage { peak_rss_bytes }
+            | Self::Compiling { peak_rss_bytes, .. }
+            | Self::Hydrating { peak_rss_bytes, .. }
+            | Self::Analyzing { peak_rss_bytes, .. }
+            | Self::Executing { peak_rss_bytes, .. } => *peak_rss_bytes,
+        }
+    }
+
+    pub fn set_peak_rss_bytes(&mut self, bytes: u64) {
+        match self {
+            Self::Loading { peak_rss_bytes }
+            | Self::DependencyLoading { peak_rss_bytes }
+            | Self::Parsing { peak_rss_bytes }
+            | Self::Scheduling { peak_rss_bytes }
+            | Self::FreshnessAnalysis { peak_rss_bytes }
+            | Self::Lineage { peak_rss_bytes }
+            | Self::Compiling { peak_rss_bytes, .. }
+            | Self::Hydrating { peak_rss_bytes, .. }
+            | Self::Analyzing { peak_rss_bytes, .. }
+            | Self::Executing { peak_rss_bytes, .. } => *peak_rss_bytes = Some(bytes),
+        }
+    }
 }
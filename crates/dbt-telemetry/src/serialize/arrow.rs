@@ -153,6 +153,7 @@ pub struct ArrowAttributes<'a> {
     // Phase fields - BuildPhaseInfo union fields
     pub phase: Option<BuildPhase>,
     pub node_count: Option<u64>,
+    pub peak_rss_bytes: Option<u64>,
     // Node fields
     pub unique_id: Option<&'a str>,
     pub fqn: Option<&'a str>,
@@ -170,6 +171,7 @@ pub struct ArrowAttributes<'a> {
     pub dbt_core_code: Option<&'a str>,
     pub original_severity_number: Option<u8>,
     pub original_severity_text: Option<&'a str>,
+    pub context: Option<String>, // owned due to JSON serialization
     // WriteArtifact fields
     pub relative_path: Option<&'a str>,
     pub duration_ms: Option<u64>,
@@ -393,22 +395,24 @@ impl<'a> From<&'a TelemetryAttributes> for ArrowAttributes<'a> {
                 ..Default::default()
             },
             TelemetryAttributes::Phase(phase_info) => match phase_info {
-                BuildPhaseInfo::Loading {}
-                | BuildPhaseInfo::DependencyLoading {}
-                | BuildPhaseInfo::Parsing {}
-                | BuildPhaseInfo::Scheduling {}
-                | BuildPhaseInfo::FreshnessAnalysis {}
-                | BuildPhaseInfo::Lineage {} => ArrowAttributes {
+                BuildPhaseInfo::Loading { .. }
+                | BuildPhaseInfo::DependencyLoading { .. }
+                | BuildPhaseInfo::Parsing { .. }
+                | BuildPhaseInfo::Scheduling { .. }
+                | BuildPhaseInfo::FreshnessAnalysis { .. }
+                | BuildPhaseInfo::Lineage { .. } => ArrowAttributes {
                     phase: Some(phase_info.into()),
+                    peak_rss_bytes: phase_info.peak_rss_bytes(),
                     event_type: TelemetryAttributesType::from(attr),
                     ..Default::default()
                 },
-                BuildPhaseInfo::Analyzing { node_count }
-                | BuildPhaseInfo::Hydrating { node_count }
-                | BuildPhaseInfo::Compiling { node_count }
-                | BuildPhaseInfo::Executing { node_count } => ArrowAttributes {
+                BuildPhaseInfo::Analyzing { node_count, .. }
+                | BuildPhaseInfo::Hydrating { node_count, .. }
+                | BuildPhaseInfo::Compiling { node_count, .. }
+                | BuildPhaseInfo::Executing { node_count, .. } => ArrowAttributes {
                     phase: Some(phase_info.into()),
                     node_count: Some(*node_count),
+                    peak_rss_bytes: phase_info.peak_rss_bytes(),
                     event_type: TelemetryAttributesType::from(attr),
                     ..Default::default()
                 },
@@ -446,6 +450,7 @@ impl<'a> From<&'a TelemetryAttributes> for ArrowAttributes<'a> {
                 dbt_core_code,
                 original_severity_number,
                 original_severity_text,
+                context,
                 location,
             }) => ArrowAttributes {
                 file: location.file.as_deref(),
@@ -456,6 +461,9 @@ impl<'a> From<&'a TelemetryAttributes> for ArrowAttributes<'a> {
                 dbt_core_code: dbt_core_code.as_deref(),
                 original_severity_number: Some(*original_severity_number as u8),
                 original_severity_text: Some(original_severity_text.as_ref()),
+                context: (!context.is_empty()).then(|| {
+                    serde_json::to_string(context).expect("Failed to serialize context to JSON")
+                }),
                 event_type: TelemetryAttributesType::from(attr),
                 ..Default::default()
             },
@@ -838,24 +846,33 @@ impl TryFrom<ArrowAttributes<'_>> for TelemetryAttributes {
             })),
             TelemetryAttributesType::Phase => {
                 let phase = arrow.phase.ok_or("Missing phase for Phase attributes")?;
+                let peak_rss_bytes = arrow.peak_rss_bytes;
                 let phase_info = match phase {
-                    BuildPhase::Loading => BuildPhaseInfo::Loading {},
-                    BuildPhase::DependencyLoading => BuildPhaseInfo::DependencyLoading {},
-                    BuildPhase::Parsing => BuildPhaseInfo::Parsing {},
-                    BuildPhase::Scheduling => BuildPhaseInfo::Scheduling {},
-                    BuildPhase::FreshnessAnalysis => BuildPhaseInfo::FreshnessAnalysis {},
-                    BuildPhase::Lineage => BuildPhaseInfo::Lineage {},
+                    BuildPhase::Loading => BuildPhaseInfo::Loading { peak_rss_bytes },
+                    BuildPhase::DependencyLoading => {
+                        BuildPhaseInfo::DependencyLoading { peak_rss_bytes }
+                    }
+                    BuildPhase::Parsing => BuildPhaseInfo::Parsing { peak_rss_bytes },
+                    BuildPhase::Scheduling => BuildPhaseInfo::Scheduling { peak_rss_bytes },
+                    BuildPhase::FreshnessAnalysis => {
+                        BuildPhaseInfo::FreshnessAnalysis { peak_rss_bytes }
+                    }
+                    BuildPhase::Lineage => BuildPhaseInfo::Lineage { peak_rss_bytes },
                     BuildPhase::Analyzing => BuildPhaseInfo::Analyzing {
                         node_count: arrow.node_count.unwrap_or(0),
+                        peak_rss_bytes,
                     },
                     BuildPhase::Hydrating => BuildPhaseInfo::Hydrating {
                         node_count: arrow.node_count.unwrap_or(0),
+                        peak_rss_bytes,
                     },
                     BuildPhase::Compiling => BuildPhaseInfo::Compiling {
                         node_count: arrow.node_count.unwrap_or(0),
+                        peak_rss_bytes,
                     },
                     BuildPhase::Executing => BuildPhaseInfo::Executing {
                         node_count: arrow.node_count.unwrap_or(0),
+                        peak_rss_bytes,
                     },
                 };
                 Ok(TelemetryAttributes::Phase(phase_info))
@@ -921,6 +938,13 @@ impl TryFrom<ArrowAttributes<'_>> for TelemetryAttributes {
                         .original_severity_text
                         .unwrap_or("INFO")
                         .to_string(),
+                    context: arrow
+                        .context
+                        .as_deref()
+                        .map(serde_json::from_str)
+                        .transpose()
+                        .map_err(|e| format!("Failed to parse context JSON: {e}"))?
+                        .unwrap_or_default(),
                     location,
                 }))
             }
@@ -1177,23 +1201,39 @@ mod tests {
                 };
 
                 let phase_info = match phase {
-                    BuildPhase::Loading => BuildPhaseInfo::Loading {},
-                    BuildPhase::DependencyLoading => BuildPhaseInfo::DependencyLoading {},
-                    BuildPhase::Parsing => BuildPhaseInfo::Parsing {},
-                    BuildPhase::Scheduling => BuildPhaseInfo::Scheduling {},
-                    BuildPhase::FreshnessAnalysis => BuildPhaseInfo::FreshnessAnalysis {},
-                    BuildPhase::Lineage => BuildPhaseInfo::Lineage {},
+                    BuildPhase::Loading => BuildPhaseInfo::Loading {
+                        peak_rss_bytes: Faker.fake_with_rng(&mut rng),
+                    },
+                    BuildPhase::DependencyLoading => BuildPhaseInfo::DependencyLoading {
+                        peak_rss_bytes: Faker.fake_with_rng(&mut rng),
+                    },
+                    BuildPhase::Parsing => BuildPhaseInfo::Parsing {
+                        peak_rss_bytes: Faker.fake_with_rng(&mut rng),
+                    },
+                    BuildPhase::Scheduling => BuildPhaseInfo::Scheduling {
+                        peak_rss_bytes: Faker.fake_with_rng(&mut rng),
+                    },
+                    BuildPhase::FreshnessAnalysis => BuildPhaseInfo::FreshnessAnalysis {
+                        peak_rss_bytes: Faker.fake_with_rng(&mut rng),
+                    },
+                    BuildPhase::Lineage => BuildPhaseInfo::Lineage {
+                        peak_rss_bytes: Faker.fake_with_rng(&mut rng),
+                    },
                     BuildPhase::Analyzing => BuildPhaseInfo::Analyzing {
                         node_count: Faker.fake_with_rng(&mut rng),
+                        peak_rss_bytes: Faker.fake_with_rng(&mut rng),
                     },
                     BuildPhase::Hydrating => BuildPhaseInfo::Hydrating {
                         node_count: Faker.fake_with_rng(&mut rng),
+                        peak_rss_bytes: Faker.fake_with_rng(&mut rng),
                     },
                     BuildPhase::Compiling => BuildPhaseInfo::Compiling {
                         node_count: Faker.fake_with_rng(&mut rng),
+                        peak_rss_bytes: Faker.fake_with_rng(&mut rng),
                     },
                     BuildPhase::Executing => BuildPhaseInfo::Executing {
                         node_count: Faker.fake_with_rng(&mut rng),
+                        peak_rss_bytes: Faker.fake_with_rng(&mut rng),
                     },
                 };
                 TelemetryAttributes::Phase(phase_info)
@@ -24,6 +24,11 @@ pub const AUTH_REFRESH_TOKEN: &str = "adbc.bigquery.sql.auth.refresh_token";
 pub const AUTH_ACCESS_TOKEN_ENDPOINT: &str = "adbc.bigquery.sql.auth.access_token_endpoint";
 pub const AUTH_ACCESS_TOKEN_SERVER_NAME: &str = "adbc.bigquery.sql.auth.access_token_server_name";
 
+// service account impersonation on top of any of the auth types
+pub const IMPERSONATE_TARGET_PRINCIPAL: &str = "adbc.bigquery.sql.impersonate.target_principal";
+// comma-separated service accounts in the delegation chain
+pub const IMPERSONATE_DELEGATES: &str = "adbc.bigquery.sql.impersonate.delegates";
+
 // The parameter mode specifies if the query uses positional syntax ("?")
 // or the named syntax ("@p"). It is illegal to mix positional and named syntax.
 // Default is QUERY_PARAMETER_MODE_POSITIONAL.
@@ -49,11 +54,21 @@ pub const QUERY_USE_LEGACY_SQL: &str = "adbc.bigquery.sql.query.use_legacy_sql";
 pub const QUERY_DRY_RUN: &str = "adbc.bigquery.sql.query.dry_run"; // bool
 pub const QUERY_CREATE_SESSION: &str = "adbc.bigquery.sql.query.create_session"; // bool
 pub const QUERY_JOB_TIMEOUT: &str = "adbc.bigquery.sql.query.job_timeout"; // i64
+pub const QUERY_LABELS: &str = "adbc.bigquery.sql.query.labels"; // JSON object of string labels
 
 pub const QUERY_RESULT_BUFFER_SIZE: &str = "adbc.bigquery.sql.query.result_buffer_size"; // i64
 pub const QUERY_PREFETCH_CONCURRENCY: &str = "adbc.bigquery.sql.query.prefetch_concurrency"; // i64
 
+// keys of the result schema metadata
+pub const QUERY_RESULT_JOB_ID: &str = "adbc.bigquery.sql.query.job_id"; // string
+pub const QUERY_RESULT_TOTAL_BYTES_PROCESSED: &str =
+    "adbc.bigquery.sql.query.total_bytes_processed"; // i64
+pub const QUERY_RESULT_NUM_DML_AFFECTED_ROWS: &str =
+    "adbc.bigquery.sql.query.num_dml_affected_rows"; // i64
+
 // values
+/// Label key used to stamp the request id on BigQuery jobs.
+pub const REQUEST_ID_LABEL: &str = "dbt_request_id";
 pub const DEFAULT_QUERY_RESULT_BUFFER_SIZE: i64 = 200;
 pub const DEFAULT_QUERY_PREFETCH_CONCURRENCY: i64 = 10;
 
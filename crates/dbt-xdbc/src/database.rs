@@ -17,7 +17,8 @@ use parking_lot::RwLockUpgradableReadGuard;
 use serde::Deserialize;
 use siphasher::sip128::{Hasher128, SipHasher24};
 use std::sync::{Arc, LazyLock};
-use std::time::Duration;
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
 use std::{collections::HashSet, ffi::c_int};
 use std::{
     hash::{Hash, Hasher},
@@ -55,6 +56,13 @@ pub trait Database: Send + Sync + DatabaseInfo {
     fn get_option_int(&self, key: OptionDatabase) -> Result<i64>;
     fn get_option_double(&self, key: OptionDatabase) -> Result<f64>;
 
+    /// Eagerly open `n` connections in the background so the first
+    /// connections requested with [Database::new_connection] don't pay
+    /// the cost of connecting on the critical path.
+    ///
+    /// Databases that don't keep connections around ignore this.
+    fn prewarm(&mut self, _n: usize) {}
+
     /// Returns the [`AdbcVersion`] reported by the driver.
     fn adbc_version(&mut self) -> Result<AdbcVersion> {
         self.new_connection()?
@@ -249,6 +257,65 @@ impl TokenRefresher {
     }
 }
 
+/// Prewarmed connections older than this are closed instead of handed out.
+const PREWARMED_CONNECTION_MAX_IDLE: Duration = Duration::from_secs(5 * 60);
+
+/// Cheap liveness check for a prewarmed connection before it's handed out.
+fn is_connection_valid(conn: &dyn Connection) -> bool {
+    conn.get_info(Some(HashSet::from_iter([InfoCode::VendorName])))
+        .is_ok()
+}
+
+/// Connections opened ahead of time by [Database::prewarm] and waiting to
+/// be handed out.
+#[derive(Default)]
+struct PrewarmedConnections {
+    idle: parking_lot::Mutex<Vec<(Instant, Box<dyn Connection>)>>,
+}
+
+impl PrewarmedConnections {
+    fn len(&self) -> usize {
+        self.idle.lock().len()
+    }
+
+    /// Open `n` connections with `open` on a background thread.
+    ///
+    /// A semaphore permit is held while each connection is being opened so
+    /// prewarming doesn't exceed the configured concurrency. Connections that
+    /// fail to open are skipped: the error will surface again when a
+    /// connection is requested for real.
+    fn prewarm(
+        self: &Arc<Self>,
+        n: usize,
+        semaphore: Option<Arc<Semaphore>>,
+        open: impl Fn() -> Result<Box<dyn Connection>> + Send + 'static,
+    ) -> JoinHandle<()> {
+        let this = Arc::clone(self);
+        thread::spawn(move || {
+            for _ in 0..n {
+                let conn = {
+                    let _permit = semaphore.as_ref().map(|s| s.acquire());
+                    open()
+                };
+                if let Ok(conn) = conn {
+                    this.idle.lock().push((Instant::now(), conn));
+                }
+            }
+        })
+    }
+
+    /// Take a prewarmed connection, closing the ones that are stale or fail
+    /// the `is_valid` check along the way.
+    fn take(&self, is_valid: impl Fn(&dyn Connection) -> bool) -> Option<Box<dyn Connection>> {
+        loop {
+            let (opened_at, conn) = self.idle.lock().pop()?;
+            if opened_at.elapsed() < PREWARMED_CONNECTION_MAX_IDLE && is_valid(conn.as_ref()) {
+                return Some(conn);
+            }
+        }
+    }
+}
+
 struct InnerAdbcDatabase {
     pub(crate) backend: Backend,
     /// Readers-writer lock to protect the database state.
@@ -266,6 +333,8 @@ struct InnerAdbcDatabase {
     ///     last_conn_success >= -1
     ///     last_conn_success < conn_attempts
     last_conn_success: AtomicI64,
+    /// Connections opened by [Database::prewarm].
+    prewarmed: Arc<PrewarmedConnections>,
 }
 
 impl InnerAdbcDatabase {
@@ -280,6 +349,7 @@ impl InnerAdbcDatabase {
             token_refresher,
             conn_attempts: AtomicI64::new(0),
             last_conn_success: AtomicI64::new(-1),
+            prewarmed: Arc::default(),
         }
     }
 
@@ -514,10 +584,27 @@ impl Database for AdbcDatabase {
         &mut self,
         conn_opts: Vec<(OptionConnection, OptionValue)>,
     ) -> Result<Box<dyn Connection>> {
+        // Prewarmed connections were opened without connection options.
+        if conn_opts.is_empty() {
+            if let Some(conn) = self.inner.prewarmed.take(is_connection_valid) {
+                return Ok(conn);
+            }
+        }
         self.inner
             .new_connection_with_opts_impl(conn_opts, self.semaphore.clone())
     }
 
+    fn prewarm(&mut self, n: usize) {
+        let inner = Arc::clone(&self.inner);
+        let semaphore = self.semaphore.clone();
+        let _ = self
+            .inner
+            .prewarmed
+            .prewarm(n, self.semaphore.clone(), move || {
+                inner.new_connection_with_opts_impl(Vec::new(), semaphore.clone())
+            });
+    }
+
     fn set_option(&mut self, key: OptionDatabase, value: OptionValue) -> Result<()> {
         let mut managed_database = self.inner.managed_database.write();
         managed_database.set_option(key, value)
@@ -825,9 +912,81 @@ pub(crate) fn fingerprint_config<'a>(
 
 #[cfg(test)]
 mod tests {
-    use crate::database::{Fingerprint, fingerprint_config};
-    use crate::{Backend, database};
+    use crate::database::{
+        Fingerprint, PrewarmedConnections, fingerprint_config, is_connection_valid,
+    };
+    use crate::semaphore::Semaphore;
+    use crate::test_utils::MockConnection;
+    use crate::{Backend, Connection, database};
+    use adbc_core::error::{Error, Status};
     use std::collections::HashSet;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// Prewarm `n` connections from a mock driver that reports the
+    /// connection `invalid_id` (if any) as broken.
+    fn prewarm(
+        n: usize,
+        invalid_id: Option<usize>,
+    ) -> (Arc<PrewarmedConnections>, Arc<AtomicUsize>) {
+        let pool = Arc::new(PrewarmedConnections::default());
+        let opened = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&opened);
+        let semaphore = Some(Arc::new(Semaphore::new(1)));
+        pool.prewarm(n, semaphore, move || {
+            let id = counter.fetch_add(1, Ordering::SeqCst);
+            let conn = if Some(id) == invalid_id {
+                MockConnection::new().broken()
+            } else {
+                MockConnection::new()
+            };
+            Ok(Box::new(conn) as Box<dyn Connection>)
+        })
+        .join()
+        .unwrap();
+        (pool, opened)
+    }
+
+    #[test]
+    fn prewarm_opens_connections_to_be_reused() {
+        let (pool, opened) = prewarm(3, None);
+        assert_eq!(opened.load(Ordering::SeqCst), 3);
+        assert_eq!(pool.len(), 3);
+
+        assert!(pool.take(is_connection_valid).is_some());
+        // the checkout reused a prewarmed connection instead of opening one
+        assert_eq!(opened.load(Ordering::SeqCst), 3);
+        assert_eq!(pool.len(), 2);
+    }
+
+    #[test]
+    fn prewarm_skips_invalid_connections() {
+        // connections are handed out last-in first-out, so the broken
+        // connection is checked (and closed) first
+        let (pool, opened) = prewarm(2, Some(1));
+        assert!(pool.take(is_connection_valid).is_some());
+        assert_eq!(pool.len(), 0);
+        assert!(pool.take(is_connection_valid).is_none());
+        assert_eq!(opened.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn prewarm_skips_connections_that_fail_to_open() {
+        let pool = Arc::new(PrewarmedConnections::default());
+        let opened = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&opened);
+        pool.prewarm(3, None, move || {
+            if counter.fetch_add(1, Ordering::SeqCst) == 0 {
+                return Err(Error::with_message_and_status("refused", Status::IO));
+            }
+            Ok(Box::new(MockConnection::new()) as Box<dyn Connection>)
+        })
+        .join()
+        .unwrap();
+        // the failure didn't stop the remaining connections from being opened
+        assert_eq!(opened.load(Ordering::SeqCst), 3);
+        assert_eq!(pool.len(), 2);
+    }
 
     #[test]
     fn config_fingerprinting() {
@@ -15,11 +15,16 @@
 // specific language governing permissions and limitations
 // under the License.
 
-//! A parser for [`Duration`] following <https://pkg.go.dev/time#ParseDuration>
-//!
+//! Conversions between [`Duration`] and Go duration strings, following
+//! <https://pkg.go.dev/time#ParseDuration> and
+//! <https://pkg.go.dev/time#Duration.String>.
 //!
+//! The Go based ADBC drivers expect durations in this format for options such
+//! as timeouts.
 
-use std::{error::Error as StdError, num::IntErrorKind, sync::LazyLock, time::Duration};
+use std::{
+    error::Error as StdError, fmt::Write as _, num::IntErrorKind, sync::LazyLock, time::Duration,
+};
 
 use adbc_core::error::{Error, Result, Status};
 use regex::Regex;
@@ -55,7 +60,7 @@ fn bad_input<T>() -> Result<T> {
 /// implementation does not support negative values i.e. it ignores sign
 /// symbols, because [`Duration`] does not support negative values, and the Go
 /// Snowflake driver uses absolute values for these durations.
-pub(crate) fn parse_duration(input: &str) -> Result<Duration> {
+pub fn parse_go_duration(input: &str) -> Result<Duration> {
     // Drop sign symbols.
     let input = input.replace(['+', '-'], "");
 
@@ -127,6 +132,53 @@ pub(crate) fn parse_duration(input: &str) -> Result<Duration> {
         })
 }
 
+/// Format the given [`Duration`] the way Go formats a `time.Duration`, e.g.
+/// `10s`, `1m30s`, `2h0m0.5s` or `300ms`.
+///
+/// Durations under a second use the largest of the `ns`, `µs` and `ms` units
+/// that keeps the integer part non-zero. The output is accepted by
+/// [`parse_go_duration`].
+pub fn format_go_duration(duration: Duration) -> String {
+    if duration.is_zero() {
+        return "0s".to_string();
+    }
+
+    let nanos = duration.as_nanos();
+    if nanos < 1_000_000_000 {
+        let (unit, scale) = match nanos {
+            0..1_000 => ("ns", 1),
+            1_000..1_000_000 => ("µs", 1_000),
+            _ => ("ms", 1_000_000),
+        };
+        return format!("{}{unit}", format_decimal(nanos, scale));
+    }
+
+    let secs = duration.as_secs();
+    let (hours, minutes) = (secs / 3600, secs / 60 % 60);
+    let mut out = String::new();
+    if hours > 0 {
+        let _ = write!(out, "{hours}h");
+    }
+    if hours > 0 || minutes > 0 {
+        let _ = write!(out, "{minutes}m");
+    }
+    let sub_minute_nanos =
+        u128::from(secs % 60) * 1_000_000_000 + u128::from(duration.subsec_nanos());
+    let _ = write!(out, "{}s", format_decimal(sub_minute_nanos, 1_000_000_000));
+    out
+}
+
+/// Format `value / scale` without trailing zeros in the fractional part.
+fn format_decimal(value: u128, scale: u128) -> String {
+    let (int, frac) = (value / scale, value % scale);
+    if frac == 0 {
+        return int.to_string();
+    }
+    let width = scale.ilog10() as usize;
+    let frac = format!("{frac:0width$}");
+    format!("{int}.{}", frac.trim_end_matches('0'))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -134,58 +186,61 @@ mod tests {
     #[allow(clippy::cognitive_complexity)]
     #[test]
     fn parse_duration_test() {
-        assert_eq!(parse_duration("0"), Ok(Duration::from_secs(0)));
-        assert_eq!(parse_duration("0000.0000s"), Ok(Duration::from_secs(0)));
-        assert_eq!(parse_duration("5s"), Ok(Duration::from_secs(5)));
-        assert_eq!(parse_duration("30s"), Ok(Duration::from_secs(30)));
-        assert_eq!(parse_duration("1478s"), Ok(Duration::from_secs(1478)));
-        assert_eq!(parse_duration("-5s"), Ok(Duration::from_secs(5)));
-        assert_eq!(parse_duration("+5s"), Ok(Duration::from_secs(5)));
-        assert_eq!(parse_duration("-0"), Ok(Duration::from_secs(0)));
-        assert_eq!(parse_duration("+0"), Ok(Duration::from_secs(0)));
-        assert_eq!(parse_duration("5.0s"), Ok(Duration::from_secs(5)));
+        assert_eq!(parse_go_duration("0"), Ok(Duration::from_secs(0)));
+        assert_eq!(parse_go_duration("0000.0000s"), Ok(Duration::from_secs(0)));
+        assert_eq!(parse_go_duration("5s"), Ok(Duration::from_secs(5)));
+        assert_eq!(parse_go_duration("30s"), Ok(Duration::from_secs(30)));
+        assert_eq!(parse_go_duration("1478s"), Ok(Duration::from_secs(1478)));
+        assert_eq!(parse_go_duration("-5s"), Ok(Duration::from_secs(5)));
+        assert_eq!(parse_go_duration("+5s"), Ok(Duration::from_secs(5)));
+        assert_eq!(parse_go_duration("-0"), Ok(Duration::from_secs(0)));
+        assert_eq!(parse_go_duration("+0"), Ok(Duration::from_secs(0)));
+        assert_eq!(parse_go_duration("5.0s"), Ok(Duration::from_secs(5)));
         assert_eq!(
-            parse_duration("5.6s"),
+            parse_go_duration("5.6s"),
             Ok(Duration::from_secs(5) + Duration::from_millis(600))
         );
-        assert_eq!(parse_duration("5.s"), Ok(Duration::from_secs(5)));
-        assert_eq!(parse_duration(".5s"), Ok(Duration::from_millis(500)));
-        assert_eq!(parse_duration("1.0s"), Ok(Duration::from_secs(1)));
-        assert_eq!(parse_duration("1.00s"), Ok(Duration::from_secs(1)));
+        assert_eq!(parse_go_duration("5.s"), Ok(Duration::from_secs(5)));
+        assert_eq!(parse_go_duration(".5s"), Ok(Duration::from_millis(500)));
+        assert_eq!(parse_go_duration("1.0s"), Ok(Duration::from_secs(1)));
+        assert_eq!(parse_go_duration("1.00s"), Ok(Duration::from_secs(1)));
         assert_eq!(
-            parse_duration("1.004s"),
+            parse_go_duration("1.004s"),
             Ok(Duration::from_secs(1) + Duration::from_millis(4))
         );
         assert_eq!(
-            parse_duration("1.0040s"),
+            parse_go_duration("1.0040s"),
             Ok(Duration::from_secs(1) + Duration::from_millis(4))
         );
         assert_eq!(
-            parse_duration("100.00100s"),
+            parse_go_duration("100.00100s"),
             Ok(Duration::from_secs(100) + Duration::from_millis(1))
         );
-        assert_eq!(parse_duration("10ns"), Ok(Duration::from_nanos(10)));
-        assert_eq!(parse_duration("11us"), Ok(Duration::from_micros(11)));
-        assert_eq!(parse_duration("12µs"), Ok(Duration::from_micros(12)));
-        assert_eq!(parse_duration("12μs"), Ok(Duration::from_micros(12)));
-        assert_eq!(parse_duration("13ms"), Ok(Duration::from_millis(13)));
-        assert_eq!(parse_duration("14s"), Ok(Duration::from_secs(14)));
-        assert_eq!(parse_duration("15m"), Ok(Duration::from_secs(60 * 15)));
-        assert_eq!(parse_duration("16h"), Ok(Duration::from_secs(60 * 60 * 16)));
+        assert_eq!(parse_go_duration("10ns"), Ok(Duration::from_nanos(10)));
+        assert_eq!(parse_go_duration("11us"), Ok(Duration::from_micros(11)));
+        assert_eq!(parse_go_duration("12µs"), Ok(Duration::from_micros(12)));
+        assert_eq!(parse_go_duration("12μs"), Ok(Duration::from_micros(12)));
+        assert_eq!(parse_go_duration("13ms"), Ok(Duration::from_millis(13)));
+        assert_eq!(parse_go_duration("14s"), Ok(Duration::from_secs(14)));
+        assert_eq!(parse_go_duration("15m"), Ok(Duration::from_secs(60 * 15)));
         assert_eq!(
-            parse_duration("3h30m"),
+            parse_go_duration("16h"),
+            Ok(Duration::from_secs(60 * 60 * 16))
+        );
+        assert_eq!(
+            parse_go_duration("3h30m"),
             Ok(Duration::from_secs(60 * 60 * 3) + Duration::from_secs(60 * 30))
         );
         assert_eq!(
-            parse_duration("10.5s4m"),
+            parse_go_duration("10.5s4m"),
             Ok(Duration::from_secs(10) + Duration::from_millis(500) + Duration::from_secs(60 * 4))
         );
         assert_eq!(
-            parse_duration("-2m3.4s"),
+            parse_go_duration("-2m3.4s"),
             Ok(Duration::from_secs(60 * 2) + Duration::from_secs(3) + Duration::from_millis(400))
         );
         assert_eq!(
-            parse_duration("1h2m3s4ms5us6ns"),
+            parse_go_duration("1h2m3s4ms5us6ns"),
             Ok(Duration::from_secs(60 * 60)
                 + Duration::from_secs(60 * 2)
                 + Duration::from_secs(3)
@@ -194,71 +249,71 @@ mod tests {
                 + Duration::from_nanos(6))
         );
         assert_eq!(
-            parse_duration("39h9m14.425s"),
+            parse_go_duration("39h9m14.425s"),
             Ok(Duration::from_secs(60 * 60 * 39)
                 + Duration::from_secs(60 * 9)
                 + Duration::from_secs(14)
                 + Duration::from_millis(425))
         );
         assert_eq!(
-            parse_duration("52763797000ns"),
+            parse_go_duration("52763797000ns"),
             Ok(Duration::from_nanos(52763797000))
         );
         assert_eq!(
-            parse_duration("0.3333333333333333333h"),
+            parse_go_duration("0.3333333333333333333h"),
             Ok(Duration::from_secs(60 * 20))
         );
         assert_eq!(
-            parse_duration("9007199254740993ns"),
+            parse_go_duration("9007199254740993ns"),
             Ok(Duration::from_nanos(9007199254740993))
         );
         assert_eq!(
-            parse_duration("9223372036854775807ns"),
+            parse_go_duration("9223372036854775807ns"),
             Ok(Duration::from_nanos(9223372036854775807))
         );
         assert_eq!(
-            parse_duration("9223372036854775.807us"),
+            parse_go_duration("9223372036854775.807us"),
             Ok(Duration::from_micros(9223372036854775) + Duration::from_nanos(807))
         );
         assert_eq!(
-            parse_duration("9223372036s854ms775us807ns"),
+            parse_go_duration("9223372036s854ms775us807ns"),
             Ok(Duration::from_secs(9223372036)
                 + Duration::from_millis(854)
                 + Duration::from_micros(775)
                 + Duration::from_nanos(807))
         );
         assert_eq!(
-            parse_duration("-9223372036854775808ns"),
+            parse_go_duration("-9223372036854775808ns"),
             Ok(Duration::from_nanos(9223372036854775808))
         );
         assert_eq!(
-            parse_duration("-9223372036854775.808us"),
+            parse_go_duration("-9223372036854775.808us"),
             Ok(Duration::from_micros(9223372036854775) + Duration::from_nanos(808))
         );
         assert_eq!(
-            parse_duration("-9223372036s854ms775us808ns"),
+            parse_go_duration("-9223372036s854ms775us808ns"),
             Ok(Duration::from_secs(9223372036)
                 + Duration::from_millis(854)
                 + Duration::from_micros(775)
                 + Duration::from_nanos(808))
         );
         assert_eq!(
-            parse_duration("-9223372036854775808ns"),
+            parse_go_duration("-9223372036854775808ns"),
             Ok(Duration::from_nanos(9223372036854775808))
         );
         assert_eq!(
-            parse_duration("-2562047h47m16.854775808s"),
+            parse_go_duration("-2562047h47m16.854775808s"),
             Ok(Duration::from_secs(60 * 60 * 2562047)
                 + Duration::from_secs(60 * 47)
                 + Duration::from_secs(16)
                 + Duration::from_nanos(854775808))
         );
         assert_eq!(
-            parse_duration("0.100000000000000000000h"),
+            parse_go_duration("0.100000000000000000000h"),
             Ok(Duration::from_secs(60 * 6))
         );
         assert_eq!(
-            parse_duration("0.830103483285477580700h"),
+            parse_go_duration("0.830103483285477580700h"),
             Ok(Duration::from_secs(60 * 49)
                 + Duration::from_secs(48)
                 + Duration::from_nanos(372539828))
@@ -266,21 +321,42 @@ mod tests {
         let bad_input = Err(invalid_arg(
             "invalid duration (valid durations are a sequence of decimal numbers, each with optional fraction and a unit suffix, such as 300ms, 1.5h, 2h45m, valid time units are ns, us, ms, s, m, h)",
         ));
-        assert_eq!(parse_duration(""), bad_input);
-        assert_eq!(parse_duration("3"), bad_input);
-        assert_eq!(parse_duration("-"), bad_input);
-        assert_eq!(parse_duration("s"), bad_input);
-        assert_eq!(parse_duration("."), bad_input);
-        assert_eq!(parse_duration("-."), bad_input);
-        assert_eq!(parse_duration(".s"), bad_input);
-        assert_eq!(parse_duration("+.s"), bad_input);
-        assert_eq!(parse_duration("1d"), bad_input);
+        assert_eq!(parse_go_duration(""), bad_input);
+        assert_eq!(parse_go_duration("3"), bad_input);
+        assert_eq!(parse_go_duration("-"), bad_input);
+        assert_eq!(parse_go_duration("s"), bad_input);
+        assert_eq!(parse_go_duration("."), bad_input);
+        assert_eq!(parse_go_duration("-."), bad_input);
+        assert_eq!(parse_go_duration(".s"), bad_input);
+        assert_eq!(parse_go_duration("+.s"), bad_input);
+        assert_eq!(parse_go_duration("1d"), bad_input);
         assert_eq!(
-            parse_duration("1.1ns"),
+            parse_go_duration("1.1ns"),
             Err(invalid_arg(
                 "unexpected fractional part for duration with ns unit"
             ))
         );
-        assert_eq!(parse_duration("9999999999999999h"), Err(overflow()));
+        assert_eq!(parse_go_duration("9999999999999999h"), Err(overflow()));
+    }
+
+    #[test]
+    fn format_duration_test() {
+        assert_eq!(format_go_duration(Duration::ZERO), "0s");
+        assert_eq!(format_go_duration(Duration::from_secs(10)), "10s");
+        assert_eq!(format_go_duration(Duration::from_secs(90)), "1m30s");
+        assert_eq!(format_go_duration(Duration::from_secs(3600)), "1h0m0s");
+        assert_eq!(format_go_duration(Duration::from_millis(1500)), "1.5s");
+        assert_eq!(format_go_duration(Duration::from_millis(300)), "300ms");
+        assert_eq!(format_go_duration(Duration::from_micros(1500)), "1.5ms");
+        assert_eq!(format_go_duration(Duration::from_nanos(12)), "12ns");
+    }
+
+    #[test]
+    fn round_trip_test() {
+        for input in ["10s", "1m30s", "2h45m0s", "1.5s", "300ms", "12µs", "7ns"] {
+            let duration = parse_go_duration(input).unwrap();
+            assert_eq!(format_go_duration(duration), input);
+        }
+        assert!(parse_go_duration("abc").is_err());
     }
 }
@@ -36,6 +36,8 @@ pub use database::Database;
 pub mod connection;
 pub use connection::Connection;
 
+pub mod duration;
+
 pub mod statement;
 pub use statement::Statement;
 
@@ -45,6 +47,9 @@ pub use query_ctx::QueryCtx;
 pub mod semaphore;
 pub mod sql;
 
+#[cfg(any(test, feature = "test-util"))]
+pub mod test_utils;
+
 #[cfg(feature = "odbc")]
 pub(crate) mod odbc;
 #[cfg(feature = "odbc")]
@@ -52,8 +57,6 @@ pub(crate) mod odbc_api;
 
 pub(crate) mod builder;
 pub(crate) mod checksums;
-#[allow(dead_code)]
-pub(crate) mod duration;
 pub(crate) mod install;
 
 // Constants for different backends
@@ -211,6 +211,18 @@ impl ManagedOdbcStatementInner {
         sql_cancel_handle(HandleType::Stmt, state.hstmt as Handle)
     }
 
+    /// Close the cursor of the last execution and forget the query, so that
+    /// another query can be set on the statement.
+    pub fn reset(&self) -> Result<()> {
+        let state = &mut *self.lock_state().unwrap();
+        state.sql_query = None;
+        state.prepared = false;
+        state.has_data_after_execute = false;
+        state.active_cursor += 1; // invalidate active cursor
+        sql_close_cursor(state.hstmt)
+            .map_err(|e| error_with_diagnostics(e, HandleType::Stmt, state.hstmt as Handle))
+    }
+
     pub fn set_sql_query(&self, query: &str) -> Result<()> {
         let state = &mut *self.lock_state().unwrap();
         if state.prepared {
@@ -303,6 +315,10 @@ impl ManagedOdbcStatement {
         self.inner.cancel()
     }
 
+    pub fn reset(&self) -> Result<()> {
+        self.inner.reset()
+    }
+
     pub fn set_sql_query(&self, query: &str) -> Result<()> {
         self.inner.set_sql_query(query)
     }
@@ -220,6 +220,11 @@ pub fn sql_cancel_handle(handle_type: HandleType, handle: Handle) -> Result<()>
     retcode_to_result(retcode)
 }
 
+pub fn sql_close_cursor(hstmt: HStmt) -> Result<()> {
+    let retcode = unsafe { SQLFreeStmt(hstmt, FreeStmtOption::Close) };
+    retcode_to_result(retcode)
+}
+
 pub fn sql_end_tran(
     handle_type: HandleType,
     handle: Handle,
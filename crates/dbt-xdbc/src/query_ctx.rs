@@ -1,6 +1,8 @@
 //! Query abstraction used to carry the query sources and associated
 //! metadata around adapter code.
 
+use std::collections::BTreeMap;
+
 use chrono::{DateTime, Utc};
 
 /// Query source plus metadata.
@@ -16,6 +18,10 @@ pub struct QueryCtx {
     created_at: DateTime<Utc>,
     // Description (abribrary string) associated with the query
     desc: Option<String>,
+    // Correlation id propagated to telemetry and warehouse query tags
+    request_id: Option<String>,
+    // Labels attached to the warehouse job (BigQuery only)
+    labels: BTreeMap<String, String>,
 }
 
 impl QueryCtx {
@@ -24,6 +30,8 @@ impl QueryCtx {
         node_unique_id: Option<String>,
         sql: Option<String>,
         desc: Option<String>,
+        request_id: Option<String>,
+        labels: BTreeMap<String, String>,
     ) -> Self {
         Self {
             adapter_type: adapter_type.into(),
@@ -31,12 +39,14 @@ impl QueryCtx {
             sql,
             created_at: Utc::now(),
             desc,
+            request_id,
+            labels,
         }
     }
 
     /// Create a new query with the given adapter type.
     pub fn new(adapter_type: impl Into<String>) -> Self {
-        Self::create(adapter_type, None, None, None)
+        Self::create(adapter_type, None, None, None, None, BTreeMap::new())
     }
 
     /// Creates a new context by keeping other fields same but
@@ -49,6 +59,8 @@ impl QueryCtx {
             Some(node_unique_id.into()),
             self.sql.clone(),
             self.desc.clone(),
+            self.request_id.clone(),
+            self.labels.clone(),
         )
     }
 
@@ -61,6 +73,8 @@ impl QueryCtx {
             self.node_unique_id.clone(),
             Some(sql.into()),
             self.desc.clone(),
+            self.request_id.clone(),
+            self.labels.clone(),
         )
     }
 
@@ -74,6 +88,41 @@ impl QueryCtx {
             self.node_unique_id.clone(),
             self.sql.clone(),
             Some(desc.into()),
+            self.request_id.clone(),
+            self.labels.clone(),
+        )
+    }
+
+    /// Create a new context by keeping other fields same and using
+    /// the given correlation/request id.
+    pub fn with_request_id(&self, request_id: impl Into<String>) -> Self {
+        // We never allow one to reassign the request id
+        assert!(self.request_id.is_none());
+        Self::create(
+            self.adapter_type.clone(),
+            self.node_unique_id.clone(),
+            self.sql.clone(),
+            self.desc.clone(),
+            Some(request_id.into()),
+            self.labels.clone(),
+        )
+    }
+
+    /// Create a new context by keeping other fields same and adding
+    /// the given job labels.
+    ///
+    /// Labels are validated against BigQuery's restrictions when the
+    /// query is executed.
+    pub fn with_labels(&self, labels: BTreeMap<String, String>) -> Self {
+        let mut merged = self.labels.clone();
+        merged.extend(labels);
+        Self::create(
+            self.adapter_type.clone(),
+            self.node_unique_id.clone(),
+            self.sql.clone(),
+            self.desc.clone(),
+            self.request_id.clone(),
+            merged,
         )
     }
 
@@ -108,6 +157,17 @@ impl QueryCtx {
     pub fn desc(&self) -> Option<String> {
         self.desc.clone()
     }
+
+    /// Returns the correlation/request id associated with the
+    /// context, if any.
+    pub fn request_id(&self) -> Option<&str> {
+        self.request_id.as_deref()
+    }
+
+    /// Returns the job labels associated with the context.
+    pub fn labels(&self) -> &BTreeMap<String, String> {
+        &self.labels
+    }
 }
 
 #[cfg(test)]
@@ -145,4 +205,39 @@ mod tests {
         let query_ctx = QueryCtx::new("fake").with_sql("select 1");
         assert_eq!(query_ctx.sql().unwrap(), "select 1");
     }
+
+    #[test]
+    fn test_request_id() {
+        let query_ctx = QueryCtx::new("fake").with_request_id("req-42");
+        assert_eq!(query_ctx.request_id(), Some("req-42"));
+
+        // The id survives clones and derived contexts
+        let derived = query_ctx
+            .clone()
+            .with_node_id("123")
+            .with_sql("select 1")
+            .with_desc("a query");
+        assert_eq!(derived.request_id(), Some("req-42"));
+        assert_eq!(QueryCtx::new("fake").request_id(), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_request_id_twice() {
+        QueryCtx::new("fake")
+            .with_request_id("abc")
+            .with_request_id("123");
+    }
+
+    #[test]
+    fn test_labels() {
+        let labels = BTreeMap::from([("model".to_string(), "orders".to_string())]);
+        let query_ctx = QueryCtx::new("fake").with_labels(labels);
+        let derived = query_ctx
+            .with_sql("select 1")
+            .with_labels(BTreeMap::from([("team".to_string(), "data".to_string())]));
+        assert_eq!(derived.labels().len(), 2);
+        assert_eq!(derived.labels()["model"], "orders");
+        assert!(QueryCtx::new("fake").labels().is_empty());
+    }
 }
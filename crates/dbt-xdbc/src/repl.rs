@@ -10,7 +10,9 @@ use dbt_common::pretty_table::{DisplayFormat, pretty_data_table};
 use dialoguer::{BasicHistory, Input, theme::ColorfulTheme};
 
 use crate::{
-    Backend, Connection, Database, Driver, QueryCtx, bigquery, connection,
+    BIGQUERY_DRIVER_VERSION, Backend, Connection, DATABRICKS_DRIVER_VERSION, Database, Driver,
+    POSTGRES_DRIVER_VERSION, QueryCtx, REDSHIFT_DRIVER_VERSION, SALESFORCE_DRIVER_VERSION,
+    SNOWFLAKE_DRIVER_VERSION, bigquery, connection,
     database::{self, LogLevel},
     driver, snowflake,
 };
@@ -189,9 +191,12 @@ enum Command {
     ReloadDriver,
     ShowSchema,
     ShowBatch,
+    ListDrivers,
     Help,
     Quit,
-    Invalid,
+    // a known command with malformed arguments
+    Usage { usage: &'static str },
+    Invalid { command: String },
 }
 
 fn parse_command(line: &str) -> Option<Command> {
@@ -203,26 +208,55 @@ fn parse_command(line: &str) -> Option<Command> {
         });
     };
 
-    match line {
-        "exit" | "quit" => Some(Command::Quit),
-        "help" => Some(Command::Help),
-        "reload" => Some(Command::ReloadDriver),
-        "show-schema" => Some(Command::ShowSchema),
-        "show-batch" => Some(Command::ShowBatch),
-        "prev" => Some(Command::Move { delta: -1 }),
-        "next" => Some(Command::Move { delta: 1 }),
-        "move" => {
-            let parts: Vec<&str> = line.split_whitespace().collect();
-            if parts.len() != 2 {
-                Some(Command::Invalid)
-            } else if let Ok(delta) = parts[1].parse::<isize>() {
-                Some(Command::Move { delta })
-            } else {
-                Some(Command::Invalid)
-            }
-        }
-        _ => Some(Command::Invalid),
+    let (name, args) = line
+        .split_once(char::is_whitespace)
+        .map(|(name, args)| (name, args.trim()))
+        .unwrap_or((line, ""));
+    match (name, args) {
+        ("exit" | "quit", "") => Some(Command::Quit),
+        ("help", "") => Some(Command::Help),
+        ("reload", "") => Some(Command::ReloadDriver),
+        ("show-schema", "") => Some(Command::ShowSchema),
+        ("show-batch", "") => Some(Command::ShowBatch),
+        ("drivers", "") => Some(Command::ListDrivers),
+        ("prev", "") => Some(Command::Move { delta: -1 }),
+        ("next", "") => Some(Command::Move { delta: 1 }),
+        ("move", args) => match args.parse::<isize>() {
+            Ok(delta) => Some(Command::Move { delta }),
+            Err(_) => Some(Command::Usage {
+                usage: ":move <int>",
+            }),
+        },
+        _ => Some(Command::Invalid {
+            command: line.to_string(),
+        }),
+    }
+}
+
+/// The ADBC backends with the driver versions this build installs.
+const DRIVERS: [(Backend, &str); 6] = [
+    (Backend::Snowflake, SNOWFLAKE_DRIVER_VERSION),
+    (Backend::BigQuery, BIGQUERY_DRIVER_VERSION),
+    (Backend::Postgres, POSTGRES_DRIVER_VERSION),
+    (Backend::Databricks, DATABRICKS_DRIVER_VERSION),
+    (Backend::Redshift, REDSHIFT_DRIVER_VERSION),
+    (Backend::Salesforce, SALESFORCE_DRIVER_VERSION),
+];
+
+// Formats the available drivers and their versions as a table
+fn format_drivers_table() -> String {
+    let width = DRIVERS
+        .iter()
+        .map(|(backend, _)| backend.to_string().len())
+        .max()
+        .unwrap_or_default()
+        .max("Backend".len());
+    let mut table = format!("{:<width$}  Version\n", "Backend");
+    table.push_str(&format!("{:-<width$}  {:-<7}\n", "", ""));
+    for (backend, version) in DRIVERS {
+        table.push_str(&format!("{:<width$}  {version}\n", backend.to_string()));
     }
+    table
 }
 
 // Prints a visualization of a schema to stdout
@@ -318,6 +352,7 @@ pub async fn run_repl(backend_str: &str) -> Result<()> {
                 println!("  <query>         - Execute SQL query");
                 println!("  :show-schema    - Show current schema");
                 println!("  :show-batch     - Show current batch");
+                println!("  :drivers        - List available drivers and their versions");
                 println!(
                     "  :move <int>     - Move current batch pointer. Negative values move backwards, positive values move forwards."
                 );
@@ -364,6 +399,9 @@ pub async fn run_repl(backend_str: &str) -> Result<()> {
                     println!("No batch found!");
                 }
             }
+            Some(Command::ListDrivers) => {
+                print!("{}", format_drivers_table());
+            }
             Some(Command::ReloadDriver) => {
                 // TODO(jasonlin45) the actual binary ends up cached in driver.rs
                 println!("Reloading driver...");
@@ -371,8 +409,11 @@ pub async fn run_repl(backend_str: &str) -> Result<()> {
                 println!("Driver reloaded successfully");
             }
             Some(Command::Quit) => break,
-            Some(Command::Invalid) => {
-                eprintln!("Invalid command. Type :help for available commands");
+            Some(Command::Usage { usage }) => {
+                eprintln!("Usage: {usage}. Type :help for available commands");
+            }
+            Some(Command::Invalid { command }) => {
+                eprintln!("Unknown command `:{command}`. Type :help for available commands");
             }
             None => {}
         }
@@ -380,3 +421,51 @@ pub async fn run_repl(backend_str: &str) -> Result<()> {
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_drivers_command() {
+        assert!(matches!(
+            parse_command(":drivers"),
+            Some(Command::ListDrivers)
+        ));
+    }
+
+    #[test]
+    fn test_parse_unknown_command() {
+        assert!(matches!(
+            parse_command(":foo"),
+            Some(Command::Invalid { command }) if command == "foo"
+        ));
+    }
+
+    #[test]
+    fn test_parse_move_command() {
+        assert!(matches!(
+            parse_command(":move -3"),
+            Some(Command::Move { delta: -3 })
+        ));
+        for malformed in [":move", ":move x", ":move 1 2"] {
+            assert!(
+                matches!(
+                    parse_command(malformed),
+                    Some(Command::Usage {
+                        usage: ":move <int>"
+                    })
+                ),
+                "{malformed}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_format_drivers_table() {
+        let table = format_drivers_table();
+        assert!(table.starts_with("Backend     Version\n"), "{table}");
+        assert!(table.contains(&format!("Snowflake   {SNOWFLAKE_DRIVER_VERSION}\n")));
+        assert_eq!(table.lines().count(), DRIVERS.len() + 2);
+    }
+}
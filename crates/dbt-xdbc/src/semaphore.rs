@@ -14,12 +14,74 @@ struct AtomicSemaphoreBase {
     /// `u32` was chosen because that is the atomic that Linux uses for futexes,
     /// and as such, the type chosen by the `atomic-wait` crate.
     a: AtomicU32,
+    /// Wait queue used to hand out permits in request order, if fair.
+    queue: Option<TicketQueue>,
+}
+
+/// FIFO wait queue based on tickets.
+///
+/// Every acquirer takes a ticket and waits until it is being served. Only the
+/// thread at the head of the queue competes for a permit, so permits are handed
+/// out in the order in which they were requested.
+struct TicketQueue {
+    /// The ticket that will be handed to the next thread joining the queue.
+    next: AtomicU32,
+    /// The ticket of the thread at the head of the queue.
+    serving: AtomicU32,
+}
+
+impl TicketQueue {
+    const fn new() -> Self {
+        Self {
+            next: AtomicU32::new(0),
+            serving: AtomicU32::new(0),
+        }
+    }
+
+    /// Join the queue and block until this thread is at its head.
+    fn enter(&self) {
+        let ticket = self.next.fetch_add(1, Ordering::Relaxed);
+        loop {
+            let serving = self.serving.load(Ordering::Acquire);
+            if serving == ticket {
+                break;
+            }
+            atomic_wait::wait(&self.serving, serving);
+        }
+    }
+
+    /// Join the queue only if it is empty, without blocking.
+    fn try_enter(&self) -> bool {
+        let serving = self.serving.load(Ordering::Acquire);
+        self.next
+            .compare_exchange(
+                serving,
+                serving.wrapping_add(1),
+                Ordering::Relaxed,
+                Ordering::Relaxed,
+            )
+            .is_ok()
+    }
+
+    /// Leave the head of the queue, letting the next thread in line proceed.
+    fn leave(&self) {
+        self.serving.fetch_add(1, Ordering::Release);
+        atomic_wait::wake_all(&self.serving);
+    }
 }
 
 impl AtomicSemaphoreBase {
     pub const fn new(count: u32) -> Self {
         let a = AtomicU32::new(count);
-        Self { a }
+        Self { a, queue: None }
+    }
+
+    pub const fn new_fair(count: u32) -> Self {
+        let a = AtomicU32::new(count);
+        Self {
+            a,
+            queue: Some(TicketQueue::new()),
+        }
     }
 
     /// Releases `update` semaphore permits.
@@ -53,7 +115,23 @@ impl AtomicSemaphoreBase {
                 .is_ok()
     }
 
+    /// Run `acquire` once this thread is at the head of the wait queue, if fair.
+    #[inline]
+    fn in_turn(&self, acquire: impl FnOnce(&Self)) {
+        if let Some(queue) = &self.queue {
+            queue.enter();
+            acquire(self);
+            queue.leave();
+        } else {
+            acquire(self);
+        }
+    }
+
     pub fn acquire(&self) {
+        self.in_turn(Self::acquire_unfair);
+    }
+
+    fn acquire_unfair(&self) {
         loop {
             // wait until the value is not 0 anymore
             atomic_wait::wait(&self.a, 0);
@@ -65,11 +143,31 @@ impl AtomicSemaphoreBase {
     }
 
     pub fn try_acquire(&self) -> bool {
-        let old = self.a.load(Ordering::Acquire);
-        self.try_acquire_impl(old, 1)
+        match &self.queue {
+            // Don't jump ahead of threads that are already waiting
+            Some(queue) if !queue.try_enter() => false,
+            Some(queue) => {
+                let old = self.a.load(Ordering::Acquire);
+                let acquired = self.try_acquire_impl(old, 1);
+                queue.leave();
+                acquired
+            }
+            None => {
+                let old = self.a.load(Ordering::Acquire);
+                self.try_acquire_impl(old, 1)
+            }
+        }
+    }
+
+    pub fn available(&self) -> u32 {
+        self.a.load(Ordering::Relaxed)
     }
 
     pub fn acquire_many(&self, ask: u32) {
+        self.in_turn(|base| base.acquire_many_unfair(ask));
+    }
+
+    fn acquire_many_unfair(&self, ask: u32) {
         debug_assert!(ask > 0, "cannot acquire zero permits");
         let mut insufficient = (ask - 1).min(self.a.load(Ordering::Relaxed));
         loop {
@@ -103,11 +201,32 @@ impl Semaphore {
         }
     }
 
+    /// Like [Semaphore::new], but permits are handed out in the order in which
+    /// they were requested.
+    ///
+    /// Under contention, a non-fair semaphore may let some threads starve while
+    /// others repeatedly grab the released permits. A fair semaphore prevents
+    /// that at the cost of a little more overhead on every acquisition.
+    pub const fn new_fair(count: u32) -> Self {
+        debug_assert!(count > 0, "Semaphore must allow for at least one permit");
+        Self {
+            max: count,
+            base: AtomicSemaphoreBase::new_fair(count),
+        }
+    }
+
     /// Get the number of available permits the semaphore started with.
     pub fn max(&self) -> u32 {
         self.max
     }
 
+    /// Get the number of permits that are currently available.
+    ///
+    /// Only meant for diagnostics: the value may be stale by the time it's used.
+    pub fn available_permits(&self) -> u32 {
+        self.base.available()
+    }
+
     /// Acquire a permit, blocking until one is available.
     #[must_use]
     pub fn acquire(&self) -> PermitGuard<'_, false> {
@@ -143,7 +262,8 @@ impl fmt::Debug for Semaphore {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_struct("Semaphore")
             .field("max", &self.max)
-            .field("available", &self.base.a.load(Ordering::Relaxed))
+            .field("available", &self.base.available())
+            .field("fair", &self.base.queue.is_some())
             .finish()
     }
 }
@@ -163,6 +283,17 @@ impl AcquireAllSemaphore {
         }
     }
 
+    /// Like [AcquireAllSemaphore::new], but permits are handed out in the order
+    /// in which they were requested, see [Semaphore::new_fair].
+    ///
+    /// A thread waiting for all permits keeps its place in line: threads asking
+    /// for a single permit after it wait until it has acquired and released them.
+    pub const fn new_fair(count: u32) -> Self {
+        Self {
+            inner: Semaphore::new_fair(count),
+        }
+    }
+
     /// Get the number of available permits the semaphore started with.
     pub fn max(&self) -> u32 {
         self.inner.max()
@@ -252,8 +383,8 @@ impl Drop for PermitGuardAll<'_> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::sync::Arc;
     use std::sync::mpsc::channel;
+    use std::sync::{Arc, Mutex};
     use std::thread;
     use std::time::Duration;
 
@@ -377,6 +508,87 @@ mod tests {
         let _ = rx.recv();
     }
 
+    #[test]
+    fn test_semaphore_fair_acquisition_order() {
+        const WAITERS: u32 = 16;
+        let semaphore = Arc::new(Semaphore::new_fair(1));
+        let order = Arc::new(Mutex::new(vec![]));
+
+        let permit = semaphore.acquire();
+        assert_eq!(semaphore.available_permits(), 0);
+
+        let mut handles = vec![];
+        for i in 0..WAITERS {
+            let sem = semaphore.clone();
+            let order = order.clone();
+            handles.push(thread::spawn(move || {
+                let permit = sem.acquire();
+                order.lock().unwrap().push(i);
+                drop(permit);
+            }));
+            // Wait until the thread has queued before spawning the next one
+            let queue = semaphore.base.queue.as_ref().unwrap();
+            while queue.next.load(Ordering::Relaxed) < i + 2 {
+                thread::yield_now();
+            }
+        }
+        // A new request must not jump ahead of the queued waiters
+        assert!(semaphore.try_acquire().is_none());
+
+        drop(permit);
+        for handle in handles {
+            handle.join().unwrap();
+        }
+        assert_eq!(*order.lock().unwrap(), (0..WAITERS).collect::<Vec<_>>());
+        assert_eq!(semaphore.available_permits(), 1);
+        assert!(semaphore.try_acquire().is_some());
+    }
+
+    #[test]
+    fn test_semaphore_fair_acquire_all_keeps_its_turn() {
+        let semaphore = Arc::new(AcquireAllSemaphore::new_fair(2));
+        let order = Arc::new(Mutex::new(vec![]));
+        let queue = semaphore.inner.base.queue.as_ref().unwrap();
+
+        let permit = semaphore.acquire();
+
+        // Queues for both permits while only one is available
+        let all = {
+            let sem = semaphore.clone();
+            let order = order.clone();
+            thread::spawn(move || {
+                let permits = sem.acquire_all();
+                order.lock().unwrap().push("all");
+                drop(permits);
+            })
+        };
+        while queue.next.load(Ordering::Relaxed) < 2 {
+            thread::yield_now();
+        }
+
+        // A permit is available, but a single acquisition must wait its turn
+        let one = {
+            let sem = semaphore.clone();
+            let order = order.clone();
+            thread::spawn(move || {
+                let permit = sem.acquire();
+                order.lock().unwrap().push("one");
+                drop(permit);
+            })
+        };
+        while queue.next.load(Ordering::Relaxed) < 3 {
+            thread::yield_now();
+        }
+        assert!(semaphore.try_acquire().is_none());
+        assert_eq!(semaphore.inner.available_permits(), 1);
+
+        drop(permit);
+        all.join().unwrap();
+        one.join().unwrap();
+        assert_eq!(*order.lock().unwrap(), vec!["all", "one"]);
+        assert_eq!(semaphore.inner.available_permits(), 2);
+    }
+
     #[test]
     #[should_panic]
     fn test_semaphore_zero_permits() {
@@ -42,9 +42,29 @@ pub const CLIENT_CACHE_MFA_TOKEN: &str = "adbc.snowflake.sql.client_option.cache
 // Turns on caching for several methods: externalbrowser, (later) OAuth-related methods
 pub const CLIENT_STORE_TEMP_CREDS: &str = "adbc.snowflake.sql.client_option.store_temp_creds";
 
+// Names of Statement options --------------------------------------------
+
+/// Number of seconds after which a running statement is cancelled.
+pub const STATEMENT_TIMEOUT_IN_SECONDS: &str =
+    "adbc.snowflake.statement.statement_timeout_in_seconds";
+/// Query tag attached to the statement, visible in the Snowflake query history.
+pub const STATEMENT_QUERY_TAG: &str = "adbc.snowflake.statement.query_tag";
+
+// Keys of the result schema metadata ------------------------------------
+
+/// Snowflake query id of the statement that produced the result.
+pub const RESULT_QUERY_ID: &str = "adbc.snowflake.statement.query_id";
+/// Number of rows inserted, updated or deleted by a DML statement.
+pub const RESULT_ROWS_AFFECTED: &str = "adbc.snowflake.statement.rows_affected";
+
 // Session Param Keys
 // https://docs.snowflake.com/en/sql-reference/parameters
 pub const S3_STAGE_VPCE_DNS_NAME_PARAM_KEY: &str = "s3_stage_vpce_dns_name";
+/// Session query tag. The most specific query tag wins: the request id of a
+/// statement ([crate::QueryCtx::with_request_id], set as [STATEMENT_QUERY_TAG]),
+/// then the top-level `query_tag` of the profile, then `QUERY_TAG` in the
+/// profile's `session_parameters`.
+pub const QUERY_TAG_PARAM_KEY: &str = "query_tag";
 
 pub mod auth_type {
     /// General username password authentication
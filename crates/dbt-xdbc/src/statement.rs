@@ -3,11 +3,13 @@
 //!
 
 use core::fmt;
+use std::collections::BTreeMap;
 use std::sync::Arc;
+use std::time::Duration;
 
 use adbc_core::{
     Optionable, PartitionedResult, Statement as _,
-    error::Result,
+    error::{Error, Result, Status},
     options::{OptionStatement, OptionValue},
 };
 use adbc_driver_manager::ManagedStatement as ManagedAdbcStatement;
@@ -16,7 +18,7 @@ use arrow_schema::Schema;
 
 #[cfg(feature = "odbc")]
 use crate::odbc::ManagedOdbcStatement;
-use crate::{Backend, QueryCtx, semaphore::Semaphore};
+use crate::{Backend, QueryCtx, bigquery, databricks, semaphore::Semaphore, snowflake};
 
 /// XDBC Statement.
 ///
@@ -109,6 +111,28 @@ pub trait Statement: Send {
     /// ADBC API revision 1.1.0
     fn cancel(&mut self) -> Result<()>;
 
+    /// Set a timeout after which the driver cancels the query.
+    ///
+    /// Returns an error if the backend doesn't support query timeouts.
+    fn set_query_timeout(&mut self, _timeout: Duration) -> Result<()> {
+        Err(Error::with_message_and_status(
+            "Query timeouts are not supported by this statement",
+            Status::NotImplemented,
+        ))
+    }
+
+    /// Make `catalog` and `schema` the defaults for unqualified names in
+    /// this statement.
+    ///
+    /// Returns an error if the backend has no notion of a per-statement
+    /// catalog and schema.
+    fn set_catalog_schema(&mut self, _catalog: &str, _schema: &str) -> Result<()> {
+        Err(Error::with_message_and_status(
+            "Setting the catalog and schema is not supported by this statement",
+            Status::NotImplemented,
+        ))
+    }
+
     // adbc_core::Optionable<Option = OptionStatement> functions -----------------------------
 
     /// Set a post-init option.
@@ -144,6 +168,216 @@ impl fmt::Debug for dyn Statement {
     }
 }
 
+/// Get the statement option that makes `backend` cancel a query after `timeout`.
+///
+/// Returns an error if the backend doesn't support query timeouts.
+pub fn query_timeout_option(
+    backend: Backend,
+    timeout: Duration,
+) -> Result<(OptionStatement, OptionValue)> {
+    match backend {
+        Backend::Snowflake => {
+            // Round up so sub-second timeouts don't turn into "no timeout"
+            let secs = timeout.as_secs() + u64::from(timeout.subsec_nanos() > 0);
+            Ok((
+                OptionStatement::Other(snowflake::STATEMENT_TIMEOUT_IN_SECONDS.to_string()),
+                OptionValue::Int(secs as i64),
+            ))
+        }
+        Backend::BigQuery => Ok((
+            OptionStatement::Other(bigquery::QUERY_JOB_TIMEOUT.to_string()),
+            OptionValue::Int(timeout.as_millis() as i64),
+        )),
+        Backend::Postgres
+        | Backend::Databricks
+        | Backend::Redshift
+        | Backend::Salesforce
+        | Backend::DatabricksODBC
+        | Backend::RedshiftODBC
+        | Backend::Generic { .. } => Err(Error::with_message_and_status(
+            format!("Query timeouts are not supported by the {backend} driver"),
+            Status::NotImplemented,
+        )),
+    }
+}
+
+/// Get the statement option that stamps `request_id` on queries run by
+/// `backend`, as a query tag (Snowflake) or job label (BigQuery). On
+/// Snowflake it overrides the query tag of the session, see
+/// [snowflake::QUERY_TAG_PARAM_KEY].
+///
+/// Returns `None` for backends without such an option.
+pub fn request_id_option(
+    backend: Backend,
+    request_id: &str,
+) -> Option<(OptionStatement, OptionValue)> {
+    match backend {
+        Backend::Snowflake => Some((
+            OptionStatement::Other(snowflake::STATEMENT_QUERY_TAG.to_string()),
+            OptionValue::String(request_id.to_string()),
+        )),
+        Backend::BigQuery => {
+            let label = sanitize_bigquery_label_value(request_id);
+            Some((
+                OptionStatement::Other(bigquery::QUERY_LABELS.to_string()),
+                OptionValue::String(format!(
+                    "{{\"{}\":\"{label}\"}}",
+                    bigquery::REQUEST_ID_LABEL
+                )),
+            ))
+        }
+        _ => None,
+    }
+}
+
+/// How a statement makes a catalog and schema the defaults for unqualified
+/// names, see [catalog_schema_context].
+#[derive(Debug)]
+pub enum CatalogSchemaContext {
+    /// Statement options understood by the driver.
+    Options(Vec<(OptionStatement, OptionValue)>),
+    /// `USE` statements to run before the statement, for drivers that have
+    /// no options for it.
+    UseStatements(Vec<String>),
+}
+
+/// Get how `backend` applies `catalog` and `schema` as session defaults for
+/// a single statement.
+///
+/// The Databricks ADBC driver takes them as statement options. The ODBC
+/// driver only accepts them when connecting, so the fallback is to run
+/// `USE CATALOG`/`USE SCHEMA` on the statement before the actual query.
+/// Unlike the options, these change the defaults of the whole session.
+///
+/// Returns an error for backends other than Databricks.
+pub fn catalog_schema_context(
+    backend: Backend,
+    catalog: &str,
+    schema: &str,
+) -> Result<CatalogSchemaContext> {
+    match backend {
+        Backend::Databricks => Ok(CatalogSchemaContext::Options(vec![
+            (
+                OptionStatement::Other(databricks::CATALOG.to_string()),
+                OptionValue::String(catalog.to_string()),
+            ),
+            (
+                OptionStatement::Other(databricks::SCHEMA.to_string()),
+                OptionValue::String(schema.to_string()),
+            ),
+        ])),
+        Backend::DatabricksODBC => {
+            let quote = |ident: &str| format!("`{}`", ident.replace('`', "``"));
+            Ok(CatalogSchemaContext::UseStatements(vec![
+                format!("USE CATALOG {}", quote(catalog)),
+                format!("USE SCHEMA {}", quote(schema)),
+            ]))
+        }
+        Backend::Snowflake
+        | Backend::BigQuery
+        | Backend::Postgres
+        | Backend::Redshift
+        | Backend::Salesforce
+        | Backend::RedshiftODBC
+        | Backend::Generic { .. } => Err(Error::with_message_and_status(
+            format!(
+                "Setting the catalog and schema per statement is not supported by the {backend} driver"
+            ),
+            Status::NotImplemented,
+        )),
+    }
+}
+
+/// Maximum length of BigQuery label keys and values.
+const BIGQUERY_LABEL_MAX_LEN: usize = 63;
+/// Maximum number of labels on a BigQuery job.
+const BIGQUERY_MAX_LABELS: usize = 64;
+
+fn is_bigquery_label_char(c: char) -> bool {
+    c.is_lowercase() || c.is_ascii_digit() || c == '_' || c == '-'
+}
+
+/// Label values may only contain lowercase letters, digits, underscores and
+/// dashes, and are at most 63 characters long.
+fn sanitize_bigquery_label_value(value: &str) -> String {
+    value
+        .chars()
+        .map(|c| match c.to_ascii_lowercase() {
+            c @ ('a'..='z' | '0'..='9' | '_' | '-') => c,
+            _ => '_',
+        })
+        .take(BIGQUERY_LABEL_MAX_LEN)
+        .collect()
+}
+
+/// Check a job label against BigQuery's restrictions [1]: keys start with a
+/// lowercase letter, keys and values contain only lowercase letters, digits,
+/// underscores and dashes, and are at most 63 characters long.
+///
+/// [1] https://cloud.google.com/bigquery/docs/labels-intro#requirements
+pub fn validate_bigquery_label(key: &str, value: &str) -> Result<()> {
+    let invalid = |what: &str| {
+        Err(Error::with_message_and_status(
+            format!("Invalid BigQuery label {key:?}={value:?}: {what}"),
+            Status::InvalidArguments,
+        ))
+    };
+    if !key.chars().next().is_some_and(char::is_lowercase) {
+        return invalid("keys must start with a lowercase letter");
+    }
+    if key.chars().count() > BIGQUERY_LABEL_MAX_LEN
+        || value.chars().count() > BIGQUERY_LABEL_MAX_LEN
+    {
+        return invalid("keys and values must be at most 63 characters long");
+    }
+    if !key.chars().chain(value.chars()).all(is_bigquery_label_char) {
+        return invalid(
+            "keys and values may only contain lowercase letters, digits, underscores and dashes",
+        );
+    }
+    Ok(())
+}
+
+/// Get the statement option that attaches `labels`, plus the request id
+/// label if any, to the BigQuery job running a query.
+///
+/// Returns `None` if there are no labels to attach, and an error if a label
+/// violates BigQuery's restrictions (see [validate_bigquery_label]).
+pub fn bigquery_labels_option(
+    request_id: Option<&str>,
+    labels: &BTreeMap<String, String>,
+) -> Result<Option<(OptionStatement, OptionValue)>> {
+    for (key, value) in labels {
+        validate_bigquery_label(key, value)?;
+    }
+    let mut labels = labels.clone();
+    if let Some(request_id) = request_id {
+        labels.insert(
+            bigquery::REQUEST_ID_LABEL.to_string(),
+            sanitize_bigquery_label_value(request_id),
+        );
+    }
+    if labels.len() > BIGQUERY_MAX_LABELS {
+        return Err(Error::with_message_and_status(
+            format!("A BigQuery job can have at most {BIGQUERY_MAX_LABELS} labels"),
+            Status::InvalidArguments,
+        ));
+    }
+    if labels.is_empty() {
+        return Ok(None);
+    }
+    // Validated labels contain no characters that need escaping in JSON.
+    let json = labels
+        .iter()
+        .map(|(key, value)| format!("\"{key}\":\"{value}\""))
+        .collect::<Vec<_>>()
+        .join(",");
+    Ok(Some((
+        OptionStatement::Other(bigquery::QUERY_LABELS.to_string()),
+        OptionValue::String(format!("{{{json}}}")),
+    )))
+}
+
 /// ADBC Statement.
 #[allow(dead_code)]
 pub(crate) struct AdbcStatement(
@@ -196,6 +430,15 @@ impl Statement for AdbcStatement {
     }
 
     fn set_sql_query(&mut self, query: &QueryCtx) -> Result<()> {
+        let option = match self.0 {
+            Backend::BigQuery => bigquery_labels_option(query.request_id(), query.labels())?,
+            backend => query
+                .request_id()
+                .and_then(|request_id| request_id_option(backend, request_id)),
+        };
+        if let Some((key, value)) = option {
+            self.1.set_option(key, value)?;
+        }
         // Because context might hot have sql (e.g., ingest)
         match query.sql() {
             Some(sql) => self.1.set_sql_query(sql),
@@ -211,6 +454,23 @@ impl Statement for AdbcStatement {
         self.1.cancel()
     }
 
+    fn set_query_timeout(&mut self, timeout: Duration) -> Result<()> {
+        let (key, value) = query_timeout_option(self.0, timeout)?;
+        self.1.set_option(key, value)
+    }
+
+    fn set_catalog_schema(&mut self, catalog: &str, schema: &str) -> Result<()> {
+        match catalog_schema_context(self.0, catalog, schema)? {
+            CatalogSchemaContext::Options(options) => options
+                .into_iter()
+                .try_for_each(|(key, value)| self.1.set_option(key, value)),
+            CatalogSchemaContext::UseStatements(_) => Err(Error::with_message_and_status(
+                format!("The {} ADBC driver has no catalog/schema options", self.0),
+                Status::NotImplemented,
+            )),
+        }
+    }
+
     // adbc_core::Optionable<Option = OptionStatement> functions -----------------------------
 
     fn set_option(&mut self, key: OptionStatement, value: OptionValue) -> Result<()> {
@@ -295,6 +555,24 @@ impl Statement for OdbcStatement {
         self.1.cancel()
     }
 
+    fn set_catalog_schema(&mut self, catalog: &str, schema: &str) -> Result<()> {
+        match catalog_schema_context(self.0, catalog, schema)? {
+            CatalogSchemaContext::Options(_) => Err(Error::with_message_and_status(
+                "ODBC statements don't take driver options",
+                Status::NotImplemented,
+            )),
+            CatalogSchemaContext::UseStatements(statements) => {
+                for sql in statements {
+                    self.1.set_sql_query(&sql)?;
+                    self.1.execute()?;
+                    // Close the (empty) result so the actual query can be set next.
+                    self.1.reset()?;
+                }
+                Ok(())
+            }
+        }
+    }
+
     // adbc_core::Optionable<Option = OptionStatement> functions -----------------------------
 
     fn set_option(&mut self, _key: OptionStatement, _value: OptionValue) -> Result<()> {
@@ -321,3 +599,155 @@ impl Statement for OdbcStatement {
         std::write!(f, "OdbcStatement")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_query_timeout_option_snowflake() {
+        let (key, value) =
+            query_timeout_option(Backend::Snowflake, Duration::from_millis(90_500)).unwrap();
+        assert!(matches!(
+            key,
+            OptionStatement::Other(key) if key == "adbc.snowflake.statement.statement_timeout_in_seconds"
+        ));
+        assert!(matches!(value, OptionValue::Int(91)));
+    }
+
+    #[test]
+    fn test_query_timeout_option_bigquery() {
+        let (key, value) =
+            query_timeout_option(Backend::BigQuery, Duration::from_secs(30)).unwrap();
+        assert!(matches!(
+            key,
+            OptionStatement::Other(key) if key == "adbc.bigquery.sql.query.job_timeout"
+        ));
+        assert!(matches!(value, OptionValue::Int(30_000)));
+    }
+
+    #[test]
+    fn test_query_timeout_option_unsupported() {
+        let err = query_timeout_option(Backend::Postgres, Duration::from_secs(30)).unwrap_err();
+        assert!(matches!(err.status, Status::NotImplemented));
+        assert!(err.message.contains("PostgreSQL"), "{}", err.message);
+    }
+
+    #[test]
+    fn test_request_id_option() {
+        let (key, value) = request_id_option(Backend::Snowflake, "Req-42").unwrap();
+        assert!(matches!(
+            key,
+            OptionStatement::Other(key) if key == "adbc.snowflake.statement.query_tag"
+        ));
+        assert!(matches!(value, OptionValue::String(tag) if tag == "Req-42"));
+
+        let (key, value) = request_id_option(Backend::BigQuery, "Req.42").unwrap();
+        assert!(matches!(
+            key,
+            OptionStatement::Other(key) if key == "adbc.bigquery.sql.query.labels"
+        ));
+        assert!(matches!(
+            value,
+            OptionValue::String(labels) if labels == r#"{"dbt_request_id":"req_42"}"#
+        ));
+
+        assert!(request_id_option(Backend::Postgres, "req-42").is_none());
+    }
+
+    #[test]
+    fn test_bigquery_labels_option() {
+        let labels = BTreeMap::from([
+            ("model".to_string(), "stg_orders".to_string()),
+            ("invocation".to_string(), "".to_string()),
+        ]);
+        let (key, value) = bigquery_labels_option(Some("Req.42"), &labels)
+            .unwrap()
+            .unwrap();
+        assert!(matches!(
+            key,
+            OptionStatement::Other(key) if key == "adbc.bigquery.sql.query.labels"
+        ));
+        assert!(matches!(
+            value,
+            OptionValue::String(labels)
+                if labels == r#"{"dbt_request_id":"req_42","invocation":"","model":"stg_orders"}"#
+        ));
+
+        assert!(
+            bigquery_labels_option(None, &BTreeMap::new())
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_bigquery_labels_option_rejects_invalid_labels() {
+        let invalid = [
+            ("Model", "orders"),  // uppercase key
+            ("1model", "orders"), // key not starting with a letter
+            ("model", "Orders"),  // uppercase value
+            ("model", "a.b"),     // invalid character
+            ("", "orders"),       // empty key
+        ];
+        for (key, value) in invalid {
+            let labels = BTreeMap::from([(key.to_string(), value.to_string())]);
+            let err = bigquery_labels_option(None, &labels).unwrap_err();
+            assert!(
+                matches!(err.status, Status::InvalidArguments),
+                "{key}={value}"
+            );
+        }
+
+        let too_long = BTreeMap::from([("model".to_string(), "x".repeat(64))]);
+        assert!(bigquery_labels_option(None, &too_long).is_err());
+        let max_len = BTreeMap::from([("model".to_string(), "x".repeat(63))]);
+        assert!(bigquery_labels_option(None, &max_len).is_ok());
+    }
+
+    #[test]
+    fn test_catalog_schema_context_databricks() {
+        let context = catalog_schema_context(Backend::Databricks, "main", "analytics").unwrap();
+        let CatalogSchemaContext::Options(options) = context else {
+            panic!("expected statement options, got {context:?}");
+        };
+        let options: Vec<_> = options
+            .into_iter()
+            .map(|(key, value)| match (key, value) {
+                (OptionStatement::Other(key), OptionValue::String(value)) => (key, value),
+                other => panic!("unexpected option {other:?}"),
+            })
+            .collect();
+        assert_eq!(
+            options,
+            vec![
+                ("adbc.databricks.catalog".to_string(), "main".to_string()),
+                (
+                    "adbc.databricks.schema".to_string(),
+                    "analytics".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_catalog_schema_context_databricks_odbc() {
+        let context = catalog_schema_context(Backend::DatabricksODBC, "main", "my`schema").unwrap();
+        let CatalogSchemaContext::UseStatements(statements) = context else {
+            panic!("expected USE statements, got {context:?}");
+        };
+        assert_eq!(
+            statements,
+            vec![
+                "USE CATALOG `main`".to_string(),
+                "USE SCHEMA `my``schema`".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_catalog_schema_context_unsupported() {
+        let err = catalog_schema_context(Backend::Snowflake, "db", "schema").unwrap_err();
+        assert!(matches!(err.status, Status::NotImplemented));
+    }
+}
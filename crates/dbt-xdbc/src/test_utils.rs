@@ -0,0 +1,186 @@
+//! In-memory [Connection] and [Statement] for tests. Only compiled for this
+//! crate's tests or with the `test-util` feature.
+
+use std::collections::{HashSet, VecDeque};
+use std::sync::{Arc, Mutex};
+
+use adbc_core::PartitionedResult;
+use adbc_core::error::{Error, Result, Status};
+use adbc_core::options::{InfoCode, OptionStatement, OptionValue};
+use arrow_array::{RecordBatch, RecordBatchIterator, RecordBatchReader};
+use arrow_schema::Schema;
+
+use crate::{Connection, QueryCtx, Statement};
+
+/// A statement executed through a [MockConnection].
+#[derive(Debug, Clone, PartialEq)]
+pub struct IssuedStatement {
+    pub sql: String,
+    /// String options set on the statement, as `key=value`
+    pub options: Vec<String>,
+}
+
+/// Configurable in-memory [Connection].
+///
+/// Every statement it creates records what it executes and, unless an error
+/// was queued with [MockConnection::with_failures], returns the batches set
+/// with [MockConnection::with_batches] (no batches by default). Clones share
+/// the recorded statements and the queued errors, so a test can keep a clone
+/// to inspect after handing the connection over.
+#[derive(Clone, Default)]
+pub struct MockConnection {
+    issued: Arc<Mutex<Vec<IssuedStatement>>>,
+    failures: Arc<Mutex<VecDeque<Error>>>,
+    batches: Vec<RecordBatch>,
+    broken: bool,
+}
+
+impl MockConnection {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the batches every successful statement returns.
+    pub fn with_batches(mut self, batches: Vec<RecordBatch>) -> Self {
+        self.batches = batches;
+        self
+    }
+
+    /// Queues errors the next executed statements fail with, in order.
+    pub fn with_failures(self, failures: impl IntoIterator<Item = Error>) -> Self {
+        self.failures.lock().unwrap().extend(failures);
+        self
+    }
+
+    /// Makes `get_info` fail, as it does on a connection that was closed by
+    /// the server.
+    pub fn broken(mut self) -> Self {
+        self.broken = true;
+        self
+    }
+
+    /// A statement on this connection.
+    pub fn statement(&self) -> MockStatement {
+        MockStatement {
+            conn: self.clone(),
+            sql: String::new(),
+            options: Vec::new(),
+        }
+    }
+
+    /// The statements executed so far, in order.
+    pub fn issued(&self) -> Vec<IssuedStatement> {
+        self.issued.lock().unwrap().clone()
+    }
+}
+
+impl Connection for MockConnection {
+    fn get_info<'a>(
+        &'a self,
+        _codes: Option<HashSet<InfoCode>>,
+    ) -> Result<Box<dyn RecordBatchReader + Send + 'a>> {
+        if self.broken {
+            return Err(Error::with_message_and_status(
+                "connection is closed",
+                Status::IO,
+            ));
+        }
+        Ok(Box::new(RecordBatchIterator::new(
+            vec![],
+            Arc::new(Schema::empty()),
+        )))
+    }
+    fn new_statement(&mut self) -> Result<Box<dyn Statement>> {
+        Ok(Box::new(self.statement()))
+    }
+    fn cancel(&mut self) -> Result<()> {
+        Ok(())
+    }
+    fn commit(&mut self) -> Result<()> {
+        Ok(())
+    }
+    fn rollback(&mut self) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// Statement created by a [MockConnection].
+pub struct MockStatement {
+    conn: MockConnection,
+    sql: String,
+    options: Vec<String>,
+}
+
+impl MockStatement {
+    /// Records the statement and pops the next queued error, if any.
+    fn issue(&mut self) -> Result<()> {
+        self.conn.issued.lock().unwrap().push(IssuedStatement {
+            sql: self.sql.clone(),
+            options: self.options.clone(),
+        });
+        match self.conn.failures.lock().unwrap().pop_front() {
+            Some(err) => Err(err),
+            None => Ok(()),
+        }
+    }
+}
+
+fn not_supported(method: &str) -> Error {
+    Error::with_message_and_status(
+        format!("{method} is not supported by MockStatement"),
+        Status::NotImplemented,
+    )
+}
+
+impl Statement for MockStatement {
+    fn bind(&mut self, _batch: RecordBatch) -> Result<()> {
+        Err(not_supported("bind"))
+    }
+    fn bind_stream(&mut self, _reader: Box<dyn RecordBatchReader + Send>) -> Result<()> {
+        Err(not_supported("bind_stream"))
+    }
+    fn execute<'a>(&'a mut self) -> Result<Box<dyn RecordBatchReader + Send + 'a>> {
+        self.issue()?;
+        let batches = self.conn.batches.clone();
+        let schema = batches
+            .first()
+            .map(RecordBatch::schema)
+            .unwrap_or_else(|| Arc::new(Schema::empty()));
+        Ok(Box::new(RecordBatchIterator::new(
+            batches.into_iter().map(Ok),
+            schema,
+        )))
+    }
+    fn execute_update(&mut self) -> Result<Option<i64>> {
+        self.issue()?;
+        Ok(None)
+    }
+    fn execute_schema(&mut self) -> Result<Schema> {
+        Err(not_supported("execute_schema"))
+    }
+    fn execute_partitions(&mut self) -> Result<PartitionedResult> {
+        Err(not_supported("execute_partitions"))
+    }
+    fn get_parameter_schema(&self) -> Result<Schema> {
+        Err(not_supported("get_parameter_schema"))
+    }
+    fn prepare(&mut self) -> Result<()> {
+        Err(not_supported("prepare"))
+    }
+    fn set_sql_query(&mut self, query: &QueryCtx) -> Result<()> {
+        self.sql = query.sql().unwrap_or_default();
+        Ok(())
+    }
+    fn set_substrait_plan(&mut self, _plan: &[u8]) -> Result<()> {
+        Err(not_supported("set_substrait_plan"))
+    }
+    fn cancel(&mut self) -> Result<()> {
+        Ok(())
+    }
+    fn set_option(&mut self, key: OptionStatement, value: OptionValue) -> Result<()> {
+        if let (OptionStatement::Other(key), OptionValue::String(value)) = (key, value) {
+            self.options.push(format!("{key}={value}"));
+        }
+        Ok(())
+    }
+}
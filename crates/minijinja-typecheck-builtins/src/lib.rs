@@ -7,33 +7,145 @@ mod assets {
     pub struct Asset;
 }
 
+use std::collections::HashSet;
 use std::sync::{Arc, OnceLock};
 
 use serde::{Deserialize, Serialize};
 
+const DEFINITIONS_SUFFIX: &str = ".sdf.yml";
+
 fn load_definitions() -> Vec<Definition> {
-    let filename = "builtins.sdf.yml".to_string();
-    let asset = assets::Asset::get(filename.as_str());
-    if asset.is_some() {
-        let asset = asset.unwrap();
+    let mut filenames = assets::Asset::iter()
+        .filter(|filename| filename.ends_with(DEFINITIONS_SUFFIX))
+        .collect::<Vec<_>>();
+    // keep the merged order stable regardless of how the assets were embedded
+    filenames.sort();
+
+    let files = filenames.into_iter().map(|filename| {
+        let asset =
+            assets::Asset::get(&filename).unwrap_or_else(|| panic!("{filename}:: missing asset"));
         let input = std::str::from_utf8(&asset.data)
             .unwrap_or_else(|_| panic!("{filename}:: corrupted asset: non UTF-8"));
+        parse_definitions(&filename, input)
+    });
+    merge_definitions(files)
+}
+
+fn parse_definitions(filename: &str, input: &str) -> Vec<Definition> {
+    dbt_serde_yaml::Deserializer::from_str(input)
+        .map(|doc| {
+            Definition::deserialize(doc)
+                .unwrap_or_else(|e| panic!("{filename}:: corrupted asset: bad definition {e}"))
+        })
+        .collect()
+}
+
+fn merge_definitions(files: impl IntoIterator<Item = Vec<Definition>>) -> Vec<Definition> {
+    let mut seen = HashSet::new();
+    let mut definitions = vec![];
+    for definition in files.into_iter().flatten() {
+        if let Some(object) = &definition.object {
+            if !seen.insert(object.id.clone()) {
+                panic!("duplicate builtin object id: {}", object.id);
+            }
+        }
+        definitions.push(definition);
+    }
+    definitions
+}
+
+/// Type names understood by the funcsign parser without a definition.
+const PRIMITIVE_TYPES: &[&str] = &[
+    "string",
+    "integer",
+    "float",
+    "bool",
+    "bytes",
+    "seq",
+    "list",
+    "dict",
+    "tuple",
+    "struct",
+    "optional",
+    "Optional",
+    "none",
+    "any",
+    "ANY",
+    "timestamp",
+];
 
-        dbt_serde_yaml::Deserializer::from_str(input)
-            .map(|doc| {
-                Definition::deserialize(doc)
-                    .unwrap_or_else(|e| panic!("{filename}:: corrupted asset: bad definition {e}"))
-            })
-            .collect()
-    } else {
-        vec![]
+/// Returns the type names referenced by a type expression, skipping struct
+/// field names.
+fn referenced_types(type_: &str) -> Vec<&str> {
+    let is_delimiter = |c: char| c.is_whitespace() || "[]{}(),:|->".contains(c);
+    let mut refs = vec![];
+    let mut rest = type_;
+    while let Some(start) = rest.find(|c| !is_delimiter(c)) {
+        let token_rest = &rest[start..];
+        let end = token_rest.find(is_delimiter).unwrap_or(token_rest.len());
+        let (token, after) = token_rest.split_at(end);
+        if !after.trim_start().starts_with(':') {
+            refs.push(token);
+        }
+        rest = after;
     }
+    refs
+}
+
+/// Checks that every `inherit_from` and alias type refers to a primitive type
+/// or to the id of a loaded definition.
+pub fn validate_definitions(definitions: &[Definition]) -> Result<(), String> {
+    let known_ids = definitions
+        .iter()
+        .filter_map(|definition| {
+            definition
+                .object
+                .as_ref()
+                .map(|object| object.id.as_str())
+                .or_else(|| definition.alias.as_ref().map(|alias| alias.id.as_str()))
+        })
+        .collect::<HashSet<_>>();
+    let is_known = |name: &str| PRIMITIVE_TYPES.contains(&name) || known_ids.contains(name);
+
+    for definition in definitions {
+        if let Some(object) = &definition.object {
+            if let Some(inherit_from) = &object.inherit_from {
+                if let Some(unknown) = referenced_types(inherit_from)
+                    .into_iter()
+                    .find(|&name| !is_known(name))
+                {
+                    return Err(format!(
+                        "object `{}` inherits from unknown type `{unknown}`",
+                        object.id
+                    ));
+                }
+            }
+        }
+        if let Some(alias) = &definition.alias {
+            if let Some(unknown) = referenced_types(&alias.type_)
+                .into_iter()
+                .find(|&name| !is_known(name))
+            {
+                return Err(format!(
+                    "alias `{}` refers to unknown type `{unknown}`",
+                    alias.id
+                ));
+            }
+        }
+    }
+    Ok(())
 }
 
 static DEFINITIONS: OnceLock<Arc<Vec<Definition>>> = OnceLock::new();
 
 pub fn get_definitions() -> Arc<Vec<Definition>> {
-    Arc::clone(DEFINITIONS.get_or_init(|| Arc::new(load_definitions())))
+    Arc::clone(DEFINITIONS.get_or_init(|| {
+        let definitions = load_definitions();
+        if let Err(e) = validate_definitions(&definitions) {
+            panic!("invalid builtin definitions: {e}");
+        }
+        Arc::new(definitions)
+    }))
 }
 
 #[derive(Deserialize, PartialEq, Debug, Clone, Default)]
@@ -83,3 +195,120 @@ pub struct Argument {
     pub type_: String,
     pub is_optional: bool,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FIRST: &str = r#"
+---
+alias:
+  id: TARGET_PACKAGE_NAME
+  type: string
+---
+object:
+  id: adapter
+  attributes:
+    - name: quote
+      type: adapter.quote
+"#;
+
+    const SECOND: &str = r#"
+---
+object:
+  id: adapter.quote
+  call:
+    arguments:
+      - name: identifier
+        type: string
+        is-optional: false
+    return-type: string
+"#;
+
+    #[test]
+    fn test_merge_definitions() {
+        let definitions = merge_definitions([
+            parse_definitions("first.sdf.yml", FIRST),
+            parse_definitions("second.sdf.yml", SECOND),
+        ]);
+        assert_eq!(definitions.len(), 3);
+        assert_eq!(
+            definitions[0].alias.as_ref().unwrap().id,
+            "TARGET_PACKAGE_NAME"
+        );
+        assert_eq!(definitions[1].object.as_ref().unwrap().id, "adapter");
+        assert_eq!(definitions[2].object.as_ref().unwrap().id, "adapter.quote");
+    }
+
+    #[test]
+    #[should_panic(expected = "duplicate builtin object id: adapter")]
+    fn test_merge_definitions_duplicate_id() {
+        merge_definitions([
+            parse_definitions("first.sdf.yml", FIRST),
+            parse_definitions("copy.sdf.yml", FIRST),
+        ]);
+    }
+
+    #[test]
+    fn test_load_embedded_definitions() {
+        assert!(!load_definitions().is_empty());
+    }
+
+    fn object(id: &str, inherit_from: Option<&str>) -> Definition {
+        Definition {
+            object: Some(Object {
+                id: id.to_string(),
+                inherit_from: inherit_from.map(str::to_string),
+                ..Default::default()
+            }),
+            alias: None,
+        }
+    }
+
+    fn alias(id: &str, type_: &str) -> Definition {
+        Definition {
+            object: None,
+            alias: Some(Alias {
+                id: id.to_string(),
+                type_: type_.to_string(),
+            }),
+        }
+    }
+
+    #[test]
+    fn test_validate_embedded_definitions() {
+        assert_eq!(validate_definitions(&load_definitions()), Ok(()));
+    }
+
+    #[test]
+    fn test_validate_inheritance_chain() {
+        let definitions = vec![
+            object("base", Some("struct{name: string, tags: list[tag]}")),
+            alias("tag", "string"),
+            object("relation", Some("base")),
+            object("snowflake_relation", Some("relation")),
+        ];
+        assert_eq!(validate_definitions(&definitions), Ok(()));
+    }
+
+    #[test]
+    fn test_validate_dangling_inherit_from() {
+        let definitions = vec![object("relation", Some("missing_base"))];
+        assert_eq!(
+            validate_definitions(&definitions),
+            Err("object `relation` inherits from unknown type `missing_base`".to_string())
+        );
+    }
+
+    #[test]
+    fn test_validate_dangling_alias_type() {
+        let definitions = vec![
+            object("relation", None),
+            alias("relations", "list[relation] | dict[string, missing]"),
+        ];
+        assert_eq!(
+            validate_definitions(&definitions),
+            Err("alias `relations` refers to unknown type `missing`".to_string())
+        );
+    }
+}
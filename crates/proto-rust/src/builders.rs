@@ -0,0 +1,132 @@
+//! Hand-written builders for generated messages that are emitted often.
+
+use std::fmt;
+use std::time::Duration;
+
+use crate::v1::public::fields::adapter_types::{AdapterNodeInfo, SqlQueryStatus};
+
+/// A required field was left empty when building a message
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MissingFieldError {
+    pub message: &'static str,
+    pub field: &'static str,
+}
+
+impl fmt::Display for MissingFieldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} requires a non-empty `{}`", self.message, self.field)
+    }
+}
+
+impl std::error::Error for MissingFieldError {}
+
+/// Builder for [SqlQueryStatus].
+///
+/// `status` and `query_id` are taken by [SqlQueryStatusBuilder::new]. `status`
+/// must be non-empty, while an empty `query_id` means the driver reported none
+/// and is kept as is. `elapsed` defaults to zero and `node_info` to unset.
+#[derive(Debug, Clone)]
+pub struct SqlQueryStatusBuilder {
+    status: String,
+    query_id: String,
+    elapsed: Duration,
+    node_info: Option<AdapterNodeInfo>,
+}
+
+impl SqlQueryStatusBuilder {
+    pub fn new(status: impl Into<String>, query_id: impl Into<String>) -> Self {
+        Self {
+            status: status.into(),
+            query_id: query_id.into(),
+            elapsed: Duration::ZERO,
+            node_info: None,
+        }
+    }
+
+    pub fn elapsed(mut self, elapsed: Duration) -> Self {
+        self.elapsed = elapsed;
+        self
+    }
+
+    pub fn node_info(mut self, node_info: AdapterNodeInfo) -> Self {
+        self.node_info = Some(node_info);
+        self
+    }
+
+    pub fn build(self) -> Result<SqlQueryStatus, MissingFieldError> {
+        let missing = |field| MissingFieldError {
+            message: "SQLQueryStatus",
+            field,
+        };
+        if self.status.is_empty() {
+            return Err(missing("status"));
+        }
+        Ok(SqlQueryStatus {
+            node_info: self.node_info,
+            status: self.status,
+            elapsed: self.elapsed.as_secs_f32(),
+            query_id: self.query_id,
+        })
+    }
+}
+
+impl SqlQueryStatus {
+    pub fn builder(
+        status: impl Into<String>,
+        query_id: impl Into<String>,
+    ) -> SqlQueryStatusBuilder {
+        SqlQueryStatusBuilder::new(status, query_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use prost::Message;
+
+    #[test]
+    fn test_sql_query_status_round_trip() {
+        let node_info = AdapterNodeInfo {
+            unique_id: "model.jaffle_shop.orders".to_string(),
+            node_name: "orders".to_string(),
+            ..Default::default()
+        };
+        let status = SqlQueryStatus::builder("SUCCESS 1", "01b2c3d4")
+            .elapsed(Duration::from_millis(1500))
+            .node_info(node_info.clone())
+            .build()
+            .unwrap();
+
+        let decoded = SqlQueryStatus::decode(status.encode_to_vec().as_slice()).unwrap();
+        assert_eq!(decoded, status);
+        assert_eq!(decoded.status, "SUCCESS 1");
+        assert_eq!(decoded.query_id, "01b2c3d4");
+        assert_eq!(decoded.elapsed, 1.5);
+        assert_eq!(decoded.node_info, Some(node_info));
+    }
+
+    #[test]
+    fn test_sql_query_status_defaults() {
+        let status = SqlQueryStatus::builder("OK", "q1").build().unwrap();
+        assert_eq!(status.elapsed, 0.0);
+        assert_eq!(status.node_info, None);
+    }
+
+    #[test]
+    fn test_sql_query_status_requires_status() {
+        assert_eq!(
+            SqlQueryStatus::builder("", "q1").build(),
+            Err(MissingFieldError {
+                message: "SQLQueryStatus",
+                field: "status",
+            })
+        );
+    }
+
+    #[test]
+    fn test_sql_query_status_without_query_id() {
+        let status = SqlQueryStatus::builder("OK", "").build().unwrap();
+        let decoded = SqlQueryStatus::decode(status.encode_to_vec().as_slice()).unwrap();
+        assert_eq!(decoded.query_id, "");
+    }
+}
@@ -24,17 +24,39 @@ pub mod v1 {
     }
 }
 
+pub mod builders;
+
+use std::sync::OnceLock;
+
+use prost::Name;
+use prost_reflect::{DescriptorPool, MessageDescriptor};
+
+// We only enable reflection for the public protobuf events.
+static PUBLIC_DESCRIPTOR_POOL: &[u8] = include_bytes!("gen/dbtlabs_proto.bin");
+
+/// The descriptor pool of the public protobuf events, decoded on first use
+pub fn descriptor_pool() -> &'static DescriptorPool {
+    static POOL: OnceLock<DescriptorPool> = OnceLock::new();
+    POOL.get_or_init(|| {
+        DescriptorPool::decode(PUBLIC_DESCRIPTOR_POOL)
+            .expect("embedded descriptor pool must be valid")
+    })
+}
+
+/// The reflection descriptor of message `T`, if it is part of the public
+/// descriptor pool
+pub fn message_descriptor_for<T: Name>() -> Option<MessageDescriptor> {
+    descriptor_pool().get_message_by_name(&T::full_name())
+}
+
 #[cfg(test)]
 mod tests {
-    use prost::Name;
-
-    // We only enable reflection for the public protobuf events.
-    static PUBLIC_DESCRIPTOR_POOL: &[u8] = include_bytes!("gen/dbtlabs_proto.bin");
+    use super::*;
 
     #[test]
     /// Test for presence of adapter events
     fn test_adapter_events() {
-        let pool = prost_reflect::DescriptorPool::decode(PUBLIC_DESCRIPTOR_POOL).unwrap();
+        let pool = descriptor_pool();
 
         let file_descriptor = pool.get_message_by_name(
             crate::v1::public::fields::adapter_types::SqlQueryStatus::type_url()
@@ -52,4 +74,20 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_message_descriptor_for_sql_query_status() {
+        let descriptor =
+            message_descriptor_for::<crate::v1::public::fields::adapter_types::SqlQueryStatus>()
+                .expect("SQLQueryStatus must be in the descriptor pool");
+
+        let field_names = descriptor
+            .fields()
+            .map(|field| field.name().to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            field_names,
+            vec!["node_info", "status", "elapsed", "query_id"]
+        );
+    }
 }